@@ -31,6 +31,13 @@ fn bench_decode(c: &mut Criterion) {
             b.iter(|| c32::decode_into(black_box(en_bytes), &mut dst).unwrap());
         });
 
+        // [`c32::decode_unchecked_into`]
+        group.bench_function(f!("decode_unchecked_into_{sample}"), |b| {
+            let capacity = c32::decoded_len(en_bytes.len());
+            let mut dst = vec![0u8; capacity];
+            b.iter(|| c32::decode_unchecked_into(black_box(en_bytes), &mut dst).unwrap());
+        });
+
         // [`c32::decode`]
         group.bench_function(f!("decode_{sample}"), |b| {
             b.iter(|| c32::decode(black_box(&en)).unwrap());
@@ -40,6 +47,61 @@ fn bench_decode(c: &mut Criterion) {
     group.finish();
 }
 
+/// A benchmark for rejecting invalid inputs.
+fn bench_decode_invalid(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode_invalid");
+
+    let garbage = [
+        ("html", "<!DOCTYPE html><html><body><p>Not Found</p></body></html>\n"),
+        ("json", "{\"error\":\"not found\",\"status\":404,\"retry\":false}\n"),
+        ("utf8", "ünïcödé gärbägé ünïcödé gärbägé ünïcödé gärbägé\n"),
+    ];
+
+    for (kind, chunk) in garbage {
+        for (size, len) in [("1kb", 1 << 10), ("64kb", 1 << 16), ("1mb", 1 << 20)] {
+            let input = chunk.repeat(len / chunk.len() + 1);
+
+            // [`c32::decode`]
+            group.bench_function(f!("decode_{kind}_{size}"), |b| {
+                b.iter(|| c32::decode(black_box(&input)).unwrap_err());
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// A benchmark for inline-capacity decoding functions.
+fn bench_decode_small(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode_small");
+
+    // Short identifiers, split from the 32-byte multi-sample.
+    let encoded: Vec<String> = samples::M_100X32B
+        .chunks(33)
+        .map(|line| c32::encode(&line[..32]))
+        .collect();
+
+    // [`c32::decode`]
+    group.bench_function("decode_m_100x32b", |b| {
+        b.iter(|| {
+            for en in &encoded {
+                black_box(c32::decode(black_box(en)).unwrap());
+            }
+        });
+    });
+
+    // [`c32::decode_small`]
+    group.bench_function("decode_small_m_100x32b", |b| {
+        b.iter(|| {
+            for en in &encoded {
+                black_box(c32::decode_small::<32>(black_box(en)).unwrap());
+            }
+        });
+    });
+
+    group.finish();
+}
+
 /// A benchmark for checksum decoding functions.
 fn bench_decode_check(c: &mut Criterion) {
     let mut group = c.benchmark_group("decode_check");
@@ -64,6 +126,42 @@ fn bench_decode_check(c: &mut Criterion) {
     group.finish();
 }
 
+/// A benchmark for bucketing checksum records by version.
+fn bench_peek_version(c: &mut Criterion) {
+    let mut group = c.benchmark_group("peek_version");
+
+    // Check-encoded records, split from the 32-byte multi-sample.
+    let records: Vec<String> = samples::M_100X32B
+        .chunks(33)
+        .enumerate()
+        .map(|(i, line)| c32::encode_check(&line[..32], (i % 32) as u8).unwrap())
+        .collect();
+
+    // [`c32::peek_version`]
+    group.bench_function("peek_version_m_100x32b", |b| {
+        b.iter(|| {
+            let mut buckets = [0usize; 32];
+            for en in &records {
+                buckets[c32::peek_version(black_box(en)).unwrap() as usize] += 1;
+            }
+            black_box(buckets)
+        });
+    });
+
+    // [`c32::decode_check`]
+    group.bench_function("decode_check_m_100x32b", |b| {
+        b.iter(|| {
+            let mut buckets = [0usize; 32];
+            for en in &records {
+                buckets[c32::decode_check(black_box(en)).unwrap().1 as usize] += 1;
+            }
+            black_box(buckets)
+        });
+    });
+
+    group.finish();
+}
+
 /// A benchmark for prefixed decoding functions.
 fn bench_decode_prefixed(c: &mut Criterion) {
     let mut group = c.benchmark_group("decode_prefixed");
@@ -99,7 +197,10 @@ fn bench_decode_check_prefixed(c: &mut Criterion) {
 criterion_group!(
     benches,
     bench_decode,
+    bench_decode_invalid,
+    bench_decode_small,
     bench_decode_check,
+    bench_peek_version,
     bench_decode_prefixed,
     bench_decode_check_prefixed
 );
@@ -5,8 +5,7 @@
 //
 // Usage of this file is permitted solely under a sanctioned license.
 
-use c32::en::Check;
-use c32::Buffer;
+use c32::buffer_for;
 use criterion::black_box;
 use criterion::criterion_group;
 use criterion::criterion_main;
@@ -20,20 +19,22 @@ fn bench_decode(c: &mut Criterion) {
     let mut group = c.benchmark_group("decode_const");
 
     macro_rules! bench {
-        ($name:expr, $n:expr, $sample:expr) => {
+        ($name:expr, $sample:expr) => {
             let en = c32::encode(*$sample);
             let en_bytes = en.as_bytes();
             group.bench_function(f!("decode_const_{}", $name), |b| {
-                b.iter(|| Buffer::<$n>::decode(black_box(en_bytes)));
+                b.iter(|| {
+                    <buffer_for!($sample.len())>::decode(black_box(en_bytes))
+                });
             });
         };
     }
 
-    bench!("m_100x32b", 5280, samples::M_100X32B);
-    bench!("m_100x64b", 10400, samples::M_100X64B);
-    bench!("m_100x128b", 20640, samples::M_100X128B);
-    bench!("m_100x256b", 41120, samples::M_100X256B);
-    bench!("m_100x512b", 82080, samples::M_100X512B);
+    bench!("m_100x32b", samples::M_100X32B);
+    bench!("m_100x64b", samples::M_100X64B);
+    bench!("m_100x128b", samples::M_100X128B);
+    bench!("m_100x256b", samples::M_100X256B);
+    bench!("m_100x512b", samples::M_100X512B);
 
     group.finish();
 }
@@ -43,22 +44,24 @@ fn bench_decode_check(c: &mut Criterion) {
     let mut group = c.benchmark_group("decode_check_const");
 
     macro_rules! bench {
-        ($name:expr, $n:expr, $sample:expr) => {
+        ($name:expr, $sample:expr) => {
             let en = c32::encode_check(*$sample, 0).unwrap();
             let en_bytes = en.as_bytes();
             group.bench_function(f!("decode_check_const_{}", $name), |b| {
                 b.iter(|| {
-                    Buffer::<$n, false, Check>::decode(black_box(en_bytes))
+                    <buffer_for!(check, $sample.len())>::decode(black_box(
+                        en_bytes,
+                    ))
                 });
             });
         };
     }
 
-    bench!("m_100x32b", 5288, samples::M_100X32B);
-    bench!("m_100x64b", 10408, samples::M_100X64B);
-    bench!("m_100x128b", 20648, samples::M_100X128B);
-    bench!("m_100x256b", 41128, samples::M_100X256B);
-    bench!("m_100x512b", 82088, samples::M_100X512B);
+    bench!("m_100x32b", samples::M_100X32B);
+    bench!("m_100x64b", samples::M_100X64B);
+    bench!("m_100x128b", samples::M_100X128B);
+    bench!("m_100x256b", samples::M_100X256B);
+    bench!("m_100x512b", samples::M_100X512B);
 
     group.finish();
 }
@@ -68,20 +71,25 @@ fn bench_decode_prefixed(c: &mut Criterion) {
     let mut group = c.benchmark_group("decode_prefixed_const");
 
     macro_rules! bench {
-        ($name:expr, $n:expr, $sample:expr) => {
+        ($name:expr, $sample:expr) => {
             let en = c32::encode_prefixed(*$sample, 'S');
             let en_bytes = en.as_bytes();
             group.bench_function(f!("decode_prefixed_const_{}", $name), |b| {
-                b.iter(|| Buffer::<$n, true>::decode(black_box(en_bytes), 'S'));
+                b.iter(|| {
+                    <buffer_for!(prefixed, $sample.len())>::decode(
+                        black_box(en_bytes),
+                        'S',
+                    )
+                });
             });
         };
     }
 
-    bench!("m_100x32b", 5281, samples::M_100X32B);
-    bench!("m_100x64b", 10401, samples::M_100X64B);
-    bench!("m_100x128b", 20641, samples::M_100X128B);
-    bench!("m_100x256b", 41121, samples::M_100X256B);
-    bench!("m_100x512b", 82081, samples::M_100X512B);
+    bench!("m_100x32b", samples::M_100X32B);
+    bench!("m_100x64b", samples::M_100X64B);
+    bench!("m_100x128b", samples::M_100X128B);
+    bench!("m_100x256b", samples::M_100X256B);
+    bench!("m_100x512b", samples::M_100X512B);
 
     group.finish();
 }
@@ -91,14 +99,14 @@ fn bench_decode_check_prefixed(c: &mut Criterion) {
     let mut group = c.benchmark_group("decode_check_prefixed_const");
 
     macro_rules! bench {
-        ($name:expr, $n:expr, $sample:expr) => {
+        ($name:expr, $sample:expr) => {
             let en = c32::encode_check_prefixed(*$sample, 'S', 0).unwrap();
             let en_bytes = en.as_bytes();
             group.bench_function(
                 f!("decode_check_prefixed_const_{}", $name),
                 |b| {
                     b.iter(|| {
-                        Buffer::<$n, true, Check>::decode(
+                        <buffer_for!(check, prefixed, $sample.len())>::decode(
                             black_box(en_bytes),
                             'S',
                         )
@@ -108,11 +116,11 @@ fn bench_decode_check_prefixed(c: &mut Criterion) {
         };
     }
 
-    bench!("m_100x32b", 5289, samples::M_100X32B);
-    bench!("m_100x64b", 10409, samples::M_100X64B);
-    bench!("m_100x128b", 20649, samples::M_100X128B);
-    bench!("m_100x256b", 41129, samples::M_100X256B);
-    bench!("m_100x512b", 82089, samples::M_100X512B);
+    bench!("m_100x32b", samples::M_100X32B);
+    bench!("m_100x64b", samples::M_100X64B);
+    bench!("m_100x128b", samples::M_100X128B);
+    bench!("m_100x256b", samples::M_100X256B);
+    bench!("m_100x512b", samples::M_100X512B);
 
     group.finish();
 }
@@ -25,7 +25,7 @@ fn bench_encode(c: &mut Criterion) {
         group.bench_function(f!("encode_into_{sample}"), |b| {
             let capacity = c32::encoded_len(bytes.len());
             let mut dst = vec![0u8; capacity];
-            b.iter(|| c32::encode_into(black_box(&bytes), &mut dst).unwrap());
+            b.iter(|| c32::encode_into(black_box(bytes), &mut dst).unwrap());
         });
 
         // [`c32::encode`]
@@ -46,7 +46,7 @@ fn bench_encode_check(c: &mut Criterion) {
         group.bench_function(f!("encode_check_into_{sample}"), |b| {
             let capacity = c32::encoded_check_len(bytes.len());
             let mut dst = vec![0u8; capacity];
-            b.iter(|| c32::encode_check_into(black_box(&bytes), &mut dst, 0).unwrap());
+            b.iter(|| c32::encode_check_into(black_box(bytes), &mut dst, 0).unwrap());
         });
 
         // [`c32::encode_check`]
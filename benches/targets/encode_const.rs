@@ -6,8 +6,7 @@
 //
 // Usage of this file is permitted solely under a sanctioned license.
 
-use c32::en::Check;
-use c32::Buffer;
+use c32::buffer_for;
 use criterion::black_box;
 use criterion::criterion_group;
 use criterion::criterion_main;
@@ -21,18 +20,20 @@ fn bench_encode(c: &mut Criterion) {
     let mut group = c.benchmark_group("encode_const");
 
     macro_rules! bench {
-        ($name:expr, $n:expr, $sample:expr) => {
+        ($name:expr, $sample:expr) => {
             group.bench_function(f!("encode_const_{}", $name), |b| {
-                b.iter(|| Buffer::<$n>::encode(black_box($sample)));
+                b.iter(|| {
+                    <buffer_for!($sample.len())>::encode(black_box($sample))
+                });
             });
         };
     }
 
-    bench!("m_100x32b", 5280, samples::M_100X32B);
-    bench!("m_100x64b", 10400, samples::M_100X64B);
-    bench!("m_100x128b", 20640, samples::M_100X128B);
-    bench!("m_100x256b", 41120, samples::M_100X256B);
-    bench!("m_100x512b", 82080, samples::M_100X512B);
+    bench!("m_100x32b", samples::M_100X32B);
+    bench!("m_100x64b", samples::M_100X64B);
+    bench!("m_100x128b", samples::M_100X128B);
+    bench!("m_100x256b", samples::M_100X256B);
+    bench!("m_100x512b", samples::M_100X512B);
 
     group.finish();
 }
@@ -42,20 +43,23 @@ fn bench_encode_check(c: &mut Criterion) {
     let mut group = c.benchmark_group("encode_check_const");
 
     macro_rules! bench {
-        ($name:expr, $n:expr, $sample:expr) => {
+        ($name:expr, $sample:expr) => {
             group.bench_function(f!("encode_check_const_{}", $name), |b| {
                 b.iter(|| {
-                    Buffer::<$n, false, Check>::encode(black_box($sample), 0)
+                    <buffer_for!(check, $sample.len())>::encode(
+                        black_box($sample),
+                        0,
+                    )
                 });
             });
         };
     }
 
-    bench!("m_100x32b", 5288, samples::M_100X32B);
-    bench!("m_100x64b", 10408, samples::M_100X64B);
-    bench!("m_100x128b", 20648, samples::M_100X128B);
-    bench!("m_100x256b", 41128, samples::M_100X256B);
-    bench!("m_100x512b", 82088, samples::M_100X512B);
+    bench!("m_100x32b", samples::M_100X32B);
+    bench!("m_100x64b", samples::M_100X64B);
+    bench!("m_100x128b", samples::M_100X128B);
+    bench!("m_100x256b", samples::M_100X256B);
+    bench!("m_100x512b", samples::M_100X512B);
 
     group.finish();
 }
@@ -65,18 +69,23 @@ fn bench_encode_prefixed(c: &mut Criterion) {
     let mut group = c.benchmark_group("encode_prefixed_const");
 
     macro_rules! bench {
-        ($name:expr, $n:expr, $sample:expr) => {
+        ($name:expr, $sample:expr) => {
             group.bench_function(f!("encode_prefixed_const_{}", $name), |b| {
-                b.iter(|| Buffer::<$n, true>::encode(black_box($sample), 'S'));
+                b.iter(|| {
+                    <buffer_for!(prefixed, $sample.len())>::encode(
+                        black_box($sample),
+                        'S',
+                    )
+                });
             });
         };
     }
 
-    bench!("m_100x32b", 5281, samples::M_100X32B);
-    bench!("m_100x64b", 10401, samples::M_100X64B);
-    bench!("m_100x128b", 20641, samples::M_100X128B);
-    bench!("m_100x256b", 41121, samples::M_100X256B);
-    bench!("m_100x512b", 82081, samples::M_100X512B);
+    bench!("m_100x32b", samples::M_100X32B);
+    bench!("m_100x64b", samples::M_100X64B);
+    bench!("m_100x128b", samples::M_100X128B);
+    bench!("m_100x256b", samples::M_100X256B);
+    bench!("m_100x512b", samples::M_100X512B);
 
     group.finish();
 }
@@ -86,12 +95,12 @@ fn bench_encode_check_prefixed(c: &mut Criterion) {
     let mut group = c.benchmark_group("encode_check_prefixed_const");
 
     macro_rules! bench {
-        ($name:expr, $n:expr, $sample:expr) => {
+        ($name:expr, $sample:expr) => {
             group.bench_function(
                 f!("encode_check_prefixed_const_{}", $name),
                 |b| {
                     b.iter(|| {
-                        Buffer::<$n, true, Check>::encode(
+                        <buffer_for!(check, prefixed, $sample.len())>::encode(
                             black_box($sample),
                             'S',
                             0,
@@ -102,11 +111,11 @@ fn bench_encode_check_prefixed(c: &mut Criterion) {
         };
     }
 
-    bench!("m_100x32b", 5289, samples::M_100X32B);
-    bench!("m_100x64b", 10409, samples::M_100X64B);
-    bench!("m_100x128b", 20649, samples::M_100X128B);
-    bench!("m_100x256b", 41129, samples::M_100X256B);
-    bench!("m_100x512b", 82089, samples::M_100X512B);
+    bench!("m_100x32b", samples::M_100X32B);
+    bench!("m_100x64b", samples::M_100X64B);
+    bench!("m_100x128b", samples::M_100X128B);
+    bench!("m_100x256b", samples::M_100X256B);
+    bench!("m_100x512b", samples::M_100X512B);
 
     group.finish();
 }
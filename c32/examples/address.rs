@@ -0,0 +1,56 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+//! Parses a Stacks-style address argument with the `clap` value parsers.
+//!
+//! ```text
+//! cargo run --example address --features clap,check -- SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7
+//! ```
+//!
+//! The address must start with `S` and be encoded with version 22, i.e.
+//! a mainnet single-signature address, and an invalid address is reported
+//! with a caret under the offending character.
+
+use c32::arg::C32CheckValueParser;
+use c32::arg::C32ValueParser;
+use clap::Arg;
+use clap::Command;
+
+fn main() {
+    let matches = Command::new("address")
+        .about("Decodes a Stacks-style c32check address")
+        .arg(
+            Arg::new("address")
+                .required(true)
+                .help("The address, e.g. SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7")
+                .value_parser(
+                    C32CheckValueParser::new().prefix('S').expect_version(22),
+                ),
+        )
+        .arg(
+            Arg::new("memo")
+                .long("memo")
+                .help("An optional Crockford Base32 memo")
+                .value_parser(C32ValueParser::new()),
+        )
+        .get_matches();
+
+    let (bytes, version) = matches
+        .get_one::<(Vec<u8>, u8)>("address")
+        .expect("the address is required");
+    println!("{version} {}", hex(bytes));
+
+    if let Some(memo) = matches.get_one::<Vec<u8>>("memo") {
+        println!("memo {}", hex(memo));
+    }
+}
+
+/// Formats bytes as lowercase hex.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
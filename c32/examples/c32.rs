@@ -0,0 +1,286 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+//! A minimal command-line tool built on the `alloc` and `check` APIs.
+//!
+//! ```text
+//! cargo run --example c32 --features std,check -- encode 2a2a2a
+//! cargo run --example c32 --features std,check -- check-decode --prefix S SPAHA58QT2DJ9
+//! ```
+//!
+//! # Usage
+//!
+//! ```text
+//! c32 encode       [--prefix C] [--lower] [HEX]
+//! c32 decode       [--prefix C] [TEXT]
+//! c32 check-encode [--prefix C] [--lower] --version N [HEX]
+//! c32 check-decode [--prefix C] [TEXT]
+//! ```
+//!
+//! The input is read from the last argument, or from stdin without trailing
+//! whitespace. Bytes are written and read as hex, and `check-decode` writes
+//! the version before the payload, e.g. `22 2a2a2a`.
+//!
+//! # Exit Codes
+//!
+//! - `0`, the command succeeded.
+//! - `1`, reading or writing failed.
+//! - `2`, the arguments are invalid.
+//! - `10 + code`, decoding or encoding failed, where `code` is the stable
+//!   [`Error::code`] of the error, e.g. `13` for an invalid character.
+
+use std::env;
+use std::fmt;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::process::ExitCode;
+
+use c32::Error;
+
+/// The command to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Encode,
+    Decode,
+    CheckEncode,
+    CheckDecode,
+}
+
+/// The parsed command-line arguments.
+#[derive(Debug)]
+struct Args {
+    command: Command,
+    prefix: Option<char>,
+    lower: bool,
+    version: Option<u8>,
+    input: Option<String>,
+}
+
+/// The failure of a command.
+#[derive(Debug)]
+enum Failure {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// The arguments are invalid.
+    Usage(String),
+    /// Encoding or decoding the input failed.
+    Codec(Error),
+}
+
+impl Failure {
+    /// Returns the exit code of the failure.
+    fn code(&self) -> u8 {
+        match self {
+            Self::Io(_) => 1,
+            Self::Usage(_) => 2,
+            // The codes are stable, and fit into an exit code.
+            Self::Codec(err) => 10 + err.code() as u8,
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Usage(msg) => write!(f, "{msg}"),
+            Self::Codec(err) => write!(f, "{err} [{}]", err.name()),
+        }
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<Error> for Failure {
+    fn from(err: Error) -> Self {
+        Self::Codec(err)
+    }
+}
+
+/// Parses the command-line arguments.
+fn parse(mut args: impl Iterator<Item = String>) -> Result<Args, Failure> {
+    let usage = |msg: &str| Failure::Usage(msg.to_string());
+
+    let command = match args.next().as_deref() {
+        Some("encode") => Command::Encode,
+        Some("decode") => Command::Decode,
+        Some("check-encode") => Command::CheckEncode,
+        Some("check-decode") => Command::CheckDecode,
+        Some(other) => {
+            return Err(usage(&format!("unknown command '{other}'")))
+        }
+        None => return Err(usage("missing command")),
+    };
+
+    let mut parsed = Args {
+        command,
+        prefix: None,
+        lower: false,
+        version: None,
+        input: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--prefix" => {
+                let value = args.next().unwrap_or_default();
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(prefix), None) => parsed.prefix = Some(prefix),
+                    _ => return Err(usage("--prefix takes one character")),
+                }
+            }
+            "--version" => {
+                let value = args.next().unwrap_or_default();
+                let version =
+                    value.parse().map_err(|_| usage("bad --version"))?;
+                parsed.version = Some(version);
+            }
+            "--lower" => parsed.lower = true,
+            _ if parsed.input.is_none() => parsed.input = Some(arg),
+            _ => return Err(usage(&format!("unexpected argument '{arg}'"))),
+        }
+    }
+
+    match parsed.command {
+        Command::CheckEncode if parsed.version.is_none() => {
+            Err(usage("check-encode requires --version"))
+        }
+        Command::Encode | Command::CheckEncode => Ok(parsed),
+        _ if parsed.lower || parsed.version.is_some() => {
+            Err(usage("--lower and --version only apply to encoding"))
+        }
+        _ => Ok(parsed),
+    }
+}
+
+/// Parses a hex string into bytes.
+fn from_hex(hex: &str) -> Result<Vec<u8>, Failure> {
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return Err(Failure::Usage(format!("invalid hex '{hex}'")));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+        .collect::<Result<_, _>>()
+        .map_err(|_| Failure::Usage(format!("invalid hex '{hex}'")))
+}
+
+/// Formats bytes as a hex string.
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Runs the command on `input`, returning the line to write.
+fn run(args: &Args, input: &str) -> Result<String, Failure> {
+    let line = match (args.command, args.prefix) {
+        (Command::Encode, prefix) => {
+            let bytes = from_hex(input)?;
+            let en = match prefix {
+                Some(prefix) => c32::encode_prefixed(bytes, prefix),
+                None => c32::encode(bytes),
+            };
+            lower(en, prefix, args.lower)
+        }
+        (Command::CheckEncode, prefix) => {
+            let bytes = from_hex(input)?;
+            let version = args.version.unwrap_or_default();
+            let en = match prefix {
+                Some(prefix) => {
+                    c32::encode_check_prefixed(bytes, prefix, version)?
+                }
+                None => c32::encode_check(bytes, version)?,
+            };
+            lower(en, prefix, args.lower)
+        }
+        (Command::Decode, Some(prefix)) => {
+            to_hex(&c32::decode_prefixed(input, prefix)?)
+        }
+        (Command::Decode, None) => to_hex(&c32::decode(input)?),
+        (Command::CheckDecode, prefix) => {
+            let (bytes, version) = match prefix {
+                Some(prefix) => c32::decode_check_prefixed(input, prefix)?,
+                None => c32::decode_check(input)?,
+            };
+            format!("{version} {}", to_hex(&bytes))
+        }
+    };
+    Ok(line)
+}
+
+/// Lowercases the encoded characters after the prefix, if enabled.
+fn lower(mut en: String, prefix: Option<char>, enabled: bool) -> String {
+    if enabled {
+        let start = prefix.map_or(0, char::len_utf8);
+        en[start..].make_ascii_lowercase();
+    }
+    en
+}
+
+/// Renders the input with a caret under the character at byte `index`.
+fn caret(input: &str, index: usize) -> Option<String> {
+    let column = input.get(..index)?.chars().count();
+    Some(format!("    {input}\n    {:>1$}", "^", column + 1))
+}
+
+/// Returns the byte index of the offending character of an error.
+fn position(err: &Error) -> Option<usize> {
+    match err {
+        Error::InvalidCharacter { index, .. } => Some(*index),
+        Error::InvalidPadding { index } => Some(*index),
+        Error::MissingPrefix { .. } => Some(0),
+        _ => None,
+    }
+}
+
+/// Reads the input from the arguments, or from stdin.
+fn read_input(args: &Args) -> io::Result<String> {
+    if let Some(input) = &args.input {
+        return Ok(input.clone());
+    }
+    let mut buf = String::new();
+    io::stdin().read_to_string(&mut buf)?;
+    Ok(buf.trim_end().to_string())
+}
+
+/// Writes the failure to stderr, and returns its exit code.
+fn report(failure: &Failure, input: &str) -> ExitCode {
+    eprintln!("c32: {failure}");
+    if let Failure::Codec(err) = failure {
+        if let Some(rendered) = position(err).and_then(|i| caret(input, i)) {
+            eprintln!("{rendered}");
+        }
+    }
+    ExitCode::from(failure.code())
+}
+
+fn main() -> ExitCode {
+    let args = match parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(failure) => return report(&failure, ""),
+    };
+
+    let input = match read_input(&args) {
+        Ok(input) => input,
+        Err(err) => return report(&Failure::Io(err), ""),
+    };
+
+    let result = run(&args, &input).and_then(|line| {
+        writeln!(io::stdout(), "{line}")?;
+        Ok(())
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => report(&failure, &input),
+    }
+}
@@ -0,0 +1,42 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+//! Generates random identifiers with 128 bits of entropy.
+//!
+//! ```text
+//! cargo run --example id_gen --features std,rand -- 3
+//! ```
+//!
+//! Every identifier is decoded again before it is written, and the number
+//! of identifiers defaults to 1.
+
+use std::env;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let count = match env::args().nth(1).map(|arg| arg.parse::<usize>()) {
+        None => 1,
+        Some(Ok(count)) => count,
+        Some(Err(err)) => {
+            eprintln!("id_gen: invalid count: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    for _ in 0..count {
+        let id = c32::random::<16>();
+
+        // Assert that the identifier round-trips.
+        let bytes = c32::decode(&id).expect("generated ids are valid");
+        assert_eq!(bytes.len(), 16);
+
+        println!("{id}");
+    }
+
+    ExitCode::SUCCESS
+}
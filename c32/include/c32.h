@@ -0,0 +1,163 @@
+#ifndef C32_H
+#define C32_H
+
+/* This file is generated by cbindgen, do not modify it manually. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/*
+ The operation succeeded.
+ */
+#define C32_OK 0
+
+/*
+ A required pointer is null.
+ */
+#define C32_ERR_NULL_POINTER -1
+
+/*
+ See [`Error::BufferTooSmall`].
+ */
+#define C32_ERR_BUFFER_TOO_SMALL -2
+
+/*
+ See [`Error::InvalidDataSize`].
+ */
+#define C32_ERR_INVALID_DATA_SIZE -3
+
+/*
+ See [`Error::InvalidCharacter`].
+ */
+#define C32_ERR_INVALID_CHARACTER -4
+
+/*
+ See [`Error::MissingPrefix`].
+ */
+#define C32_ERR_MISSING_PREFIX -5
+
+/*
+ See [`Error::InvalidFrame`].
+ */
+#define C32_ERR_INVALID_FRAME -6
+
+/*
+ See [`Error::OutputTooLarge`].
+ */
+#define C32_ERR_OUTPUT_TOO_LARGE -7
+
+/*
+ See [`Error::InvalidVersion`].
+ */
+#define C32_ERR_INVALID_VERSION -8
+
+/*
+ See [`Error::InsufficientData`].
+ */
+#define C32_ERR_INSUFFICIENT_DATA -9
+
+/*
+ See [`Error::ChecksumMismatch`].
+ */
+#define C32_ERR_CHECKSUM_MISMATCH -10
+
+/*
+ See [`Error::InvalidPadding`].
+ */
+#define C32_ERR_INVALID_PADDING -11
+
+/*
+ See [`Error::AllocationFailed`].
+ */
+#define C32_ERR_ALLOCATION_FAILED -12
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Returns the maximum encoded length of `n` bytes.
+ */
+size_t c32_encoded_len(size_t n);
+
+/*
+ Returns the maximum Base32Check-encoded length of `n` bytes.
+ */
+size_t c32_encoded_check_len(size_t n);
+
+/*
+ Returns the maximum decoded length of `n` characters.
+ */
+size_t c32_decoded_len(size_t n);
+
+/*
+ Returns the maximum Base32Check-decoded length of `n` characters.
+ */
+size_t c32_decoded_check_len(size_t n);
+
+/*
+ Encodes `src_len` bytes at `src` into Crockford Base32.
+
+ The characters are written to `out`, and their number to `out_len`.
+
+ # Safety
+
+ - `src` must be valid for reads of `src_len` bytes, or may be null if
+   `src_len` is zero.
+ - `out` must be valid for writes of `out_cap` bytes, or may be null if
+   `out_cap` is zero.
+ - `out_len` must be valid for writes, or null.
+ - The buffers must not overlap.
+ */
+int c32_encode(const uint8_t *src, size_t src_len, uint8_t *out, size_t out_cap, size_t *out_len);
+
+/*
+ Decodes `src_len` Crockford Base32 characters at `src`.
+
+ The bytes are written to `out`, and their number to `out_len`.
+
+ # Safety
+
+ See [`c32_encode`].
+ */
+int c32_decode(const uint8_t *src, size_t src_len, uint8_t *out, size_t out_cap, size_t *out_len);
+
+/*
+ Encodes `src_len` bytes at `src` into Crockford Base32Check, with the
+ given `version`.
+
+ The characters are written to `out`, and their number to `out_len`.
+
+ # Safety
+
+ See [`c32_encode`].
+ */
+int c32_encode_check(const uint8_t *src,
+                     size_t src_len,
+                     uint8_t version,
+                     uint8_t *out,
+                     size_t out_cap,
+                     size_t *out_len);
+
+/*
+ Decodes `src_len` Crockford Base32Check characters at `src`.
+
+ The payload is written to `out`, its length to `out_len`, and the
+ version to `version`.
+
+ # Safety
+
+ See [`c32_encode`], and `version` must be valid for writes, or null.
+ */
+int c32_decode_check(const uint8_t *src,
+                     size_t src_len,
+                     uint8_t *out,
+                     size_t out_cap,
+                     size_t *out_len,
+                     uint8_t *version);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* C32_H */
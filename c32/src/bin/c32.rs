@@ -0,0 +1,385 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+//! A command-line tool for Crockford Base32.
+//!
+//! The input is read from a file, or from stdin, and the output is written
+//! to stdout. Encoded output is followed by a newline, and trailing
+//! whitespace is ignored when decoding.
+//!
+//! # Exit Codes
+//!
+//! - `0`, the command succeeded.
+//! - `1`, reading or writing failed.
+//! - `2`, the input or an argument is invalid.
+//! - `3`, the checksum does not match.
+
+use std::fs::File;
+use std::io;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use c32::Error;
+use clap::Args;
+use clap::Parser;
+use clap::Subcommand;
+
+/// Encodes and decodes Crockford Base32.
+#[derive(Debug, Parser)]
+#[command(name = "c32", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Encodes bytes into Crockford Base32.
+    Encode(EncodeArgs),
+    /// Decodes Crockford Base32 into bytes.
+    Decode(DecodeArgs),
+    /// Encodes bytes into Crockford Base32Check.
+    CheckEncode {
+        /// The version byte, from 0 to 31.
+        #[arg(long)]
+        version: u8,
+        #[command(flatten)]
+        args: EncodeArgs,
+    },
+    /// Decodes Crockford Base32Check into bytes.
+    ///
+    /// The payload is written as it is decoded, before the checksum is
+    /// verified, so the output must be discarded if the command fails.
+    CheckDecode {
+        /// The version byte the input must have.
+        #[arg(long)]
+        expect_version: Option<u8>,
+        #[command(flatten)]
+        args: DecodeArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+struct EncodeArgs {
+    /// The prefix character to prepend.
+    #[arg(long)]
+    prefix: Option<char>,
+    /// Emits lowercase characters.
+    #[arg(long)]
+    lower: bool,
+    /// The input file, instead of stdin.
+    file: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct DecodeArgs {
+    /// The prefix character the input must start with.
+    #[arg(long)]
+    prefix: Option<char>,
+    /// The input file, instead of stdin.
+    file: Option<PathBuf>,
+}
+
+/// The failure of a command.
+#[derive(Debug)]
+enum Failure {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// The input or an argument is invalid.
+    Input(Error),
+}
+
+impl Failure {
+    /// Returns the exit code of the failure.
+    fn code(&self) -> u8 {
+        match self {
+            Self::Io(_) => 1,
+            Self::Input(Error::ChecksumMismatch { .. }) => 3,
+            Self::Input(_) => 2,
+        }
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(err: io::Error) -> Self {
+        // Recover the typed error of the streaming functions.
+        match err.get_ref().and_then(|e| e.downcast_ref::<Error>()) {
+            Some(err) => Self::Input(*err),
+            None => Self::Io(err),
+        }
+    }
+}
+
+impl From<Error> for Failure {
+    fn from(err: Error) -> Self {
+        Self::Input(err)
+    }
+}
+
+/// A seekable input, which is either a file or the buffered stdin.
+enum Input {
+    File(BufReader<File>),
+    Stdin(Cursor<Vec<u8>>),
+}
+
+impl Input {
+    /// Opens `file`, or buffers stdin if no file is given.
+    fn open(file: Option<&Path>) -> io::Result<Self> {
+        match file {
+            Some(path) => Ok(Self::File(BufReader::new(File::open(path)?))),
+            None => {
+                let mut buf = Vec::new();
+                io::stdin().lock().read_to_end(&mut buf)?;
+                Ok(Self::Stdin(Cursor::new(buf)))
+            }
+        }
+    }
+}
+
+impl Read for Input {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::File(file) => file.read(buf),
+            Self::Stdin(stdin) => stdin.read(buf),
+        }
+    }
+}
+
+impl Seek for Input {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self {
+            Self::File(file) => file.seek(pos),
+            Self::Stdin(stdin) => stdin.seek(pos),
+        }
+    }
+}
+
+/// A reader which ends before the trailing ASCII whitespace of `inner`.
+struct Trimmed<R> {
+    /// The inner reader.
+    inner: R,
+    /// The current position.
+    pos: u64,
+    /// The position of the trailing whitespace.
+    end: u64,
+}
+
+impl<R: Read + Seek> Trimmed<R> {
+    /// Creates a new [`Trimmed`] reader, starting at the current position.
+    fn new(mut inner: R) -> io::Result<Self> {
+        let pos = inner.stream_position()?;
+        let mut end = inner.seek(SeekFrom::End(0))?;
+
+        // Scan backwards over the trailing whitespace.
+        let mut byte = [0u8; 1];
+        while end > pos {
+            inner.seek(SeekFrom::Start(end - 1))?;
+            inner.read_exact(&mut byte)?;
+            if !byte[0].is_ascii_whitespace() {
+                break;
+            }
+            end -= 1;
+        }
+
+        inner.seek(SeekFrom::Start(pos))?;
+        Ok(Self { inner, pos, end })
+    }
+
+    /// Returns the number of bytes before the trailing whitespace.
+    fn remaining(&self) -> u64 {
+        self.end.saturating_sub(self.pos)
+    }
+}
+
+impl<R: Read> Read for Trimmed<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let max = self.end.saturating_sub(self.pos);
+        let len = buf.len().min(usize::try_from(max).unwrap_or(usize::MAX));
+        let n = self.inner.read(&mut buf[..len])?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R: Seek> Seek for Trimmed<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        // Seek relative to the trimmed end.
+        let pos = match pos {
+            SeekFrom::End(offset) => {
+                let pos = self.end.checked_add_signed(offset);
+                SeekFrom::Start(pos.ok_or(io::ErrorKind::InvalidInput)?)
+            }
+            pos => pos,
+        };
+        self.pos = self.inner.seek(pos)?;
+        Ok(self.pos)
+    }
+}
+
+/// A writer which optionally lowercases the characters written to `inner`.
+struct Lower<W> {
+    /// The inner writer.
+    inner: W,
+    /// Whether to lowercase the characters.
+    enabled: bool,
+}
+
+impl<W: Write> Write for Lower<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.enabled {
+            self.inner.write_all(&buf.to_ascii_lowercase())?;
+            Ok(buf.len())
+        } else {
+            self.inner.write(buf)
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Asserts that `input` starts with `prefix`, and skips it.
+///
+/// # Returns
+///
+/// The length of the prefix in bytes.
+fn skip_prefix<R: Read>(
+    input: &mut R,
+    prefix: Option<char>,
+) -> Result<usize, Failure> {
+    let Some(prefix) = prefix else {
+        return Ok(0);
+    };
+
+    let mut buf = [0u8; 4];
+    let expected = prefix.encode_utf8(&mut buf).as_bytes();
+
+    let mut got = Vec::with_capacity(expected.len());
+    input.take(expected.len() as u64).read_to_end(&mut got)?;
+    if got != expected {
+        return Err(Failure::Input(Error::MissingPrefix {
+            char: prefix,
+            got: String::from_utf8_lossy(&got).chars().next(),
+        }));
+    }
+
+    Ok(expected.len())
+}
+
+/// Adjusts the index of an [`Error::InvalidCharacter`] by `offset`.
+fn shift(err: Failure, offset: usize) -> Failure {
+    match err {
+        Failure::Input(Error::InvalidCharacter {
+            char,
+            index,
+            decoded,
+        }) => Failure::Input(Error::InvalidCharacter {
+            char,
+            index: index + offset,
+            decoded,
+        }),
+        err => err,
+    }
+}
+
+/// Runs the command, writing its output to `out`.
+fn run<W: Write>(command: Command, mut out: W) -> Result<(), Failure> {
+    match command {
+        Command::Encode(args) => {
+            let mut input: Box<dyn Read> = match &args.file {
+                Some(path) => Box::new(BufReader::new(File::open(path)?)),
+                None => Box::new(io::stdin().lock()),
+            };
+
+            if let Some(prefix) = args.prefix {
+                write!(out, "{prefix}")?;
+            }
+
+            let inner = Lower {
+                inner: &mut out,
+                enabled: args.lower,
+            };
+            let mut writer = c32::io::EncodeWriter::new(inner);
+            io::copy(&mut input, &mut writer)?;
+            writer.finish()?;
+            writeln!(out)?;
+        }
+        Command::Decode(args) => {
+            let mut input = Trimmed::new(Input::open(args.file.as_deref())?)?;
+            let offset = skip_prefix(&mut input, args.prefix)?;
+
+            let mut reader = c32::io::DecodeReader::new(input);
+            io::copy(&mut reader, &mut out)
+                .map_err(|e| shift(e.into(), offset))?;
+        }
+        Command::CheckEncode { version, args } => {
+            // Assert that the version is valid before writing anything.
+            c32::Version::try_from(version)?;
+            let input = Input::open(args.file.as_deref())?;
+
+            if let Some(prefix) = args.prefix {
+                write!(out, "{prefix}")?;
+            }
+
+            let writer = Lower {
+                inner: &mut out,
+                enabled: args.lower,
+            };
+            c32::io::encode_check_from_reader(input, version, writer)?;
+            writeln!(out)?;
+        }
+        Command::CheckDecode {
+            expect_version,
+            args,
+        } => {
+            let mut input = Trimmed::new(Input::open(args.file.as_deref())?)?;
+            let offset = skip_prefix(&mut input, args.prefix)?;
+
+            let len = input.remaining();
+            let (_, version) = c32::io::decode_check_from_reader_with_len(
+                input, len, &mut out,
+            )
+            .map_err(|e| shift(e.into(), offset))?;
+
+            if expect_version.is_some_and(|expected| expected != version) {
+                return Err(Failure::Input(Error::InvalidVersion {
+                    expected: "must match the expected version",
+                    version,
+                }));
+            }
+        }
+    }
+
+    out.flush()?;
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let out = BufWriter::new(io::stdout().lock());
+
+    match run(cli.command, out) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => {
+            match &failure {
+                Failure::Io(err) => eprintln!("c32: {err}"),
+                Failure::Input(err) => eprintln!("c32: {err}"),
+            }
+            ExitCode::from(failure.code())
+        }
+    }
+}
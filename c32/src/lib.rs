@@ -7,6 +7,9 @@
 // Usage of this file is permitted solely under a sanctioned license.
 
 #![no_std]
+#![deny(clippy::std_instead_of_core)]
+#![deny(clippy::std_instead_of_alloc)]
+#![deny(clippy::alloc_instead_of_core)]
 #![allow(clippy::doc_markdown)]
 #![allow(clippy::wildcard_imports)]
 #![allow(clippy::missing_errors_doc)]
@@ -78,6 +81,10 @@
 //! The `check` feature enables methods for encoding data with SHA256-based
 //! checksum verification.
 //!
+//! The built-in SHA256 implementation is portable, `const` and free of `std`,
+//! so `check` builds for bare-metal targets. For a hardware-accelerated or
+//! assembly implementation, use `check-core` with a custom [`Sha256Backend`].
+//!
 //! The encoded data follows this layout:
 //! ```text
 //! [version (1B)] + [payload (nB)] + [checksum (4B)]
@@ -110,12 +117,68 @@
 //! # Ok::<(), c32::Error>(())
 //! ```
 //!
+//! ## Zeroization
+//!
+//! With the `zeroize` feature, decoding clears the intermediate buffers it
+//! owns before returning, so that decoded secrets, such as private keys, do
+//! not linger in freed memory:
+//!
+//! - [`decode`], [`decode_check`] and their `_prefixed`, `_with`, `_small`,
+//!   `_arrayvec` and `_heapless` variants clear their scratch buffers and the
+//!   unused tail of the output, including the trailing checksum bytes.
+//! - [`Buffer`], [`C32Text`] and [`C32Builder`] implement `Zeroize`, and
+//!   [`C32Builder`] and the decoding adapters of [`io`] and [`stream`] also
+//!   implement `ZeroizeOnDrop`.
+//!
+//! Independently of the feature, [`decode_into`], [`decode_check_into`] and
+//! [`decode_in_place`] clear the bytes they wrote to the provided buffer if an
+//! error is returned after decoding began, and leave the rest untouched.
+//!
+//! The returned values are owned by the caller, and are not zeroized. Copies
+//! made by moves on the stack, by reallocations while an adapter buffers its
+//! input, or inside the hasher state of [`checksum`] are also not covered.
+//!
+//! ## Stability
+//!
+//! The encoded output is part of the public API. For the same input, every
+//! encoding function produces the same characters across all releases of a
+//! major version, including the trimming of zeros, the version characters
+//! and the input of the checksum. The outputs are pinned by snapshot tests,
+//! and any change to them requires a major version bump.
+//!
 //! # Features
 //!
-//!  Feature | Description
-//! ---------|-------------------------------------------------------------
-//!  `alloc` | Allocation-based API via [`encode`] and [`decode`]
-//!  `check` | Support for checksum validation
+//!  Feature          | Description
+//! ------------------|----------------------------------------------------
+//!  `alloc`          | Allocation-based API via [`encode`] and [`decode`]
+//!  `check`          | Support for checksum validation
+//!  `check-core`     | Checksums with a custom [`Sha256Backend`] only
+//!  `check-ring`     | A `ring` backend via [`RingBackend`]
+//!  `arrayvec`       | Fixed-capacity API via [`encode_arrayvec`]
+//!  `smallvec`       | Inline-capacity API via [`decode_small`]
+//!  `heapless`       | Fixed-capacity API via [`encode_heapless`]
+//!  `bytes`          | `Buf`/`BufMut` API via [`encode_buf`]
+//!  `rand`           | Random identifier generation via [`random_into`]
+//!  `postgres-types` | `ToSql`/`FromSql` implementations for [`C32Text`]
+//!  `sqlx`           | `Type`/`Encode`/`Decode` implementations for [`C32Text`]
+//!  `serde`          | `Serialize`/`Deserialize` implementations for [`Buffer`]
+//!  `serde_with`     | `serde_as` adapters via [`AsC32`] and [`AsC32Check`]
+//!  `schemars`       | `JsonSchema` for [`C32Text`] and [`AsC32`]
+//!  `std`            | Sync IO adapters via [`io::EncodeWriter`] and [`fs`]
+//!  `tokio`          | Async adapters via [`io::AsyncEncodeWriter`]
+//!  `futures`        | Stream adapters via [`stream::EncodeStream`]
+//!  `embedded-io`    | `no_std` IO adapters via [`embedded::EncodeWriter`]
+//!  `defmt`          | `defmt::Format` implementation for [`Error`]
+//!  `ufmt`           | `uWrite` output via [`u_display`]
+//!  `uuid`           | `Uuid` API via [`encode_uuid`] and [`decode_uuid`]
+//!  `metrics`        | Call, byte and error counters for [`encode`] and others
+//!  `tracing`        | `TRACE` spans around [`encode`], [`decode`] and friends
+//!  `clap`           | Argument value parsers via [`arg::C32ValueParser`]
+//!  `cli`            | A `c32` command-line tool, see `c32 --help`
+//!  `ffi`            | C ABI bindings via [`ffi`], with a generated header
+//!  `reference`      | A naive [`mod@reference`] codec for differential tests
+//!  `stacks`         | Stacks addresses via [`stacks::encode_address`]
+//!  `zeroize`        | Wiping of temporaries, see [Zeroization](#zeroization)
 //!
 //! For more details, please refer to the full [API Reference][Docs.rs].
 //!
@@ -125,10 +188,15 @@
 //! [License-Apache]: https://opensource.org/licenses/Apache-2.0
 //! [License-MIT]: https://opensource.org/licenses/MIT
 //! [Crockford]: https://www.crockford.com/base32.html
+//! [`Sha256Backend`]: checksum::Sha256Backend
+//! [`RingBackend`]: checksum::RingBackend
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::error;
 use core::fmt;
 use core::marker;
@@ -140,6 +208,7 @@ use core::str;
 /// This module exports common allocation types.
 #[cfg(feature = "alloc")]
 pub(crate) mod __private {
+    pub use alloc::borrow::Cow;
     pub use alloc::string::String;
     pub use alloc::vec;
     pub use alloc::vec::Vec;
@@ -151,8 +220,9 @@ pub(crate) use __private::*;
 /// This module provides methods for computing [`SHA-256`] checksums.
 ///
 /// [`SHA-256`]: https://helix.stormhub.org/papers/SHA-256.pdf
-#[cfg(feature = "check")]
+#[cfg(feature = "check-core")]
 pub mod checksum {
+    #[cfg(feature = "check")]
     use sha2::Sha256;
 
     use super::*;
@@ -160,9 +230,111 @@ pub mod checksum {
     /// Length of the [`Checksum`] in bytes.
     pub const BYTE_LENGTH: usize = 4;
 
-    /// A type alias for a [`Sha256`] checksum.
+    /// A type alias for a SHA-256 checksum.
     pub type Checksum = [u8; BYTE_LENGTH];
 
+    /// A trait for SHA-256 implementations used to compute checksums.
+    ///
+    /// This allows the `*_with_backend` methods, e.g.
+    /// [`encode_check_with_backend`], to use a hardware-accelerated or
+    /// otherwise preferred implementation. The remaining check methods use
+    /// [`Sha2Backend`], regardless of the enabled backends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// # #[cfg(all(feature = "check", feature = "alloc"))] {
+    /// use c32::checksum::Sha256Backend;
+    /// use c32::checksum::Sha2Backend;
+    ///
+    /// struct Custom;
+    ///
+    /// impl Sha256Backend for Custom {
+    ///     fn digest(data_parts: &[&[u8]]) -> [u8; 32] {
+    ///         // e.g. feed the parts to a hardware peripheral.
+    ///         Sha2Backend::digest(data_parts)
+    ///     }
+    /// }
+    ///
+    /// let en = c32::encode_check_with_backend::<Custom, _>([42, 42, 42], 0)?;
+    /// assert_eq!(en, "0AHA59B9201Z");
+    /// # }
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`encode_check_with_backend`]: crate::encode_check_with_backend
+    pub trait Sha256Backend {
+        /// Computes the SHA-256 digest of the concatenated `data_parts`.
+        fn digest(data_parts: &[&[u8]]) -> [u8; 32];
+    }
+
+    /// The built-in [`Sha256Backend`], a portable software implementation.
+    ///
+    /// This is also used by [`compute`] and the `const` [`Buffer`] methods.
+    #[cfg(feature = "check")]
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct Sha2Backend;
+
+    #[cfg(feature = "check")]
+    impl Sha256Backend for Sha2Backend {
+        #[inline]
+        fn digest(data_parts: &[&[u8]]) -> [u8; 32] {
+            let mut hasher = Sha256::new();
+            for part in data_parts {
+                hasher = hasher.update(part);
+            }
+            hasher.finalize()
+        }
+    }
+
+    /// A [`Sha256Backend`] using the [`ring`] crate.
+    ///
+    /// [`ring`]: https://docs.rs/ring
+    #[cfg(feature = "check-ring")]
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct RingBackend;
+
+    #[cfg(feature = "check-ring")]
+    impl Sha256Backend for RingBackend {
+        #[inline]
+        fn digest(data_parts: &[&[u8]]) -> [u8; 32] {
+            let mut context = ring::digest::Context::new(&ring::digest::SHA256);
+            for part in data_parts {
+                context.update(part);
+            }
+
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(context.finish().as_ref());
+            hash
+        }
+    }
+
+    /// Computes a 4-byte [`Checksum`] from a byte array and version, using
+    /// the given [`Sha256Backend`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "check")] {
+    /// use c32::checksum;
+    /// use c32::checksum::Sha2Backend;
+    ///
+    /// let sum = checksum::compute_with_backend::<Sha2Backend>(&[42], 0);
+    /// assert_eq!(sum, checksum::compute(&[42], 0));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn compute_with_backend<H: Sha256Backend>(
+        bytes: &[u8],
+        version: u8,
+    ) -> Checksum {
+        let buffer = H::digest(&[&[version], bytes]);
+        let hash = H::digest(&[&buffer]);
+        from_slice(&hash)
+    }
+
     /// Computes a 4-byte [`Checksum`]from a byte array and version.
     ///
     /// The checksum is computed by:
@@ -172,6 +344,9 @@ pub mod checksum {
     /// 3. Taking the SHA256 hash of the result.
     /// 4. Using the first 4 bytes as the checksum.
     ///
+    /// This is the numeric convention, see [`compute_over_symbol`] for
+    /// checksums over the version character.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -183,1727 +358,14183 @@ pub mod checksum {
     /// ```
     #[inline]
     #[must_use]
+    #[cfg(feature = "check")]
     pub const fn compute(bytes: &[u8], version: u8) -> Checksum {
         let buffer = Sha256::new().update(&[version]).update(bytes).finalize();
         let hash = Sha256::new().update(&buffer).finalize();
         from_slice(&hash)
     }
 
-    /// Creates a [`Checksum`] from a byte slice.
+    /// Computes a 4-byte [`Checksum`] from a byte array and a validated
+    /// [`Version`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// use c32::checksum;
+    /// use c32::Version;
     ///
-    /// let hash = [1, 2, 3, 4, 5, 6, 7, 8];
-    /// let sum = checksum::from_slice(&hash);
-    /// assert_eq!(sum, [1, 2, 3, 4]);
+    /// let version = Version::new(22).unwrap();
+    /// let sum = checksum::compute_versioned(&[42, 42, 42], version);
+    /// assert_eq!(sum, checksum::compute(&[42, 42, 42], 22));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn from_slice(bytes: &[u8]) -> Checksum {
-        let mut sum = [0u8; BYTE_LENGTH];
-        __internal::memcpy(&mut sum, 0, bytes, 0, BYTE_LENGTH);
-        sum
+    #[cfg(feature = "check")]
+    pub const fn compute_versioned(bytes: &[u8], version: Version) -> Checksum {
+        compute(bytes, version.get())
     }
-}
-
-/// The Crockford Base32 alphabet used for encoding and decoding.
-pub(crate) const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
-
-/// A mapping from ASCII characters to their Crockford Base32 values.
-pub(crate) const BYTE_MAP: [i8; 128] = [
-    -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
-    -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
-    -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, -1,
-    -1, -1, -1, -1, -1, -1, 10, 11, 12, 13, 14, 15, 16, 17, 1, 18, 19, 1, 20,
-    21, 0, 22, 23, 24, 25, 26, -1, 27, 28, 29, 30, 31, -1, -1, -1, -1, -1, -1,
-    10, 11, 12, 13, 14, 15, 16, 17, 1, 18, 19, 1, 20, 21, 0, 22, 23, 24, 25,
-    26, -1, 27, 28, 29, 30, 31, -1, -1, -1, -1, -1,
-];
 
-/// Error variants for fallible Crockford Base32 operations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum Error {
-    /// The buffer size is insufficient for the operation.
-    ///
-    /// # Fields
-    ///
-    /// * `min` - The minimum required buffer size.
-    /// * `len` - The actual size of the provided buffer.
-    BufferTooSmall { min: usize, len: usize },
-    /// The input data size doesn't match the expected size.
-    ///
-    /// # Fields
-    ///
-    /// * `expected` - The expected data size in bytes.
-    /// * `got` - The actual size of the provided data.
-    InvalidDataSize { expected: usize, got: usize },
-    /// An invalid character was encountered during decoding.
+    /// Computes a 4-byte [`Checksum`] from a byte array and a version
+    /// character.
     ///
-    /// # Fields
+    /// This is the symbol-based convention, where the checksum covers the
+    /// emitted version character, e.g. `b'P'`, instead of the numeric
+    /// version, e.g. `22`, as in [`compute`]. Both conventions produce the
+    /// same version character, but different checksums.
     ///
-    /// * `char` - The invalid character found in the input.
-    /// * `index` - The byte index of the character.
-    InvalidCharacter { char: char, index: usize },
-    /// The expected prefix character is missing.
+    /// # Examples
     ///
-    /// # Fields
+    /// ```rust
+    /// use c32::checksum;
     ///
-    /// * `char` - The expected prefix character.
-    /// * `got` - The actual first character found.
-    MissingPrefix { char: char, got: Option<char> },
+    /// let sum = checksum::compute_over_symbol(&[42, 42, 42], b'P');
+    /// assert_eq!(sum, checksum::compute(&[42, 42, 42], b'P'));
+    /// assert_ne!(sum, checksum::compute(&[42, 42, 42], 22));
+    /// ```
+    #[inline]
+    #[must_use]
     #[cfg(feature = "check")]
-    /// The provided version byte is invalid.
-    ///
-    /// # Fields
+    pub const fn compute_over_symbol(bytes: &[u8], symbol: u8) -> Checksum {
+        compute(bytes, symbol)
+    }
+
+    /// Creates a [`Checksum`] from the first 4 bytes of a byte slice.
     ///
-    /// * `expected` - The expected version constraints.
-    /// * `version` - The invalid version byte.
-    InvalidVersion { expected: &'static str, version: u8 },
-    /// The input has fewer bytes than are required.
+    /// # Panics
     ///
-    /// # Fields
+    /// This method will panic if the slice is shorter than [`BYTE_LENGTH`],
+    /// use [`try_from_slice`] for a non-panicking alternative.
     ///
-    /// * `min` - The minimum required amount of bytes.
-    /// * `len` - The actual number of bytes provided.
-    #[cfg(feature = "check")]
-    InsufficientData { min: usize, len: usize },
-    /// The computed checksum does not match the expected sum.
+    /// # Examples
     ///
-    /// # Fields
+    /// ```rust
+    /// use c32::checksum;
     ///
-    /// * `expected` - The expected checksum.
-    /// * `got` - The actual checksum.
-    #[cfg(feature = "check")]
-    ChecksumMismatch {
-        expected: checksum::Checksum,
-        got: checksum::Checksum,
-    },
-}
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::BufferTooSmall { min, len } => {
-                write!(f, "Buffer size '{len}' is less than required '{min}'")
-            }
-            Self::InvalidDataSize { expected, got } => {
-                write!(f, "Invalid data size '{got}', expected: '{expected}'")
-            }
-            Self::InvalidCharacter { char, index } => {
-                write!(f, "Invalid character '{char}' at position {index}")
-            }
-            Self::MissingPrefix { char, got } => {
-                write!(f, "Expected prefix '{char}', found '{got:?}'")
-            }
-            #[cfg(feature = "check")]
-            Self::InvalidVersion { expected, version } => {
-                write!(f, "Invalid version byte '{version}': {expected}")
-            }
-            #[cfg(feature = "check")]
-            Self::InsufficientData { min, len } => {
-                write!(f, "Input size '{len}' is less than required '{min}'")
-            }
-            #[cfg(feature = "check")]
-            Self::ChecksumMismatch { expected, got } => {
-                write!(f, "Expected checksum '{expected:?}', got '{got:?}'")
-            }
+    /// let hash = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let sum = checksum::from_slice(&hash);
+    /// assert_eq!(sum, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_slice(bytes: &[u8]) -> Checksum {
+        match try_from_slice(bytes) {
+            Some(sum) => sum,
+            None => panic!("Slice must contain min. 4 bytes"),
         }
     }
-}
-
-impl error::Error for Error {}
-
-/// Result type for fallible Crockford Base32 operations.
-pub type Result<T> = core::result::Result<T, Error>;
-
-/// A marker trait for Crockford Base32 variations.
-///
-/// # Generics
-///
-/// * `PREFIX` - Whether to include a prefix character.
-pub trait Encoding<const PREFIX: bool> {}
-
-/// [`Encoding`] implementations.
-///
-/// This module exports various [`Encoding`] types.
-pub mod en {
-    use super::*;
 
-    /// Default Crockford Base32 encoding.
+    /// Creates a [`Checksum`] from the first 4 bytes of a byte slice.
     ///
-    /// # Generics
-    ///
-    /// * `PREFIX` - Whether to include a prefix character.
+    /// Returns `None` if the slice is shorter than [`BYTE_LENGTH`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::en::Default;
-    /// use c32::Buffer;
+    /// use c32::checksum;
     ///
-    /// // Default encoding w/o prefix
-    /// let en = Buffer::<5, false, Default>::encode(&[42, 42, 42]);
-    /// assert_eq!(en.as_str(), "2MAHA");
+    /// let sum = checksum::try_from_slice(&[1, 2, 3, 4, 5]);
+    /// assert_eq!(sum, Some([1, 2, 3, 4]));
     ///
-    /// // Default encoding with prefix
-    /// let en = Buffer::<6, true, Default>::encode(&[42, 42, 42], 'S');
-    /// assert_eq!(en.as_str(), "S2MAHA");
+    /// let sum = checksum::try_from_slice(&[1, 2]);
+    /// assert_eq!(sum, None);
     /// ```
-    pub struct Default;
-    impl<const PREFIX: bool> Encoding<PREFIX> for Default {}
-
-    #[cfg(feature = "check")]
-    mod __check {
-        use super::*;
+    #[inline]
+    #[must_use]
+    pub const fn try_from_slice(bytes: &[u8]) -> Option<Checksum> {
+        if bytes.len() < BYTE_LENGTH {
+            return None;
+        }
 
-        /// Crockford Base32 encoding with checksum validation.
-        ///
-        /// This encoding format includes a 4-byte `Checksum`.
-        ///
-        /// # Generics
-        ///
-        /// * `PREFIX` - Whether to include a prefix character.
-        ///
-        /// # Examples
-        ///
-        /// ```rust
-        /// use c32::en::Check;
-        /// use c32::Buffer;
-        ///
-        /// // Check encoding w/o prefix
-        /// let en = Buffer::<13, false, Check>::encode(&[42, 42, 42], 0);
-        /// assert_eq!(en.as_str(), "0AHA59B9201Z");
-        ///
-        /// // Check encoding with prefix
-        /// let en = Buffer::<14, true, Check>::encode(&[42, 42, 42], 'S', 0);
-        /// assert_eq!(en.as_str(), "S0AHA59B9201Z");
-        /// ```
-        pub struct Check;
-        impl<const PREFIX: bool> Encoding<PREFIX> for Check {}
+        let mut sum = [0u8; BYTE_LENGTH];
+        __internal::memcpy(&mut sum, 0, bytes, 0, BYTE_LENGTH);
+        Some(sum)
     }
-
-    #[cfg(feature = "check")]
-    pub use __check::*;
 }
 
-/// A fixed-size buffer for encoding or decoding Crockford's Base32.
+/// A Crockford Base32Check version byte, guaranteed to be less than 32.
 ///
-/// [`Buffer`] manages a fixed-size array of bytes and tracks the number of
-/// bytes written during encoding and decoding operations, and is primarily
-/// designed for use in a constant context.
+/// # Examples
 ///
-/// # Generics
+/// ```rust
+/// use c32::Version;
 ///
-/// * `LEN` - The size of the byte array in bytes.
-/// * `PREFIX` - Whether to include a prefix character, defaults to `false`.
-/// * `E` - The [`Encoding`] format to use, defaults to [`en::Default`].
+/// let version = Version::new(22).unwrap();
+/// assert_eq!(u8::from(version), 22);
 ///
-/// # Examples
+/// assert!(Version::new(32).is_none());
+/// ```
+///
+/// It can also be parsed from a decimal string:
 ///
 /// ```rust
-/// use c32::Buffer;
+/// # use c32::Error;
+/// use c32::Version;
 ///
-/// const BYTES: [u8; 3] = [42, 42, 42];
-/// const EN: Buffer<5> = Buffer::<5>::encode(&BYTES);
-/// assert_eq!(EN.as_str(), "2MAHA");
+/// let version: Version = "22".parse()?;
+/// assert_eq!(version.get(), 22);
 ///
-/// const DE: Buffer<5> = Buffer::<5>::decode(EN.as_bytes());
-/// assert_eq!(DE.as_bytes(), [42, 42, 42]);
+/// assert!("32".parse::<Version>().is_err());
+/// # Ok::<(), Error>(())
 /// ```
-pub struct Buffer<
-    const LEN: usize,
-    const PREFIX: bool = false,
-    E: Encoding<PREFIX> = en::Default,
-> {
-    /// The underlying byte array.
-    __raw: [u8; LEN],
-    /// The number of written bytes to the buffer.
-    __pos: usize,
-    /// The associated [`Encoding`] format `E`.
-    __marker: marker::PhantomData<E>,
-}
+#[cfg(feature = "check-core")]
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version(u8);
 
-impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>>
-    Buffer<LEN, PREFIX, E>
-{
-    /// An empty buffer with no bytes written.
+#[cfg(feature = "check-core")]
+impl Version {
+    /// Creates a new [`Version`], returning `None` if `version` is 32 or
+    /// greater.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::Buffer;
+    /// use c32::Version;
     ///
-    /// let buffer = Buffer::<10>::EMPTY;
-    /// assert_eq!(buffer.pos(), 0);
-    /// assert_eq!(buffer.as_bytes(), &[]);
+    /// const VERSION: Option<Version> = Version::new(31);
+    /// assert!(VERSION.is_some());
     /// ```
-    pub const EMPTY: Self = Self {
-        __raw: [0u8; LEN],
-        __pos: 0,
-        __marker: marker::PhantomData,
-    };
-
-    /// Creates a new [`Buffer`].
-    ///
-    /// This is an internal method.
-    const fn new(__raw: [u8; LEN], __pos: usize) -> Self {
-        Self {
-            __raw,
-            __pos,
-            __marker: marker::PhantomData,
+    #[inline]
+    #[must_use]
+    pub const fn new(version: u8) -> Option<Self> {
+        if version >= 32 {
+            return None;
         }
+        Some(Self(version))
     }
 
-    /// Returns the number of bytes written to the buffer.
+    /// Returns the version as a `u8`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::Buffer;
+    /// use c32::Version;
     ///
-    /// const INPUT: [u8; 3] = [42, 42, 42];
-    /// const EN: Buffer<5> = Buffer::<5>::encode(&INPUT);
-    /// assert_eq!(EN.pos(), 5);
+    /// let version = Version::new(22).unwrap();
+    /// assert_eq!(version.get(), 22);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn pos(&self) -> usize {
-        self.__pos
+    pub const fn get(self) -> u8 {
+        self.0
     }
+}
 
-    /// Returns a string slice of the written bytes.
+#[cfg(feature = "check-core")]
+impl TryFrom<u8> for Version {
+    type Error = Error;
+
+    fn try_from(version: u8) -> Result<Self> {
+        Self::new(version).ok_or(Error::InvalidVersion {
+            expected: "must be < 32",
+            version,
+        })
+    }
+}
+
+#[cfg(feature = "check-core")]
+impl From<Version> for u8 {
+    fn from(version: Version) -> Self {
+        version.0
+    }
+}
+
+#[cfg(feature = "check-core")]
+impl core::str::FromStr for Version {
+    type Err = Error;
+
+    /// Parses a decimal version, e.g. `"22"`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::InsufficientData`], the string is empty.
+    /// - [`Error::InvalidCharacter`], the string contains a non-digit.
+    /// - [`Error::InvalidVersion`], the version is 32 or greater, which is
+    ///   saturated to `255` for larger numbers.
+    fn from_str(str: &str) -> Result<Self> {
+        if str.is_empty() {
+            return Err(Error::InsufficientData { min: 1, len: 0 });
+        }
+
+        let mut version = 0u8;
+        for (index, char) in str.char_indices() {
+            let Some(digit) = char.to_digit(10) else {
+                return Err(Error::InvalidCharacter {
+                    char,
+                    index,
+                    decoded: 0,
+                });
+            };
+
+            // The digit is below 10, so the truncation is lossless.
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = digit as u8;
+            version = version.saturating_mul(10).saturating_add(digit);
+        }
+
+        Self::try_from(version)
+    }
+}
+
+#[cfg(feature = "check-core")]
+impl TryFrom<&str> for Version {
+    type Error = Error;
+
+    fn try_from(str: &str) -> Result<Self> {
+        str.parse()
+    }
+}
+
+/// This module provides helpers for Stacks addresses.
+///
+/// A Stacks address is the [`PREFIX`] character followed by the
+/// Crockford Base32Check encoding of a 20-byte `hash160`, using a version
+/// byte that identifies the [`Network`] and [`AddressKind`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use c32::address::AddressKind;
+/// use c32::address::Network;
+/// use c32::address::{self};
+///
+/// let hash160 = [0u8; 20];
+/// let addr = address::encode_address_versioned(
+///     Network::Mainnet,
+///     AddressKind::SingleSig,
+///     &hash160,
+/// );
+/// assert_eq!(addr, "SP000000000000000000002Q6VF78");
+/// # }
+/// ```
+///
+/// [`PREFIX`]: address::PREFIX
+/// [`Network`]: address::Network
+/// [`AddressKind`]: address::AddressKind
+#[cfg(feature = "check")]
+pub mod address {
+    #[cfg(feature = "alloc")]
+    use super::*;
+
+    /// The prefix character of every Stacks address.
+    pub const PREFIX: char = 'S';
+
+    /// Length of a `hash160` payload in bytes.
+    pub const HASH160_LENGTH: usize = 20;
+
+    /// Version byte of a mainnet single-signature address (`SP`).
+    pub const ADDR_VERSION_MAINNET_SINGLESIG: u8 = 22;
+
+    /// Version byte of a mainnet multi-signature address (`SM`).
+    pub const ADDR_VERSION_MAINNET_MULTISIG: u8 = 20;
+
+    /// Version byte of a testnet single-signature address (`ST`).
+    pub const ADDR_VERSION_TESTNET_SINGLESIG: u8 = 26;
+
+    /// Version byte of a testnet multi-signature address (`SN`).
+    pub const ADDR_VERSION_TESTNET_MULTISIG: u8 = 21;
+
+    /// The network an address belongs to.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Network {
+        /// The Stacks mainnet.
+        Mainnet,
+        /// The Stacks testnet.
+        Testnet,
+    }
+
+    /// The kind of principal an address identifies.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum AddressKind {
+        /// A single-signature principal.
+        SingleSig,
+        /// A multi-signature principal.
+        MultiSig,
+    }
+
+    /// Returns the version byte for a [`Network`] and [`AddressKind`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::Buffer;
+    /// use c32::address::AddressKind;
+    /// use c32::address::Network;
+    /// use c32::address::{self};
     ///
-    /// const INPUT: [u8; 3] = [42, 42, 42];
-    /// const EN: Buffer<5> = Buffer::<5>::encode(&INPUT);
-    /// assert_eq!(EN.as_str(), "2MAHA");
+    /// let version = address::version(Network::Testnet, AddressKind::SingleSig);
+    /// assert_eq!(version, address::ADDR_VERSION_TESTNET_SINGLESIG);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn as_str(&self) -> &str {
-        // SAFETY: We only push valid UTF-8 to `self.__raw`.
-        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    pub const fn version(network: Network, kind: AddressKind) -> u8 {
+        match (network, kind) {
+            (Network::Mainnet, AddressKind::SingleSig) => {
+                ADDR_VERSION_MAINNET_SINGLESIG
+            }
+            (Network::Mainnet, AddressKind::MultiSig) => {
+                ADDR_VERSION_MAINNET_MULTISIG
+            }
+            (Network::Testnet, AddressKind::SingleSig) => {
+                ADDR_VERSION_TESTNET_SINGLESIG
+            }
+            (Network::Testnet, AddressKind::MultiSig) => {
+                ADDR_VERSION_TESTNET_MULTISIG
+            }
+        }
     }
 
-    /// Returns a slice of the written bytes.
+    /// Returns the [`Network`] and [`AddressKind`] of a version byte.
+    ///
+    /// Returns `None` if the version is not a known address version.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::Buffer;
+    /// use c32::address::AddressKind;
+    /// use c32::address::Network;
+    /// use c32::address::{self};
     ///
-    /// const INPUT: [u8; 3] = [42, 42, 42];
-    /// const EN: Buffer<5> = Buffer::<5>::encode(&INPUT);
-    /// assert_eq!(EN.as_bytes(), b"2MAHA");
+    /// let parts = address::from_version(22);
+    /// assert_eq!(parts, Some((Network::Mainnet, AddressKind::SingleSig)));
+    ///
+    /// assert_eq!(address::from_version(0), None);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn as_bytes(&self) -> &[u8] {
-        // SAFETY: `self.__pos` is always within bounds.
-        unsafe { slice::from_raw_parts(self.as_ptr(), self.__pos) }
+    pub const fn from_version(version: u8) -> Option<(Network, AddressKind)> {
+        match version {
+            ADDR_VERSION_MAINNET_SINGLESIG => {
+                Some((Network::Mainnet, AddressKind::SingleSig))
+            }
+            ADDR_VERSION_MAINNET_MULTISIG => {
+                Some((Network::Mainnet, AddressKind::MultiSig))
+            }
+            ADDR_VERSION_TESTNET_SINGLESIG => {
+                Some((Network::Testnet, AddressKind::SingleSig))
+            }
+            ADDR_VERSION_TESTNET_MULTISIG => {
+                Some((Network::Testnet, AddressKind::MultiSig))
+            }
+            _ => None,
+        }
     }
 
-    /// Returns a raw pointer to the buffer's data.
+    /// Encodes a `hash160` into a Stacks address.
+    ///
+    /// # Panics
+    ///
+    /// This method will not panic, as every [`Network`] and [`AddressKind`]
+    /// maps to a version less than 32.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use c32::Buffer;
-    /// let buffer = Buffer::<10, false>::EMPTY;
-    /// let ptr = buffer.as_ptr();
+    /// use c32::address::AddressKind;
+    /// use c32::address::Network;
+    /// use c32::address::{self};
+    ///
+    /// let addr = address::encode_address_versioned(
+    ///     Network::Testnet,
+    ///     AddressKind::SingleSig,
+    ///     &[0u8; 20],
+    /// );
+    /// assert_eq!(addr, "ST000000000000000000002AMW42H");
     /// ```
     #[inline]
     #[must_use]
-    pub const fn as_ptr(&self) -> *const u8 {
-        self.__raw.as_ptr()
+    #[cfg(feature = "alloc")]
+    pub fn encode_address_versioned(
+        network: Network,
+        kind: AddressKind,
+        hash160: &[u8; HASH160_LENGTH],
+    ) -> String {
+        // This should not panic, as all known versions are less than 32.
+        encode_check_prefixed(hash160, PREFIX, version(network, kind)).unwrap()
     }
-}
 
-impl<const N: usize> Buffer<N, false, en::Default> {
-    /// Encodes a byte array into a [`Buffer`].
+    /// Decodes a Stacks address into its parts and `hash160`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::MissingPrefix`], the input does not start with [`PREFIX`].
+    /// - [`Error::InvalidCharacter`], the input contains invalid characters.
+    /// - [`Error::InsufficientData`], the input has fewer bytes than required.
+    /// - [`Error::ChecksumMismatch`], the checksum's do not match.
+    /// - [`Error::InvalidVersion`], the version is not a known address version.
+    /// - [`Error::InvalidDataSize`], the payload is not [`HASH160_LENGTH`]
+    ///   bytes.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::Buffer;
+    /// # use c32::Error;
+    /// use c32::address::AddressKind;
+    /// use c32::address::Network;
+    /// use c32::address::{self};
     ///
-    /// const INPUT: [u8; 3] = [42, 42, 42];
-    /// const EN: Buffer<5> = Buffer::<5>::encode(&INPUT);
-    /// assert_eq!(EN.as_str(), "2MAHA");
+    /// let (network, kind, hash160) =
+    ///     address::decode_address("SP000000000000000000002Q6VF78")?;
+    /// assert_eq!(network, Network::Mainnet);
+    /// assert_eq!(kind, AddressKind::SingleSig);
+    /// assert_eq!(hash160, [0u8; 20]);
+    /// # Ok::<(), Error>(())
     /// ```
     #[inline]
-    #[must_use]
-    pub const fn encode<const M: usize>(src: &[u8; M]) -> Self {
-        const { assert!(N >= encoded_len(M), "Size 'N' is too small") }
-
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
+    #[cfg(feature = "alloc")]
+    pub fn decode_address(
+        str: &str,
+    ) -> Result<(Network, AddressKind, [u8; HASH160_LENGTH])> {
+        let (bytes, version) = decode_check_prefixed(str, PREFIX)?;
+
+        // Assert that the version is a known address version.
+        let Some((network, kind)) = from_version(version) else {
+            return Err(Error::InvalidVersion {
+                expected: "must be a known Stacks address version",
+                version,
+            });
+        };
 
-        // Encode the input into the buffer.
-        let __pos = __internal::en(src, 0, M, &mut __raw, 0, None);
+        // Assert that the payload is a 'hash160'.
+        let hash160 = <[u8; HASH160_LENGTH]>::try_from(bytes.as_slice())
+            .map_err(|_| Error::InvalidDataSize {
+                expected: HASH160_LENGTH,
+                got: bytes.len(),
+            })?;
 
-        Self::new(__raw, __pos)
+        Ok((network, kind, hash160))
     }
+}
 
-    /// Encodes a byte array into a [`Buffer`].
+/// This module provides encoding and decoding helpers for Stacks addresses.
+///
+/// An address is the canonical [SIP-005] format: the `S` prefix, the version
+/// character and the Crockford Base32Check encoding of a 20-byte `hash160`.
+/// The versions are restricted to the known set of [`address`], unless the
+/// `_any_version` functions are used.
+///
+/// The known versions are also provided as [`Version`] constants, such as
+/// [`MAINNET_SINGLESIG`](stacks::MAINNET_SINGLESIG), for the `_versioned`
+/// functions, e.g. [`encode_check_prefixed_versioned`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use c32::stacks;
+///
+/// let addr = stacks::encode_address(22, &[0u8; 20])?;
+/// assert_eq!(addr, "SP000000000000000000002Q6VF78");
+/// # }
+/// # Ok::<(), c32::Error>(())
+/// ```
+///
+/// [SIP-005]: https://github.com/stacksgov/sips/blob/main/sips/sip-005/sip-005-blocks-and-transactions.md
+#[cfg(feature = "stacks")]
+pub mod stacks {
+    use address::HASH160_LENGTH;
+    use address::PREFIX;
+
+    use super::*;
+
+    /// The [`Version`] of a mainnet single-signature address (`SP`).
+    pub const MAINNET_SINGLESIG: Version =
+        Version(address::ADDR_VERSION_MAINNET_SINGLESIG);
+
+    /// The [`Version`] of a mainnet multi-signature address (`SM`).
+    pub const MAINNET_MULTISIG: Version =
+        Version(address::ADDR_VERSION_MAINNET_MULTISIG);
+
+    /// The [`Version`] of a testnet single-signature address (`ST`).
+    pub const TESTNET_SINGLESIG: Version =
+        Version(address::ADDR_VERSION_TESTNET_SINGLESIG);
+
+    /// The [`Version`] of a testnet multi-signature address (`SN`).
+    pub const TESTNET_MULTISIG: Version =
+        Version(address::ADDR_VERSION_TESTNET_MULTISIG);
+
+    /// The maximum length of an address in bytes.
+    pub const ADDRESS_MAX_LENGTH: usize = 1 + encoded_check_len(HASH160_LENGTH);
+
+    /// Encodes a `hash160` into a Stacks address.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::InvalidVersion`], the version is not a known address version,
+    ///   see [`address::from_version`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::Buffer;
-    /// use c32::Error;
+    /// # use c32::Error;
+    /// use c32::stacks;
     ///
-    /// let input = [42, 42, 42];
-    /// let en = Buffer::<5>::try_encode(&input)?;
-    /// assert_eq!(en.as_str(), "2MAHA");
+    /// let addr = stacks::encode_address(26, &[0u8; 20])?;
+    /// assert_eq!(addr, "ST000000000000000000002AMW42H");
+    ///
+    /// let err = stacks::encode_address(0, &[0u8; 20]).unwrap_err();
+    /// assert!(matches!(err, Error::InvalidVersion { version: 0, .. }));
     /// # Ok::<(), Error>(())
     /// ```
     #[inline]
-    pub const fn try_encode<const M: usize>(src: &[u8; M]) -> Result<Self> {
-        // Assert that the buffer has enough capacity.
-        let capacity = encoded_len(M);
-        if N < capacity {
-            return Err(Error::BufferTooSmall {
-                min: capacity,
-                len: N,
-            });
-        }
-
-        Ok(Self::encode(src))
+    #[cfg(feature = "alloc")]
+    pub fn encode_address(
+        version: u8,
+        hash160: &[u8; HASH160_LENGTH],
+    ) -> Result<String> {
+        known_version(version)?;
+        encode_address_any_version(version, hash160)
     }
 
-    /// Decodes a slice of encoded bytes into a [`Buffer`].
+    /// Encodes a `hash160` into a Stacks address, with any version below 32.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::InvalidVersion`], the version is 32 or greater.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::Buffer;
+    /// # use c32::Error;
+    /// use c32::stacks;
     ///
-    /// const INPUT: [u8; 5] = *b"2MAHA";
-    /// const DE: Buffer<5> = Buffer::<5>::decode(&INPUT);
-    /// assert_eq!(DE.as_bytes(), [42, 42, 42]);
+    /// let addr = stacks::encode_address_any_version(0, &[0u8; 20])?;
+    /// assert_eq!(addr, "S0000000000000000000002AA028H");
+    /// # Ok::<(), Error>(())
     /// ```
     #[inline]
-    #[must_use]
-    pub const fn decode(src: &[u8]) -> Self {
-        assert!(N >= decoded_len(src.len()), "Size 'N' is too small");
-
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
-
-        // Decode the input to the buffer.
-        let __pos = match __internal::de(src, 0, src.len(), &mut __raw, 0) {
-            Ok(pos) => pos,
-            Err(Error::InvalidCharacter { char: _, index: _ }) => {
-                panic!("Input contains invalid characters")
-            }
-            _ => unreachable!(),
-        };
-
-        Self::new(__raw, __pos)
+    #[cfg(feature = "alloc")]
+    pub fn encode_address_any_version(
+        version: u8,
+        hash160: &[u8; HASH160_LENGTH],
+    ) -> Result<String> {
+        encode_check_prefixed(hash160, PREFIX, version)
     }
 
-    /// Decodes a slice of encoded bytes into a [`Buffer`].
+    /// Encodes a `hash160` into a Stacks address in a provided buffer.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to the output buffer.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::InvalidVersion`], the version is not a known address version,
+    ///   see [`address::from_version`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use c32::Error;
-    /// use c32::Buffer;
+    /// use c32::stacks;
     ///
-    /// let input = b"2MAHA";
-    /// let de = Buffer::<5>::try_decode(input)?;
-    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
+    /// let mut dst = [0u8; stacks::ADDRESS_MAX_LENGTH];
+    /// let offset = stacks::encode_address_into(22, &[0u8; 20], &mut dst)?;
+    /// assert_eq!(&dst[..offset], b"SP000000000000000000002Q6VF78");
     /// # Ok::<(), Error>(())
     /// ```
     #[inline]
-    pub const fn try_decode(src: &[u8]) -> Result<Self> {
-        // Assert that the buffer has enough capacity.
-        let capacity = decoded_len(src.len());
-        if N < capacity {
-            return Err(Error::BufferTooSmall {
-                min: capacity,
-                len: N,
-            });
-        }
+    pub fn encode_address_into(
+        version: u8,
+        hash160: &[u8; HASH160_LENGTH],
+        dst: &mut [u8; ADDRESS_MAX_LENGTH],
+    ) -> Result<usize> {
+        known_version(version)?;
+        encode_address_any_version_into(version, hash160, dst)
+    }
 
-        Ok(Self::decode(src))
+    /// Encodes a `hash160` into a Stacks address in a provided buffer, with
+    /// any version below 32.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to the output buffer.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::InvalidVersion`], the version is 32 or greater.
+    #[inline]
+    pub fn encode_address_any_version_into(
+        version: u8,
+        hash160: &[u8; HASH160_LENGTH],
+        dst: &mut [u8; ADDRESS_MAX_LENGTH],
+    ) -> Result<usize> {
+        encode_check_prefixed_into(hash160, dst, PREFIX, version)
     }
-}
 
-impl<const N: usize> Buffer<N, true, en::Default> {
-    /// Encodes a byte array with a prefix into a [`Buffer`].
+    /// Decodes a Stacks address into its version and `hash160`.
+    ///
+    /// The address is decoded into a stack buffer, so this does not
+    /// allocate.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::MissingPrefix`], the input does not start with [`PREFIX`].
+    /// - [`Error::InvalidCharacter`], the input contains invalid characters.
+    /// - [`Error::InsufficientData`], the input has fewer bytes than required.
+    /// - [`Error::ChecksumMismatch`], the checksum's do not match.
+    /// - [`Error::InvalidVersion`], the version is not a known address version,
+    ///   see [`address::from_version`].
+    /// - [`Error::InvalidDataSize`], the payload is not [`HASH160_LENGTH`]
+    ///   bytes.
+    ///
+    /// # Notes
+    ///
+    /// - Input longer than [`ADDRESS_MAX_LENGTH`] is rejected with
+    ///   [`Error::InvalidDataSize`] before the characters are validated.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::Buffer;
+    /// # use c32::Error;
+    /// use c32::stacks;
     ///
-    /// const INPUT: [u8; 3] = [42, 42, 42];
-    /// const EN: Buffer<6, true> = Buffer::<6, true>::encode(&INPUT, 'S');
-    /// assert_eq!(EN.as_str(), "S2MAHA");
+    /// let (version, hash160) =
+    ///     stacks::decode_address("SP000000000000000000002Q6VF78")?;
+    /// assert_eq!(version, 22);
+    /// assert_eq!(hash160, [0u8; 20]);
+    ///
+    /// let err = stacks::decode_address("S0000000000000000000002AA028H");
+    /// assert!(matches!(err, Err(Error::InvalidVersion { version: 0, .. })));
+    /// # Ok::<(), Error>(())
     /// ```
     #[inline]
-    #[must_use]
-    pub const fn encode<const M: usize>(src: &[u8; M], prefix: char) -> Self {
-        const { assert!(N > encoded_len(M), "Size 'N' is too small") }
-        assert!(prefix.is_ascii(), "Prefix must be an ASCII character");
-
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
-
-        // Prepend the prefix character.
-        __raw[0] = prefix as u8;
-
-        // Encode the input to the buffer.
-        let __pos = __internal::en(src, 0, M, &mut __raw, 1, None) + 1;
-
-        Self::new(__raw, __pos)
+    pub fn decode_address(str: &str) -> Result<(u8, [u8; HASH160_LENGTH])> {
+        decode(str, true)
     }
 
-    /// Encodes a byte array with a prefix into a [`Buffer`].
+    /// Decodes a Stacks address into its version and `hash160`, with any
+    /// version below 32.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::MissingPrefix`], the input does not start with [`PREFIX`].
+    /// - [`Error::InvalidCharacter`], the input contains invalid characters.
+    /// - [`Error::InsufficientData`], the input has fewer bytes than required.
+    /// - [`Error::InvalidVersion`], the version is 32 or greater.
+    /// - [`Error::ChecksumMismatch`], the checksum's do not match.
+    /// - [`Error::InvalidDataSize`], the payload is not [`HASH160_LENGTH`]
+    ///   bytes.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use c32::Error;
-    /// use c32::Buffer;
+    /// use c32::stacks;
     ///
-    /// let input = [42, 42, 42];
-    /// let en = Buffer::<6, true>::try_encode(&input, 'S')?;
-    /// assert_eq!(en.as_str(), "S2MAHA");
+    /// let (version, hash160) =
+    ///     stacks::decode_address_any_version("S0000000000000000000002AA028H")?;
+    /// assert_eq!(version, 0);
+    /// assert_eq!(hash160, [0u8; 20]);
     /// # Ok::<(), Error>(())
     /// ```
     #[inline]
-    pub const fn try_encode<const M: usize>(
-        src: &[u8; M],
-        prefix: char,
-    ) -> Result<Self> {
-        const { assert!(N > encoded_len(M), "Size 'N' is too small") }
+    pub fn decode_address_any_version(
+        str: &str,
+    ) -> Result<(u8, [u8; HASH160_LENGTH])> {
+        decode(str, false)
+    }
 
-        // Assert that the prefix is ASCII.
-        if !prefix.is_ascii() {
-            return Err(Error::InvalidCharacter {
-                char: prefix,
-                index: 0,
+    /// Decodes a Stacks address, optionally asserting a known version.
+    fn decode(str: &str, known: bool) -> Result<(u8, [u8; HASH160_LENGTH])> {
+        let src = str.as_bytes();
+
+        // Reject a payload too long for the buffer before decoding it. This
+        // always exceeds the limit, as 40 or more characters after the
+        // version decode to at least 25 bytes.
+        if src.len() > ADDRESS_MAX_LENGTH && src[0] == PREFIX as u8 {
+            if let Some(got) = __internal::exceeds_limit::<{ __internal::STRICT }>(
+                &src[2..],
+                checksum::BYTE_LENGTH,
+                HASH160_LENGTH,
+            ) {
+                return Err(Error::InvalidDataSize {
+                    expected: HASH160_LENGTH,
+                    got,
+                });
+            }
+        }
+
+        // Decode and verify the address.
+        let mut dst = [0u8; ADDRESS_MAX_LENGTH];
+        let (offset, version) =
+            decode_check_prefixed_into(src, &mut dst, PREFIX)?;
+
+        if known {
+            known_version(version)?;
+        }
+
+        // Assert that the payload is a 'hash160'.
+        if offset != HASH160_LENGTH {
+            return Err(Error::InvalidDataSize {
+                expected: HASH160_LENGTH,
+                got: offset,
             });
         }
 
-        Ok(Self::encode(src, prefix))
+        let mut hash160 = [0u8; HASH160_LENGTH];
+        hash160.copy_from_slice(&dst[..HASH160_LENGTH]);
+        Ok((version, hash160))
     }
 
-    /// Decodes a slice of prefixed encoded bytes into a [`Buffer`].
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use c32::Buffer;
-    ///
-    /// const INPUT: [u8; 6] = *b"S2MAHA";
-    /// const DE: Buffer<6, true> = Buffer::<6, true>::decode(&INPUT, 'S');
-    /// assert_eq!(DE.as_bytes(), [42, 42, 42]);
-    /// ```
-    #[inline]
-    #[must_use]
-    pub const fn decode(src: &[u8], prefix: char) -> Self {
-        assert!(N >= decoded_len(src.len() - 1), "Size 'N' is too small");
-        assert!(prefix.is_ascii(), "Prefix must be an ASCII character");
-        assert!(!src.is_empty(), "Input must contain min. 1 character");
-        assert!(src[0] == prefix as u8, "Input must start with prefix");
+    /// Asserts that the version is a known address version.
+    fn known_version(version: u8) -> Result<()> {
+        match address::from_version(version) {
+            Some(_) => Ok(()),
+            None => Err(Error::InvalidVersion {
+                expected: "must be a known Stacks address version",
+                version,
+            }),
+        }
+    }
+}
 
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
+/// The Crockford Base32 alphabet used for encoding and decoding.
+pub(crate) const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
 
-        // Decode the input (without prefix) to the buffer.
-        let __pos = match __internal::de(src, 1, src.len() - 1, &mut __raw, 0) {
-            Ok(pos) => pos,
-            Err(Error::InvalidCharacter { char: _, index: _ }) => {
-                panic!("Input contains invalid characters")
-            }
-            _ => unreachable!(),
-        };
+/// The map value of bytes outside of an alphabet.
+pub(crate) const INVALID: u8 = 0xFF;
 
-        Self::new(__raw, __pos)
+/// A mapping from bytes to their Crockford Base32 values.
+pub(crate) const BYTE_MAP: [u8; 256] = __internal::crockford_map(ALPHABET);
+
+// Assert that every character of the alphabet maps back to its value.
+const _: () = {
+    let mut i = 0;
+    while i < ALPHABET.len() {
+        let byte = ALPHABET[i];
+        assert!(BYTE_MAP[byte as usize] as usize == i);
+        assert!(BYTE_MAP[byte.to_ascii_lowercase() as usize] as usize == i);
+        i += 1;
     }
+};
 
-    /// Decodes a slice of prefixed encoded bytes into a [`Buffer`].
+/// Alphabets used for encoding and decoding.
+///
+/// # Examples
+///
+/// ```rust
+/// use c32::alphabet::Alphabet;
+/// use c32::alphabet::{self};
+///
+/// const LOWER: Alphabet = Alphabet::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+/// assert_eq!(LOWER.as_bytes()[10], b'a');
+/// assert_eq!(alphabet::CROCKFORD.as_bytes()[10], b'A');
+/// ```
+pub mod alphabet {
+    use super::*;
+
+    /// The standard Crockford Base32 alphabet.
     ///
-    /// # Examples
+    /// # Notes
     ///
-    /// ```rust
-    /// # use c32::Error;
-    /// use c32::Buffer;
+    /// - Decoding is case-insensitive and accepts `I`, `L` and `O` as aliases.
+    pub const CROCKFORD: Alphabet = Alphabet {
+        chars: *ALPHABET,
+        map: BYTE_MAP,
+    };
+
+    /// The z-base-32 alphabet.
     ///
-    /// let input = b"S2MAHA";
-    /// let de = Buffer::<6, true>::try_decode(input, 'S')?;
-    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
-    /// # Ok::<(), Error>(())
-    /// ```
-    #[inline]
-    pub const fn try_decode(src: &[u8], prefix: char) -> Result<Self> {
-        // Assert that the input is not empty.
-        if src.is_empty() {
-            return Err(Error::MissingPrefix {
-                char: prefix,
-                got: None,
-            });
-        }
+    /// # Notes
+    ///
+    /// - Only the character set follows z-base-32; bytes are still packed as a
+    ///   big-endian number with leading zero bytes preserved. The output
+    ///   matches the z-base-32 bit stream when the input length is a multiple
+    ///   of 5 bytes and the first byte is at least `0x08`.
+    /// - Decoding is case-sensitive and only accepts lowercase characters.
+    pub const ZBASE32: Alphabet =
+        Alphabet::new(b"ybndrfg8ejkmcpqxot1uwisza345h769");
+
+    /// A 32-character alphabet with its decoding map.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Alphabet {
+        /// The characters used for encoding, indexed by value.
+        chars: [u8; 32],
+        /// A mapping from bytes to their values, or [`INVALID`].
+        map: [u8; 256],
+    }
 
-        // Assert that the prefix is ASCII.
-        if !prefix.is_ascii() {
-            return Err(Error::InvalidCharacter {
-                char: prefix,
-                index: 0,
-            });
-        }
+    impl Alphabet {
+        /// Creates a new [`Alphabet`] from 32 characters.
+        ///
+        /// The decoding map is derived from the characters and matches
+        /// them exactly, without case folding or aliases.
+        ///
+        /// # Panics
+        ///
+        /// This method will panic if any character is not ASCII or occurs
+        /// more than once. In a const context this fails the build.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use c32::alphabet::Alphabet;
+        ///
+        /// const LOWER: Alphabet = Alphabet::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+        /// assert_eq!(LOWER.as_bytes()[31], b'z');
+        /// ```
+        ///
+        /// Duplicate characters are rejected at compile time:
+        ///
+        /// ```compile_fail
+        /// use c32::alphabet::Alphabet;
+        ///
+        /// const DUPLICATE: Alphabet =
+        ///     Alphabet::new(b"0023456789abcdefghjkmnpqrstvwxyz");
+        /// ```
+        ///
+        /// As are non-ASCII characters:
+        ///
+        /// ```compile_fail
+        /// use c32::alphabet::Alphabet;
+        ///
+        /// const NON_ASCII: Alphabet =
+        ///     Alphabet::new(b"\xFF123456789abcdefghjkmnpqrstvwxyz");
+        /// ```
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub const fn new(chars: &[u8; 32]) -> Self {
+            let mut map = [INVALID; 256];
+
+            let mut i = 0;
+            while i < chars.len() {
+                let byte = chars[i];
+
+                // Assert that the character is ASCII and unique.
+                assert!(byte < 128, "Alphabet characters must be ASCII");
+                assert!(
+                    map[byte as usize] == INVALID,
+                    "Alphabet characters must be unique"
+                );
+
+                map[byte as usize] = i as u8;
+                i += 1;
+            }
 
-        // Assert that the string starts with the prefix.
-        if src[0] != prefix as u8 {
-            return Err(Error::MissingPrefix {
-                char: prefix,
-                got: Some(src[0] as char),
-            });
+            Self { chars: *chars, map }
         }
 
-        // Assert that the buffer has enough capacity.
-        let capacity = decoded_len(src.len() - 1); // Always 1 for ASCII prefixes
-        if N < capacity {
-            return Err(Error::BufferTooSmall {
-                min: capacity,
-                len: N,
-            });
+        /// Returns the characters of the alphabet, indexed by value.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use c32::alphabet;
+        ///
+        /// let chars = alphabet::CROCKFORD.as_bytes();
+        /// assert_eq!(chars, b"0123456789ABCDEFGHJKMNPQRSTVWXYZ");
+        /// ```
+        #[inline]
+        #[must_use]
+        pub const fn as_bytes(&self) -> &[u8; 32] {
+            &self.chars
         }
 
-        // Allocate the output buffer
-        let mut __raw = [0u8; N];
+        /// Returns the character for a 5-bit value.
+        #[inline]
+        #[must_use]
+        pub(crate) const fn char(&self, value: usize) -> u8 {
+            self.chars[value]
+        }
 
-        // Decode the input into the buffer.
-        let __pos = match __internal::de(src, 1, src.len() - 1, &mut __raw, 0) {
-            Ok(pos) => pos,
-            Err(Error::InvalidCharacter { char, index }) => {
-                return Err(Error::InvalidCharacter {
-                    char,
-                    index: index + 1,
-                });
-            }
-            Err(e) => return Err(e),
-        };
+        /// Returns the value of a character, or [`INVALID`] if the
+        /// character is not part of the alphabet.
+        #[inline]
+        #[must_use]
+        pub(crate) const fn value(&self, byte: u8) -> u8 {
+            self.map[byte as usize]
+        }
+    }
 
-        Ok(Self::new(__raw, __pos))
+    impl Default for Alphabet {
+        #[inline]
+        fn default() -> Self {
+            CROCKFORD
+        }
     }
 }
 
-#[cfg(feature = "check")]
-impl<const N: usize> Buffer<N, false, en::Check> {
-    /// Encodes a byte array with a checksum into a [`Buffer`].
+/// Error variants for fallible Crockford Base32 operations.
+///
+/// # Notes
+///
+/// - This enum is `#[non_exhaustive]`, so matches on it require a wildcard arm.
+///   Fields may also be added to the variants, so patterns should use `..` for
+///   fields they do not bind, e.g. `Error::InvalidCharacter { char, index, ..
+///   }`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Error {
+    /// The buffer size is insufficient for the operation.
     ///
-    /// # Examples
+    /// # Fields
     ///
-    /// ```rust,no_fmt
-    /// use c32::en::Check;
-    /// use c32::Buffer;
+    /// * `min` - The minimum required buffer size.
+    /// * `len` - The actual size of the provided buffer.
+    BufferTooSmall { min: usize, len: usize },
+    /// The input data size doesn't match the expected size.
     ///
-    /// const INPUT: [u8; 3] = [42, 42, 42];
-    /// const EN: Buffer<13, false, Check> = Buffer::<13, false, Check>::encode(&INPUT, 0);
-    /// assert_eq!(EN.as_str(), "0AHA59B9201Z");
-    /// ```
-    #[inline]
-    #[must_use]
-    pub const fn encode<const M: usize>(src: &[u8; M], version: u8) -> Self {
-        const { assert!(N >= encoded_check_len(M), "Size 'N' is too small") }
-        assert!(version < 32, "Version must be < 32");
-
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
-
-        // Prepend the version character.
-        __raw[0] = ALPHABET[version as usize];
-
-        // Compute the checksum.
-        let sum = checksum::compute(src, version);
-
-        // Encode the input and checksum to the buffer.
-        let __pos = __internal::en(src, 0, M, &mut __raw, 1, Some(sum)) + 1;
-
-        Self::new(__raw, __pos)
-    }
+    /// # Fields
+    ///
+    /// * `expected` - The expected data size in bytes.
+    /// * `got` - The actual size of the provided data.
+    InvalidDataSize { expected: usize, got: usize },
+    /// An invalid character was encountered during decoding.
+    ///
+    /// # Fields
+    ///
+    /// * `char` - The invalid character found in the input.
+    /// * `index` - The byte index of the character, see
+    ///   [`Error::invalid_char_position`] for its `char` index.
+    /// * `decoded` - The number of leading output bytes which the characters
+    ///   before `index` decode to, which is what a streaming decoder writes
+    ///   before it stops. Check strings count from the byte after the version.
+    InvalidCharacter {
+        char: char,
+        index: usize,
+        decoded: usize,
+    },
+    /// The expected prefix character is missing.
+    ///
+    /// # Fields
+    ///
+    /// * `char` - The expected prefix character.
+    /// * `got` - The actual first character found.
+    MissingPrefix { char: char, got: Option<char> },
+    /// The framing of the decoded data is malformed.
+    ///
+    /// # Fields
+    ///
+    /// * `field` - The index of the malformed field.
+    /// * `reason` - A description of the malformation.
+    InvalidFrame { field: usize, reason: &'static str },
+    /// The decoded output would exceed the maximum length.
+    ///
+    /// # Fields
+    ///
+    /// * `max` - The maximum output length in bytes.
+    /// * `got` - The length the output would have in bytes.
+    OutputTooLarge { max: usize, got: usize },
+    /// The `=` padding of a block-encoded input is malformed.
+    ///
+    /// # Fields
+    ///
+    /// * `index` - The byte index of the offending character, or the input
+    ///   length if the final block is incomplete.
+    InvalidPadding { index: usize },
+    /// An allocation of the output failed.
+    ///
+    /// # Fields
+    ///
+    /// * `requested` - The requested capacity in bytes, or `usize::MAX` if it
+    ///   overflows.
+    AllocationFailed { requested: usize },
+    #[cfg(feature = "check-core")]
+    /// The provided version byte is invalid.
+    ///
+    /// # Fields
+    ///
+    /// * `expected` - The expected version constraints.
+    /// * `version` - The invalid version byte.
+    ///
+    /// # Notes
+    ///
+    /// - Code written against the tuple form `InvalidVersion(u8)` should match
+    ///   `InvalidVersion { version, .. }`, which binds the same byte. The
+    ///   message is rendered as `Invalid version {version}: {expected}`.
+    InvalidVersion { expected: &'static str, version: u8 },
+    /// The input has fewer bytes than are required.
+    ///
+    /// # Fields
+    ///
+    /// * `min` - The minimum required amount of bytes.
+    /// * `len` - The actual number of bytes provided.
+    #[cfg(feature = "check-core")]
+    InsufficientData { min: usize, len: usize },
+    /// The computed checksum does not match the expected sum.
+    ///
+    /// # Fields
+    ///
+    /// * `expected` - The expected checksum.
+    /// * `got` - The actual checksum.
+    /// * `version` - The decoded version byte.
+    /// * `len` - The length of the decoded payload in bytes.
+    #[cfg(feature = "check-core")]
+    ChecksumMismatch {
+        expected: checksum::Checksum,
+        got: checksum::Checksum,
+        version: u8,
+        len: usize,
+    },
+}
 
-    /// Encodes a byte array with a checksum into a [`Buffer`].
+impl Error {
+    /// The maximum length of the rendered error in bytes.
+    ///
+    /// This bounds the output of [`Error::write_to`] and the [`Display`]
+    /// implementation for every error produced by this crate, including
+    /// fields of `usize::MAX`, so it can be rendered into a fixed buffer.
+    ///
+    /// # Notes
+    ///
+    /// - The `reason` and `expected` fields are messages of this crate. An
+    ///   error constructed with a longer message may exceed the bound.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use c32::en::Check;
-    /// use c32::Buffer;
     /// use c32::Error;
     ///
-    /// let input = [42, 42, 42];
-    /// let en = Buffer::<13, false, Check>::try_encode(&input, 0)?;
-    /// assert_eq!(en.as_str(), "0AHA59B9201Z");
-    /// # Ok::<(), Error>(())
+    /// let error = Error::BufferTooSmall {
+    ///     min: usize::MAX,
+    ///     len: usize::MAX,
+    /// };
+    /// assert!(error.to_string().len() <= Error::MAX_DISPLAY_LEN);
     /// ```
-    #[inline]
-    pub const fn try_encode<const M: usize>(
-        src: &[u8; M],
-        version: u8,
-    ) -> Result<Self> {
-        const { assert!(N >= encoded_check_len(M), "Size 'N' is too small") }
-
-        // Assert that the version is valid (< 32).
-        if version >= 32 {
-            return Err(Error::InvalidVersion {
-                expected: "must be < 32",
-                version,
-            });
-        }
-
-        Ok(Self::encode(src, version))
-    }
+    ///
+    /// [`Display`]: fmt::Display
+    pub const MAX_DISPLAY_LEN: usize = 64;
 
-    /// Decodes a slice of check-encoded bytes into a [`Buffer`].
+    /// Writes the human-readable error message to `w`.
     ///
-    /// # Examples
+    /// This is the `#![no_std]` friendly counterpart of the [`Display`]
+    /// implementation, and writes at most [`Error::MAX_DISPLAY_LEN`] bytes.
     ///
-    /// ```rust,no_fmt
-    /// use c32::Buffer;
-    /// use c32::en::Check;
+    /// # Errors
     ///
-    /// const INPUT: [u8; 12] = *b"0AHA59B9201Z";
-    /// const RESULT: (Buffer<12, false, Check>, u8) = Buffer::<12, false, Check>::decode(&INPUT);
-    /// assert_eq!(RESULT.0.as_bytes(), [42, 42, 42]);
-    /// assert_eq!(RESULT.1, 0);
-    // ```
-    #[inline]
-    #[must_use]
-    pub const fn decode(src: &[u8]) -> (Self, u8) {
-        assert!(N >= decoded_check_len(src.len()), "Size 'N' is too small");
-        assert!(src.len() >= 2, "Input must contain min. 2 characters");
-
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
-
-        // Extract the version byte
-        let mut buffer = [0u8; 1];
-        let _ = match __internal::de(&[src[0]], 0, 1, &mut buffer, 0) {
-            Ok(pos) => pos,
-            Err(Error::InvalidCharacter { char: _, index: _ }) => {
-                panic!("Input must not contain invalid characters")
+    /// This method will return an [`fmt::Error`] if writing to `w` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Error;
+    ///
+    /// # fn main() -> core::fmt::Result {
+    /// let error = Error::InvalidCharacter {
+    ///     char: '!',
+    ///     index: 2,
+    ///     decoded: 1,
+    /// };
+    ///
+    /// let mut message = String::new();
+    /// error.write_to(&mut message)?;
+    /// assert_eq!(message, "Invalid character '!' at position 2");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Display`]: fmt::Display
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall { min, len } => {
+                write!(w, "Buffer too small: {len} < {min}")
             }
-            _ => unreachable!(),
-        };
-
-        // Assert that the version is valid (< 32).
-        let version = buffer[0];
-        assert!(version < 32, "Version must be < 32");
-
-        // Decode the remaining bytes into the output buffer.
-        let __pos = match __internal::de(src, 1, src.len() - 1, &mut __raw, 0) {
-            Ok(pos) => pos,
-            Err(Error::InvalidCharacter { char: _, index: _ }) => {
-                panic!("Input must not contain invalid characters")
+            Self::InvalidDataSize { expected, got } => {
+                write!(w, "Invalid data size: {got} != {expected}")
             }
-            _ => unreachable!(),
-        };
-
-        let __pos = __pos - checksum::BYTE_LENGTH;
-
-        // Extract the checksum.
-        let mut sum = [0u8; checksum::BYTE_LENGTH];
-        __internal::memcpy(&mut sum, 0, &__raw, __pos, checksum::BYTE_LENGTH);
-
-        // Compute the expected checksum.
-        let mut expected = [0u8; checksum::BYTE_LENGTH];
-        __internal::memcpy(&mut expected, 0, &__raw, __pos, 4);
-
-        // Assert that the computed and actual checksums match.
-        assert!(__internal::memcmp(&expected, &sum, 4), "Checksum mismatch");
-
-        (Self::new(__raw, __pos), version)
+            Self::InvalidCharacter { char, index, .. } => {
+                write!(w, "Invalid character '{char}' at position {index}")
+            }
+            Self::MissingPrefix {
+                char,
+                got: Some(got),
+            } => {
+                write!(w, "Expected prefix '{char}', found '{got}'")
+            }
+            Self::MissingPrefix { char, got: None } => {
+                write!(w, "Expected prefix '{char}', found nothing")
+            }
+            Self::InvalidFrame { field, reason } => {
+                write!(w, "Frame field {field}: {reason}")
+            }
+            Self::OutputTooLarge { max, got } => {
+                write!(w, "Output too large: {got} > {max}")
+            }
+            Self::InvalidPadding { index } => {
+                write!(w, "Invalid padding at position {index}")
+            }
+            Self::AllocationFailed { requested } => {
+                write!(w, "Allocation failed: {requested} bytes")
+            }
+            #[cfg(feature = "check-core")]
+            Self::InvalidVersion { expected, version } => {
+                write!(w, "Invalid version {version}: {expected}")
+            }
+            #[cfg(feature = "check-core")]
+            Self::InsufficientData { min, len } => {
+                write!(w, "Insufficient data: {len} < {min}")
+            }
+            #[cfg(feature = "check-core")]
+            Self::ChecksumMismatch { expected, got, .. } => {
+                let [e0, e1, e2, e3] = expected;
+                let [g0, g1, g2, g3] = got;
+                write!(
+                    w,
+                    "Checksum mismatch: expected {e0:02x}{e1:02x}{e2:02x}{e3:02x}, \
+                     got {g0:02x}{g1:02x}{g2:02x}{g3:02x}"
+                )
+            }
+        }
     }
 
-    /// Decodes a slice of check-encoded bytes into a [`Buffer`].
+    /// Returns a short, stable name of the error variant.
+    ///
+    /// The name is in `snake_case` and does not include any of the fields,
+    /// which makes it suitable as a low-cardinality label, e.g. for metrics.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use c32::Error;
-    /// use c32::en::Check;
-    /// use c32::Buffer;
-    ///
-    /// let input = b"0AHA59B9201Z";
-    /// let (de, version) = Buffer::<12, false, Check>::try_decode(input)?;
-    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
-    /// assert_eq!(version, 0);
-    /// # Ok::<(), Error>(())
+    /// let error = c32::decode("2M!HA").unwrap_err();
+    /// assert_eq!(error.kind(), "invalid_character");
     /// ```
     #[inline]
-    pub const fn try_decode(src: &[u8]) -> Result<(Self, u8)> {
-        // Assert that the buffer has enough capacity.
-        let capacity = decoded_check_len(src.len());
-        if N < capacity {
-            return Err(Error::BufferTooSmall {
-                min: capacity,
-                len: N,
-            });
-        }
-
-        // Assert that the input bytes contain the minimum amount.
-        if src.len() < 2 {
-            return Err(Error::InsufficientData {
-                min: 2,
-                len: src.len(),
-            });
-        }
-
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
-
-        // Extract the version byte
-        let mut buffer = [0u8; 1];
-        let _ = match __internal::de(&[src[0]], 0, 1, &mut buffer, 0) {
-            Ok(pos) => pos,
-            Err(err) => return Err(err),
-        };
-
-        // Assert that the version is valid (< 32).
-        let version = buffer[0];
-        if version >= 32 {
-            return Err(Error::InvalidVersion {
-                expected: "must be < 32",
-                version,
-            });
-        }
-
-        // Decode the remaining bytes into the output buffer.
-        let __pos = match __internal::de(src, 1, src.len() - 1, &mut __raw, 0) {
-            Ok(pos) => pos,
-            Err(Error::InvalidCharacter { char, index }) => {
-                return Err(Error::InvalidCharacter {
-                    char,
-                    index: index + 1,
-                });
-            }
-            Err(e) => return Err(e),
-        };
-
-        let __pos = __pos - checksum::BYTE_LENGTH;
-
-        // Extract the checksum.
-        let mut sum = [0u8; checksum::BYTE_LENGTH];
-        __internal::memcpy(&mut sum, 0, &__raw, __pos, checksum::BYTE_LENGTH);
-
-        // Compute the expected checksum.
-        let mut expected = [0u8; checksum::BYTE_LENGTH];
-        __internal::memcpy(&mut expected, 0, &__raw, __pos, 4);
-
-        // Assert that the computed and actual checksums match.
-        if !__internal::memcmp(&expected, &sum, checksum::BYTE_LENGTH) {
-            return Err(Error::ChecksumMismatch { expected, got: sum });
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::BufferTooSmall { .. } => "buffer_too_small",
+            Self::InvalidDataSize { .. } => "invalid_data_size",
+            Self::InvalidCharacter { .. } => "invalid_character",
+            Self::MissingPrefix { .. } => "missing_prefix",
+            Self::InvalidFrame { .. } => "invalid_frame",
+            Self::OutputTooLarge { .. } => "output_too_large",
+            Self::InvalidPadding { .. } => "invalid_padding",
+            Self::AllocationFailed { .. } => "allocation_failed",
+            #[cfg(feature = "check-core")]
+            Self::InvalidVersion { .. } => "invalid_version",
+            #[cfg(feature = "check-core")]
+            Self::InsufficientData { .. } => "insufficient_data",
+            #[cfg(feature = "check-core")]
+            Self::ChecksumMismatch { .. } => "checksum_mismatch",
         }
-
-        Ok((Self::new(__raw, __pos), version))
     }
-}
 
-#[cfg(feature = "check")]
-impl<const N: usize> Buffer<N, true, en::Check> {
-    /// Encodes a byte array with a checksum and prefix into a [`Buffer`].
+    /// Returns the stable numeric code of the error variant.
     ///
+    /// See [`ErrorCode`] for the assigned values, which are never reused.
     ///
     /// # Examples
     ///
-    /// ```rust,no_fmt
-    /// use c32::en::Check;
-    /// use c32::Buffer;
+    /// ```rust
+    /// use c32::ErrorCode;
     ///
-    /// const INPUT: [u8; 3] = [42, 42, 42];
-    /// const EN: Buffer<14, true, Check> = Buffer::<14, true, Check>::encode(&INPUT, 'S', 0);
-    /// assert_eq!(EN.as_str(), "S0AHA59B9201Z");
+    /// let error = c32::decode("2M!HA").unwrap_err();
+    /// assert_eq!(error.code(), 3);
+    /// assert_eq!(
+    ///     ErrorCode::try_from(error.code()),
+    ///     Ok(ErrorCode::InvalidCharacter)
+    /// );
     /// ```
     #[inline]
     #[must_use]
-    pub const fn encode<const M: usize>(
-        src: &[u8; M],
-        prefix: char,
-        version: u8,
-    ) -> Self {
-        const { assert!(N > encoded_check_len(M), "Size 'N' is too small") }
-        assert!(version < 32, "Version must be < 32");
-
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
-
-        // Prepend the prefix character.
-        __raw[0] = prefix as u8;
-
-        // Prepend the version character.
-        __raw[1] = ALPHABET[version as usize];
-
-        // Compute the checksum.
-        let sum = checksum::compute(src, version);
-
-        // Encode the input and checksum to the buffer.
-        let __pos = __internal::en(src, 0, M, &mut __raw, 2, Some(sum)) + 2;
-
-        Self::new(__raw, __pos)
+    pub const fn code(&self) -> u16 {
+        ErrorCode::of(self) as u16
     }
 
-    /// Encodes a byte array with a checksum and prefix into a [`Buffer`].
+    /// Returns the stable machine-readable name of the error variant.
+    ///
+    /// The name is in `SCREAMING_SNAKE_CASE` with a `C32_E_` prefix, for use
+    /// across languages, see [`kind`] for a label in `snake_case`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use c32::Error;
-    /// use c32::en::Check;
-    /// use c32::Buffer;
-    ///
-    /// let input = [42, 42, 42];
-    /// let en = Buffer::<14, true, Check>::try_encode(&input, 'S', 0)?;
-    /// assert_eq!(en.as_str(), "S0AHA59B9201Z");
-    /// # Ok::<(), Error>(())
+    /// let error = c32::decode("2M!HA").unwrap_err();
+    /// assert_eq!(error.name(), "C32_E_INVALID_CHARACTER");
     /// ```
+    ///
+    /// [`kind`]: Error::kind
     #[inline]
-    pub const fn try_encode<const M: usize>(
-        src: &[u8; M],
-        prefix: char,
-        version: u8,
-    ) -> Result<Self> {
-        const { assert!(N > encoded_check_len(M), "Size 'N' is too small") }
-
-        // Assert that the version is valid (< 32).
-        if version >= 32 {
-            return Err(Error::InvalidVersion {
-                expected: "must be < 32",
-                version,
-            });
-        }
-
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
-
-        // Prepend the prefix character.
-        __raw[0] = prefix as u8;
-
-        // Prepend the version character.
-        __raw[1] = ALPHABET[version as usize];
-
-        // Compute the checksum.
-        let sum = checksum::compute(src, version);
-
-        // Encode the input and checksum to the buffer.
-        let __pos = __internal::en(src, 0, M, &mut __raw, 2, Some(sum)) + 2;
-
-        Ok(Self::new(__raw, __pos))
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        ErrorCode::of(self).name()
     }
 
-    /// Decodes a slice of prefixed check-encoded bytes into a [`Buffer`].
+    /// Returns the `char` index of an [`Error::InvalidCharacter`] within the
+    /// `original` input.
     ///
-    /// # Examples
+    /// The `index` field is a byte offset, which a multi-byte prefix shifts
+    /// past the visible position of the character. This maps it onto the
+    /// `char` positions of `original`, e.g. to point at it in a terminal.
     ///
-    /// ```rust,no_fmt
-    /// use c32::en::Check;
-    /// use c32::Buffer;
+    /// Returns `None` for any other variant, or if the byte index is not a
+    /// `char` boundary of `original`.
     ///
-    /// const INPUT: [u8; 13] = *b"S0AHA59B9201Z";
-    /// const RESULT: (Buffer<14, true, Check>, u8) = Buffer::<14, true, Check>::decode(&INPUT, 'S');
-    /// assert_eq!(RESULT.0.as_bytes(), [42, 42, 42]);
-    /// assert_eq!(RESULT.1, 0);
+    /// # Examples
+    ///
+    /// ```rust
+    /// let error = c32::decode_prefixed("€2M!HA", '€').unwrap_err();
+    /// assert!(matches!(
+    ///     error,
+    ///     c32::Error::InvalidCharacter { index: 5, .. }
+    /// ));
+    /// assert_eq!(error.invalid_char_position("€2M!HA"), Some(3));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn decode(src: &[u8], prefix: char) -> (Self, u8) {
-        assert!(N >= decoded_check_len(src.len() - 1), "'N' is too small");
-        assert!(prefix.is_ascii(), "Prefix must be an ASCII character");
-        assert!(src.len() >= 3, "Input must contain min. 3 characters");
-        assert!(src[0] == prefix as u8, "Input must start with prefix");
-
-        // Extract the version byte.
-        let mut buffer = [0u8; 1];
-        let _ = match __internal::de(&[src[1]], 0, 1, &mut buffer, 0) {
-            Ok(pos) => pos,
-            Err(Error::InvalidCharacter { char: _, index: _ }) => {
-                panic!("Input must not contain invalid characters")
+    pub fn invalid_char_position(&self, original: &str) -> Option<usize> {
+        match self {
+            Self::InvalidCharacter { index, .. } => {
+                Some(original.get(..*index)?.chars().count())
             }
-            _ => unreachable!(),
-        };
+            _ => None,
+        }
+    }
+}
 
-        // Assert that the version is < 32.
-        let version = buffer[0];
-        assert!(version < 32, "Version must be < 32");
+/// Stable numeric codes of the [`Error`] variants.
+///
+/// The codes are assigned once, and are never changed or reused, including
+/// those of removed variants and of the variants behind the `check` feature.
+///
+///  Code | Name                       | Variant
+/// ------|----------------------------|------------------------------------
+///  `1`  | `C32_E_BUFFER_TOO_SMALL`   | [`Error::BufferTooSmall`]
+///  `2`  | `C32_E_INVALID_DATA_SIZE`  | [`Error::InvalidDataSize`]
+///  `3`  | `C32_E_INVALID_CHARACTER`  | [`Error::InvalidCharacter`]
+///  `4`  | `C32_E_MISSING_PREFIX`     | [`Error::MissingPrefix`]
+///  `5`  | `C32_E_INVALID_FRAME`      | [`Error::InvalidFrame`]
+///  `6`  | `C32_E_OUTPUT_TOO_LARGE`   | [`Error::OutputTooLarge`]
+///  `7`  | `C32_E_INVALID_VERSION`    | `Error::InvalidVersion`
+///  `8`  | `C32_E_INSUFFICIENT_DATA`  | `Error::InsufficientData`
+///  `9`  | `C32_E_CHECKSUM_MISMATCH`  | `Error::ChecksumMismatch`
+///  `10` | `C32_E_INVALID_PADDING`    | [`Error::InvalidPadding`]
+///  `11` | `C32_E_ALLOCATION_FAILED`  | [`Error::AllocationFailed`]
+///
+/// # Examples
+///
+/// ```rust
+/// use c32::ErrorCode;
+///
+/// let code = ErrorCode::try_from(9).unwrap();
+/// assert_eq!(code, ErrorCode::ChecksumMismatch);
+/// assert_eq!(code.name(), "C32_E_CHECKSUM_MISMATCH");
+/// assert_eq!(code as u16, 9);
+///
+/// assert_eq!(ErrorCode::try_from(0), Err(0));
+/// ```
+#[non_exhaustive]
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorCode {
+    /// See [`Error::BufferTooSmall`].
+    BufferTooSmall = 1,
+    /// See [`Error::InvalidDataSize`].
+    InvalidDataSize = 2,
+    /// See [`Error::InvalidCharacter`].
+    InvalidCharacter = 3,
+    /// See [`Error::MissingPrefix`].
+    MissingPrefix = 4,
+    /// See [`Error::InvalidFrame`].
+    InvalidFrame = 5,
+    /// See [`Error::OutputTooLarge`].
+    OutputTooLarge = 6,
+    /// See `Error::InvalidVersion`.
+    InvalidVersion = 7,
+    /// See `Error::InsufficientData`.
+    InsufficientData = 8,
+    /// See `Error::ChecksumMismatch`.
+    ChecksumMismatch = 9,
+    /// See [`Error::InvalidPadding`].
+    InvalidPadding = 10,
+    /// See [`Error::AllocationFailed`].
+    AllocationFailed = 11,
+}
 
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
+impl ErrorCode {
+    /// Returns the code of an [`Error`].
+    #[inline]
+    #[must_use]
+    pub const fn of(error: &Error) -> Self {
+        match error {
+            Error::BufferTooSmall { .. } => Self::BufferTooSmall,
+            Error::InvalidDataSize { .. } => Self::InvalidDataSize,
+            Error::InvalidCharacter { .. } => Self::InvalidCharacter,
+            Error::MissingPrefix { .. } => Self::MissingPrefix,
+            Error::InvalidFrame { .. } => Self::InvalidFrame,
+            Error::OutputTooLarge { .. } => Self::OutputTooLarge,
+            Error::InvalidPadding { .. } => Self::InvalidPadding,
+            Error::AllocationFailed { .. } => Self::AllocationFailed,
+            #[cfg(feature = "check-core")]
+            Error::InvalidVersion { .. } => Self::InvalidVersion,
+            #[cfg(feature = "check-core")]
+            Error::InsufficientData { .. } => Self::InsufficientData,
+            #[cfg(feature = "check-core")]
+            Error::ChecksumMismatch { .. } => Self::ChecksumMismatch,
+        }
+    }
 
-        // Decode the payload.
-        let pos = match __internal::de(src, 2, src.len() - 2, &mut __raw, 0) {
-            Ok(pos) => pos,
-            Err(Error::InvalidCharacter { char: _, index: _ }) => {
-                panic!("Input must not contain invalid characters")
-            }
-            _ => unreachable!(),
-        };
+    /// Returns the stable machine-readable name of the code.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::BufferTooSmall => "C32_E_BUFFER_TOO_SMALL",
+            Self::InvalidDataSize => "C32_E_INVALID_DATA_SIZE",
+            Self::InvalidCharacter => "C32_E_INVALID_CHARACTER",
+            Self::MissingPrefix => "C32_E_MISSING_PREFIX",
+            Self::InvalidFrame => "C32_E_INVALID_FRAME",
+            Self::OutputTooLarge => "C32_E_OUTPUT_TOO_LARGE",
+            Self::InvalidVersion => "C32_E_INVALID_VERSION",
+            Self::InsufficientData => "C32_E_INSUFFICIENT_DATA",
+            Self::ChecksumMismatch => "C32_E_CHECKSUM_MISMATCH",
+            Self::InvalidPadding => "C32_E_INVALID_PADDING",
+            Self::AllocationFailed => "C32_E_ALLOCATION_FAILED",
+        }
+    }
+}
 
-        let __pos = pos - checksum::BYTE_LENGTH;
+impl From<&Error> for ErrorCode {
+    #[inline]
+    fn from(error: &Error) -> Self {
+        Self::of(error)
+    }
+}
 
-        // Extract the checksum.
-        let mut sum = [0u8; checksum::BYTE_LENGTH];
-        __internal::memcpy(&mut sum, 0, &__raw, __pos, checksum::BYTE_LENGTH);
+impl From<ErrorCode> for u16 {
+    #[inline]
+    fn from(code: ErrorCode) -> Self {
+        code as u16
+    }
+}
 
-        // Compute the expected checksum.
-        let mut expected = [0u8; checksum::BYTE_LENGTH];
-        __internal::memcpy(&mut expected, 0, &__raw, __pos, 4);
+impl TryFrom<u16> for ErrorCode {
+    type Error = u16;
+
+    /// Returns the [`ErrorCode`] with the given value, or the value if it is
+    /// not assigned.
+    fn try_from(code: u16) -> core::result::Result<Self, Self::Error> {
+        match code {
+            1 => Ok(Self::BufferTooSmall),
+            2 => Ok(Self::InvalidDataSize),
+            3 => Ok(Self::InvalidCharacter),
+            4 => Ok(Self::MissingPrefix),
+            5 => Ok(Self::InvalidFrame),
+            6 => Ok(Self::OutputTooLarge),
+            7 => Ok(Self::InvalidVersion),
+            8 => Ok(Self::InsufficientData),
+            9 => Ok(Self::ChecksumMismatch),
+            10 => Ok(Self::InvalidPadding),
+            11 => Ok(Self::AllocationFailed),
+            code => Err(code),
+        }
+    }
+}
 
-        // Assert that the computed and actual checksums match.
-        assert!(__internal::memcmp(&expected, &sum, 4), "Checksum mismatch");
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
 
-        (Self::new(__raw, __pos), version)
+#[cfg(feature = "defmt")]
+impl defmt::Format for Error {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::BufferTooSmall { min, len } => defmt::write!(
+                f,
+                "Buffer too small: {=usize} < {=usize}",
+                len,
+                min
+            ),
+            Self::InvalidDataSize { expected, got } => defmt::write!(
+                f,
+                "Invalid data size: {=usize} != {=usize}",
+                got,
+                expected
+            ),
+            Self::InvalidCharacter { char, index, .. } => defmt::write!(
+                f,
+                "Invalid character '{=char}' at position {=usize}",
+                char,
+                index
+            ),
+            Self::MissingPrefix {
+                char,
+                got: Some(got),
+            } => defmt::write!(
+                f,
+                "Expected prefix '{=char}', found '{=char}'",
+                char,
+                got
+            ),
+            Self::MissingPrefix { char, got: None } => {
+                defmt::write!(
+                    f,
+                    "Expected prefix '{=char}', found nothing",
+                    char
+                );
+            }
+            Self::InvalidFrame { field, reason } => {
+                defmt::write!(f, "Frame field {=usize}: {=str}", field, reason);
+            }
+            Self::OutputTooLarge { max, got } => defmt::write!(
+                f,
+                "Output too large: {=usize} > {=usize}",
+                got,
+                max
+            ),
+            Self::InvalidPadding { index } => {
+                defmt::write!(f, "Invalid padding at position {=usize}", index);
+            }
+            Self::AllocationFailed { requested } => {
+                defmt::write!(
+                    f,
+                    "Allocation failed: {=usize} bytes",
+                    requested
+                );
+            }
+            #[cfg(feature = "check-core")]
+            Self::InvalidVersion { expected, version } => defmt::write!(
+                f,
+                "Invalid version {=u8}: {=str}",
+                version,
+                expected
+            ),
+            #[cfg(feature = "check-core")]
+            Self::InsufficientData { min, len } => defmt::write!(
+                f,
+                "Insufficient data: {=usize} < {=usize}",
+                len,
+                min
+            ),
+            #[cfg(feature = "check-core")]
+            Self::ChecksumMismatch { expected, got, .. } => defmt::write!(
+                f,
+                "Checksum mismatch: expected {=[u8; 4]:x}, got {=[u8; 4]:x}",
+                expected,
+                got
+            ),
+        }
     }
+}
 
-    /// Decodes a slice of prefixed check-encoded bytes into a [`Buffer`].
+impl error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    /// Converts an [`Error`] into an [`io::Error`], keeping it as the source.
+    ///
+    /// The [`io::ErrorKind`] is chosen per variant:
+    ///
+    /// - [`io::ErrorKind::InvalidData`], the decoded data is malformed.
+    /// - [`io::ErrorKind::InvalidInput`], a version, prefix or limit of the
+    ///   caller is violated.
+    /// - [`io::ErrorKind::WriteZero`], the output buffer is too small.
+    /// - [`io::ErrorKind::Other`], the output exceeds the maximum length.
+    /// - [`io::ErrorKind::OutOfMemory`], an allocation failed.
     ///
     /// # Examples
     ///
-    ///  ```rust
-    /// # use c32::Error;
-    /// use c32::en::Check;
-    /// use c32::Buffer;
+    /// ```rust
+    /// use std::io;
     ///
-    /// let input = b"S0AHA59B9201Z";
-    /// let (de, version) = Buffer::<14, true, Check>::try_decode(input, 'S')?;
-    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
-    /// assert_eq!(version, 0);
-    /// # Ok::<(), Error>(())
+    /// let error = c32::decode("!").unwrap_err();
+    /// let error = io::Error::from(error);
+    /// assert_eq!(error.kind(), io::ErrorKind::InvalidData);
     /// ```
-    #[inline]
-    pub const fn try_decode(src: &[u8], prefix: char) -> Result<(Self, u8)> {
-        // Assert that the buffer has enough capacity.
-        let capacity = decoded_check_len(src.len() - prefix.len_utf8());
-        if N < capacity {
-            return Err(Error::BufferTooSmall {
-                min: capacity,
-                len: N,
-            });
-        }
-
-        // Assert that the prefix is ASCII.
-        if !prefix.is_ascii() {
-            return Err(Error::InvalidCharacter {
-                char: prefix,
-                index: 0,
-            });
-        }
-
-        // Assert that the input is not empty.
-        if src.is_empty() {
-            return Err(Error::MissingPrefix {
-                char: prefix,
-                got: None,
-            });
-        }
-
-        // Assert that the input has the minimum required length.
-        if src.len() < 3 {
-            return Err(Error::InsufficientData {
-                min: 3,
-                len: src.len(),
-            });
-        }
-
-        // Assert that the string starts with the prefix.
-        if src[0] != prefix as u8 {
-            return Err(Error::MissingPrefix {
-                char: prefix,
-                got: Some(src[0] as char),
-            });
-        }
-
-        // Extract the version byte
-        let mut buffer = [0u8; 1];
-        let _ = match __internal::de(&[src[1]], 0, 1, &mut buffer, 0) {
-            Ok(pos) => pos,
-            Err(err) => return Err(err),
+    ///
+    /// [`io::Error`]: std::io::Error
+    /// [`io::ErrorKind`]: std::io::ErrorKind
+    /// [`io::ErrorKind::InvalidData`]: std::io::ErrorKind::InvalidData
+    /// [`io::ErrorKind::InvalidInput`]: std::io::ErrorKind::InvalidInput
+    /// [`io::ErrorKind::WriteZero`]: std::io::ErrorKind::WriteZero
+    /// [`io::ErrorKind::Other`]: std::io::ErrorKind::Other
+    /// [`io::ErrorKind::OutOfMemory`]: std::io::ErrorKind::OutOfMemory
+    fn from(err: Error) -> Self {
+        use std::io::ErrorKind;
+
+        let kind = match err {
+            Error::InvalidDataSize { .. }
+            | Error::InvalidCharacter { .. }
+            | Error::InvalidFrame { .. }
+            | Error::InvalidPadding { .. } => ErrorKind::InvalidData,
+            Error::MissingPrefix { .. } => ErrorKind::InvalidInput,
+            Error::BufferTooSmall { .. } => ErrorKind::WriteZero,
+            Error::OutputTooLarge { .. } => ErrorKind::Other,
+            Error::AllocationFailed { .. } => ErrorKind::OutOfMemory,
+            #[cfg(feature = "check-core")]
+            Error::InsufficientData { .. } | Error::ChecksumMismatch { .. } => {
+                ErrorKind::InvalidData
+            }
+            #[cfg(feature = "check-core")]
+            Error::InvalidVersion { .. } => ErrorKind::InvalidInput,
         };
+        Self::new(kind, err)
+    }
+}
 
-        // Assert that the version is valid (< 32).
-        let version = buffer[0];
-        if version >= 32 {
-            return Err(Error::InvalidVersion {
-                expected: "must be < 32",
-                version,
-            });
-        }
+/// Result type for fallible Crockford Base32 operations.
+pub type Result<T> = core::result::Result<T, Error>;
 
-        // Allocate the output buffer.
-        let mut __raw = [0u8; N];
+/// A marker trait for Crockford Base32 variations.
+///
+/// # Generics
+///
+/// * `PREFIX` - Whether to include a prefix character.
+pub trait Encoding<const PREFIX: bool> {
+    /// Whether the encoding adds a version character and a 4-byte checksum.
+    const CHECKSUM: bool = false;
+}
 
-        // Decode the payload into the buffer.
-        let mut __pos =
-            match __internal::de(src, 2, src.len() - 2, &mut __raw, 0) {
-                Ok(pos) => pos,
-                Err(Error::InvalidCharacter { char, index }) => {
-                    return Err(Error::InvalidCharacter {
-                        char,
-                        index: index + 2,
-                    });
-                }
-                Err(e) => return Err(e),
-            };
+/// [`Encoding`] implementations.
+///
+/// This module exports various [`Encoding`] types.
+pub mod en {
+    use super::*;
 
-        // Extract the checksum.
-        __pos -= checksum::BYTE_LENGTH;
-        let mut sum = [0u8; checksum::BYTE_LENGTH];
-        __internal::memcpy(&mut sum, 0, &__raw, __pos, checksum::BYTE_LENGTH);
+    /// Default Crockford Base32 encoding.
+    ///
+    /// # Generics
+    ///
+    /// * `PREFIX` - Whether to include a prefix character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::en::Default;
+    /// use c32::Buffer;
+    ///
+    /// // Default encoding w/o prefix
+    /// let en = Buffer::<5, false, Default>::encode(&[42, 42, 42]);
+    /// assert_eq!(en.as_str(), "2MAHA");
+    ///
+    /// // Default encoding with prefix
+    /// let en = Buffer::<6, true, Default>::encode(&[42, 42, 42], 'S');
+    /// assert_eq!(en.as_str(), "S2MAHA");
+    /// ```
+    pub struct Default;
+    impl<const PREFIX: bool> Encoding<PREFIX> for Default {}
 
-        // Compute the expected checksum.
-        let mut expected = [0u8; checksum::BYTE_LENGTH];
-        __internal::memcpy(&mut expected, 0, &__raw, __pos, 4);
+    #[cfg(feature = "check")]
+    mod __check {
+        use super::*;
 
-        // Assert that the checksums match.
-        if !__internal::memcmp(&expected, &sum, checksum::BYTE_LENGTH) {
-            return Err(Error::ChecksumMismatch { expected, got: sum });
+        /// Crockford Base32 encoding with checksum validation.
+        ///
+        /// This encoding format includes a 4-byte `Checksum`.
+        ///
+        /// # Generics
+        ///
+        /// * `PREFIX` - Whether to include a prefix character.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use c32::en::Check;
+        /// use c32::Buffer;
+        ///
+        /// // Check encoding w/o prefix
+        /// let en = Buffer::<13, false, Check>::encode(&[42, 42, 42], 0);
+        /// assert_eq!(en.as_str(), "0AHA59B9201Z");
+        ///
+        /// // Check encoding with prefix
+        /// let en = Buffer::<14, true, Check>::encode(&[42, 42, 42], 'S', 0);
+        /// assert_eq!(en.as_str(), "S0AHA59B9201Z");
+        /// ```
+        pub struct Check;
+        impl<const PREFIX: bool> Encoding<PREFIX> for Check {
+            const CHECKSUM: bool = true;
         }
-
-        Ok((Self::new(__raw, __pos), version))
     }
+
+    #[cfg(feature = "check")]
+    pub use __check::*;
 }
 
-/// Computes the required capacity for encoding into Crockford Base32.
-///
-/// # Notes
+/// A Crockford Base32 variation, selected at runtime.
 ///
-/// The calculation breaks down into:
-///
-/// - For every 5 bytes (40 bits), exactly 8 Base32 characters are needed.
-/// - For remaining bytes, more characters are needed based on the total bits.
+/// This is the runtime counterpart of the [`Encoding`] markers, accepted by
+/// [`encode_mode`] and [`decode_mode`].
 ///
 /// # Examples
 ///
 /// ```rust
-/// assert_eq!(c32::encoded_len(0), 0);
-/// assert_eq!(c32::encoded_len(1), 2);
-/// assert_eq!(c32::encoded_len(3), 5);
-/// ```
-#[inline]
-#[must_use]
-pub const fn encoded_len(n: usize) -> usize {
-    (n * 8 + 4) / 5
-}
-
-/// Computes the required capacity for encoding into Crockford Base32Check.
-///
-/// # Notes
-///
-/// The calculation breaks down into:
+/// # use c32::Error;
+/// use c32::Mode;
 ///
-/// - One byte for the version character.
-/// - The encoded length of the payload plus a 4-byte checksum.
+/// fn mode(config: &str) -> Mode {
+///     match config {
+///         "prefixed" => Mode::Prefixed('S'),
+///         _ => Mode::Default,
+///     }
+/// }
 ///
-/// # Examples
+/// let en = c32::encode_mode(&[42, 42, 42], mode("prefixed"))?;
+/// assert_eq!(en, "S2MAHA");
 ///
-/// ```rust
-/// assert_eq!(c32::encoded_check_len(0), 8);
-/// assert_eq!(c32::encoded_check_len(1), 9);
-/// assert_eq!(c32::encoded_check_len(3), 13);
+/// let de = c32::decode_mode(&en, mode("prefixed"))?;
+/// assert_eq!(de, [42, 42, 42]);
+/// # Ok::<(), Error>(())
 /// ```
-#[inline]
-#[must_use]
-#[cfg(feature = "check")]
-pub const fn encoded_check_len(n: usize) -> usize {
-    1 + encoded_len(n + 4)
-}
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Default Crockford Base32 encoding, see [`encode`].
+    Default,
 
-/// Computes the required capacity for decoding from Crockford Base32.
-///
-/// # Examples
-///
-/// ```rust
-/// assert_eq!(c32::decoded_len(0), 0);
-/// assert_eq!(c32::decoded_len(1), 1);
-/// assert_eq!(c32::decoded_len(2), 2);
-/// ```
-#[inline]
-#[must_use]
-pub const fn decoded_len(n: usize) -> usize {
-    n
-}
+    /// Default encoding with a prefix character, see [`encode_prefixed`].
+    Prefixed(char),
 
-/// Computes the required capacity for decoding from Crockford Base32Check.
-///
-/// # Examples
-///
-/// ```rust
-/// assert_eq!(c32::decoded_check_len(8), 8);
-/// assert_eq!(c32::decoded_check_len(9), 9);
-/// assert_eq!(c32::decoded_check_len(13), 13);
-/// ```
-#[inline]
-#[must_use]
-#[cfg(feature = "check")]
-pub const fn decoded_check_len(n: usize) -> usize {
-    n
+    /// Encoding with checksum validation, see [`encode_check`].
+    ///
+    /// # Fields
+    ///
+    /// * `version` - The version byte, which must be below 32.
+    #[cfg(feature = "check")]
+    Check { version: u8 },
+
+    /// Encoding with checksum validation and a prefix character, see
+    /// [`encode_check_prefixed`].
+    ///
+    /// # Fields
+    ///
+    /// * `prefix` - The prefix character.
+    /// * `version` - The version byte, which must be below 32.
+    #[cfg(feature = "check")]
+    CheckPrefixed { prefix: char, version: u8 },
 }
 
-/// Encodes bytes into a Crockford Base32-encoded string.
-///
-/// # Panics
-///
-/// This method can panic in two cases:
-///
-/// - If encoding fails despitce sufficient buffer capacity.
-/// - If the encoded output contains non-UTF8 bytes.
+/// The handling of ASCII whitespace while decoding.
 ///
-/// Both panics should never occur under normal circumstances.
+/// Encoded data copied from emails and terminals often contains line breaks
+/// and stray spaces, which [`Whitespace::Ignore`] skips without a cleaned
+/// copy of the input.
 ///
 /// # Examples
 ///
 /// ```rust
-/// let en = c32::encode([42, 42, 42]);
-/// assert_eq!(en, "2MAHA");
+/// # use c32::Error;
+/// use c32::Whitespace;
+///
+/// # let mut dst = [0u8; 16];
+/// let src = b" 2M\tAH\r\nA ";
+/// let offset =
+///     c32::decode_into_with_whitespace(src, &mut dst, Whitespace::Ignore)?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+///
+/// let result =
+///     c32::decode_into_with_whitespace(src, &mut dst, Whitespace::Reject);
+/// assert!(matches!(result, Err(Error::InvalidCharacter { .. })));
+/// # Ok::<(), Error>(())
 /// ```
-#[inline]
-#[must_use]
-#[cfg(feature = "alloc")]
-pub fn encode<B>(src: B) -> String
-where
-    B: AsRef<[u8]>,
-{
-    let src = src.as_ref();
-
-    // Allocate the output buffer.
-    let capacity = encoded_len(src.len());
-    let mut dst = vec![0u8; capacity];
-
-    // This should not panic, as we allocate enough space.
-    let offset = encode_into(src, &mut dst).unwrap();
-    dst.truncate(offset);
-
-    // This should not panic, as we only push valid ASCII.
-    String::from_utf8(dst).unwrap()
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Whitespace {
+    /// Whitespace is rejected as an invalid character, as by [`decode_into`].
+    #[default]
+    Reject,
+
+    /// ASCII space, tab, CR and LF are skipped, wherever they occur.
+    Ignore,
 }
 
-/// Decodes a Crockford Base32-encoded string.
-///
-/// # Errors
-///
-/// This method will return an [`Error`] if:
+/// A configurable decoder for Crockford's Base32.
 ///
-/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// [`Decoder`] combines the decoding options of the free functions, the
+/// handling of [`Whitespace`] and a maximum output length, as in
+/// [`decode_bounded`].
 ///
 /// # Examples
 ///
 /// ```rust
+/// # #[cfg(feature = "alloc")] {
 /// # use c32::Error;
-/// let de = c32::decode("2MAHA")?;
+/// use c32::Decoder;
+/// use c32::Whitespace;
+///
+/// let decoder = Decoder::new().whitespace(Whitespace::Ignore).max_len(3);
+///
+/// let de = decoder.decode("2MA\r\nHA")?;
 /// assert_eq!(de, [42, 42, 42]);
-/// # Ok::<(), Error>(())
+///
+/// let result = decoder.decode("2MA\r\nHA4");
+/// assert_eq!(result, Err(Error::OutputTooLarge { max: 3, got: 4 }));
+/// # }
+/// # Ok::<(), c32::Error>(())
 /// ```
-#[inline]
-#[cfg(feature = "alloc")]
-pub fn decode(str: &str) -> Result<Vec<u8>> {
-    let bytes = str.as_bytes();
-
-    // Allocate the output buffer.
-    let capacity = decoded_len(bytes.len());
-    let mut dst = vec![0u8; capacity];
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Decoder {
+    /// The handling of whitespace.
+    __whitespace: Whitespace,
+    /// The maximum number of output bytes, if any.
+    __max_len: Option<usize>,
+}
 
-    // Decode the input bytes into the buffer.
-    let offset = decode_into(bytes, &mut dst)?;
-    dst.truncate(offset);
-
-    Ok(dst)
-}
+impl Decoder {
+    /// Creates a new [`Decoder`], which rejects whitespace and has no maximum
+    /// output length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Decoder;
+    ///
+    /// # let mut dst = [0u8; 5];
+    /// let offset = Decoder::new().decode_into(b"2MAHA", &mut dst)?;
+    /// assert_eq!(&dst[..offset], [42, 42, 42]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            __whitespace: Whitespace::Reject,
+            __max_len: None,
+        }
+    }
 
-/// Encodes bytes into a prefixed Crockford Base32-encoded string.
-///
-/// # Examples
-///
-/// ```rust
-/// let en = c32::encode_prefixed([42, 42, 42], 'S');
-/// assert_eq!(en, "S2MAHA");
-/// ```
-#[inline]
-#[must_use]
-#[cfg(feature = "alloc")]
-pub fn encode_prefixed<B>(src: B, prefix: char) -> String
-where
-    B: AsRef<[u8]>,
-{
-    let src = src.as_ref();
+    /// Sets the handling of whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Decoder;
+    /// use c32::Whitespace;
+    ///
+    /// # let mut dst = [0u8; 5];
+    /// let decoder = Decoder::new().whitespace(Whitespace::Ignore);
+    /// let offset = decoder.decode_into(b" 2MA HA\n", &mut dst)?;
+    /// assert_eq!(&dst[..offset], [42, 42, 42]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn whitespace(mut self, whitespace: Whitespace) -> Self {
+        self.__whitespace = whitespace;
+        self
+    }
 
-    // Encode the input bytes.
-    let encoded = encode(src);
+    /// Sets the maximum number of output bytes.
+    ///
+    /// For check-encoded input, the maximum applies to the payload, excluding
+    /// the version and the checksum, as in [`decode_check_bounded`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Decoder;
+    /// use c32::Error;
+    ///
+    /// # let mut dst = [0u8; 5];
+    /// let result = Decoder::new().max_len(2).decode_into(b"2MAHA", &mut dst);
+    /// assert_eq!(result, Err(Error::OutputTooLarge { max: 2, got: 3 }));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_len(mut self, max: usize) -> Self {
+        self.__max_len = Some(max);
+        self
+    }
 
-    // Allocate the output string.
-    let capacity = prefix.len_utf8() + encoded.len();
-    let mut dst = String::with_capacity(capacity);
+    /// Decodes Crockford Base32-encoded bytes into a provided buffer.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to the output buffer.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::OutputTooLarge`], the output would exceed the maximum.
+    /// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+    /// - [`Error::InvalidCharacter`], the input contains invalid characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Decoder;
+    ///
+    /// # let mut dst = [0u8; 5];
+    /// let offset = Decoder::new().max_len(3).decode_into(b"2MAHA", &mut dst)?;
+    /// assert_eq!(&dst[..offset], [42, 42, 42]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub fn decode_into(&self, src: &[u8], dst: &mut [u8]) -> Result<usize> {
+        self.check_len(src, 0)?;
+        decode_into_with_whitespace(src, dst, self.__whitespace)
+    }
 
-    // Append the prefix and encoded string.
-    dst.push(prefix);
-    dst.push_str(&encoded);
-    dst
-}
+    /// Decodes a Crockford Base32-encoded string.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::OutputTooLarge`], the output would exceed the maximum.
+    /// - [`Error::InvalidCharacter`], the input contains invalid characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Decoder;
+    ///
+    /// let de = Decoder::new().decode("2MAHA")?;
+    /// assert_eq!(de, [42, 42, 42]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn decode(&self, str: &str) -> Result<Vec<u8>> {
+        self.check_len(str.as_bytes(), 0)?;
+        match self.__whitespace {
+            Whitespace::Reject => decode(str),
+            Whitespace::Ignore => decode_ignoring_whitespace(str),
+        }
+    }
 
-/// Decodes a prefixed Crockford Base32-encoded string.
-///
-/// # Errors
-///
-/// This method will return an [`Error`] if:
-///
-/// - [`Error::MissingPrefix`], the input does not start with the prefix.
-/// - [`Error::InvalidCharacter`], the input contains invalid characters.
-///
-/// # Examples
-///
-/// ```rust
-/// # use c32::Error;
-/// let de = c32::decode_prefixed("P2MAHA", 'P')?;
-/// assert_eq!(de, [42, 42, 42]);
-/// # Ok::<(), Error>(())
-/// ```
-#[inline]
-#[cfg(feature = "alloc")]
-pub fn decode_prefixed(str: &str, prefix: char) -> Result<Vec<u8>> {
-    // Assert that the string starts with the prefix.
-    if !str.starts_with(prefix) {
-        return Err(Error::MissingPrefix {
-            char: prefix,
-            got: str.chars().next(),
-        });
+    /// Decodes Crockford Base32Check-encoded bytes into a provided buffer.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to the output buffer and the version.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::OutputTooLarge`], the payload would exceed the maximum.
+    /// - [`Error::InvalidCharacter`], the input contains invalid characters.
+    /// - [`Error::InsufficientData`], the input has fewer bytes than required.
+    /// - [`Error::InvalidVersion`], the version is 32 or greater.
+    /// - [`Error::ChecksumMismatch`], the checksum's do not match.
+    /// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Decoder;
+    ///
+    /// # let mut dst = [0u8; 12];
+    /// let (offset, version) =
+    ///     Decoder::new().decode_check_into(b"0AHA59B9201Z", &mut dst)?;
+    /// assert_eq!(&dst[..offset], [42, 42, 42]);
+    /// assert_eq!(version, 0);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    #[cfg(feature = "check")]
+    pub fn decode_check_into(
+        &self,
+        src: &[u8],
+        dst: &mut [u8],
+    ) -> Result<(usize, u8)> {
+        self.check_payload_len(src)?;
+        decode_check_into_with_whitespace(src, dst, self.__whitespace)
     }
 
-    // Skip the prefix character and decode the rest.
-    match decode(&str[prefix.len_utf8()..]) {
-        Ok(bytes) => Ok(bytes),
-        Err(Error::InvalidCharacter { char, index }) => {
-            // This adjusts the index in an 'InvalidCharacter' to account for
-            // the prefix in the original string that we don't decode.
-            Err(Error::InvalidCharacter {
-                char,
-                index: index + prefix.len_utf8(),
-            })
+    /// Decodes a Crockford Base32Check-encoded string.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::OutputTooLarge`], the payload would exceed the maximum.
+    /// - [`Error::InvalidCharacter`], the input contains invalid characters.
+    /// - [`Error::InsufficientData`], the input has fewer bytes than required.
+    /// - [`Error::InvalidVersion`], the version is 32 or greater.
+    /// - [`Error::ChecksumMismatch`], the checksum's do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Decoder;
+    /// use c32::Whitespace;
+    ///
+    /// let decoder = Decoder::new().whitespace(Whitespace::Ignore);
+    /// let (bytes, version) = decoder.decode_check(" 0AHA59B\n9201Z")?;
+    /// assert_eq!(bytes, [42, 42, 42]);
+    /// assert_eq!(version, 0);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    #[cfg(all(feature = "alloc", feature = "check"))]
+    pub fn decode_check(&self, str: &str) -> Result<(Vec<u8>, u8)> {
+        self.check_payload_len(str.as_bytes())?;
+        match self.__whitespace {
+            Whitespace::Reject => decode_check(str),
+            Whitespace::Ignore => decode_check_ignoring_whitespace(str),
         }
-        Err(e) => Err(e),
     }
-}
 
-/// Encodes bytes into a Crockford Base32Check-encoded string.
-///
-/// # Panics
-///
-/// This method can panic in two cases:
-///
-/// - If encoding fails despite sufficient buffer capacity.
-/// - If the encoded output contains non-UTF8 bytes.
-///
-/// Both panics should never occur under normal circumstances.
-///
-/// # Errors
-///
-/// This method will return an [`Error`] if:
-///
-/// - [`Error::InvalidVersion`], the version is 32 or greater.
-///
-/// # Examples
-///
-/// ```rust
-/// # use c32::Error;
-/// let en = c32::encode_check([42, 42, 42], 0)?;
-/// assert_eq!(en, "0AHA59B9201Z");
-/// # Ok::<(), Error>(())
-/// ```
-#[inline]
-#[cfg(all(feature = "alloc", feature = "check"))]
-pub fn encode_check<B>(src: B, version: u8) -> Result<String>
-where
-    B: AsRef<[u8]>,
-{
-    let src = src.as_ref();
+    /// Asserts that `src` without `overhead` bytes does not decode to more
+    /// than the maximum number of output bytes.
+    #[inline]
+    fn check_len(&self, src: &[u8], overhead: usize) -> Result<()> {
+        let Some(max) = self.__max_len else {
+            return Ok(());
+        };
 
-    // Allocate the output string.
-    let capacity = encoded_check_len(src.len());
-    let mut dst = vec![0u8; capacity];
+        let got = match self.__whitespace {
+            Whitespace::Reject => __internal::exceeds_limit::<
+                { __internal::STRICT },
+            >(src, overhead, max),
+            Whitespace::Ignore => __internal::exceeds_limit::<
+                { __internal::WHITESPACE },
+            >(src, overhead, max),
+        };
 
-    // This should not panic, as we allocate enough space.
-    let offset = encode_check_into(src, &mut dst, version)?;
-    dst.truncate(offset);
+        match got {
+            Some(got) => Err(Error::OutputTooLarge { max, got }),
+            None => Ok(()),
+        }
+    }
 
-    // This should not panic, as we only push valid ASCII.
-    Ok(String::from_utf8(dst).unwrap())
+    /// Asserts that the payload of check-encoded `src` does not exceed the
+    /// maximum number of output bytes.
+    #[inline]
+    #[cfg(feature = "check")]
+    fn check_payload_len(&self, src: &[u8]) -> Result<()> {
+        // Skip the whitespace before the version character.
+        let start = match self.__whitespace {
+            Whitespace::Reject => 0,
+            Whitespace::Ignore => src
+                .iter()
+                .position(|&b| !__internal::is_whitespace(b))
+                .unwrap_or(src.len()),
+        };
+
+        match src[start..].split_first() {
+            Some((_, payload)) => {
+                self.check_len(payload, checksum::BYTE_LENGTH)
+            }
+            None => Ok(()),
+        }
+    }
 }
 
-/// Decodes a Crockford Base32Check-encoded string.
+/// A fixed-size buffer for encoding or decoding Crockford's Base32.
 ///
-/// # Errors
+/// [`Buffer`] manages a fixed-size array of bytes and tracks the number of
+/// bytes written during encoding and decoding operations, and is primarily
+/// designed for use in a constant context.
 ///
-/// This method will return an [`Error`] if:
+/// # Generics
 ///
-/// - [`Error::InvalidCharacter`], the input contains invalid characters.
-/// - [`Error::InsufficientData`], the input has fewer bytes than required.
-/// - [`Error::ChecksumMismatch`], the checksum's do not match.
-/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// * `LEN` - The size of the byte array in bytes.
+/// * `PREFIX` - Whether to include a prefix character, defaults to `false`.
+/// * `E` - The [`Encoding`] format to use, defaults to [`en::Default`].
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use c32::Error;
-/// let (bytes, version) = c32::decode_check("0AHA59B9201Z")?;
-/// assert_eq!(bytes, [42, 42, 42]);
-/// assert_eq!(version, 0);
-/// # Ok::<(), Error>(())
-/// ```
-#[inline]
-#[cfg(all(feature = "alloc", feature = "check"))]
-pub fn decode_check(str: &str) -> Result<(Vec<u8>, u8)> {
-    let bytes = str.as_bytes();
-
-    // Allocate the output buffer.
-    let capacity = decoded_check_len(bytes.len());
-    let mut dst = vec![0u8; capacity];
-
-    // Decode the input bytes into the buffer.
-    let (offset, version) = decode_check_into(bytes, &mut dst)?;
-    dst.truncate(offset);
-
-    Ok((dst, version))
-}
-
-/// Encodes bytes into a prefixed Crockford Base32Check-encoded string.
-///
-/// # Errors
-///
-/// This method will return an [`Error`] if:
+/// use c32::Buffer;
 ///
-/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// const BYTES: [u8; 3] = [42, 42, 42];
+/// const EN: Buffer<5> = Buffer::<5>::encode(&BYTES);
+/// assert_eq!(EN.as_str(), "2MAHA");
 ///
-/// # Examples
-///
-/// ```rust
-/// # use c32::Error;
-/// let en = c32::encode_check_prefixed([42, 42, 42], 'S', 0)?;
-/// assert_eq!(en, "S0AHA59B9201Z");
-/// # Ok::<(), Error>(())
+/// const DE: Buffer<5> = Buffer::<5>::decode(EN.as_bytes());
+/// assert_eq!(DE.as_bytes(), [42, 42, 42]);
 /// ```
-#[inline]
-#[cfg(all(feature = "alloc", feature = "check"))]
-pub fn encode_check_prefixed<B>(
-    src: B,
-    prefix: char,
-    version: u8,
-) -> Result<String>
-where
-    B: AsRef<[u8]>,
+pub struct Buffer<
+    const LEN: usize,
+    const PREFIX: bool = false,
+    E: Encoding<PREFIX> = en::Default,
+> {
+    /// The underlying byte array.
+    __raw: [u8; LEN],
+    /// The number of written bytes to the buffer.
+    __pos: usize,
+    /// The associated [`Encoding`] format `E`.
+    __marker: marker::PhantomData<E>,
+}
+
+impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>>
+    Buffer<LEN, PREFIX, E>
 {
-    let src = src.as_ref();
+    /// An empty buffer with no bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// let buffer = Buffer::<10>::EMPTY;
+    /// assert_eq!(buffer.pos(), 0);
+    /// assert!(buffer.as_bytes().is_empty());
+    /// ```
+    pub const EMPTY: Self = Self {
+        __raw: [0u8; LEN],
+        __pos: 0,
+        __marker: marker::PhantomData,
+    };
 
-    // Encode the input bytes.
-    let encoded = encode_check(src, version)?;
+    /// Computes the required size `LEN` for encoding a payload of the given
+    /// length, accounting for the prefix and checksum overhead of the buffer.
+    ///
+    /// A buffer of this size can also decode the encoded payload, see
+    /// [`buffer_for!`] for naming the sized type directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// assert_eq!(Buffer::<0>::required_for(3), 5);
+    /// assert_eq!(Buffer::<0, true>::required_for(3), 6);
+    /// ```
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "check")] {
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// assert_eq!(Buffer::<0, false, Check>::required_for(3), 13);
+    /// assert_eq!(Buffer::<0, true, Check>::required_for(3), 14);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn required_for(payload_len: usize) -> usize {
+        let len = if E::CHECKSUM {
+            1 + encoded_len(payload_len + 4)
+        } else {
+            encoded_len(payload_len)
+        };
+        len + PREFIX as usize
+    }
 
-    // Allocate the output string.
-    let capacity = prefix.len_utf8() + encoded.len();
-    let mut dst = String::with_capacity(capacity);
+    /// Creates a new [`Buffer`].
+    ///
+    /// This is an internal method.
+    const fn new(__raw: [u8; LEN], __pos: usize) -> Self {
+        Self {
+            __raw,
+            __pos,
+            __marker: marker::PhantomData,
+        }
+    }
 
-    // Append the prefix and encoded string.
-    dst.push(prefix);
-    dst.push_str(&encoded);
-    Ok(dst)
+    /// Returns the number of bytes written to the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 3] = [42, 42, 42];
+    /// const EN: Buffer<5> = Buffer::<5>::encode(&INPUT);
+    /// assert_eq!(EN.pos(), 5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn pos(&self) -> usize {
+        self.__pos
+    }
+
+    /// Returns a string slice of the written bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 3] = [42, 42, 42];
+    /// const EN: Buffer<5> = Buffer::<5>::encode(&INPUT);
+    /// assert_eq!(EN.as_str(), "2MAHA");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: We only push valid UTF-8 to `self.__raw`.
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Returns a slice of the written bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 3] = [42, 42, 42];
+    /// const EN: Buffer<5> = Buffer::<5>::encode(&INPUT);
+    /// assert_eq!(EN.as_bytes(), b"2MAHA");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `self.__pos` is always within bounds.
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.__pos) }
+    }
+
+    /// Returns a raw pointer to the buffer's data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Buffer;
+    /// let buffer = Buffer::<10, false>::EMPTY;
+    /// let ptr = buffer.as_ptr();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.__raw.as_ptr()
+    }
 }
 
-/// Decodes a prefixed Crockford Base32Check-encoded string.
-///
-/// # Errors
-///
-/// This method will return an [`Error`] if:
-///
-/// - [`Error::MissingPrefix`], the input does not start with the prefix.
-/// - [`Error::InvalidCharacter`], the input contains invalid characters.
-/// - [`Error::InsufficientData`], the input has fewer bytes than required.
-/// - [`Error::InvalidVersion`], the version is 32 or greater.
-/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>> fmt::Debug
+    for Buffer<LEN, PREFIX, E>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Buffer")
+            .field("bytes", &self.as_bytes())
+            .finish()
+    }
+}
+
+/// Compares the written bytes of a [`Buffer`] with a string slice.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use c32::Error;
-/// let (bytes, version) = c32::decode_check_prefixed("S0AHA59B9201Z", 'S')?;
-/// assert_eq!(bytes, [42, 42, 42]);
-/// assert_eq!(version, 0);
-/// # Ok::<(), Error>(())
+/// use c32::Buffer;
+///
+/// const EN: Buffer<5> = Buffer::<5>::encode(&[42, 42, 42]);
+/// assert_eq!(EN, *"2MAHA");
+/// assert_eq!(*"2MAHA", EN);
 /// ```
-#[inline]
-#[cfg(all(feature = "alloc", feature = "check"))]
-pub fn decode_check_prefixed(str: &str, prefix: char) -> Result<(Vec<u8>, u8)> {
-    // Assert that the string starts with the prefix.
-    if !str.starts_with(prefix) {
-        return Err(Error::MissingPrefix {
-            char: prefix,
-            got: str.chars().next(),
-        });
-    }
-
-    // Skip the prefix character and decode the rest.
-    match decode_check(&str[prefix.len_utf8()..]) {
-        Ok(bytes) => Ok(bytes),
-        Err(Error::InvalidCharacter { char, index }) => {
-            // This adjusts the index in an 'InvalidCharacter' to account for
-            // the prefix in the original string that we don't decode.
-            Err(Error::InvalidCharacter {
-                char,
-                index: index + prefix.len_utf8(),
-            })
-        }
-        Err(e) => Err(e),
+impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>> PartialEq<str>
+    for Buffer<LEN, PREFIX, E>
+{
+    fn eq(&self, other: &str) -> bool {
+        self.as_bytes() == other.as_bytes()
     }
 }
 
-/// Encodes bytes as Crockford Base32 into a provided buffer.
-///
-/// # Returns
-///
-/// The number of bytes written to the output buffer.
-///
-/// # Errors
-///
-/// This method will return an [`Error`] if:
-///
-/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// Compares the written bytes of a [`Buffer`] with a string slice.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use c32::Error;
-/// # let mut dst = [0u8; 5];
-/// let offset = c32::encode_into(&[42, 42, 42], &mut dst)?;
-/// assert_eq!(&dst[..offset], b"2MAHA");
-/// assert_eq!(offset, 5);
-/// # Ok::<(), Error>(())
+/// use c32::Buffer;
+///
+/// const EN: Buffer<5> = Buffer::<5>::encode(&[42, 42, 42]);
+/// assert_eq!(EN, "2MAHA");
+/// assert_eq!("2MAHA", EN);
 /// ```
-#[inline]
-pub fn encode_into(src: &[u8], dst: &mut [u8]) -> Result<usize> {
-    // Assert that the buffer has enough capacity.
-    let capacity = encoded_len(src.len());
-    if dst.len() < capacity {
-        return Err(Error::BufferTooSmall {
-            min: capacity,
-            len: dst.len(),
-        });
+impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>> PartialEq<&str>
+    for Buffer<LEN, PREFIX, E>
+{
+    fn eq(&self, other: &&str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>>
+    PartialEq<Buffer<LEN, PREFIX, E>> for str
+{
+    fn eq(&self, other: &Buffer<LEN, PREFIX, E>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>>
+    PartialEq<Buffer<LEN, PREFIX, E>> for &str
+{
+    fn eq(&self, other: &Buffer<LEN, PREFIX, E>) -> bool {
+        self.as_bytes() == other.as_bytes()
     }
+}
+
+impl<const N: usize> Buffer<N, false, en::Default> {
+    /// Encodes a byte array into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 3] = [42, 42, 42];
+    /// const EN: Buffer<5> = Buffer::<5>::encode(&INPUT);
+    /// assert_eq!(EN.as_str(), "2MAHA");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn encode<const M: usize>(src: &[u8; M]) -> Self {
+        const {
+            assert!(
+                N >= encoded_len(M),
+                "Size 'N' is too small, see `Buffer::required_for`"
+            );
+        }
+
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the input into the buffer.
+        let result = __internal::buffer_en(src, &mut __raw, None, None);
+        let __pos = __internal::expect_buffer_en(result);
+
+        Self::new(__raw, __pos)
+    }
+
+    /// Encodes a byte array into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    /// use c32::Error;
+    ///
+    /// let input = [42, 42, 42];
+    /// let en = Buffer::<5>::try_encode(&input)?;
+    /// assert_eq!(en.as_str(), "2MAHA");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_encode<const M: usize>(src: &[u8; M]) -> Result<Self> {
+        Self::encode_slice(src)
+    }
+
+    /// Encodes a byte slice of runtime length into a [`Buffer`].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::BufferTooSmall`], the size `N` is below
+    ///   [`encoded_len`]`(src.len())`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Buffer;
+    ///
+    /// let input = vec![42, 42, 42];
+    /// let en = Buffer::<5>::encode_slice(&input)?;
+    /// assert_eq!(en.as_str(), "2MAHA");
+    ///
+    /// let err = Buffer::<4>::encode_slice(&input).unwrap_err();
+    /// assert_eq!(err, Error::BufferTooSmall { min: 5, len: 4 });
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn encode_slice(src: &[u8]) -> Result<Self> {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the input into the buffer.
+        match __internal::buffer_en(src, &mut __raw, None, None) {
+            Ok(__pos) => Ok(Self::new(__raw, __pos)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Decodes a slice of encoded bytes into a [`Buffer`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the size `N` is too small or the input
+    /// contains invalid characters, use [`try_decode`](Self::try_decode)
+    /// for a non-panicking alternative. In a const context this fails the
+    /// build.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 5] = *b"2MAHA";
+    /// const DE: Buffer<5> = Buffer::<5>::decode(&INPUT);
+    /// assert_eq!(DE.as_bytes(), [42, 42, 42]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn decode(src: &[u8]) -> Self {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Decode the input into the buffer.
+        let result = __internal::buffer_de(src, &mut __raw, None, false);
+        let (__pos, _) = __internal::expect_buffer_de(result);
+
+        Self::new(__raw, __pos)
+    }
+
+    /// Decodes a slice of encoded bytes into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Buffer;
+    ///
+    /// let input = b"2MAHA";
+    /// let de = Buffer::<5>::try_decode(input)?;
+    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_decode(src: &[u8]) -> Result<Self> {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Decode the input into the buffer.
+        match __internal::buffer_de(src, &mut __raw, None, false) {
+            Ok((__pos, _)) => Ok(Self::new(__raw, __pos)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Encodes a UTF-8 string into a [`Buffer`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the buffer lacks capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const EN: Buffer<8> = Buffer::<8>::encode_str("***");
+    /// assert_eq!(EN.as_str(), "2MAHA");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn encode_str(src: &str) -> Self {
+        let src = src.as_bytes();
+
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the input into the buffer.
+        let result = __internal::buffer_en(src, &mut __raw, None, None);
+        let __pos = __internal::expect_buffer_en(result);
+
+        Self::new(__raw, __pos)
+    }
+
+    /// Encodes a UTF-8 string into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Buffer;
+    ///
+    /// let en = Buffer::<8>::try_encode_str("***")?;
+    /// assert_eq!(en.as_str(), "2MAHA");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_encode_str(src: &str) -> Result<Self> {
+        Self::encode_slice(src.as_bytes())
+    }
+
+    /// Decodes an encoded string into a [`Buffer`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the size `N` is too small or the input
+    /// contains invalid characters, use
+    /// [`try_decode_str`](Self::try_decode_str) for a non-panicking
+    /// alternative. In a const context this fails the build.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const DE: Buffer<5> = Buffer::<5>::decode_str("2MAHA");
+    /// assert_eq!(DE.as_bytes(), [42, 42, 42]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn decode_str(src: &str) -> Self {
+        Self::decode(src.as_bytes())
+    }
+
+    /// Decodes an encoded string into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Buffer;
+    ///
+    /// let de = Buffer::<5>::try_decode_str("2MAHA")?;
+    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// The result can be handled in a const context:
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const DE: Buffer<8> = match Buffer::<8>::try_decode_str("2MAHA") {
+    ///     Ok(buffer) => buffer,
+    ///     Err(_) => panic!("invalid identifier"),
+    /// };
+    /// assert_eq!(DE.as_bytes(), [42, 42, 42]);
+    /// ```
+    #[inline]
+    pub const fn try_decode_str(src: &str) -> Result<Self> {
+        Self::try_decode(src.as_bytes())
+    }
+}
+
+impl<const N: usize> Buffer<N, true, en::Default> {
+    /// Encodes a byte array with a prefix into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 3] = [42, 42, 42];
+    /// const EN: Buffer<6, true> = Buffer::<6, true>::encode(&INPUT, 'S');
+    /// assert_eq!(EN.as_str(), "S2MAHA");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn encode<const M: usize>(src: &[u8; M], prefix: char) -> Self {
+        const {
+            assert!(
+                N > encoded_len(M),
+                "Size 'N' is too small, see `Buffer::required_for`"
+            );
+        }
+
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the input into the buffer.
+        let result = __internal::buffer_en(src, &mut __raw, Some(prefix), None);
+        let __pos = __internal::expect_buffer_en(result);
+
+        Self::new(__raw, __pos)
+    }
+
+    /// Encodes a byte array with a prefix into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Buffer;
+    ///
+    /// let input = [42, 42, 42];
+    /// let en = Buffer::<6, true>::try_encode(&input, 'S')?;
+    /// assert_eq!(en.as_str(), "S2MAHA");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_encode<const M: usize>(
+        src: &[u8; M],
+        prefix: char,
+    ) -> Result<Self> {
+        Self::encode_slice(src, prefix)
+    }
+
+    /// Encodes a byte slice of runtime length with a prefix into a [`Buffer`].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::BufferTooSmall`], the size `N` is below
+    ///   [`encoded_len`]`(src.len()) + 1`.
+    /// - [`Error::InvalidCharacter`], the prefix is not an ASCII character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Buffer;
+    ///
+    /// let input = vec![42, 42, 42];
+    /// let en = Buffer::<6, true>::encode_slice(&input, 'S')?;
+    /// assert_eq!(en.as_str(), "S2MAHA");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn encode_slice(src: &[u8], prefix: char) -> Result<Self> {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the input into the buffer.
+        match __internal::buffer_en(src, &mut __raw, Some(prefix), None) {
+            Ok(__pos) => Ok(Self::new(__raw, __pos)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Decodes a slice of prefixed encoded bytes into a [`Buffer`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the prefix is not an ASCII character, the
+    /// input does not start with it, the size `N` is too small or the input
+    /// contains invalid characters, use [`try_decode`](Self::try_decode)
+    /// for a non-panicking alternative. In a const context this fails the
+    /// build.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 6] = *b"S2MAHA";
+    /// const DE: Buffer<6, true> = Buffer::<6, true>::decode(&INPUT, 'S');
+    /// assert_eq!(DE.as_bytes(), [42, 42, 42]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn decode(src: &[u8], prefix: char) -> Self {
+        assert!(prefix.is_ascii(), "Prefix must be an ASCII character");
+
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Decode the input into the buffer.
+        let result =
+            __internal::buffer_de(src, &mut __raw, Some(prefix), false);
+        let (__pos, _) = __internal::expect_buffer_de(result);
+
+        Self::new(__raw, __pos)
+    }
+
+    /// Decodes a slice of prefixed encoded bytes into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Buffer;
+    ///
+    /// let input = b"S2MAHA";
+    /// let de = Buffer::<6, true>::try_decode(input, 'S')?;
+    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_decode(src: &[u8], prefix: char) -> Result<Self> {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Decode the input into the buffer.
+        match __internal::buffer_de(src, &mut __raw, Some(prefix), false) {
+            Ok((__pos, _)) => Ok(Self::new(__raw, __pos)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Decodes a prefixed encoded string into a [`Buffer`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the prefix is not an ASCII character, the
+    /// input does not start with it, the size `N` is too small or the input
+    /// contains invalid characters, use
+    /// [`try_decode_str`](Self::try_decode_str) for a non-panicking
+    /// alternative. In a const context this fails the build.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// const DE: Buffer<6, true> = Buffer::<6, true>::decode_str("S2MAHA", 'S');
+    /// assert_eq!(DE.as_bytes(), [42, 42, 42]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn decode_str(src: &str, prefix: char) -> Self {
+        Self::decode(src.as_bytes(), prefix)
+    }
+
+    /// Decodes a prefixed encoded string into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::Buffer;
+    ///
+    /// let de = Buffer::<6, true>::try_decode_str("S2MAHA", 'S')?;
+    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_decode_str(src: &str, prefix: char) -> Result<Self> {
+        Self::try_decode(src.as_bytes(), prefix)
+    }
+}
+
+#[cfg(feature = "check")]
+impl<const N: usize> Buffer<N, false, en::Check> {
+    /// Encodes a byte array with a checksum into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_fmt
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 3] = [42, 42, 42];
+    /// const EN: Buffer<13, false, Check> = Buffer::<13, false, Check>::encode(&INPUT, 0);
+    /// assert_eq!(EN.as_str(), "0AHA59B9201Z");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn encode<const M: usize>(src: &[u8; M], version: u8) -> Self {
+        const {
+            assert!(
+                N >= encoded_check_len(M),
+                "Size 'N' is too small, see `Buffer::required_for`"
+            );
+        }
+
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the input into the buffer.
+        let result =
+            __internal::buffer_en(src, &mut __raw, None, Some(version));
+        let __pos = __internal::expect_buffer_en(result);
+
+        Self::new(__raw, __pos)
+    }
+
+    /// Encodes a byte array with a checksum into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    /// use c32::Error;
+    ///
+    /// let input = [42, 42, 42];
+    /// let en = Buffer::<13, false, Check>::try_encode(&input, 0)?;
+    /// assert_eq!(en.as_str(), "0AHA59B9201Z");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_encode<const M: usize>(
+        src: &[u8; M],
+        version: u8,
+    ) -> Result<Self> {
+        Self::encode_slice(src, version)
+    }
+
+    /// Encodes a byte slice of runtime length with a checksum into a
+    /// [`Buffer`].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::BufferTooSmall`], the size `N` is below
+    ///   [`encoded_check_len`]`(src.len())`.
+    /// - [`Error::InvalidVersion`], the version is 32 or greater.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// let input = vec![42, 42, 42];
+    /// let en = Buffer::<13, false, Check>::encode_slice(&input, 0)?;
+    /// assert_eq!(en.as_str(), "0AHA59B9201Z");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn encode_slice(src: &[u8], version: u8) -> Result<Self> {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the input into the buffer.
+        match __internal::buffer_en(src, &mut __raw, None, Some(version)) {
+            Ok(__pos) => Ok(Self::new(__raw, __pos)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Encodes a byte array with a checksum into a [`Buffer`], with a
+    /// [`Version`] that is known to be valid.
+    ///
+    /// Unlike [`Buffer::encode`], this method cannot panic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_fmt
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    /// use c32::Version;
+    ///
+    /// const VERSION: Version = match Version::new(0) {
+    ///     Some(version) => version,
+    ///     None => panic!("invalid version"),
+    /// };
+    /// const EN: Buffer<13, false, Check> = Buffer::<13, false, Check>::encode_versioned(&[42, 42, 42], VERSION);
+    /// assert_eq!(EN.as_str(), "0AHA59B9201Z");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn encode_versioned<const M: usize>(
+        src: &[u8; M],
+        version: Version,
+    ) -> Self {
+        Self::encode(src, version.get())
+    }
+
+    /// Decodes a slice of check-encoded bytes into a [`Buffer`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the size `N` is too small or the input
+    /// contains invalid characters, the checksum is missing or does not
+    /// match, or the version is 32 or greater, use
+    /// [`try_decode`](Self::try_decode) for a non-panicking alternative. In a
+    /// const context this fails the build.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_fmt
+    /// use c32::Buffer;
+    /// use c32::en::Check;
+    ///
+    /// const INPUT: [u8; 12] = *b"0AHA59B9201Z";
+    /// const RESULT: (Buffer<12, false, Check>, u8) = Buffer::<12, false, Check>::decode(&INPUT);
+    /// assert_eq!(RESULT.0.as_bytes(), [42, 42, 42]);
+    /// assert_eq!(RESULT.1, 0);
+    // ```
+    #[inline]
+    #[must_use]
+    pub const fn decode(src: &[u8]) -> (Self, u8) {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Decode the input into the buffer.
+        let result = __internal::buffer_de(src, &mut __raw, None, true);
+        let (__pos, version) = __internal::expect_buffer_de(result);
+
+        (Self::new(__raw, __pos), version)
+    }
+
+    /// Decodes a slice of check-encoded bytes into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// let input = b"0AHA59B9201Z";
+    /// let (de, version) = Buffer::<12, false, Check>::try_decode(input)?;
+    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
+    /// assert_eq!(version, 0);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_decode(src: &[u8]) -> Result<(Self, u8)> {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Decode the input into the buffer.
+        match __internal::buffer_de(src, &mut __raw, None, true) {
+            Ok((__pos, version)) => Ok((Self::new(__raw, __pos), version)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Decodes a check-encoded string into a [`Buffer`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the size `N` is too small or the input
+    /// contains invalid characters, the checksum is missing or does not
+    /// match, or the version is 32 or greater, use
+    /// [`try_decode_str`](Self::try_decode_str) for a non-panicking
+    /// alternative. In a const context this fails the build.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_fmt
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// const RESULT: (Buffer<12, false, Check>, u8) = Buffer::<12, false, Check>::decode_str("0AHA59B9201Z");
+    /// assert_eq!(RESULT.0.as_bytes(), [42, 42, 42]);
+    /// assert_eq!(RESULT.1, 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn decode_str(src: &str) -> (Self, u8) {
+        Self::decode(src.as_bytes())
+    }
+
+    /// Decodes a check-encoded string into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// let (de, version) =
+    ///     Buffer::<12, false, Check>::try_decode_str("0AHA59B9201Z")?;
+    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
+    /// assert_eq!(version, 0);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_decode_str(src: &str) -> Result<(Self, u8)> {
+        Self::try_decode(src.as_bytes())
+    }
+}
+
+#[cfg(feature = "check")]
+impl<const N: usize> Buffer<N, true, en::Check> {
+    /// Encodes a byte array with a checksum and prefix into a [`Buffer`].
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_fmt
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 3] = [42, 42, 42];
+    /// const EN: Buffer<14, true, Check> = Buffer::<14, true, Check>::encode(&INPUT, 'S', 0);
+    /// assert_eq!(EN.as_str(), "S0AHA59B9201Z");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn encode<const M: usize>(
+        src: &[u8; M],
+        prefix: char,
+        version: u8,
+    ) -> Self {
+        const {
+            assert!(
+                N > encoded_check_len(M),
+                "Size 'N' is too small, see `Buffer::required_for`"
+            );
+        }
+
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the input into the buffer.
+        let result =
+            __internal::buffer_en(src, &mut __raw, Some(prefix), Some(version));
+        let __pos = __internal::expect_buffer_en(result);
+
+        Self::new(__raw, __pos)
+    }
+
+    /// Encodes a byte array with a checksum and prefix into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// let input = [42, 42, 42];
+    /// let en = Buffer::<14, true, Check>::try_encode(&input, 'S', 0)?;
+    /// assert_eq!(en.as_str(), "S0AHA59B9201Z");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_encode<const M: usize>(
+        src: &[u8; M],
+        prefix: char,
+        version: u8,
+    ) -> Result<Self> {
+        Self::encode_slice(src, prefix, version)
+    }
+
+    /// Encodes a byte slice of runtime length with a checksum and prefix into
+    /// a [`Buffer`].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::BufferTooSmall`], the size `N` is below
+    ///   [`encoded_check_len`]`(src.len()) + 1`.
+    /// - [`Error::InvalidCharacter`], the prefix is not an ASCII character.
+    /// - [`Error::InvalidVersion`], the version is 32 or greater.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// let input = vec![42, 42, 42];
+    /// let en = Buffer::<14, true, Check>::encode_slice(&input, 'S', 0)?;
+    /// assert_eq!(en.as_str(), "S0AHA59B9201Z");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn encode_slice(
+        src: &[u8],
+        prefix: char,
+        version: u8,
+    ) -> Result<Self> {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the input into the buffer.
+        match __internal::buffer_en(
+            src,
+            &mut __raw,
+            Some(prefix),
+            Some(version),
+        ) {
+            Ok(__pos) => Ok(Self::new(__raw, __pos)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Encodes a byte array with a checksum and prefix into a [`Buffer`],
+    /// with a [`Version`] that is known to be valid.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the prefix is not an ASCII character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_fmt
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    /// use c32::Version;
+    ///
+    /// const VERSION: Version = match Version::new(0) {
+    ///     Some(version) => version,
+    ///     None => panic!("invalid version"),
+    /// };
+    /// const EN: Buffer<14, true, Check> = Buffer::<14, true, Check>::encode_versioned(&[42, 42, 42], 'S', VERSION);
+    /// assert_eq!(EN.as_str(), "S0AHA59B9201Z");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn encode_versioned<const M: usize>(
+        src: &[u8; M],
+        prefix: char,
+        version: Version,
+    ) -> Self {
+        Self::encode(src, prefix, version.get())
+    }
+
+    /// Decodes a slice of prefixed check-encoded bytes into a [`Buffer`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the prefix is not an ASCII character, the
+    /// input does not start with it, the size `N` is too small or the input
+    /// contains invalid characters, the checksum is missing or does not
+    /// match, or the version is 32 or greater, use
+    /// [`try_decode`](Self::try_decode) for a non-panicking alternative. In a
+    /// const context this fails the build.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_fmt
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// const INPUT: [u8; 13] = *b"S0AHA59B9201Z";
+    /// const RESULT: (Buffer<14, true, Check>, u8) = Buffer::<14, true, Check>::decode(&INPUT, 'S');
+    /// assert_eq!(RESULT.0.as_bytes(), [42, 42, 42]);
+    /// assert_eq!(RESULT.1, 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn decode(src: &[u8], prefix: char) -> (Self, u8) {
+        assert!(prefix.is_ascii(), "Prefix must be an ASCII character");
+
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Decode the input into the buffer.
+        let result = __internal::buffer_de(src, &mut __raw, Some(prefix), true);
+        let (__pos, version) = __internal::expect_buffer_de(result);
+
+        (Self::new(__raw, __pos), version)
+    }
+
+    /// Decodes a slice of prefixed check-encoded bytes into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    ///  ```rust
+    /// # use c32::Error;
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// let input = b"S0AHA59B9201Z";
+    /// let (de, version) = Buffer::<14, true, Check>::try_decode(input, 'S')?;
+    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
+    /// assert_eq!(version, 0);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_decode(src: &[u8], prefix: char) -> Result<(Self, u8)> {
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Decode the input into the buffer.
+        match __internal::buffer_de(src, &mut __raw, Some(prefix), true) {
+            Ok((__pos, version)) => Ok((Self::new(__raw, __pos), version)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Decodes a prefixed check-encoded string into a [`Buffer`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the prefix is not an ASCII character, the
+    /// input does not start with it, the size `N` is too small or the input
+    /// contains invalid characters, the checksum is missing or does not
+    /// match, or the version is 32 or greater, use
+    /// [`try_decode_str`](Self::try_decode_str) for a non-panicking
+    /// alternative. In a const context this fails the build.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_fmt
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// const RESULT: (Buffer<14, true, Check>, u8) = Buffer::<14, true, Check>::decode_str("S0AHA59B9201Z", 'S');
+    /// assert_eq!(RESULT.0.as_bytes(), [42, 42, 42]);
+    /// assert_eq!(RESULT.1, 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn decode_str(src: &str, prefix: char) -> (Self, u8) {
+        Self::decode(src.as_bytes(), prefix)
+    }
+
+    /// Decodes a prefixed check-encoded string into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// let (de, version) =
+    ///     Buffer::<14, true, Check>::try_decode_str("S0AHA59B9201Z", 'S')?;
+    /// assert_eq!(de.as_bytes(), [42, 42, 42]);
+    /// assert_eq!(version, 0);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn try_decode_str(src: &str, prefix: char) -> Result<(Self, u8)> {
+        Self::try_decode(src.as_bytes(), prefix)
+    }
+}
+
+#[cfg(feature = "check")]
+impl<const N: usize> Buffer<N, false, en::Check> {
+    /// Transcodes a default [`Buffer`] into a check-encoded [`Buffer`].
+    ///
+    /// The bytes of `buffer` are decoded on the stack and check-encoded with
+    /// the given version, without allocating.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::BufferTooSmall`], the size `N` is below [`encoded_check_len`]
+    ///   of the decoded length.
+    /// - [`Error::InvalidVersion`], the version is 32 or greater.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// const EN: Buffer<5> = Buffer::<5>::encode(&[42, 42, 42]);
+    /// const CHECK: Result<Buffer<13, false, Check>, Error> =
+    ///     Buffer::<13, false, Check>::from_default(&EN, 0);
+    /// assert_eq!(CHECK?.as_str(), "0AHA59B9201Z");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn from_default<const M: usize>(
+        buffer: &Buffer<M, false, en::Default>,
+        version: u8,
+    ) -> Result<Self> {
+        // Decode the buffer to the stack.
+        let mut bytes = [0u8; M];
+        let len = match __internal::buffer_de(
+            buffer.as_bytes(),
+            &mut bytes,
+            None,
+            false,
+        ) {
+            Ok((len, _)) => len,
+            Err(e) => return Err(e),
+        };
+        let (src, _) = bytes.split_at(len);
+
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the bytes into the buffer.
+        match __internal::buffer_en(src, &mut __raw, None, Some(version)) {
+            Ok(__pos) => Ok(Self::new(__raw, __pos)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "check")]
+impl<const N: usize> Buffer<N, false, en::Default> {
+    /// Transcodes a check-encoded [`Buffer`] into a default [`Buffer`].
+    ///
+    /// The checksum of `buffer` is verified while its payload is decoded on
+    /// the stack, without allocating.
+    ///
+    /// # Returns
+    ///
+    /// The transcoded buffer, and the version of `buffer`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::BufferTooSmall`], the size `N` is below [`encoded_len`] of
+    ///   the payload length.
+    /// - [`Error::InsufficientData`], `buffer` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::en::Check;
+    /// use c32::Buffer;
+    ///
+    /// const CHECK: Buffer<13, false, Check> =
+    ///     Buffer::<13, false, Check>::encode(&[42, 42, 42], 0);
+    /// let (en, version) = Buffer::<5>::from_check(&CHECK)?;
+    /// assert_eq!(en.as_str(), "2MAHA");
+    /// assert_eq!(version, 0);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn from_check<const M: usize>(
+        buffer: &Buffer<M, false, en::Check>,
+    ) -> Result<(Self, u8)> {
+        // Decode the buffer to the stack, verifying the checksum.
+        let mut bytes = [0u8; M];
+        let (len, version) = match __internal::buffer_de(
+            buffer.as_bytes(),
+            &mut bytes,
+            None,
+            true,
+        ) {
+            Ok(result) => result,
+            Err(e) => return Err(e),
+        };
+        let (src, _) = bytes.split_at(len);
+
+        // Allocate the output buffer.
+        let mut __raw = [0u8; N];
+
+        // Encode the payload into the buffer.
+        match __internal::buffer_en(src, &mut __raw, None, None) {
+            Ok(__pos) => Ok((Self::new(__raw, __pos), version)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// A [`Buffer`] sized for check-encoding a 20-byte payload, e.g. a hash160.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "check")] {
+/// use c32::CheckBuffer20;
+///
+/// let en = CheckBuffer20::encode_payload(&[0xAB; 20], 22);
+/// assert_eq!(en.as_str().len(), c32::check_buffer_len(20, false));
+/// # }
+/// ```
+#[cfg(feature = "check")]
+pub type CheckBuffer20 =
+    Buffer<{ check_buffer_len(20, false) }, false, en::Check>;
+
+/// A [`Buffer`] sized for check-encoding a 20-byte payload with a prefix.
+#[cfg(feature = "check")]
+pub type PrefixedCheckBuffer20 =
+    Buffer<{ check_buffer_len(20, true) }, true, en::Check>;
+
+/// A [`Buffer`] sized for check-encoding a 32-byte payload, e.g. a SHA-256.
+#[cfg(feature = "check")]
+pub type CheckBuffer32 =
+    Buffer<{ check_buffer_len(32, false) }, false, en::Check>;
+
+/// A [`Buffer`] sized for check-encoding a 32-byte payload with a prefix.
+#[cfg(feature = "check")]
+pub type PrefixedCheckBuffer32 =
+    Buffer<{ check_buffer_len(32, true) }, true, en::Check>;
+
+/// Implements the fixed-payload constructors of the check buffer aliases.
+#[cfg(feature = "check")]
+macro_rules! impl_check_buffer {
+    ($($n:literal),*) => {$(
+        impl Buffer<{ check_buffer_len($n, false) }, false, en::Check> {
+            #[doc = concat!(
+                "Encodes a ", stringify!($n), "-byte payload with a ",
+                "checksum into a [`Buffer`].",
+            )]
+            ///
+            /// Unlike [`Buffer::encode`], the payload must have exactly the
+            /// size of the buffer, which is checked at compile time.
+            ///
+            /// # Panics
+            ///
+            /// This method will panic if the version is 32 or greater.
+            #[inline]
+            #[must_use]
+            pub const fn encode_payload(src: &[u8; $n], version: u8) -> Self {
+                Self::encode(src, version)
+            }
+        }
+
+        impl Buffer<{ check_buffer_len($n, true) }, true, en::Check> {
+            #[doc = concat!(
+                "Encodes a ", stringify!($n), "-byte payload with a ",
+                "checksum and prefix into a [`Buffer`].",
+            )]
+            ///
+            /// Unlike [`Buffer::encode`], the payload must have exactly the
+            /// size of the buffer, which is checked at compile time.
+            ///
+            /// # Panics
+            ///
+            /// This method will panic if the version is 32 or greater.
+            #[inline]
+            #[must_use]
+            pub const fn encode_payload(
+                src: &[u8; $n],
+                prefix: char,
+                version: u8,
+            ) -> Self {
+                Self::encode(src, prefix, version)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "check")]
+impl_check_buffer!(20, 32);
+
+#[cfg(feature = "rand")]
+impl<const N: usize> Buffer<N, false, en::Default> {
+    /// Encodes `M` random bytes into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::Buffer;
+    ///
+    /// let mut rng = rand::rng();
+    /// let id = Buffer::<32>::random::<20, _>(&mut rng);
+    ///
+    /// let de = Buffer::<32>::try_decode(id.as_bytes())?;
+    /// assert_eq!(de.pos(), 20);
+    /// # Ok::<(), c32::Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn random<const M: usize, R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let mut bytes = [0u8; M];
+        rng.fill_bytes(&mut bytes);
+        Self::encode(&bytes)
+    }
+}
+
+/// A builder that accumulates byte chunks and encodes them into a
+/// fixed-width [`Buffer`].
+///
+/// [`C32Builder`] does not require the total length up front, and does not
+/// allocate, which makes it suitable for accumulating fragments in a
+/// `#![no_std]` environment.
+///
+/// # Notes
+///
+/// - Crockford Base32 encodes its input as a single big-endian number, so no
+///   character is final before the input is complete. The chunks are stored in
+///   the internal array, and encoded by [`C32Builder::finish`].
+/// - The output always has [`encoded_len`] characters of the concatenated
+///   chunks, left-padded with the zero symbol. Unlike [`encode`], leading zero
+///   bytes are not preserved as individual symbols.
+///
+/// # Generics
+///
+/// * `CAP` - The capacity of the encoded output in bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::C32Builder;
+///
+/// let mut builder = C32Builder::<8>::new();
+/// builder.push_bytes(&[42])?;
+/// builder.push_bytes(&[42, 42])?;
+/// assert_eq!(builder.finish().as_str(), "2MAHA");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`encode`]: crate::encode
+#[derive(Debug, Clone)]
+pub struct C32Builder<const CAP: usize> {
+    /// The accumulated bytes.
+    __raw: [u8; CAP],
+    /// The number of accumulated bytes.
+    __pos: usize,
+}
+
+impl<const CAP: usize> C32Builder<CAP> {
+    /// Creates a new, empty [`C32Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::C32Builder;
+    ///
+    /// let builder = C32Builder::<8>::new();
+    /// assert_eq!(builder.remaining_capacity(), 5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            __raw: [0u8; CAP],
+            __pos: 0,
+        }
+    }
+
+    /// Returns the number of bytes that can still be pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::C32Builder;
+    ///
+    /// let mut builder = C32Builder::<8>::new();
+    /// builder.push_bytes(&[42, 42])?;
+    /// assert_eq!(builder.remaining_capacity(), 3);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP * 5 / 8 - self.__pos
+    }
+
+    /// Appends a chunk of bytes.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::BufferTooSmall`], the capacity `CAP` is insufficient.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::C32Builder;
+    /// use c32::Error;
+    ///
+    /// let mut builder = C32Builder::<4>::new();
+    /// let result = builder.push_bytes(&[1, 2, 3]);
+    /// assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 4 }));
+    /// ```
+    #[inline]
+    pub fn push_bytes(&mut self, chunk: &[u8]) -> Result<()> {
+        // Assert that the output has enough capacity.
+        if chunk.len() > self.remaining_capacity() {
+            return Err(Error::BufferTooSmall {
+                min: encoded_len(self.__pos + chunk.len()),
+                len: CAP,
+            });
+        }
+
+        let end = self.__pos + chunk.len();
+        self.__raw[self.__pos..end].copy_from_slice(chunk);
+        self.__pos = end;
+        Ok(())
+    }
+
+    /// Encodes the accumulated bytes into a [`Buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use c32::Error;
+    /// use c32::C32Builder;
+    ///
+    /// let mut builder = C32Builder::<8>::new();
+    /// builder.push_bytes(&[0, 1])?;
+    /// assert_eq!(builder.finish().as_str(), "0001");
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[must_use]
+    pub fn finish(self) -> Buffer<CAP> {
+        let width = encoded_len(self.__pos);
+
+        // Encode the bytes in canonical form.
+        let mut canonical = [0u8; CAP];
+        let len =
+            __internal::en(&self.__raw, 0, self.__pos, &mut canonical, 0, None);
+
+        // Skip the leading zero symbols, which carry no value.
+        let mut start = 0;
+        while start < len && canonical[start] == ALPHABET[0] {
+            start += 1;
+        }
+
+        // Left-pad the value with the zero symbol.
+        let mut __raw = [ALPHABET[0]; CAP];
+        let offset = width - (len - start);
+        __raw[offset..width].copy_from_slice(&canonical[start..len]);
+        __internal::wipe(&mut canonical);
+
+        Buffer::new(__raw, width)
+    }
+}
+
+impl<const CAP: usize> Default for C32Builder<CAP> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The accumulated bytes are wiped with the `zeroize` feature. The impl exists
+// in every build, so that enabling the feature does not change the type.
+impl<const CAP: usize> Drop for C32Builder<CAP> {
+    #[inline]
+    fn drop(&mut self) {
+        __internal::wipe(&mut self.__raw);
+    }
+}
+
+/// Names a [`Buffer`] type sized for encoding a payload of the given length.
+///
+/// The size is computed with [`Buffer::required_for`], and the optional
+/// `check` and `prefixed` flags select the [`en::Check`] encoding and the
+/// prefix character, in that order.
+///
+/// # Examples
+///
+/// ```rust
+/// use c32::buffer_for;
+///
+/// const EN: buffer_for!(3) = <buffer_for!(3)>::encode(&[42, 42, 42]);
+/// assert_eq!(EN.as_str(), "2MAHA");
+///
+/// type Prefixed = buffer_for!(prefixed, 3);
+/// const PREFIXED: Prefixed = Prefixed::encode(&[42, 42, 42], 'S');
+/// assert_eq!(PREFIXED.as_str(), "S2MAHA");
+/// ```
+///
+/// ```rust
+/// # #[cfg(feature = "check")] {
+/// use c32::buffer_for;
+///
+/// type Id = buffer_for!(check, prefixed, 3);
+/// const ID: Id = Id::encode(&[42, 42, 42], 'S', 0);
+/// assert_eq!(ID.as_str(), "S0AHA59B9201Z");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! buffer_for {
+    (check, prefixed, $len:expr $(,)?) => {
+        $crate::buffer_for!(@ true, $crate::en::Check, $len)
+    };
+    (check, $len:expr $(,)?) => {
+        $crate::buffer_for!(@ false, $crate::en::Check, $len)
+    };
+    (prefixed, $len:expr $(,)?) => {
+        $crate::buffer_for!(@ true, $crate::en::Default, $len)
+    };
+    (@ $prefix:literal, $e:ty, $len:expr) => {
+        $crate::Buffer<
+            { $crate::Buffer::<0, $prefix, $e>::required_for($len) },
+            $prefix,
+            $e,
+        >
+    };
+    ($len:expr $(,)?) => {
+        $crate::buffer_for!(@ false, $crate::en::Default, $len)
+    };
+}
+
+/// Encodes a byte string literal into a Crockford Base32 `&'static str` at
+/// compile time.
+///
+/// The input must be a constant expression of type `&[u8; N]`, and is
+/// encoded with [`Buffer::encode`].
+///
+/// # Examples
+///
+/// ```rust
+/// const ID: &str = c32::c32!(b"usque ad finem");
+/// assert_eq!(ID, "1TQ6WBNCMG62S10CSMPWSBD");
+///
+/// const BYTES: &str = c32::c32!(&[42, 42, 42]);
+/// assert_eq!(BYTES, "2MAHA");
+/// ```
+///
+/// The input must be a byte array:
+///
+/// ```compile_fail
+/// const ID: &str = c32::c32!("usque ad finem");
+/// ```
+///
+/// And must be known at compile time:
+///
+/// ```compile_fail
+/// let bytes = [42, 42, 42];
+/// let id: &str = c32::c32!(&bytes);
+/// ```
+#[macro_export]
+macro_rules! c32 {
+    ($bytes:expr $(,)?) => {{
+        const __SRC: &[u8] = $bytes;
+        const __M: usize = __SRC.len();
+        const __N: usize = $crate::encoded_len(__M);
+        const __BYTES: &[u8; __M] = $bytes;
+        const __BUF: $crate::Buffer<__N> =
+            $crate::Buffer::<__N>::encode(__BYTES);
+        const __STR: &str = __BUF.as_str();
+        __STR
+    }};
+}
+
+/// Encodes a byte string literal into a Crockford Base32Check
+/// `&'static str` at compile time.
+///
+/// The input must be a constant expression of type `&[u8; N]`, and is
+/// encoded with [`Buffer::encode`] using the [`en::Check`] encoding.
+///
+/// # Examples
+///
+/// ```rust
+/// const ID: &str = c32::c32_check!(b"usque ad finem", 22);
+/// assert_eq!(ID, "P7AWVHENJJ0RB441K6JVK5DNJ7J3V5");
+/// ```
+///
+/// The version must be less than 32:
+///
+/// ```compile_fail
+/// const ID: &str = c32::c32_check!(b"usque ad finem", 32);
+/// ```
+///
+/// [`Buffer::encode`]: Buffer#method.encode-2
+#[cfg(feature = "check")]
+#[macro_export]
+macro_rules! c32_check {
+    ($bytes:expr, $version:expr $(,)?) => {{
+        const __SRC: &[u8] = $bytes;
+        const __M: usize = __SRC.len();
+        const __N: usize = $crate::encoded_check_len(__M);
+        const __BYTES: &[u8; __M] = $bytes;
+        const __BUF: $crate::Buffer<__N, false, $crate::en::Check> =
+            $crate::Buffer::<__N, false, $crate::en::Check>::encode(
+                __BYTES, $version,
+            );
+        const __STR: &str = __BUF.as_str();
+        __STR
+    }};
+}
+
+/// A fixed-size byte array stored as Crockford Base32 text.
+///
+/// [`C32Text`] encodes its bytes when written as text, and enforces the
+/// expected byte length `N` when parsed, which makes it suitable for
+/// identifiers stored in textual columns.
+///
+/// # Generics
+///
+/// * `N` - The size of the decoded byte array in bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::C32Text;
+///
+/// let text = C32Text::new([42, 42, 42]);
+/// assert_eq!(text.to_string(), "2MAHA");
+///
+/// let text: C32Text<3> = "2MAHA".parse()?;
+/// assert_eq!(text.as_bytes(), &[42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct C32Text<const N: usize>(pub [u8; N]);
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> C32Text<N> {
+    /// Creates a new [`C32Text`] from a byte array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::C32Text;
+    ///
+    /// let text = C32Text::new([42, 42, 42]);
+    /// assert_eq!(text.as_bytes(), &[42, 42, 42]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns a reference to the underlying byte array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::C32Text;
+    ///
+    /// let text = C32Text::new([42, 42, 42]);
+    /// assert_eq!(text.as_bytes(), &[42, 42, 42]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Consumes the [`C32Text`], returning the underlying byte array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::C32Text;
+    ///
+    /// let text = C32Text::new([42, 42, 42]);
+    /// assert_eq!(text.into_inner(), [42, 42, 42]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> [u8; N] {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<[u8; N]> for C32Text<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<C32Text<N>> for [u8; N] {
+    fn from(text: C32Text<N>) -> Self {
+        text.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> fmt::Display for C32Text<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&encode(self.0))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> str::FromStr for C32Text<N> {
+    type Err = Error;
+
+    fn from_str(str: &str) -> Result<Self> {
+        let bytes = decode(str)?;
+
+        // Assert that the decoded bytes match the expected size.
+        if bytes.len() != N {
+            return Err(Error::InvalidDataSize {
+                expected: N,
+                got: bytes.len(),
+            });
+        }
+
+        let mut dst = [0u8; N];
+        dst.copy_from_slice(&bytes);
+        Ok(Self(dst))
+    }
+}
+
+/// [`postgres-types`] integration for [`C32Text`].
+///
+/// [`postgres-types`]: https://docs.rs/postgres-types
+#[cfg(feature = "postgres-types")]
+mod __postgres {
+    use bytes::BytesMut;
+    use postgres_types::FromSql;
+    use postgres_types::IsNull;
+    use postgres_types::ToSql;
+    use postgres_types::Type;
+
+    use super::*;
+
+    /// A type alias for the error type used by [`postgres_types`].
+    type BoxError = alloc::boxed::Box<dyn error::Error + Sync + Send>;
+
+    impl<const N: usize> ToSql for C32Text<N> {
+        fn to_sql(
+            &self,
+            ty: &Type,
+            out: &mut BytesMut,
+        ) -> core::result::Result<IsNull, BoxError> {
+            encode(self.0).to_sql(ty, out)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <&str as ToSql>::accepts(ty)
+        }
+
+        fn to_sql_checked(
+            &self,
+            ty: &Type,
+            out: &mut BytesMut,
+        ) -> core::result::Result<IsNull, BoxError> {
+            encode(self.0).to_sql_checked(ty, out)
+        }
+    }
+
+    impl<'a, const N: usize> FromSql<'a> for C32Text<N> {
+        fn from_sql(
+            ty: &Type,
+            raw: &'a [u8],
+        ) -> core::result::Result<Self, BoxError> {
+            let str = <&str as FromSql>::from_sql(ty, raw)?;
+            Ok(str.parse()?)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <&str as FromSql>::accepts(ty)
+        }
+    }
+}
+
+/// [`sqlx`] integration for [`C32Text`].
+///
+/// [`sqlx`]: https://docs.rs/sqlx
+#[cfg(feature = "sqlx")]
+mod __sqlx {
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::Database;
+    use sqlx::Decode;
+    use sqlx::Encode;
+    use sqlx::Type;
+
+    use super::*;
+
+    impl<const N: usize, DB: Database> Type<DB> for C32Text<N>
+    where
+        String: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, const N: usize, DB: Database> Encode<'q, DB> for C32Text<N>
+    where
+        String: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut DB::ArgumentBuffer<'q>,
+        ) -> core::result::Result<IsNull, BoxDynError> {
+            <String as Encode<'q, DB>>::encode(encode(self.0), buf)
+        }
+    }
+
+    impl<'r, const N: usize, DB: Database> Decode<'r, DB> for C32Text<N>
+    where
+        &'r str: Decode<'r, DB>,
+    {
+        fn decode(
+            value: DB::ValueRef<'r>,
+        ) -> core::result::Result<Self, BoxDynError> {
+            let str = <&'r str as Decode<'r, DB>>::decode(value)?;
+            Ok(str.parse()?)
+        }
+    }
+}
+
+/// [`zeroize`] integration for the owned types and decoding temporaries.
+///
+/// [`zeroize`]: https://docs.rs/zeroize
+#[cfg(feature = "zeroize")]
+mod __zeroize {
+    use zeroize::Zeroize;
+    use zeroize::ZeroizeOnDrop;
+
+    use super::*;
+
+    impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>> Zeroize
+        for Buffer<LEN, PREFIX, E>
+    {
+        fn zeroize(&mut self) {
+            self.__raw.zeroize();
+            self.__pos = 0;
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<const N: usize> Zeroize for C32Text<N> {
+        fn zeroize(&mut self) {
+            self.0.zeroize();
+        }
+    }
+
+    impl<const CAP: usize> Zeroize for C32Builder<CAP> {
+        fn zeroize(&mut self) {
+            self.__raw.zeroize();
+            self.__pos = 0;
+        }
+    }
+
+    impl<const CAP: usize> ZeroizeOnDrop for C32Builder<CAP> {}
+
+    // The adapters own their buffers through '__stream' types, which are
+    // zeroized on drop.
+    #[cfg(feature = "std")]
+    impl<R> ZeroizeOnDrop for io::DecodeReader<R> {}
+
+    #[cfg(feature = "tokio")]
+    impl<R> ZeroizeOnDrop for io::AsyncDecodeReader<R> {}
+
+    #[cfg(feature = "futures")]
+    impl<S> ZeroizeOnDrop for stream::DecodeStream<S> {}
+}
+
+/// [`metrics`] instrumentation for the allocating entry points.
+///
+/// Every call is recorded with an `op` label naming the entry point:
+///
+/// - `c32_calls_total`, the number of calls.
+/// - `c32_bytes_in_total`, the number of input bytes.
+/// - `c32_bytes_out_total`, the number of output bytes of successful calls.
+/// - `c32_errors_total`, the number of failed calls, with a `kind` label from
+///   [`Error::kind`].
+///
+/// [`metrics`]: https://docs.rs/metrics
+#[cfg(feature = "metrics")]
+mod __metrics {
+    use metrics::counter;
+
+    use super::*;
+
+    /// Records a call to `op` with `input` bytes and its `output` length.
+    #[inline]
+    pub(crate) fn record(
+        op: &'static str,
+        input: usize,
+        output: core::result::Result<usize, &Error>,
+    ) {
+        counter!("c32_calls_total", "op" => op).increment(1);
+        counter!("c32_bytes_in_total", "op" => op).increment(input as u64);
+
+        match output {
+            Ok(len) => {
+                counter!("c32_bytes_out_total", "op" => op)
+                    .increment(len as u64);
+            }
+            Err(err) => {
+                counter!("c32_errors_total", "op" => op, "kind" => err.kind())
+                    .increment(1);
+            }
+        }
+    }
+}
+
+/// [`tracing`] instrumentation for the allocating entry points.
+///
+/// Every call is wrapped in a `c32` span at the `TRACE` level, with fields:
+///
+/// - `op`, the name of the entry point.
+/// - `input`, the number of input bytes.
+/// - `output`, the number of output bytes of successful calls.
+/// - `error`, the [`Error::kind`] of failed calls.
+///
+/// [`tracing`]: https://docs.rs/tracing
+#[cfg(feature = "tracing")]
+mod __tracing {
+    use tracing::field::Empty;
+    use tracing::span::EnteredSpan;
+    use tracing::trace_span;
+
+    use super::*;
+
+    /// Enters a span for a call to `op` with `input` bytes.
+    #[inline]
+    pub(crate) fn enter(op: &'static str, input: usize) -> EnteredSpan {
+        trace_span!("c32", op, input, output = Empty, error = Empty).entered()
+    }
+
+    /// Records the `output` length of a call, and exits its span.
+    #[inline]
+    pub(crate) fn exit(
+        span: EnteredSpan,
+        output: core::result::Result<usize, &Error>,
+    ) {
+        match output {
+            Ok(len) => span.record("output", len),
+            Err(err) => span.record("error", err.kind()),
+        };
+        span.exit();
+    }
+}
+
+/// [`serde`] integration for [`Buffer`].
+///
+/// A [`Buffer`] is serialized as its textual form, and deserialized from a
+/// string that is validated and stored as written, without allocating.
+///
+/// [`serde`]: https://docs.rs/serde
+#[cfg(feature = "serde")]
+mod __serde {
+    use serde::de;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    use super::*;
+
+    impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>> Serialize
+        for Buffer<LEN, PREFIX, E>
+    {
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> core::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de, const LEN: usize> Deserialize<'de> for Buffer<LEN> {
+        fn deserialize<D>(
+            deserializer: D,
+        ) -> core::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(BufferVisitor::<LEN>)
+        }
+    }
+
+    /// A visitor that copies an encoded string into a [`Buffer`].
+    struct BufferVisitor<const LEN: usize>;
+
+    impl<const LEN: usize> de::Visitor<'_> for BufferVisitor<LEN> {
+        type Value = Buffer<LEN>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a Crockford Base32 string of at most {LEN} characters")
+        }
+
+        fn visit_str<E>(self, str: &str) -> core::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            let bytes = str.as_bytes();
+
+            // Assert that the string decodes.
+            validate(bytes).map_err(de::Error::custom)?;
+
+            // Assert that the string fits into the buffer.
+            if bytes.len() > LEN {
+                return Err(de::Error::custom(Error::BufferTooSmall {
+                    min: bytes.len(),
+                    len: LEN,
+                }));
+            }
+
+            let mut __raw = [0u8; LEN];
+            __raw[..bytes.len()].copy_from_slice(bytes);
+            Ok(Buffer::new(__raw, bytes.len()))
+        }
+    }
+}
+
+/// [`ufmt`] integration for [`UDisplay`], [`Buffer`] and [`Error`].
+///
+/// The output is written through the [`uWrite`] trait, which `ufmt`
+/// re-exports, so it never goes through `core::fmt`.
+///
+/// [`ufmt`]: https://docs.rs/ufmt
+/// [`uWrite`]: ufmt_write::uWrite
+#[cfg(feature = "ufmt")]
+mod __ufmt {
+    use ufmt_write::uWrite;
+
+    use super::*;
+
+    /// The size of the chunks written to the sink.
+    const CHUNK: usize = 8;
+
+    /// Writes ASCII bytes to the sink.
+    fn write_ascii<W>(
+        w: &mut W,
+        bytes: &[u8],
+    ) -> core::result::Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        // This should not panic, as we only write valid ASCII.
+        w.write_str(str::from_utf8(bytes).unwrap())
+    }
+
+    /// Writes an unsigned integer in decimal to the sink.
+    #[allow(clippy::cast_possible_truncation)]
+    fn write_usize<W>(
+        w: &mut W,
+        mut n: usize,
+    ) -> core::result::Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let mut digits = [0u8; 20];
+        let mut pos = digits.len();
+        loop {
+            pos -= 1;
+            digits[pos] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        write_ascii(w, &digits[pos..])
+    }
+
+    /// Writes a checksum in lowercase hexadecimal.
+    #[cfg(feature = "check-core")]
+    fn write_checksum<W>(
+        w: &mut W,
+        sum: checksum::Checksum,
+    ) -> core::result::Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+
+        let mut hex = [0u8; 2 * checksum::BYTE_LENGTH];
+        for (i, &byte) in sum.iter().enumerate() {
+            hex[2 * i] = HEX[usize::from(byte >> 4)];
+            hex[2 * i + 1] = HEX[usize::from(byte & 0xF)];
+        }
+        write_ascii(w, &hex)
+    }
+
+    impl UDisplay<'_> {
+        /// Encodes the bytes into `w`, in chunks of at most 8 characters.
+        ///
+        /// The output is identical to [`encode`].
+        ///
+        /// # Errors
+        ///
+        /// This method will return an error if writing to `w` fails.
+        ///
+        /// [`encode`]: crate::encode
+        pub fn uwrite_to<W>(
+            &self,
+            w: &mut W,
+        ) -> core::result::Result<(), W::Error>
+        where
+            W: uWrite + ?Sized,
+        {
+            let mut packer = __stream::Packer::new(self.bytes.len() as u64);
+            let mut chunk = [0u8; CHUNK];
+            let mut pos = 0;
+
+            for &byte in self.bytes {
+                // Flush the chunk before it could overflow.
+                if pos + 3 > CHUNK {
+                    write_ascii(w, &chunk[..pos])?;
+                    pos = 0;
+                }
+
+                let mut chars = [0u8; 3];
+                let n = packer.push_byte(byte, &mut chars);
+                chunk[pos..pos + n].copy_from_slice(&chars[..n]);
+                pos += n;
+            }
+
+            write_ascii(w, &chunk[..pos])
+        }
+    }
+
+    impl<const LEN: usize, const PREFIX: bool, E: Encoding<PREFIX>>
+        Buffer<LEN, PREFIX, E>
+    {
+        /// Writes the buffer to a [`uWrite`] sink.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an error if writing to `w` fails.
+        ///
+        /// [`uWrite`]: ufmt_write::uWrite
+        pub fn uwrite_to<W>(
+            &self,
+            w: &mut W,
+        ) -> core::result::Result<(), W::Error>
+        where
+            W: uWrite + ?Sized,
+        {
+            w.write_str(self.as_str())
+        }
+    }
+
+    impl Error {
+        /// Writes the error to a [`uWrite`] sink, matching its `Display`
+        /// implementation.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an error if writing to `w` fails.
+        ///
+        /// [`uWrite`]: ufmt_write::uWrite
+        pub fn uwrite_to<W>(
+            &self,
+            w: &mut W,
+        ) -> core::result::Result<(), W::Error>
+        where
+            W: uWrite + ?Sized,
+        {
+            match self {
+                Self::BufferTooSmall { min, len } => {
+                    w.write_str("Buffer too small: ")?;
+                    write_usize(w, *len)?;
+                    w.write_str(" < ")?;
+                    write_usize(w, *min)
+                }
+                Self::InvalidDataSize { expected, got } => {
+                    w.write_str("Invalid data size: ")?;
+                    write_usize(w, *got)?;
+                    w.write_str(" != ")?;
+                    write_usize(w, *expected)
+                }
+                Self::InvalidCharacter { char, index, .. } => {
+                    w.write_str("Invalid character '")?;
+                    w.write_char(*char)?;
+                    w.write_str("' at position ")?;
+                    write_usize(w, *index)
+                }
+                Self::MissingPrefix { char, got } => {
+                    w.write_str("Expected prefix '")?;
+                    w.write_char(*char)?;
+                    match got {
+                        Some(got) => {
+                            w.write_str("', found '")?;
+                            w.write_char(*got)?;
+                            w.write_str("'")
+                        }
+                        None => w.write_str("', found nothing"),
+                    }
+                }
+                Self::InvalidFrame { field, reason } => {
+                    w.write_str("Frame field ")?;
+                    write_usize(w, *field)?;
+                    w.write_str(": ")?;
+                    w.write_str(reason)
+                }
+                Self::OutputTooLarge { max, got } => {
+                    w.write_str("Output too large: ")?;
+                    write_usize(w, *got)?;
+                    w.write_str(" > ")?;
+                    write_usize(w, *max)
+                }
+                Self::InvalidPadding { index } => {
+                    w.write_str("Invalid padding at position ")?;
+                    write_usize(w, *index)
+                }
+                Self::AllocationFailed { requested } => {
+                    w.write_str("Allocation failed: ")?;
+                    write_usize(w, *requested)?;
+                    w.write_str(" bytes")
+                }
+                #[cfg(feature = "check-core")]
+                Self::InvalidVersion { expected, version } => {
+                    w.write_str("Invalid version ")?;
+                    write_usize(w, usize::from(*version))?;
+                    w.write_str(": ")?;
+                    w.write_str(expected)
+                }
+                #[cfg(feature = "check-core")]
+                Self::InsufficientData { min, len } => {
+                    w.write_str("Insufficient data: ")?;
+                    write_usize(w, *len)?;
+                    w.write_str(" < ")?;
+                    write_usize(w, *min)
+                }
+                #[cfg(feature = "check-core")]
+                Self::ChecksumMismatch { expected, got, .. } => {
+                    w.write_str("Checksum mismatch: expected ")?;
+                    write_checksum(w, *expected)?;
+                    w.write_str(", got ")?;
+                    write_checksum(w, *got)
+                }
+            }
+        }
+    }
+}
+
+/// A [`serde_with`] adapter for bytes stored as Crockford Base32 strings.
+///
+/// Supports any `AsRef<[u8]>` when serializing, and `Vec<u8>` or `[u8; N]`
+/// when deserializing. Fixed-size arrays reject decoded data of any other
+/// length with [`Error::InvalidDataSize`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde::Serialize;
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde_as(as = "c32::AsC32")]
+///     id: [u8; 3],
+/// }
+///
+/// let record = Record { id: [42, 42, 42] };
+/// let json = serde_json::to_string(&record).unwrap();
+/// assert_eq!(json, r#"{"id":"2MAHA"}"#);
+/// ```
+///
+/// [`serde_with`]: https://docs.rs/serde_with
+#[cfg(feature = "serde_with")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsC32;
+
+/// A [`serde_with`] adapter for bytes stored as Crockford Base32Check
+/// strings with the version `VERSION`.
+///
+/// Deserializing rejects strings encoded with any other version with
+/// [`Error::InvalidVersion`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde::Serialize;
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde_as(as = "c32::AsC32Check<0>")]
+///     id: Vec<u8>,
+/// }
+///
+/// let record = Record {
+///     id: vec![42, 42, 42],
+/// };
+/// let json = serde_json::to_string(&record).unwrap();
+/// assert_eq!(json, r#"{"id":"0AHA59B9201Z"}"#);
+/// ```
+///
+/// [`serde_with`]: https://docs.rs/serde_with
+#[cfg(all(feature = "serde_with", feature = "check"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsC32Check<const VERSION: u8>;
+
+/// Private module containing [`serde_with`] implementations.
+///
+/// [`serde_with`]: https://docs.rs/serde_with
+#[cfg(feature = "serde_with")]
+mod __serde_with {
+    use serde::de;
+    #[cfg(feature = "check")]
+    use serde::ser;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serializer;
+    use serde_with::DeserializeAs;
+    use serde_with::SerializeAs;
+
+    use super::*;
+
+    /// Copies decoded bytes into a fixed-size array.
+    fn to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N]> {
+        // Assert that the decoded data has the expected size.
+        if bytes.len() != N {
+            return Err(Error::InvalidDataSize {
+                expected: N,
+                got: bytes.len(),
+            });
+        }
+
+        let mut array = [0u8; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+
+    impl<T> SerializeAs<T> for AsC32
+    where
+        T: AsRef<[u8]>,
+    {
+        fn serialize_as<S>(
+            source: &T,
+            serializer: S,
+        ) -> core::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&encode(source))
+        }
+    }
+
+    impl<'de> DeserializeAs<'de, Vec<u8>> for AsC32 {
+        fn deserialize_as<D>(
+            deserializer: D,
+        ) -> core::result::Result<Vec<u8>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let str = String::deserialize(deserializer)?;
+            decode(&str).map_err(de::Error::custom)
+        }
+    }
+
+    impl<'de, const N: usize> DeserializeAs<'de, [u8; N]> for AsC32 {
+        fn deserialize_as<D>(
+            deserializer: D,
+        ) -> core::result::Result<[u8; N], D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let bytes: Vec<u8> = AsC32::deserialize_as(deserializer)?;
+            to_array(&bytes).map_err(de::Error::custom)
+        }
+    }
+
+    #[cfg(feature = "check")]
+    impl<T, const VERSION: u8> SerializeAs<T> for AsC32Check<VERSION>
+    where
+        T: AsRef<[u8]>,
+    {
+        fn serialize_as<S>(
+            source: &T,
+            serializer: S,
+        ) -> core::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let str =
+                encode_check(source, VERSION).map_err(ser::Error::custom)?;
+            serializer.serialize_str(&str)
+        }
+    }
+
+    #[cfg(feature = "check")]
+    impl<'de, const VERSION: u8> DeserializeAs<'de, Vec<u8>>
+        for AsC32Check<VERSION>
+    {
+        fn deserialize_as<D>(
+            deserializer: D,
+        ) -> core::result::Result<Vec<u8>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let str = String::deserialize(deserializer)?;
+            let (bytes, version) =
+                decode_check(&str).map_err(de::Error::custom)?;
+
+            // Assert that the version matches the expected version.
+            if version != VERSION {
+                return Err(de::Error::custom(Error::InvalidVersion {
+                    expected: "must match the expected version",
+                    version,
+                }));
+            }
+
+            Ok(bytes)
+        }
+    }
+
+    #[cfg(feature = "check")]
+    impl<'de, const N: usize, const VERSION: u8> DeserializeAs<'de, [u8; N]>
+        for AsC32Check<VERSION>
+    {
+        fn deserialize_as<D>(
+            deserializer: D,
+        ) -> core::result::Result<[u8; N], D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let bytes: Vec<u8> =
+                AsC32Check::<VERSION>::deserialize_as(deserializer)?;
+            to_array(&bytes).map_err(de::Error::custom)
+        }
+    }
+}
+
+/// [`schemars`] integration for [`C32Text`] and the [`serde_with`] adapters.
+///
+/// [`schemars`]: https://docs.rs/schemars
+/// [`serde_with`]: https://docs.rs/serde_with
+#[cfg(feature = "schemars")]
+mod __schemars {
+    use alloc::format;
+
+    use schemars::json_schema;
+    use schemars::JsonSchema;
+    use schemars::Schema;
+    use schemars::SchemaGenerator;
+
+    use super::*;
+
+    /// A pattern matching the accepted Crockford Base32 characters.
+    ///
+    /// # Notes
+    ///
+    /// - Matching is case-insensitive and accepts `I`, `L` and `O` as aliases.
+    const PATTERN: &str = "^[0-9A-TV-Za-tv-z]*$";
+
+    /// Builds a string schema with an optional length range.
+    fn string_schema(format: &str, len: Option<(usize, usize)>) -> Schema {
+        let mut schema = json_schema!({
+            "type": "string",
+            "format": format,
+            "pattern": PATTERN,
+        });
+
+        if let Some((min, max)) = len {
+            schema.insert("minLength".into(), min.into());
+            schema.insert("maxLength".into(), max.into());
+        }
+
+        schema
+    }
+
+    /// Returns the length range of an encoded `N`-byte array.
+    ///
+    /// # Notes
+    ///
+    /// - Every byte encodes into at least one character, which makes `N` the
+    ///   lower bound, and [`encoded_len`] the upper bound.
+    const fn text_len(n: usize) -> (usize, usize) {
+        (n, encoded_len(n))
+    }
+
+    impl<const N: usize> JsonSchema for C32Text<N> {
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            format!("C32Text_{N}").into()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            format!("c32::C32Text<{N}>").into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            string_schema("c32", Some(text_len(N)))
+        }
+    }
+
+    #[cfg(feature = "serde_with")]
+    mod __serde_with {
+        use serde_with::schemars_1::JsonSchemaAs;
+
+        use super::*;
+
+        /// Implements [`JsonSchemaAs`] for `Vec<u8>` and `[u8; N]`.
+        macro_rules! impl_schema_as {
+            (
+                $adapter:ty, $name:expr, $format:literal, $len:expr
+                $(, const $version:ident: u8)?
+            ) => {
+                impl$(<const $version: u8>)? JsonSchemaAs<Vec<u8>> for $adapter {
+                    fn inline_schema() -> bool {
+                        true
+                    }
+
+                    fn schema_name() -> Cow<'static, str> {
+                        format!("{}", $name).into()
+                    }
+
+                    fn schema_id() -> Cow<'static, str> {
+                        format!("c32::{}", $name).into()
+                    }
+
+                    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                        string_schema($format, None)
+                    }
+                }
+
+                impl<const N: usize $(, const $version: u8)?> JsonSchemaAs<[u8; N]>
+                    for $adapter
+                {
+                    fn inline_schema() -> bool {
+                        true
+                    }
+
+                    fn schema_name() -> Cow<'static, str> {
+                        format!("{}_{N}", $name).into()
+                    }
+
+                    fn schema_id() -> Cow<'static, str> {
+                        format!("c32::{}<[u8; {N}]>", $name).into()
+                    }
+
+                    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                        string_schema($format, Some($len(N)))
+                    }
+                }
+            };
+        }
+
+        impl_schema_as!(AsC32, "AsC32", "c32", text_len);
+
+        #[cfg(feature = "check")]
+        impl_schema_as!(
+            AsC32Check<VERSION>,
+            format!("AsC32Check_{VERSION}"),
+            "c32check",
+            check_len,
+            const VERSION: u8
+        );
+
+        /// Returns the length range of an `N`-byte array encoded with a
+        /// version character and a 4-byte checksum.
+        #[cfg(feature = "check")]
+        const fn check_len(n: usize) -> (usize, usize) {
+            (1 + n + 4, encoded_check_len(n))
+        }
+    }
+}
+
+/// Computes the required capacity for encoding into Crockford Base32.
+///
+/// # Notes
+///
+/// The calculation breaks down into:
+///
+/// - For every 5 bytes (40 bits), exactly 8 Base32 characters are needed.
+/// - For remaining bytes, more characters are needed based on the total bits.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::encoded_len(0), 0);
+/// assert_eq!(c32::encoded_len(1), 2);
+/// assert_eq!(c32::encoded_len(3), 5);
+/// ```
+#[inline]
+#[must_use]
+pub const fn encoded_len(n: usize) -> usize {
+    (n * 8).div_ceil(5)
+}
+
+/// Computes the required capacity for encoding into Crockford Base32, or
+/// `None` if it overflows a `usize`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::checked_encoded_len(3), Some(5));
+/// assert_eq!(c32::checked_encoded_len(usize::MAX), None);
+/// ```
+#[inline]
+#[must_use]
+pub const fn checked_encoded_len(n: usize) -> Option<usize> {
+    // Split the input into whole 5-byte groups and the remainder, so that
+    // only the result itself can overflow.
+    let Some(groups) = (n / 5).checked_mul(8) else {
+        return None;
+    };
+    groups.checked_add((n % 5 * 8).div_ceil(5))
+}
+
+/// Computes the required capacity for encoding into Crockford Base32Check.
+///
+/// # Notes
+///
+/// The calculation breaks down into:
+///
+/// - One byte for the version character.
+/// - The encoded length of the payload plus a 4-byte checksum.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::encoded_check_len(0), 8);
+/// assert_eq!(c32::encoded_check_len(1), 9);
+/// assert_eq!(c32::encoded_check_len(3), 13);
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "check-core")]
+pub const fn encoded_check_len(n: usize) -> usize {
+    1 + encoded_len(n + 4)
+}
+
+/// Computes the required capacity for encoding into Crockford Base32Check,
+/// or `None` if it overflows a `usize`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::checked_encoded_check_len(3), Some(13));
+/// assert_eq!(c32::checked_encoded_check_len(usize::MAX), None);
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "check-core")]
+pub const fn checked_encoded_check_len(n: usize) -> Option<usize> {
+    let Some(n) = n.checked_add(4) else {
+        return None;
+    };
+    let Some(len) = checked_encoded_len(n) else {
+        return None;
+    };
+    len.checked_add(1)
+}
+
+/// Computes the size of a [`Buffer`] for check-encoding an `m`-byte payload,
+/// with an optional prefix character.
+///
+/// This equals [`Buffer::required_for`] of the [`en::Check`] encoding, and
+/// sizes the aliases such as [`CheckBuffer20`].
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::check_buffer_len(20, false), 40);
+/// assert_eq!(c32::check_buffer_len(20, true), 41);
+/// assert_eq!(c32::check_buffer_len(32, false), 59);
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "check-core")]
+pub const fn check_buffer_len(m: usize, prefixed: bool) -> usize {
+    encoded_check_len(m) + prefixed as usize
+}
+
+/// Computes the exact length of encoding bytes into Crockford Base32.
+///
+/// Unlike [`encoded_len`], this accounts for leading zero bytes and for the
+/// leading zero bits of the most significant non-zero byte, so the result
+/// equals the length of the output of [`encode`].
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::encoded_len_exact(&[]), 0);
+/// assert_eq!(c32::encoded_len_exact(&[0, 1]), 2);
+/// assert_eq!(c32::encoded_len_exact(&[42, 42, 42]), 5);
+/// assert_eq!(c32::encoded_len(2), 4);
+/// ```
+#[inline]
+#[must_use]
+pub const fn encoded_len_exact(src: &[u8]) -> usize {
+    __internal::exact_len(src, None)
+}
+
+/// Computes the exact length of encoding bytes into Crockford Base32Check.
+///
+/// Unlike [`encoded_check_len`], the result equals the length of the output
+/// of [`encode_check`].
+///
+/// # Notes
+///
+/// - The length depends on the checksum, so this computes a double SHA256 of
+///   the input, which is as expensive as encoding it.
+///
+/// # Panics
+///
+/// This method will panic if the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::encoded_check_len_exact(&[42, 42, 42], 0), 12);
+/// assert_eq!(c32::encoded_check_len(3), 13);
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "check")]
+pub const fn encoded_check_len_exact(src: &[u8], version: u8) -> usize {
+    assert!(version < 32, "Version must be < 32");
+
+    // Compute the checksum.
+    let sum = checksum::compute(src, version);
+
+    1 + __internal::exact_len(src, Some(sum))
+}
+
+/// Counts the leading zero bytes of the input.
+///
+/// Each leading zero byte is encoded as a single `0` character, as the
+/// numeric value of the input alone cannot represent them.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::leading_zero_bytes(&[0, 0, 1, 0]), 2);
+/// assert_eq!(c32::leading_zero_chars(b"0080"), 2);
+/// ```
+#[inline]
+#[must_use]
+pub const fn leading_zero_bytes(src: &[u8]) -> usize {
+    let mut count = 0;
+    while count < src.len() && src[count] == 0 {
+        count += 1;
+    }
+    count
+}
+
+/// Counts the leading `0` characters of the encoded input.
+///
+/// Each leading `0` character is decoded as a single zero byte.
+///
+/// # Notes
+///
+/// - The lenient `O` and `o` aliases are counted like `0`, matching [`decode`]
+///   and the reference `c32check` implementation.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::leading_zero_chars(b"00G0"), 2);
+/// assert_eq!(c32::leading_zero_chars(b"O0G0"), 2);
+/// ```
+#[inline]
+#[must_use]
+pub const fn leading_zero_chars(src: &[u8]) -> usize {
+    let mut count = 0;
+    while count < src.len() && BYTE_MAP[src[count] as usize] == 0 {
+        count += 1;
+    }
+    count
+}
+
+/// Computes the required capacity for decoding from Crockford Base32.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::decoded_len(0), 0);
+/// assert_eq!(c32::decoded_len(1), 1);
+/// assert_eq!(c32::decoded_len(2), 2);
+/// ```
+#[inline]
+#[must_use]
+pub const fn decoded_len(n: usize) -> usize {
+    n
+}
+
+/// Computes the required capacity for decoding from Crockford Base32Check.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::decoded_check_len(8), 8);
+/// assert_eq!(c32::decoded_check_len(9), 9);
+/// assert_eq!(c32::decoded_check_len(13), 13);
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "check-core")]
+pub const fn decoded_check_len(n: usize) -> usize {
+    n
+}
+
+/// Computes the required capacity for encoding into padded Crockford Base32
+/// blocks.
+///
+/// Every 5 bytes are encoded into a block of 8 characters, and the final
+/// partial block is padded with `=` to 8 characters.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::encoded_blocks_len(0), 0);
+/// assert_eq!(c32::encoded_blocks_len(1), 8);
+/// assert_eq!(c32::encoded_blocks_len(6), 16);
+/// ```
+#[inline]
+#[must_use]
+pub const fn encoded_blocks_len(n: usize) -> usize {
+    n.div_ceil(5) * 8
+}
+
+/// Computes the required capacity for decoding from padded Crockford Base32
+/// blocks.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::decoded_blocks_len(0), 0);
+/// assert_eq!(c32::decoded_blocks_len(8), 5);
+/// assert_eq!(c32::decoded_blocks_len(16), 10);
+/// ```
+#[inline]
+#[must_use]
+pub const fn decoded_blocks_len(n: usize) -> usize {
+    n.div_ceil(8) * 5
+}
+
+/// Returns the character encoding a Crockford Base32Check version byte.
+///
+/// Returns `None` if the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::version_char(22), Some('P'));
+/// assert_eq!(c32::version_char(32), None);
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "check-core")]
+pub const fn version_char(version: u8) -> Option<char> {
+    if version >= 32 {
+        return None;
+    }
+    Some(ALPHABET[version as usize] as char)
+}
+
+/// Returns the Crockford Base32Check version byte encoded by a character.
+///
+/// Decoding is lenient, so lowercase characters and the `I`, `L` and `O`
+/// aliases are accepted. Returns `None` for any other character.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::version_from_char('P'), Some(22));
+/// assert_eq!(c32::version_from_char('p'), Some(22));
+/// assert_eq!(c32::version_from_char('U'), None);
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "check-core")]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn version_from_char(char: char) -> Option<u8> {
+    let code = char as u32;
+    if code >= 128 {
+        return None;
+    }
+
+    let index = BYTE_MAP[code as usize];
+    if index == INVALID {
+        return None;
+    }
+    Some(index)
+}
+
+/// Returns the version of a Crockford Base32Check-encoded string, decoding
+/// only the leading version character.
+///
+/// This neither decodes the payload nor verifies the checksum, which makes it
+/// a cheap filter before committing to [`decode_check`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InsufficientData`], the input is empty.
+/// - [`Error::InvalidCharacter`], the version character is invalid.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// assert_eq!(
+///     c32::peek_version("P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7")?,
+///     22
+/// );
+/// assert_eq!(c32::peek_version("T")?, 26);
+///
+/// let result = c32::peek_version("U000");
+/// assert!(matches!(
+///     result,
+///     Err(Error::InvalidCharacter { char: 'U', .. })
+/// ));
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "check-core")]
+pub fn peek_version(str: &str) -> Result<u8> {
+    let Some(char) = str.chars().next() else {
+        return Err(Error::InsufficientData { min: 1, len: 0 });
+    };
+
+    // Decode the version character.
+    let Some(version) = version_from_char(char) else {
+        return Err(Error::InvalidCharacter {
+            char,
+            index: 0,
+            decoded: 0,
+        });
+    };
+
+    // Assert that the recovered version is valid. (< 32).
+    if version >= 32 {
+        return Err(Error::InvalidVersion {
+            expected: "must be < 32",
+            version,
+        });
+    }
+
+    Ok(version)
+}
+
+/// Encodes bytes into a Crockford Base32-encoded string.
+///
+/// The returned string has no excess capacity, see [`encoded_len_exact`].
+///
+/// # Notes
+///
+/// - Encoding is lossless, `decode(encode(x)) == x` holds for every input.
+///   Leading zero bytes are encoded as `0` characters, while trailing zero
+///   bytes are part of the numeric value.
+///
+/// # Panics
+///
+/// This method can panic in two cases:
+///
+/// - If encoding fails despitce sufficient buffer capacity.
+/// - If the encoded output contains non-UTF8 bytes.
+///
+/// Both panics should never occur under normal circumstances.
+///
+/// # Examples
+///
+/// ```rust
+/// let en = c32::encode([42, 42, 42]);
+/// assert_eq!(en, "2MAHA");
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode<B>(src: B) -> String
+where
+    B: AsRef<[u8]>,
+{
+    let src = src.as_ref();
+
+    #[cfg(feature = "tracing")]
+    let span = __tracing::enter("encode", src.len());
+
+    let encoded = __internal::encode_string(src);
+
+    #[cfg(feature = "tracing")]
+    __tracing::exit(span, Ok(encoded.len()));
+    #[cfg(feature = "metrics")]
+    __metrics::record("encode", src.len(), Ok(encoded.len()));
+
+    encoded
+}
+
+/// Encodes bytes into Crockford Base32, appending the characters to `out`.
+///
+/// The characters are encoded directly into the spare capacity of `out`,
+/// so a single [`String`] can be reused across many calls without
+/// allocating, once it has grown large enough.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut out = String::from("id:");
+/// c32::encode_append([42, 42, 42], &mut out);
+/// assert_eq!(out, "id:2MAHA");
+///
+/// out.push(',');
+/// c32::encode_append([1], &mut out);
+/// assert_eq!(out, "id:2MAHA,1");
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+#[allow(clippy::missing_panics_doc)]
+pub fn encode_append<B>(src: B, out: &mut String)
+where
+    B: AsRef<[u8]>,
+{
+    let src = src.as_ref();
+
+    // SAFETY: The appended bytes are zero-initialized and then overwritten
+    // with ASCII characters, so the string is valid UTF-8 at every point,
+    // including if this panics.
+    let dst = unsafe { out.as_mut_vec() };
+    let start = dst.len();
+    dst.resize(start + encoded_len(src.len()), 0);
+
+    // This should not panic, as we allocate enough space.
+    let offset = encode_into(src, &mut dst[start..]).unwrap();
+    dst.truncate(start + offset);
+}
+
+/// Wraps bytes for lazy encoding into a [`uWrite`] sink.
+///
+/// # Examples
+///
+/// ```rust
+/// use ufmt_write::uWrite;
+///
+/// struct Sink(String);
+///
+/// impl uWrite for Sink {
+///     type Error = ();
+///
+///     fn write_str(&mut self, s: &str) -> Result<(), ()> {
+///         self.0.push_str(s);
+///         Ok(())
+///     }
+/// }
+///
+/// let mut sink = Sink(String::new());
+/// c32::u_display(&[42, 42, 42]).uwrite_to(&mut sink)?;
+/// assert_eq!(sink.0, "2MAHA");
+/// # Ok::<(), ()>(())
+/// ```
+///
+/// [`uWrite`]: ufmt_write::uWrite
+#[inline]
+#[must_use]
+#[cfg(feature = "ufmt")]
+pub const fn u_display(bytes: &[u8]) -> UDisplay<'_> {
+    UDisplay { bytes }
+}
+
+/// Bytes which are encoded lazily into a [`uWrite`] sink.
+///
+/// This is created by [`u_display`].
+///
+/// [`uWrite`]: ufmt_write::uWrite
+#[cfg(feature = "ufmt")]
+#[derive(Debug, Clone, Copy)]
+pub struct UDisplay<'a> {
+    /// The bytes to encode.
+    bytes: &'a [u8],
+}
+
+/// Decodes a Crockford Base32-encoded string.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode("2MAHA")?;
+/// assert_eq!(de, [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode(str: &str) -> Result<Vec<u8>> {
+    #[cfg(feature = "tracing")]
+    let span = __tracing::enter("decode", str.len());
+
+    let decoded = __internal::decode_vec(str);
+
+    #[cfg(feature = "tracing")]
+    __tracing::exit(span, decoded.as_ref().map(Vec::len));
+    #[cfg(feature = "metrics")]
+    __metrics::record("decode", str.len(), decoded.as_ref().map(Vec::len));
+
+    decoded
+}
+
+/// Decodes an owned Crockford Base32-encoded string, reusing its allocation
+/// for the output.
+///
+/// The string is decoded in place with [`decode_in_place`], which is sound
+/// as the decoded output is never longer than the input, so no further
+/// allocation is made.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Notes
+///
+/// - The returned vector keeps the capacity of the string, which may be
+///   released with [`Vec::shrink_to_fit`] at the cost of a reallocation.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = String::from("2MAHA");
+/// let de = c32::decode_owned(en)?;
+/// assert_eq!(de, [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_owned(str: String) -> Result<Vec<u8>> {
+    let mut buf = str.into_bytes();
+
+    // Decode the input bytes into the same buffer.
+    let offset = decode_in_place(&mut buf)?;
+    __internal::wipe(&mut buf[offset..]);
+    buf.truncate(offset);
+
+    Ok(buf)
+}
+
+/// Decodes a Crockford Base32-encoded string, rejecting output longer than
+/// `max` bytes.
+///
+/// The exact decoded length is computed from the leading characters of the
+/// input before anything is allocated or decoded, so oversized input is
+/// rejected in time proportional to its leading zeros only.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::OutputTooLarge`], the output would exceed `max` bytes.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Notes
+///
+/// - The computed length is the inverse of [`encoded_len_exact`], so the
+///   encoding of `n` bytes is accepted if and only if `n <= max`, and `got` is
+///   the length [`decode`] would return.
+/// - The length is checked before the characters are validated, so invalid
+///   input may be reported as [`Error::OutputTooLarge`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_bounded("2MAHA", 3)?;
+/// assert_eq!(de, [42, 42, 42]);
+///
+/// let result = c32::decode_bounded("2MAHA", 2);
+/// assert_eq!(result, Err(Error::OutputTooLarge { max: 2, got: 3 }));
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+#[doc(alias = "decode_with_limit")]
+pub fn decode_bounded(str: &str, max: usize) -> Result<Vec<u8>> {
+    let bytes = str.as_bytes();
+
+    // Assert that the output does not exceed the maximum length.
+    if let Some(got) =
+        __internal::exceeds_limit::<{ __internal::STRICT }>(bytes, 0, max)
+    {
+        return Err(Error::OutputTooLarge { max, got });
+    }
+
+    __internal::decode_vec(str)
+}
+
+/// Validates Crockford Base32-encoded bytes without decoding them.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Notes
+///
+/// - The error matches the one returned by [`decode_into`], which reports the
+///   last invalid character, as decoding scans in reverse.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// assert!(c32::validate(b"2MAHA").is_ok());
+///
+/// let result = c32::validate(b"2M!HA");
+/// assert!(matches!(
+///     result,
+///     Err(Error::InvalidCharacter { index: 2, .. })
+/// ));
+/// ```
+#[inline]
+pub fn validate(src: &[u8]) -> Result<()> {
+    match __internal::rfind_invalid(src) {
+        Some(index) => Err(Error::InvalidCharacter {
+            char: src[index] as char,
+            index,
+            decoded: __internal::decoded_before::<{ __internal::STRICT }>(
+                &alphabet::CROCKFORD,
+                src,
+                0,
+                src.len(),
+                index,
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Decodes a Crockford Base32-encoded string, decoding invalid characters as
+/// zero.
+///
+/// See [`decode_lossy_into`] for the substitution.
+///
+/// # Returns
+///
+/// The decoded bytes, and the byte positions of the substituted characters in
+/// ascending order, which is empty if the input is valid.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, substituted) = c32::decode_lossy("2M!HA");
+/// assert_eq!(bytes, [42, 2, 42]);
+/// assert_eq!(substituted, [2]);
+///
+/// // An extra character shifts the bytes before it.
+/// let (bytes, substituted) = c32::decode_lossy("2MA!HA");
+/// assert_eq!(bytes, c32::decode("2MA0HA")?);
+/// assert_eq!(substituted, [3]);
+///
+/// let (bytes, substituted) = c32::decode_lossy("2MAHA");
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert!(substituted.is_empty());
+/// # Ok::<(), Error>(())
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+#[allow(clippy::missing_panics_doc)]
+pub fn decode_lossy(str: &str) -> (Vec<u8>, Vec<usize>) {
+    let bytes = str.as_bytes();
+
+    // Allocate the output buffer.
+    let capacity = decoded_len(bytes.len());
+    let mut dst = vec![0u8; capacity];
+
+    // This should not panic, as the buffer is properly sized.
+    let (offset, _) = decode_lossy_into(bytes, &mut dst).unwrap();
+    dst.truncate(offset);
+
+    // Collect the positions of the substituted characters.
+    let substituted = bytes
+        .iter()
+        .enumerate()
+        .filter(|&(_, &byte)| !__internal::is_valid(byte))
+        .map(|(index, _)| index)
+        .collect();
+
+    (dst, substituted)
+}
+
+/// Encodes bytes into a prefixed Crockford Base32-encoded string.
+///
+/// # Notes
+///
+/// - The prefix may be any `char`, including multi-byte ones, while the
+///   [`Buffer`] and `_into` APIs only accept ASCII prefixes.
+///
+/// # Examples
+///
+/// ```rust
+/// let en = c32::encode_prefixed([42, 42, 42], 'S');
+/// assert_eq!(en, "S2MAHA");
+///
+/// let en = c32::encode_prefixed([42, 42, 42], '€');
+/// assert_eq!(en, "€2MAHA");
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_prefixed<B>(src: B, prefix: char) -> String
+where
+    B: AsRef<[u8]>,
+{
+    let src = src.as_ref();
+
+    #[cfg(feature = "tracing")]
+    let span = __tracing::enter("encode_prefixed", src.len());
+
+    // Encode the input bytes.
+    let encoded = __internal::encode_string(src);
+
+    // Allocate the output string.
+    let capacity = prefix.len_utf8() + encoded.len();
+    let mut dst = String::with_capacity(capacity);
+
+    // Append the prefix and encoded string.
+    dst.push(prefix);
+    dst.push_str(&encoded);
+
+    #[cfg(feature = "tracing")]
+    __tracing::exit(span, Ok(dst.len()));
+    #[cfg(feature = "metrics")]
+    __metrics::record("encode_prefixed", src.len(), Ok(dst.len()));
+
+    dst
+}
+
+/// Decodes a prefixed Crockford Base32-encoded string.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::MissingPrefix`], the input does not start with the prefix.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Notes
+///
+/// - The prefix may be any `char`. The `index` of an
+///   [`Error::InvalidCharacter`] is a byte offset into `str`, so it includes
+///   the `prefix.len_utf8()` bytes of the prefix.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_prefixed("P2MAHA", 'P')?;
+/// assert_eq!(de, [42, 42, 42]);
+///
+/// let result = c32::decode_prefixed("€2M!HA", '€');
+/// assert!(matches!(
+///     result,
+///     Err(Error::InvalidCharacter { index: 5, .. })
+/// ));
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_prefixed(str: &str, prefix: char) -> Result<Vec<u8>> {
+    #[cfg(feature = "tracing")]
+    let span = __tracing::enter("decode_prefixed", str.len());
+
+    // Assert that the string starts with the prefix.
+    let decoded = if str.starts_with(prefix) {
+        // Skip the prefix character and decode the rest.
+        match __internal::decode_vec(&str[prefix.len_utf8()..]) {
+            Ok(bytes) => Ok(bytes),
+            Err(Error::InvalidCharacter {
+                char,
+                index,
+                decoded,
+            }) => {
+                // This adjusts the index in an 'InvalidCharacter' to account
+                // for the prefix in the original string that we don't decode.
+                Err(Error::InvalidCharacter {
+                    char,
+                    index: index + prefix.len_utf8(),
+                    decoded,
+                })
+            }
+            Err(e) => Err(e),
+        }
+    } else {
+        Err(Error::MissingPrefix {
+            char: prefix,
+            got: str.chars().next(),
+        })
+    };
+
+    #[cfg(feature = "tracing")]
+    __tracing::exit(span, decoded.as_ref().map(Vec::len));
+    #[cfg(feature = "metrics")]
+    __metrics::record(
+        "decode_prefixed",
+        str.len(),
+        decoded.as_ref().map(Vec::len),
+    );
+
+    decoded
+}
+
+/// Encodes bytes as a little-endian integer into a Crockford Base32-encoded
+/// string.
+///
+/// The default [`encode`] treats the input as a big-endian integer, while
+/// this method reverses the byte order first, such that
+/// `encode_le(x) == encode(x.reversed())` holds for every input.
+///
+/// # Examples
+///
+/// ```rust
+/// let en = c32::encode_le([1, 2, 3]);
+/// assert_eq!(en, "60G1");
+/// assert_eq!(en, c32::encode([3, 2, 1]));
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_le<B>(src: B) -> String
+where
+    B: AsRef<[u8]>,
+{
+    let mut bytes = src.as_ref().to_vec();
+    bytes.reverse();
+    encode(bytes)
+}
+
+/// Decodes a Crockford Base32-encoded string into a little-endian integer.
+///
+/// This is the inverse of [`encode_le`], and reverses the byte order of the
+/// output of [`decode`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_le("60G1")?;
+/// assert_eq!(de, [1, 2, 3]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_le(str: &str) -> Result<Vec<u8>> {
+    let mut bytes = decode(str)?;
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Encodes bytes into a Crockford Base32Check-encoded string.
+///
+/// The returned string has no excess capacity, see
+/// [`encoded_check_len_exact`].
+///
+/// # Panics
+///
+/// This method can panic in two cases:
+///
+/// - If encoding fails despite sufficient buffer capacity.
+/// - If the encoded output contains non-UTF8 bytes.
+///
+/// Both panics should never occur under normal circumstances.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_check([42, 42, 42], 0)?;
+/// assert_eq!(en, "0AHA59B9201Z");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// The first character is the [`version_char`] of the version:
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_check([42, 42, 42], 22)?;
+/// assert_eq!(c32::version_char(22), Some('P'));
+/// assert!(en.starts_with('P'));
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn encode_check<B>(src: B, version: u8) -> Result<String>
+where
+    B: AsRef<[u8]>,
+{
+    let src = src.as_ref();
+
+    #[cfg(feature = "tracing")]
+    let span = __tracing::enter("encode_check", src.len());
+
+    let encoded = __internal::encode_check_string(src, version);
+
+    #[cfg(feature = "tracing")]
+    __tracing::exit(span, encoded.as_ref().map(String::len));
+    #[cfg(feature = "metrics")]
+    __metrics::record(
+        "encode_check",
+        src.len(),
+        encoded.as_ref().map(String::len),
+    );
+
+    encoded
+}
+
+/// Encodes bytes into a Crockford Base32Check-encoded string, with a
+/// [`Version`] that is known to be valid.
+///
+/// Unlike [`encode_check`], this method cannot fail.
+///
+/// # Panics
+///
+/// This method will not panic, as a [`Version`] is always less than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use c32::Version;
+///
+/// const VERSION: Version = match Version::new(22) {
+///     Some(version) => version,
+///     None => panic!("invalid version"),
+/// };
+/// let en = c32::encode_check_versioned([42, 42, 42], VERSION);
+/// assert_eq!(en, "PAHA58QT2DJ9");
+/// ```
+#[inline]
+#[must_use]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn encode_check_versioned<B>(src: B, version: Version) -> String
+where
+    B: AsRef<[u8]>,
+{
+    // This should not panic, as the version is less than 32.
+    encode_check(src, version.get()).unwrap()
+}
+
+/// Decodes a Crockford Base32Check-encoded string.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, version) = c32::decode_check("0AHA59B9201Z")?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check(str: &str) -> Result<(Vec<u8>, u8)> {
+    #[cfg(feature = "tracing")]
+    let span = __tracing::enter("decode_check", str.len());
+
+    let decoded = __internal::decode_check_vec(str);
+
+    #[cfg(feature = "tracing")]
+    __tracing::exit(span, decoded.as_ref().map(|(bytes, _)| bytes.len()));
+    #[cfg(feature = "metrics")]
+    __metrics::record(
+        "decode_check",
+        str.len(),
+        decoded.as_ref().map(|(bytes, _)| bytes.len()),
+    );
+
+    decoded
+}
+
+/// Decodes a Crockford Base32Check-encoded string, rejecting a payload longer
+/// than `max` bytes.
+///
+/// The limit applies to the payload, excluding the version character and the
+/// checksum. As in [`decode_bounded`], the exact payload length is computed
+/// before anything is allocated or decoded.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::OutputTooLarge`], the payload would exceed `max` bytes.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Notes
+///
+/// - The length is checked before the characters are validated, so invalid
+///   input may be reported as [`Error::OutputTooLarge`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, version) = c32::decode_check_bounded("0AHA59B9201Z", 3)?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+///
+/// let result = c32::decode_check_bounded("0AHA59B9201Z", 2);
+/// assert_eq!(result, Err(Error::OutputTooLarge { max: 2, got: 3 }));
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+#[doc(alias = "decode_check_with_limit")]
+pub fn decode_check_bounded(str: &str, max: usize) -> Result<(Vec<u8>, u8)> {
+    let bytes = str.as_bytes();
+
+    // Assert that the payload does not exceed the maximum length.
+    if let Some((_, payload)) = bytes.split_first() {
+        if let Some(got) = __internal::exceeds_limit::<{ __internal::STRICT }>(
+            payload,
+            checksum::BYTE_LENGTH,
+            max,
+        ) {
+            return Err(Error::OutputTooLarge { max, got });
+        }
+    }
+
+    __internal::decode_check_vec(str)
+}
+
+/// Decodes a Crockford Base32Check-encoded string without a version
+/// character, using the given `version` for the checksum.
+///
+/// The input is the encoded payload followed by the checksum, as produced by
+/// [`encode_check`] without its leading version character. This is useful for
+/// formats where the version is known out-of-band.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_check([42, 42, 42], 22)?;
+/// assert_eq!(en, "PAHA58QT2DJ9");
+///
+/// let de = c32::decode_check_with_version(&en[1..], 22)?;
+/// assert_eq!(de, [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check_with_version(str: &str, version: u8) -> Result<Vec<u8>> {
+    // Assert that the provided version is valid. (< 32).
+    if version >= 32 {
+        return Err(Error::InvalidVersion {
+            expected: "must be < 32",
+            version,
+        });
+    }
+
+    // Decode the payload, followed by the checksum.
+    let mut dst = __internal::decode_vec(str)?;
+
+    // Extract the checksum.
+    let Some(offset) = dst.len().checked_sub(checksum::BYTE_LENGTH) else {
+        let len = dst.len();
+        __internal::wipe(&mut dst);
+        return Err(Error::InsufficientData {
+            min: checksum::BYTE_LENGTH,
+            len,
+        });
+    };
+    let mut sum = checksum::from_slice(&dst[offset..]);
+
+    // Compute the expected checksum.
+    let mut expected = checksum::compute(&dst[..offset], version);
+
+    // Assert that the computed and actual checksums match.
+    if expected != sum {
+        __internal::wipe(&mut dst);
+        return Err(Error::ChecksumMismatch {
+            expected,
+            got: sum,
+            version,
+            len: offset,
+        });
+    }
+
+    // Clear the checksum scratch, including the trailing checksum bytes.
+    __internal::wipe(&mut expected);
+    __internal::wipe(&mut sum);
+    __internal::wipe(&mut dst[offset..]);
+    dst.truncate(offset);
+
+    Ok(dst)
+}
+
+/// Decodes a Crockford Base32Check-encoded string, and returns the version
+/// character as it appears in the input.
+///
+/// The version character may differ from [`version_char`] of the version,
+/// as decoding accepts lowercase characters and the `I`, `L` and `O`
+/// aliases.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Panics
+///
+/// This method will not panic, as decoding requires a version character.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, version, char) =
+///     c32::decode_check_full("p7awvhenjj0rb441k6jvk5dnj7j3v5")?;
+/// assert_eq!(bytes, b"usque ad finem");
+/// assert_eq!(version, 22);
+/// assert_eq!(char, 'p');
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check_full(str: &str) -> Result<(Vec<u8>, u8, char)> {
+    let (bytes, version) = decode_check(str)?;
+
+    // This should not panic, as decoding requires a version character.
+    let char = str.chars().next().unwrap();
+
+    Ok((bytes, version, char))
+}
+
+/// Encodes bytes into a Base32Check-encoded string, using a custom
+/// [`Alphabet`].
+///
+/// The checksum is computed over the raw bytes and is independent of the
+/// alphabet; only the version symbol and the payload encoding differ.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Panics
+///
+/// This method will not panic, as alphabets only contain ASCII.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::alphabet::Alphabet;
+///
+/// const LOWER: Alphabet = Alphabet::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+///
+/// let en = c32::encode_check_with(&LOWER, [42, 42, 42], 0)?;
+/// assert_eq!(en, "0aha59b9201z");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Alphabet`]: alphabet::Alphabet
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn encode_check_with<B>(
+    alphabet: &alphabet::Alphabet,
+    src: B,
+    version: u8,
+) -> Result<String>
+where
+    B: AsRef<[u8]>,
+{
+    let src = src.as_ref();
+
+    // Allocate the output buffer.
+    let capacity = encoded_check_len(src.len());
+    let mut dst = vec![0u8; capacity];
+
+    // Encode the input bytes into the buffer.
+    let offset = encode_check_into_with(alphabet, src, &mut dst, version)?;
+    dst.truncate(offset);
+    dst.shrink_to_fit();
+
+    // This should not panic, as alphabets only contain ASCII.
+    Ok(String::from_utf8(dst).unwrap())
+}
+
+/// Decodes a Base32Check-encoded string, using a custom [`Alphabet`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::alphabet::Alphabet;
+///
+/// const LOWER: Alphabet = Alphabet::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+///
+/// let (bytes, version) = c32::decode_check_with(&LOWER, "0aha59b9201z")?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Alphabet`]: alphabet::Alphabet
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check_with(
+    alphabet: &alphabet::Alphabet,
+    str: &str,
+) -> Result<(Vec<u8>, u8)> {
+    let bytes = str.as_bytes();
+
+    // Allocate the output buffer.
+    let capacity = decoded_check_len(bytes.len());
+    let mut dst = vec![0u8; capacity];
+
+    // Decode the input bytes into the buffer.
+    let (offset, version) = decode_check_into_with(alphabet, bytes, &mut dst)?;
+    __internal::wipe(&mut dst[offset..]);
+    dst.truncate(offset);
+
+    Ok((dst, version))
+}
+
+/// Encodes bytes into a Crockford Base32Check-encoded string, using the
+/// given [`Sha256Backend`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Panics
+///
+/// This method will not panic, as the output only contains ASCII.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # #[cfg(feature = "check")] {
+/// use c32::checksum::Sha2Backend;
+///
+/// let en = c32::encode_check_with_backend::<Sha2Backend, _>([42, 42, 42], 0)?;
+/// assert_eq!(en, "0AHA59B9201Z");
+/// # }
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Sha256Backend`]: checksum::Sha256Backend
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check-core"))]
+pub fn encode_check_with_backend<H, B>(src: B, version: u8) -> Result<String>
+where
+    H: checksum::Sha256Backend,
+    B: AsRef<[u8]>,
+{
+    let src = src.as_ref();
+
+    // Allocate the output buffer.
+    let capacity = encoded_check_len(src.len());
+    let mut dst = vec![0u8; capacity];
+
+    // Encode the input bytes into the buffer.
+    let offset = encode_check_into_with_backend::<H>(src, &mut dst, version)?;
+    dst.truncate(offset);
+    dst.shrink_to_fit();
+
+    // This should not panic, as we only write valid ASCII.
+    Ok(String::from_utf8(dst).unwrap())
+}
+
+/// Decodes a Crockford Base32Check-encoded string, using the given
+/// [`Sha256Backend`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # #[cfg(feature = "check")] {
+/// use c32::checksum::Sha2Backend;
+///
+/// let (bytes, version) =
+///     c32::decode_check_with_backend::<Sha2Backend>("0AHA59B9201Z")?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # }
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Sha256Backend`]: checksum::Sha256Backend
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check-core"))]
+pub fn decode_check_with_backend<H: checksum::Sha256Backend>(
+    str: &str,
+) -> Result<(Vec<u8>, u8)> {
+    let bytes = str.as_bytes();
+
+    // Allocate the output buffer.
+    let capacity = decoded_check_len(bytes.len());
+    let mut dst = vec![0u8; capacity];
+
+    // Decode the input bytes into the buffer.
+    let (offset, version) =
+        decode_check_into_with_backend::<H>(bytes, &mut dst)?;
+    __internal::wipe(&mut dst[offset..]);
+    dst.truncate(offset);
+
+    Ok((dst, version))
+}
+
+/// Encodes bytes into a Crockford Base32Check-encoded string, computing the
+/// checksum over the version character.
+///
+/// Unlike [`encode_check`], which checksums the numeric version, this uses
+/// the symbol-based convention of [`checksum::compute_over_symbol`]. The
+/// version character is identical in both conventions, but the checksum is
+/// not, so the output only decodes with [`decode_check_symbol`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Panics
+///
+/// This method will not panic, as the output only contains ASCII.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_check_symbol([42, 42, 42], 22)?;
+/// assert_eq!(en, "PAHA5BBFJB7W");
+///
+/// // The numeric convention shares the version character only.
+/// assert_eq!(c32::encode_check([42, 42, 42], 22)?, "PAHA58QT2DJ9");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn encode_check_symbol<B>(src: B, version: u8) -> Result<String>
+where
+    B: AsRef<[u8]>,
+{
+    let src = src.as_ref();
+
+    // Allocate the output buffer.
+    let capacity = encoded_check_len(src.len());
+    let mut dst = vec![0u8; capacity];
+
+    // Encode the input bytes into the buffer.
+    let offset = __internal::en_check::<checksum::Sha2Backend, true>(
+        &alphabet::CROCKFORD,
+        src,
+        &mut dst,
+        version,
+    )?;
+    dst.truncate(offset);
+    dst.shrink_to_fit();
+
+    // This should not panic, as we only write valid ASCII.
+    Ok(String::from_utf8(dst).unwrap())
+}
+
+/// Decodes a Crockford Base32Check-encoded string, verifying the checksum
+/// over the version character.
+///
+/// This is the counterpart of [`encode_check_symbol`]. The checksum covers
+/// the canonical version character, so lowercase input and aliases decode
+/// the same as their canonical form.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, version) = c32::decode_check_symbol("PAHA5BBFJB7W")?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 22);
+///
+/// // The numeric convention does not verify.
+/// assert!(c32::decode_check_symbol("PAHA58QT2DJ9").is_err());
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check_symbol(str: &str) -> Result<(Vec<u8>, u8)> {
+    let bytes = str.as_bytes();
+
+    // Allocate the output buffer.
+    let capacity = decoded_check_len(bytes.len());
+    let mut dst = vec![0u8; capacity];
+
+    // Decode the input bytes into the buffer.
+    let (offset, version) = __internal::de_check::<
+        __internal::Incremental,
+        true,
+        { __internal::STRICT },
+    >(&alphabet::CROCKFORD, bytes, &mut dst)?;
+    __internal::wipe(&mut dst[offset..]);
+    dst.truncate(offset);
+
+    Ok((dst, version))
+}
+
+/// Encodes bytes into a prefixed Crockford Base32Check-encoded string.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Notes
+///
+/// - The prefix may be any `char`, including multi-byte ones, see
+///   [`encode_prefixed`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_check_prefixed([42, 42, 42], 'S', 0)?;
+/// assert_eq!(en, "S0AHA59B9201Z");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn encode_check_prefixed<B>(
+    src: B,
+    prefix: char,
+    version: u8,
+) -> Result<String>
+where
+    B: AsRef<[u8]>,
+{
+    let src = src.as_ref();
+
+    #[cfg(feature = "tracing")]
+    let span = __tracing::enter("encode_check_prefixed", src.len());
+
+    // Encode the input bytes, and prepend the prefix.
+    let encoded =
+        __internal::encode_check_string(src, version).map(|encoded| {
+            // Allocate the output string.
+            let capacity = prefix.len_utf8() + encoded.len();
+            let mut dst = String::with_capacity(capacity);
+
+            // Append the prefix and encoded string.
+            dst.push(prefix);
+            dst.push_str(&encoded);
+            dst
+        });
+
+    #[cfg(feature = "tracing")]
+    __tracing::exit(span, encoded.as_ref().map(String::len));
+    #[cfg(feature = "metrics")]
+    __metrics::record(
+        "encode_check_prefixed",
+        src.len(),
+        encoded.as_ref().map(String::len),
+    );
+
+    encoded
+}
+
+/// Encodes bytes into a prefixed Crockford Base32Check-encoded string, with
+/// a [`Version`] that is known to be valid.
+///
+/// Unlike [`encode_check_prefixed`], this method cannot fail.
+///
+/// # Panics
+///
+/// This method will not panic, as a [`Version`] is always less than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::Version;
+///
+/// let version = Version::try_from(0)?;
+/// let en = c32::encode_check_prefixed_versioned([42, 42, 42], 'S', version);
+/// assert_eq!(en, "S0AHA59B9201Z");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[must_use]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn encode_check_prefixed_versioned<B>(
+    src: B,
+    prefix: char,
+    version: Version,
+) -> String
+where
+    B: AsRef<[u8]>,
+{
+    // This should not panic, as the version is less than 32.
+    encode_check_prefixed(src, prefix, version.get()).unwrap()
+}
+
+/// Decodes a prefixed Crockford Base32Check-encoded string.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::MissingPrefix`], the input does not start with the prefix.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+///
+/// # Notes
+///
+/// - The prefix may be any `char`. The `index` of an
+///   [`Error::InvalidCharacter`] is a byte offset into `str`, so it includes
+///   the `prefix.len_utf8()` bytes of the prefix.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, version) = c32::decode_check_prefixed("S0AHA59B9201Z", 'S')?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check_prefixed(str: &str, prefix: char) -> Result<(Vec<u8>, u8)> {
+    #[cfg(feature = "tracing")]
+    let span = __tracing::enter("decode_check_prefixed", str.len());
+
+    // Assert that the string starts with the prefix.
+    let decoded = if str.starts_with(prefix) {
+        // Skip the prefix character and decode the rest.
+        match __internal::decode_check_vec(&str[prefix.len_utf8()..]) {
+            Ok(bytes) => Ok(bytes),
+            Err(Error::InvalidCharacter {
+                char,
+                index,
+                decoded,
+            }) => {
+                // This adjusts the index in an 'InvalidCharacter' to account
+                // for the prefix in the original string that we don't decode.
+                Err(Error::InvalidCharacter {
+                    char,
+                    index: index + prefix.len_utf8(),
+                    decoded,
+                })
+            }
+            Err(e) => Err(e),
+        }
+    } else {
+        Err(Error::MissingPrefix {
+            char: prefix,
+            got: str.chars().next(),
+        })
+    };
+
+    #[cfg(feature = "tracing")]
+    __tracing::exit(span, decoded.as_ref().map(|(bytes, _)| bytes.len()));
+    #[cfg(feature = "metrics")]
+    __metrics::record(
+        "decode_check_prefixed",
+        str.len(),
+        decoded.as_ref().map(|(bytes, _)| bytes.len()),
+    );
+
+    decoded
+}
+
+/// Encodes bytes into a Crockford Base32-encoded string, returning an error
+/// instead of aborting if the output cannot be allocated.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::AllocationFailed`], the output could not be allocated, or its
+///   capacity overflows a `usize`.
+///
+/// # Notes
+///
+/// - Unlike [`encode`], the output is not shrunk to fit, as this may
+///   reallocate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_fallible([42, 42, 42])?;
+/// assert_eq!(en, "2MAHA");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_fallible<B>(src: B) -> Result<String>
+where
+    B: AsRef<[u8]>,
+{
+    __internal::try_encode_string(src.as_ref(), None)
+}
+
+/// Decodes a Crockford Base32-encoded string, returning an error instead of
+/// aborting if the output cannot be allocated.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::AllocationFailed`], the output could not be allocated.
+///
+/// # Notes
+///
+/// - The input is validated before the output is allocated, so invalid input
+///   never reports [`Error::AllocationFailed`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_fallible("2MAHA")?;
+/// assert_eq!(de, [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_fallible(str: &str) -> Result<Vec<u8>> {
+    __internal::try_decode_vec(str)
+}
+
+/// Encodes bytes into a prefixed Crockford Base32-encoded string, returning
+/// an error instead of aborting if the output cannot be allocated.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::AllocationFailed`], the output could not be allocated, or its
+///   capacity overflows a `usize`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_prefixed_fallible([42, 42, 42], 'P')?;
+/// assert_eq!(en, "P2MAHA");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_prefixed_fallible<B>(src: B, prefix: char) -> Result<String>
+where
+    B: AsRef<[u8]>,
+{
+    __internal::try_encode_string(src.as_ref(), Some(prefix))
+}
+
+/// Decodes a prefixed Crockford Base32-encoded string, returning an error
+/// instead of aborting if the output cannot be allocated.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::MissingPrefix`], the input does not start with the prefix.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::AllocationFailed`], the output could not be allocated.
+///
+/// # Notes
+///
+/// - The `index` of an [`Error::InvalidCharacter`] includes the prefix, as in
+///   [`decode_prefixed`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_prefixed_fallible("P2MAHA", 'P')?;
+/// assert_eq!(de, [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_prefixed_fallible(str: &str, prefix: char) -> Result<Vec<u8>> {
+    __internal::decode_after_prefix(str, prefix, __internal::try_decode_vec)
+}
+
+/// Encodes bytes into a Crockford Base32Check-encoded string, returning an
+/// error instead of aborting if the output cannot be allocated.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::AllocationFailed`], the output could not be allocated, or its
+///   capacity overflows a `usize`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_check_fallible([42, 42, 42], 0)?;
+/// assert_eq!(en, "0AHA59B9201Z");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn encode_check_fallible<B>(src: B, version: u8) -> Result<String>
+where
+    B: AsRef<[u8]>,
+{
+    __internal::try_encode_check_string(src.as_ref(), None, version)
+}
+
+/// Decodes a Crockford Base32Check-encoded string, returning an error
+/// instead of aborting if the output cannot be allocated.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::AllocationFailed`], the output could not be allocated.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, version) = c32::decode_check_fallible("0AHA59B9201Z")?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check_fallible(str: &str) -> Result<(Vec<u8>, u8)> {
+    __internal::try_decode_check_vec(str)
+}
+
+/// Encodes bytes into a prefixed Crockford Base32Check-encoded string,
+/// returning an error instead of aborting if the output cannot be allocated.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::AllocationFailed`], the output could not be allocated, or its
+///   capacity overflows a `usize`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_check_prefixed_fallible([42, 42, 42], 'S', 0)?;
+/// assert_eq!(en, "S0AHA59B9201Z");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn encode_check_prefixed_fallible<B>(
+    src: B,
+    prefix: char,
+    version: u8,
+) -> Result<String>
+where
+    B: AsRef<[u8]>,
+{
+    __internal::try_encode_check_string(src.as_ref(), Some(prefix), version)
+}
+
+/// Decodes a prefixed Crockford Base32Check-encoded string, returning an
+/// error instead of aborting if the output cannot be allocated.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::MissingPrefix`], the input does not start with the prefix.
+/// - [`Error::AllocationFailed`], the output could not be allocated.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, version) =
+///     c32::decode_check_prefixed_fallible("S0AHA59B9201Z", 'S')?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check_prefixed_fallible(
+    str: &str,
+    prefix: char,
+) -> Result<(Vec<u8>, u8)> {
+    __internal::decode_after_prefix(
+        str,
+        prefix,
+        __internal::try_decode_check_vec,
+    )
+}
+
+/// Decodes a Crockford Base32-encoded string, skipping ASCII whitespace.
+///
+/// ASCII space, tab, CR and LF are skipped wherever they occur, without a
+/// cleaned copy of the input, see [`Whitespace::Ignore`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Notes
+///
+/// - The `index` of an [`Error::InvalidCharacter`] refers to the original
+///   input, including the whitespace.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_ignoring_whitespace(" 2MA\r\nHA\n")?;
+/// assert_eq!(de, [42, 42, 42]);
+///
+/// let result = c32::decode_ignoring_whitespace("2M A!A");
+/// assert!(matches!(
+///     result,
+///     Err(Error::InvalidCharacter { index: 4, .. })
+/// ));
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_ignoring_whitespace(str: &str) -> Result<Vec<u8>> {
+    let bytes = str.as_bytes();
+
+    // Allocate the output buffer.
+    let capacity = decoded_len(__internal::significant_len::<
+        { __internal::WHITESPACE },
+    >(bytes));
+    let mut dst = vec![0u8; capacity];
+
+    // Decode the input bytes into the buffer.
+    let offset =
+        decode_into_with_whitespace(bytes, &mut dst, Whitespace::Ignore)?;
+    __internal::wipe(&mut dst[offset..]);
+    dst.truncate(offset);
+
+    Ok(dst)
+}
+
+/// Decodes a Crockford Base32Check-encoded string, skipping ASCII
+/// whitespace.
+///
+/// ASCII space, tab, CR and LF are skipped wherever they occur, including
+/// before the version character, see [`Whitespace::Ignore`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, version) =
+///     c32::decode_check_ignoring_whitespace("\t0 AHA59B\n9201Z\n")?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check_ignoring_whitespace(str: &str) -> Result<(Vec<u8>, u8)> {
+    let bytes = str.as_bytes();
+
+    // Allocate the output buffer.
+    let capacity = decoded_check_len(__internal::significant_len::<
+        { __internal::WHITESPACE },
+    >(bytes));
+    let mut dst = vec![0u8; capacity];
+
+    // Decode the input bytes into the buffer.
+    let (offset, version) =
+        decode_check_into_with_whitespace(bytes, &mut dst, Whitespace::Ignore)?;
+    __internal::wipe(&mut dst[offset..]);
+    dst.truncate(offset);
+
+    Ok((dst, version))
+}
+
+/// Encodes bytes into a Crockford Base32-encoded string, in the given [`Mode`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version of a check mode is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::Mode;
+///
+/// let en = c32::encode_mode(&[42, 42, 42], Mode::Default)?;
+/// assert_eq!(en, "2MAHA");
+///
+/// let en = c32::encode_mode(&[42, 42, 42], Mode::Prefixed('S'))?;
+/// assert_eq!(en, "S2MAHA");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_mode<B>(src: B, mode: Mode) -> Result<String>
+where
+    B: AsRef<[u8]>,
+{
+    match mode {
+        Mode::Default => Ok(encode(src)),
+        Mode::Prefixed(prefix) => Ok(encode_prefixed(src, prefix)),
+        #[cfg(feature = "check")]
+        Mode::Check { version } => encode_check(src, version),
+        #[cfg(feature = "check")]
+        Mode::CheckPrefixed { prefix, version } => {
+            encode_check_prefixed(src, prefix, version)
+        }
+    }
+}
+
+/// Decodes a Crockford Base32-encoded string, in the given [`Mode`].
+///
+/// The check modes verify the checksum, and that the decoded version matches
+/// the version of the mode.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::MissingPrefix`], the input does not start with the prefix.
+/// - [`Error::InsufficientData`], the input is too short for a check mode.
+/// - [`Error::ChecksumMismatch`], the checksum of a check mode is invalid.
+/// - [`Error::InvalidVersion`], the decoded version does not match the mode.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::Mode;
+///
+/// let de = c32::decode_mode("S2MAHA", Mode::Prefixed('S'))?;
+/// assert_eq!(de, [42, 42, 42]);
+///
+/// let err = c32::decode_mode("2MAHA", Mode::Prefixed('S')).unwrap_err();
+/// assert_eq!(
+///     err,
+///     Error::MissingPrefix {
+///         char: 'S',
+///         got: Some('2')
+///     }
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_mode(str: &str, mode: Mode) -> Result<Vec<u8>> {
+    #[cfg(feature = "check")]
+    let expect = |(bytes, version): (Vec<u8>, u8), expected: u8| {
+        // Assert that the version matches the version of the mode.
+        if version != expected {
+            return Err(Error::InvalidVersion {
+                expected: "must match the expected version",
+                version,
+            });
+        }
+        Ok(bytes)
+    };
+
+    match mode {
+        Mode::Default => decode(str),
+        Mode::Prefixed(prefix) => decode_prefixed(str, prefix),
+        #[cfg(feature = "check")]
+        Mode::Check { version } => expect(decode_check(str)?, version),
+        #[cfg(feature = "check")]
+        Mode::CheckPrefixed { prefix, version } => {
+            expect(decode_check_prefixed(str, prefix)?, version)
+        }
+    }
+}
+
+/// Suggests single-character corrections for a Base32Check-encoded string.
+///
+/// Each suggestion is a `(index, char)` pair, where replacing the character
+/// at byte `index` with `char` yields a string with a valid checksum. Adjacent
+/// transpositions are also tried, and reported as the first swapped position
+/// with the character that belongs there.
+///
+/// # Returns
+///
+/// - At most `max_suggestions` suggestions, ordered by position.
+/// - No suggestions if the input is already valid, or is longer than 64
+///   characters, which bounds the search to `64 × 32` checksum computations.
+///
+/// # Examples
+///
+/// ```rust
+/// // The valid encoding is "P7AWVHENJJ0RB441K6JVK5DNJ7J3V5".
+/// let suggestions =
+///     c32::suggest_corrections("P7AWVHENJJ0RB441K6JVK5DNJ7J3V6", 4);
+/// assert!(suggestions.contains(&(29, '5')));
+/// ```
+#[must_use]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn suggest_corrections(
+    str: &str,
+    max_suggestions: usize,
+) -> Vec<(usize, char)> {
+    const MAX_LEN: usize = 64;
+
+    let src = str.as_bytes();
+    let mut suggestions = Vec::new();
+
+    // Assert that the input is within the supported bounds.
+    if src.len() > MAX_LEN || max_suggestions == 0 {
+        return suggestions;
+    }
+
+    // Skip the search if the input is already valid.
+    let mut dst = [0u8; decoded_check_len(MAX_LEN)];
+    if decode_check_into(src, &mut dst).is_ok() {
+        return suggestions;
+    }
+
+    let mut buffer = [0u8; MAX_LEN];
+    let candidate = &mut buffer[..src.len()];
+    candidate.copy_from_slice(src);
+
+    // Returns whether two bytes decode to the same value.
+    let is_same = |a: u8, b: u8| {
+        __internal::is_valid(a)
+            && __internal::is_valid(b)
+            && BYTE_MAP[a as usize] == BYTE_MAP[b as usize]
+    };
+
+    for index in 0..candidate.len() {
+        let original = candidate[index];
+
+        // Try every substitution at this position.
+        for &char in ALPHABET {
+            if is_same(original, char) {
+                continue;
+            }
+
+            candidate[index] = char;
+            if decode_check_into(candidate, &mut dst).is_ok() {
+                suggestions.push((index, char as char));
+            }
+        }
+        candidate[index] = original;
+
+        // Try swapping this position with the next one.
+        if let Some(&next) = candidate.get(index + 1) {
+            if !is_same(original, next) && next.is_ascii() {
+                candidate.swap(index, index + 1);
+                let suggestion = (index, next as char);
+                if decode_check_into(candidate, &mut dst).is_ok()
+                    && !suggestions.contains(&suggestion)
+                {
+                    suggestions.push(suggestion);
+                }
+                candidate.swap(index, index + 1);
+            }
+        }
+
+        if suggestions.len() >= max_suggestions {
+            suggestions.truncate(max_suggestions);
+            break;
+        }
+    }
+
+    suggestions
+}
+
+/// Returns a cheap hint for where a Base32Check-encoded string is damaged.
+///
+/// The hint is the byte index of the first character outside the Crockford
+/// alphabet, i.e. the end of the longest prefix that could still be part of
+/// a valid encoding.
+///
+/// # Returns
+///
+/// - `Some(index)`, the position of the first invalid character.
+/// - `None`, every character is valid, in which case only a checksum failure
+///   remains and [`suggest_corrections`] is needed to localize it.
+///
+/// # Examples
+///
+/// ```rust
+/// let hint = c32::damage_position_hint("P7AWVHENJJ0RB441K6JUK5DNJ7J3V5");
+/// assert_eq!(hint, Some(19));
+///
+/// let hint = c32::damage_position_hint("P7AWVHENJJ0RB441K6JVK5DNJ7J3V6");
+/// assert_eq!(hint, None);
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "check")]
+pub fn damage_position_hint(str: &str) -> Option<usize> {
+    str.bytes().position(|byte| !__internal::is_valid(byte))
+}
+
+/// An extension trait for collecting bytes into a Crockford Base32 string.
+///
+/// # Examples
+///
+/// ```rust
+/// use c32::C32CollectExt;
+///
+/// let en = [42, 42, 42].into_iter().collect_c32();
+/// assert_eq!(en, "2MAHA");
+/// ```
+#[cfg(feature = "alloc")]
+pub trait C32CollectExt: Iterator<Item = u8> {
+    /// Collects the bytes and encodes them into a Crockford Base32 string.
+    ///
+    /// This produces the same output as [`encode`] on the collected bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::C32CollectExt;
+    ///
+    /// let en = (1..=3).collect_c32();
+    /// assert_eq!(en, c32::encode([1, 2, 3]));
+    /// ```
+    #[must_use]
+    fn collect_c32(self) -> String
+    where
+        Self: Sized,
+    {
+        encode(self.collect::<Vec<u8>>())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = u8>> C32CollectExt for I {}
+
+/// A trait for types that present themselves as Crockford Base32.
+///
+/// Implementors provide their canonical bytes, and receive [`to_c32`] for
+/// free, which standardizes the textual form of domain types.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::borrow::Cow;
+///
+/// use c32::C32Encode;
+///
+/// struct AccountId([u8; 4]);
+///
+/// impl C32Encode for AccountId {
+///     fn c32_bytes(&self) -> Cow<'_, [u8]> {
+///         Cow::Borrowed(&self.0)
+///     }
+/// }
+///
+/// let id = AccountId([0, 42, 42, 42]);
+/// assert_eq!(id.to_c32(), "02MAHA");
+/// ```
+///
+/// [`to_c32`]: C32Encode::to_c32
+#[cfg(feature = "alloc")]
+pub trait C32Encode {
+    /// Returns the bytes to encode.
+    fn c32_bytes(&self) -> Cow<'_, [u8]>;
+
+    /// Encodes [`c32_bytes`] into a Crockford Base32 string.
+    ///
+    /// [`c32_bytes`]: C32Encode::c32_bytes
+    #[inline]
+    #[must_use]
+    fn to_c32(&self) -> String {
+        encode(self.c32_bytes())
+    }
+}
+
+/// A trait for types that can be parsed from Crockford Base32.
+///
+/// This is the companion of [`C32Encode`], implementors validate the decoded
+/// bytes and receive [`from_c32`] for free.
+///
+/// # Examples
+///
+/// ```rust
+/// use c32::C32Decode;
+/// use c32::Error;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct AccountId([u8; 4]);
+///
+/// impl C32Decode for AccountId {
+///     type Error = Error;
+///
+///     fn from_c32_bytes(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+///         let got = bytes.len();
+///         let bytes = bytes
+///             .try_into()
+///             .map_err(|_| Error::InvalidDataSize { expected: 4, got })?;
+///         Ok(Self(bytes))
+///     }
+/// }
+///
+/// let id = AccountId::from_c32("02MAHA")?;
+/// assert_eq!(id, AccountId([0, 42, 42, 42]));
+///
+/// let result = AccountId::from_c32("2MAHA");
+/// assert_eq!(
+///     result,
+///     Err(Error::InvalidDataSize {
+///         expected: 4,
+///         got: 3
+///     })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`from_c32`]: C32Decode::from_c32
+#[cfg(feature = "alloc")]
+pub trait C32Decode: Sized {
+    /// The error returned when decoding fails.
+    type Error: From<crate::Error>;
+
+    /// Creates a value from the decoded bytes.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the bytes do not form a valid
+    /// value.
+    fn from_c32_bytes(
+        bytes: Vec<u8>,
+    ) -> core::result::Result<Self, Self::Error>;
+
+    /// Decodes a Crockford Base32 string into a value.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    ///
+    /// - [`Error::InvalidCharacter`], the input contains invalid characters.
+    /// - The decoded bytes are rejected by [`from_c32_bytes`].
+    ///
+    /// [`from_c32_bytes`]: C32Decode::from_c32_bytes
+    #[inline]
+    fn from_c32(str: &str) -> core::result::Result<Self, Self::Error> {
+        Self::from_c32_bytes(decode(str)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl C32Encode for [u8] {
+    fn c32_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> C32Encode for [u8; N] {
+    fn c32_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl C32Encode for Vec<u8> {
+    fn c32_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> C32Encode for C32Text<N> {
+    fn c32_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(&self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> C32Decode for [u8; N] {
+    type Error = Error;
+
+    fn from_c32_bytes(bytes: Vec<u8>) -> Result<Self> {
+        // Assert that the decoded bytes match the expected size.
+        let got = bytes.len();
+        bytes
+            .try_into()
+            .map_err(|_| Error::InvalidDataSize { expected: N, got })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl C32Decode for Vec<u8> {
+    type Error = Error;
+
+    fn from_c32_bytes(bytes: Vec<u8>) -> Result<Self> {
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> C32Decode for C32Text<N> {
+    type Error = Error;
+
+    fn from_c32_bytes(bytes: Vec<u8>) -> Result<Self> {
+        <[u8; N]>::from_c32_bytes(bytes).map(Self)
+    }
+}
+
+/// Encodes `len` random bytes into a Crockford Base32-encoded string.
+///
+/// # Notes
+///
+/// - The bytes are drawn uniformly and then encoded, rather than drawing
+///   characters, so there is no modulo bias. The string carries `8 × len` bits
+///   of entropy, at 5 bits per character.
+/// - Each leading zero byte encodes as a single `0`, so the string is at most
+///   [`encoded_len`]`(len)` characters long, and may be shorter.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut rng = rand::rng();
+/// let id = c32::random_string(16, &mut rng);
+/// assert_eq!(c32::decode(&id)?.len(), 16);
+/// # Ok::<(), c32::Error>(())
+/// ```
+#[inline]
+#[must_use]
+#[cfg(all(feature = "alloc", feature = "rand"))]
+pub fn random_string<R>(len: usize, rng: &mut R) -> String
+where
+    R: rand::Rng + ?Sized,
+{
+    let mut bytes = vec![0u8; len];
+    rng.fill_bytes(&mut bytes);
+    encode(bytes)
+}
+
+/// Encodes `len` random bytes into a Crockford Base32Check-encoded string.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut rng = rand::rng();
+/// let id = c32::random_check(20, 22, &mut rng)?;
+/// let (bytes, version) = c32::decode_check(&id)?;
+/// assert_eq!(bytes.len(), 20);
+/// assert_eq!(version, 22);
+/// # Ok::<(), c32::Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check", feature = "rand"))]
+pub fn random_check<R>(len: usize, version: u8, rng: &mut R) -> Result<String>
+where
+    R: rand::Rng + ?Sized,
+{
+    let mut bytes = vec![0u8; len];
+    rng.fill_bytes(&mut bytes);
+    encode_check(bytes, version)
+}
+
+/// Encodes `BYTES` random bytes from the thread-local generator.
+///
+/// This is a shorthand for [`random_string`] with [`rand::rng`], which has
+/// the same entropy, see [`random_string`].
+///
+/// # Examples
+///
+/// ```rust
+/// // 128 bits of entropy, in at most 26 characters.
+/// let id = c32::random::<16>();
+/// assert!(id.len() <= c32::encoded_len(16));
+/// assert_eq!(c32::decode(&id)?.len(), 16);
+/// # Ok::<(), c32::Error>(())
+/// ```
+#[inline]
+#[must_use]
+#[cfg(all(feature = "std", feature = "rand"))]
+pub fn random<const BYTES: usize>() -> String {
+    random_string(BYTES, &mut rand::rng())
+}
+
+/// Encodes `BYTES` random bytes into a Crockford Base32-encoded slice.
+///
+/// This does not allocate, and has the same entropy as [`random_string`].
+///
+/// # Returns
+///
+/// The number of bytes written to `dst`.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output is smaller than
+///   [`encoded_len`]`(BYTES)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let mut rng = rand::rng();
+///
+/// let mut dst = [0u8; c32::encoded_len(16)];
+/// let n = c32::random_into::<16, _>(&mut dst, &mut rng)?;
+///
+/// let mut de = [0u8; c32::decoded_len(c32::encoded_len(16))];
+/// assert_eq!(c32::decode_into(&dst[..n], &mut de)?, 16);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "rand")]
+pub fn random_into<const BYTES: usize, R>(
+    dst: &mut [u8],
+    rng: &mut R,
+) -> Result<usize>
+where
+    R: rand::RngCore + ?Sized,
+{
+    let mut bytes = [0u8; BYTES];
+    rng.fill_bytes(&mut bytes);
+
+    let result = encode_into(&bytes, dst);
+    __internal::wipe(&mut bytes);
+    result
+}
+
+/// Decodes the leading Crockford Base32-encoded characters of a string.
+///
+/// Decoding stops at the first character that is not part of the alphabet,
+/// which is treated as the end of the input rather than an error.
+///
+/// # Returns
+///
+/// The decoded bytes and the number of input bytes consumed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, consumed) = c32::decode_partial("2MAHA/rest")?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(consumed, 5);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_partial(str: &str) -> Result<(Vec<u8>, usize)> {
+    let bytes = str.as_bytes();
+
+    // Allocate the output buffer.
+    let capacity = decoded_len(bytes.len());
+    let mut dst = vec![0u8; capacity];
+
+    // Decode the input bytes into the buffer.
+    let (offset, consumed) = decode_partial_into(bytes, &mut dst)?;
+    __internal::wipe(&mut dst[offset..]);
+    dst.truncate(offset);
+
+    Ok((dst, consumed))
+}
+
+/// Decodes the leading Crockford Base32Check-encoded characters of a string.
+///
+/// Decoding stops at the first character that is not part of the alphabet,
+/// and the consumed characters must form a valid check-encoded string.
+///
+/// # Returns
+///
+/// The decoded bytes, the version and the number of input bytes consumed.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InsufficientData`], the consumed input is too short.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (bytes, version, consumed) =
+///     c32::decode_check_partial("0AHA59B9201Z/rest")?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// assert_eq!(consumed, 12);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn decode_check_partial(str: &str) -> Result<(Vec<u8>, u8, usize)> {
+    let bytes = str.as_bytes();
+
+    // Allocate the output buffer.
+    let capacity = decoded_check_len(bytes.len());
+    let mut dst = vec![0u8; capacity];
+
+    // Decode the input bytes into the buffer.
+    let (offset, version, consumed) =
+        decode_check_partial_into(bytes, &mut dst)?;
+    __internal::wipe(&mut dst[offset..]);
+    dst.truncate(offset);
+
+    Ok((dst, version, consumed))
+}
+
+/// Scans free-form text for valid Crockford Base32Check-encoded strings.
+///
+/// The text is split into maximal runs of alphabet characters by the scanner
+/// of [`scan`], and each run of at least 8 characters is verified once, so
+/// scanning is linear in the length of the text. Shorter runs are skipped
+/// without being decoded. The length of a candidate is not capped.
+///
+/// If a `prefix` is provided, candidates must start with the prefix, and
+/// the yielded range includes the prefix character, see
+/// [`scan::Tokens::prefix`].
+///
+/// # Notes
+///
+/// - A run is verified as a whole, so a valid string directly followed or
+///   preceded by alphabet characters is not found.
+///
+/// # Examples
+///
+/// ```rust
+/// let text = "sent to S0AHA59B9201Z, ok";
+/// let mut scan = c32::scan_check(text, Some('S'));
+///
+/// let (range, version, bytes) = scan.next().unwrap();
+/// assert_eq!(&text[range], "S0AHA59B9201Z");
+/// assert_eq!(version, 0);
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert!(scan.next().is_none());
+/// ```
+#[inline]
+#[must_use]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub fn scan_check(text: &str, prefix: Option<char>) -> ScanCheck<'_> {
+    let tokens = scan::find_tokens(text, ScanCheck::MIN_LEN);
+    ScanCheck {
+        tokens: match prefix {
+            Some(prefix) => tokens.prefix(prefix),
+            None => tokens,
+        },
+        buffer: Vec::new(),
+    }
+}
+
+/// An iterator over the Crockford Base32Check-encoded strings in a text.
+///
+/// This struct is created by [`scan_check`].
+#[derive(Debug, Clone)]
+#[cfg(all(feature = "alloc", feature = "check"))]
+pub struct ScanCheck<'a> {
+    /// The tokens of the text.
+    tokens: scan::Tokens<'a>,
+    /// A scratch buffer for decoding candidates.
+    buffer: Vec<u8>,
+}
+
+#[cfg(all(feature = "alloc", feature = "check"))]
+impl ScanCheck<'_> {
+    /// The minimum length of a candidate, excluding any prefix.
+    const MIN_LEN: usize = 8;
+}
+
+#[cfg(all(feature = "alloc", feature = "check"))]
+impl Iterator for ScanCheck<'_> {
+    type Item = (core::ops::Range<usize>, u8, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for token in self.tokens.by_ref() {
+            let src = token.encoded().as_bytes();
+
+            // Decode and verify the candidate into the scratch buffer.
+            self.buffer.resize(decoded_check_len(src.len()), 0);
+            if let Ok((offset, version)) =
+                decode_check_into(src, &mut self.buffer)
+            {
+                return Some((
+                    token.span(),
+                    version,
+                    self.buffer[..offset].to_vec(),
+                ));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "check"))]
+impl core::iter::FusedIterator for ScanCheck<'_> {}
+
+/// This module provides a scanner for Crockford Base32 tokens in text.
+///
+/// Tokens are maximal runs of alphabet characters, found by a two-state
+/// scanner over the haystack. Neither scanning nor validating a [`Token`]
+/// allocates, which makes it suitable for grepping large logs.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "check")] {
+/// use c32::scan;
+///
+/// let text = "from SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7 to SPOOFED";
+/// let mut tokens = scan::find_tokens(text, 8).prefix('S').verified_only();
+///
+/// let token = tokens.next().unwrap();
+/// assert_eq!(token.as_str(), "SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7");
+/// assert_eq!(token.validate_check(), Ok(22));
+/// assert!(tokens.next().is_none());
+/// # }
+/// ```
+///
+/// [`Token`]: scan::Token
+#[cfg(feature = "alloc")]
+pub mod scan {
+    use core::ops::Range;
+
+    use super::*;
+
+    /// Finds the Crockford Base32 tokens of at least `min_len` characters in
+    /// a haystack.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::scan;
+    ///
+    /// let text = "ids: 2MAHA,2MAHB;  x";
+    /// let tokens: Vec<_> = scan::find_tokens(text, 5).collect();
+    ///
+    /// assert_eq!(tokens.len(), 2);
+    /// assert_eq!(tokens[0].span(), 5..10);
+    /// assert_eq!(tokens[1].as_str(), "2MAHB");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn find_tokens(haystack: &str, min_len: usize) -> Tokens<'_> {
+        Tokens {
+            haystack,
+            alphabet: &alphabet::CROCKFORD,
+            min_len,
+            prefix: None,
+            #[cfg(feature = "check")]
+            verified_only: false,
+            pos: 0,
+        }
+    }
+
+    /// A Crockford Base32 token found in a haystack.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Token<'a> {
+        /// The haystack containing the token.
+        haystack: &'a str,
+        /// The alphabet of the token.
+        alphabet: &'a alphabet::Alphabet,
+        /// The start of the token, including any prefix.
+        start: usize,
+        /// The start of the encoded characters.
+        body: usize,
+        /// The end of the token.
+        end: usize,
+    }
+
+    impl<'a> Token<'a> {
+        /// Returns the byte range of the token within the haystack,
+        /// including any prefix.
+        #[inline]
+        #[must_use]
+        pub const fn span(&self) -> Range<usize> {
+            self.start..self.end
+        }
+
+        /// Returns the token, including any prefix.
+        #[inline]
+        #[must_use]
+        pub fn as_str(&self) -> &'a str {
+            &self.haystack[self.start..self.end]
+        }
+
+        /// Returns the encoded characters of the token, without any prefix.
+        #[inline]
+        #[must_use]
+        pub fn encoded(&self) -> &'a str {
+            &self.haystack[self.body..self.end]
+        }
+
+        /// Verifies the token as Crockford Base32Check, without allocating.
+        ///
+        /// The result is computed on every call, so tokens which are never
+        /// validated cost nothing beyond the scan.
+        ///
+        /// # Returns
+        ///
+        /// The version byte of the token.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an [`Error`] if:
+        ///
+        /// - [`Error::InsufficientData`], the token has fewer bytes than
+        ///   required.
+        /// - [`Error::ChecksumMismatch`], the checksum's do not match.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use c32::scan;
+        ///
+        /// let mut tokens = scan::find_tokens("0AHA59B9201Z 0AHA59B9201Y", 8);
+        /// assert_eq!(tokens.next().unwrap().validate_check(), Ok(0));
+        /// assert!(tokens.next().unwrap().validate_check().is_err());
+        /// ```
+        #[inline]
+        #[cfg(feature = "check")]
+        pub fn validate_check(&self) -> Result<u8> {
+            let src = self.encoded().as_bytes();
+            __stream::verify_check_with(self.alphabet, src)
+        }
+    }
+
+    /// An iterator over the Crockford Base32 tokens in a haystack.
+    ///
+    /// This struct is created by [`find_tokens`].
+    #[derive(Debug, Clone)]
+    pub struct Tokens<'a> {
+        /// The haystack being scanned.
+        haystack: &'a str,
+        /// The alphabet of the tokens.
+        alphabet: &'a alphabet::Alphabet,
+        /// The minimum number of encoded characters.
+        min_len: usize,
+        /// The optional prefix character.
+        prefix: Option<char>,
+        /// Whether to skip tokens that fail verification.
+        #[cfg(feature = "check")]
+        verified_only: bool,
+        /// The current position within the haystack.
+        pos: usize,
+    }
+
+    impl<'a> Tokens<'a> {
+        /// Scans for tokens in a custom [`Alphabet`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use c32::alphabet;
+        /// use c32::scan;
+        ///
+        /// let text = "ZBASE:pbtkd/CROCKFORD:2MAHA";
+        /// let mut tokens = scan::find_tokens(text, 5).alphabet(&alphabet::ZBASE32);
+        /// assert_eq!(tokens.next().unwrap().as_str(), "pbtkd");
+        /// ```
+        ///
+        /// [`Alphabet`]: alphabet::Alphabet
+        #[inline]
+        #[must_use]
+        pub fn alphabet(mut self, alphabet: &'a alphabet::Alphabet) -> Self {
+            self.alphabet = alphabet;
+            self
+        }
+
+        /// Only yields tokens that start with `prefix`.
+        ///
+        /// If the prefix is part of the alphabet, it must be the first
+        /// character of the run, otherwise it must directly precede the run.
+        /// In both cases the span includes the prefix.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use c32::scan;
+        ///
+        /// let mut tokens = scan::find_tokens("P0AHA S0AHA", 4).prefix('S');
+        /// let token = tokens.next().unwrap();
+        /// assert_eq!(token.as_str(), "S0AHA");
+        /// assert_eq!(token.encoded(), "0AHA");
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn prefix(mut self, prefix: char) -> Self {
+            self.prefix = Some(prefix);
+            self
+        }
+
+        /// Only yields tokens that pass [`Token::validate_check`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use c32::scan;
+        ///
+        /// let text = "0AHA59B9201Y 0AHA59B9201Z";
+        /// let mut tokens = scan::find_tokens(text, 8).verified_only();
+        /// assert_eq!(tokens.next().unwrap().span(), 13..25);
+        /// assert!(tokens.next().is_none());
+        /// ```
+        #[inline]
+        #[must_use]
+        #[cfg(feature = "check")]
+        pub fn verified_only(mut self) -> Self {
+            self.verified_only = true;
+            self
+        }
+
+        /// Returns whether the byte is part of the alphabet.
+        fn contains(&self, byte: u8) -> bool {
+            self.alphabet.value(byte) != INVALID
+        }
+
+        /// Creates a token from a run, applying the prefix and length rules.
+        fn token(&self, start: usize, end: usize) -> Option<Token<'a>> {
+            let src = self.haystack.as_bytes();
+            let (start, body) = match self.prefix {
+                None => (start, start),
+                // The prefix is part of the alphabet, and starts the run.
+                Some(prefix)
+                    if prefix.is_ascii() && self.contains(prefix as u8) =>
+                {
+                    if src[start] != prefix as u8 {
+                        return None;
+                    }
+                    (start, start + 1)
+                }
+                // The prefix must directly precede the run.
+                Some(prefix) => {
+                    let n = prefix.len_utf8();
+                    if !self.haystack[..start].ends_with(prefix) {
+                        return None;
+                    }
+                    (start - n, start)
+                }
+            };
+
+            // Assert that the token has the minimum length.
+            if end - body < self.min_len.max(1) {
+                return None;
+            }
+
+            Some(Token {
+                haystack: self.haystack,
+                alphabet: self.alphabet,
+                start,
+                body,
+                end,
+            })
+        }
+    }
+
+    impl<'a> Iterator for Tokens<'a> {
+        type Item = Token<'a>;
+
+        fn next(&mut self) -> Option<Token<'a>> {
+            let src = self.haystack.as_bytes();
+            while self.pos < src.len() {
+                // Skip characters outside the alphabet.
+                if !self.contains(src[self.pos]) {
+                    self.pos += 1;
+                    continue;
+                }
+
+                // Consume the run of alphabet characters.
+                let start = self.pos;
+                while self.pos < src.len() && self.contains(src[self.pos]) {
+                    self.pos += 1;
+                }
+
+                let Some(token) = self.token(start, self.pos) else {
+                    continue;
+                };
+
+                #[cfg(feature = "check")]
+                if self.verified_only && token.validate_check().is_err() {
+                    continue;
+                }
+
+                return Some(token);
+            }
+            None
+        }
+    }
+
+    impl core::iter::FusedIterator for Tokens<'_> {}
+}
+
+/// Encodes bytes into a fixed-width Crockford Base32-encoded string, which
+/// sorts in the same order as the numeric value of the bytes.
+///
+/// The bytes are read as a big-endian unsigned number, and the encoding is
+/// left-padded with the zero symbol to `width` characters.
+///
+/// # Notes
+///
+/// Unlike [`encode`], the output is not canonical Crockford Base32:
+///
+/// - Leading zero bytes are not preserved, so `[0, 1]` and `[1]` encode
+///   identically. Decoding yields one zero byte per padding symbol, so the
+///   original length must be known to recover the input.
+/// - Lexicographic order only matches numeric order for equal `width`s.
+///
+/// A `width` of [`encoded_len`] of the maximum input length always suffices.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the value does not fit into `width`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let a = c32::encode_sortable(&[0x1F], 4)?;
+/// let b = c32::encode_sortable(&[0x01, 0x00], 4)?;
+/// assert_eq!(a, "000Z");
+/// assert_eq!(b, "0080");
+/// assert!(a < b);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn encode_sortable(src: &[u8], width: usize) -> Result<String> {
+    let encoded = encode(src);
+
+    // Strip the leading zero symbols, which carry no value.
+    let digits = encoded.trim_start_matches(char::from(ALPHABET[0]));
+
+    // Assert that the value fits into the width.
+    if digits.len() > width {
+        return Err(Error::BufferTooSmall {
+            min: digits.len(),
+            len: width,
+        });
+    }
+
+    // Left-pad the value with the zero symbol.
+    let mut dst = String::with_capacity(width);
+    for _ in digits.len()..width {
+        dst.push(char::from(ALPHABET[0]));
+    }
+    dst.push_str(digits);
+    Ok(dst)
+}
+
+/// Encodes an `i64` into a fixed-width Crockford Base32-encoded string.
+///
+/// The value is encoded as its 8 two's-complement bytes in big-endian order,
+/// read as an unsigned number and left-padded with the zero symbol to
+/// [`encoded_len`]`(8)`, i.e. 13 characters.
+///
+/// # Notes
+///
+/// - This is a fixed-width encoding, not the minimal numeric form of
+///   [`encode`], which would strip the sign-extension bytes of small values and
+///   could not tell `1` from `-1` in a wider type. Use [`decode_i64`] to decode
+///   it.
+/// - Negative values sort after positive values, as their top bit is set.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::encode_i64(0), "0000000000000");
+/// assert_eq!(c32::encode_i64(42), "000000000001A");
+/// assert_eq!(c32::encode_i64(-1), "FZZZZZZZZZZZZ");
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_i64(value: i64) -> String {
+    let mut dst = [0u8; encoded_len(8)];
+    let value = u64::from_ne_bytes(value.to_ne_bytes());
+    __internal::en_fixed(u128::from(value), &mut dst);
+
+    // SAFETY: The buffer consists of ASCII characters only.
+    String::from(unsafe { core::str::from_utf8_unchecked(&dst) })
+}
+
+/// Decodes an `i64` from a fixed-width encoding, see [`encode_i64`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InvalidDataSize`], the input is not 13 characters long.
+/// - [`Error::OutputTooLarge`], the value does not fit into 8 bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// assert_eq!(c32::decode_i64("000000000001A")?, 42);
+/// assert_eq!(c32::decode_i64("FZZZZZZZZZZZZ")?, -1);
+/// assert_eq!(
+///     c32::decode_i64("1A"),
+///     Err(Error::InvalidDataSize {
+///         expected: 13,
+///         got: 2
+///     })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_i64(str: &str) -> Result<i64> {
+    let value = __internal::de_fixed(str.as_bytes(), 8)?;
+
+    // The value fits into 8 bytes, so the truncation is lossless.
+    #[allow(clippy::cast_possible_truncation)]
+    let value = value as u64;
+    Ok(i64::from_ne_bytes(value.to_ne_bytes()))
+}
+
+/// Encodes an `i128` into a fixed-width Crockford Base32-encoded string.
+///
+/// The value is encoded as its 16 two's-complement bytes in big-endian
+/// order, see [`encode_i64`], left-padded to [`encoded_len`]`(16)`, i.e. 26
+/// characters.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(c32::encode_i128(42), "0000000000000000000000001A");
+/// assert_eq!(c32::encode_i128(-1), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_i128(value: i128) -> String {
+    let mut dst = [0u8; encoded_len(16)];
+    __internal::en_fixed(u128::from_ne_bytes(value.to_ne_bytes()), &mut dst);
+
+    // SAFETY: The buffer consists of ASCII characters only.
+    String::from(unsafe { core::str::from_utf8_unchecked(&dst) })
+}
+
+/// Decodes an `i128` from a fixed-width encoding, see [`encode_i128`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InvalidDataSize`], the input is not 26 characters long.
+/// - [`Error::OutputTooLarge`], the value does not fit into 16 bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// assert_eq!(c32::decode_i128("7ZZZZZZZZZZZZZZZZZZZZZZZZZ")?, -1);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_i128(str: &str) -> Result<i128> {
+    let value = __internal::de_fixed(str.as_bytes(), 16)?;
+    Ok(i128::from_ne_bytes(value.to_ne_bytes()))
+}
+
+/// Encodes multiple byte fields into a single Crockford Base32-encoded
+/// string.
+///
+/// Each field is prefixed with its length as an unsigned LEB128 varint,
+/// before the concatenation is encoded.
+///
+/// # Examples
+///
+/// ```rust
+/// let en = c32::encode_framed(&[&[42, 42], &[], &[1]]);
+/// assert_eq!(en, "258N00081");
+/// ```
+#[inline]
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_framed(fields: &[&[u8]]) -> String {
+    // Allocate the framed buffer.
+    let capacity = fields.iter().map(|field| 10 + field.len()).sum();
+    let mut bytes = Vec::with_capacity(capacity);
+
+    // Append the length prefix and the bytes of every field.
+    for field in fields {
+        let mut len = field.len();
+        while len >= 0x80 {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.push((len as u8 & 0x7F) | 0x80);
+            len >>= 7;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.push(len as u8);
+        bytes.extend_from_slice(field);
+    }
+
+    encode(bytes)
+}
+
+/// Decodes a Crockford Base32-encoded string into multiple byte fields.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InvalidFrame`], the decoded framing is malformed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let fields = c32::decode_framed("258N00081")?;
+/// assert_eq!(fields, [vec![42, 42], vec![], vec![1]]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_framed(str: &str) -> Result<Vec<Vec<u8>>> {
+    let bytes = decode(str)?;
+
+    // Split the decoded bytes into fields.
+    let mut fields = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let (start, end) = __internal::frame(&bytes, pos, fields.len())?;
+        fields.push(bytes[start..end].to_vec());
+        pos = end;
+    }
+
+    Ok(fields)
+}
+/// Encodes bytes into padded Crockford Base32 blocks.
+///
+/// Unlike [`encode`], the bytes are not read as a single number. Every 5
+/// bytes are encoded into a block of 8 characters, most significant bit
+/// first, and the final partial block is padded with `=`, as in [RFC 4648],
+/// but using the Crockford alphabet.
+///
+/// # Examples
+///
+/// ```rust
+/// let en = c32::encode_blocks(&[42, 42, 42]);
+/// assert_eq!(en, "58N2M===");
+/// ```
+///
+/// [RFC 4648]: https://www.rfc-editor.org/rfc/rfc4648#section-6
+#[inline]
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn encode_blocks(src: &[u8]) -> String {
+    let mut dst = vec![0u8; encoded_blocks_len(src.len())];
+    let _ = encode_blocks_into(src, &mut dst);
+
+    // SAFETY: The blocks consist of ASCII characters only.
+    unsafe { String::from_utf8_unchecked(dst) }
+}
+
+/// Decodes padded Crockford Base32 blocks, see [`encode_blocks`].
+///
+/// The padding is validated strictly, so every input is the canonical
+/// encoding of exactly one byte sequence.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InvalidPadding`], the padding is malformed, see
+///   [`decode_blocks_into`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_blocks("58N2M===")?;
+/// assert_eq!(de, [42, 42, 42]);
+///
+/// let result = c32::decode_blocks("58N2M==");
+/// assert_eq!(result, Err(Error::InvalidPadding { index: 7 }));
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn decode_blocks(str: &str) -> Result<Vec<u8>> {
+    let mut dst = vec![0u8; decoded_blocks_len(str.len())];
+    let offset = decode_blocks_into(str.as_bytes(), &mut dst)?;
+    dst.truncate(offset);
+    Ok(dst)
+}
+
+/// Encodes bytes as Crockford Base32 into a provided buffer.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 5];
+/// let offset = c32::encode_into(&[42, 42, 42], &mut dst)?;
+/// assert_eq!(&dst[..offset], b"2MAHA");
+/// assert_eq!(offset, 5);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn encode_into(src: &[u8], dst: &mut [u8]) -> Result<usize> {
+    encode_into_with_alphabet(&alphabet::CROCKFORD, src, dst)
+}
+
+/// Encodes bytes as Base32 into a provided buffer, using a custom
+/// [`Alphabet`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::alphabet;
+///
+/// # let mut dst = [0u8; 8];
+/// let offset =
+///     c32::encode_into_with_alphabet(&alphabet::ZBASE32, b"hello", &mut dst)?;
+/// assert_eq!(&dst[..offset], b"pb1sa5dx");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Alphabet`]: alphabet::Alphabet
+#[inline]
+pub const fn encode_into_with_alphabet(
+    alphabet: &alphabet::Alphabet,
+    src: &[u8],
+    dst: &mut [u8],
+) -> Result<usize> {
+    // Assert that the buffer has enough capacity.
+    let capacity = encoded_len(src.len());
+    if dst.len() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.len(),
+        });
+    }
+
+    // Encode the input bytes, and return the amount of bytes written.
+    let offset = __internal::en_with(alphabet, src, 0, src.len(), dst, 0, None);
+    Ok(offset)
+}
+
+/// Decodes Crockford Base32-encoded bytes into a provided buffer.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 5];
+/// let offset = c32::decode_into(b"2MAHA", &mut dst)?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// assert_eq!(offset, 3);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_into(src: &[u8], dst: &mut [u8]) -> Result<usize> {
+    decode_into_with_alphabet(&alphabet::CROCKFORD, src, dst)
+}
+
+/// Decodes Crockford Base32-encoded bytes into a provided buffer, with the
+/// given handling of [`Whitespace`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Notes
+///
+/// - With [`Whitespace::Ignore`], the buffer only needs capacity for the
+///   characters which are not whitespace, and the `index` of an
+///   [`Error::InvalidCharacter`] refers to the original input.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::Whitespace;
+///
+/// # let mut dst = [0u8; 5];
+/// let offset = c32::decode_into_with_whitespace(
+///     b"2M AHA\n",
+///     &mut dst,
+///     Whitespace::Ignore,
+/// )?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_into_with_whitespace(
+    src: &[u8],
+    dst: &mut [u8],
+    whitespace: Whitespace,
+) -> Result<usize> {
+    if whitespace == Whitespace::Reject {
+        return decode_into(src, dst);
+    }
+
+    // Assert that the buffer has enough capacity.
+    let capacity = decoded_len(__internal::significant_len::<
+        { __internal::WHITESPACE },
+    >(src));
+    if dst.len() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.len(),
+        });
+    }
+
+    // Decode the input bytes, skipping the whitespace.
+    __internal::de::<{ __internal::WHITESPACE }>(src, 0, src.len(), dst, 0)
+}
+
+/// Decodes trusted Crockford Base32-encoded bytes into a provided buffer,
+/// without validating the characters.
+///
+/// This shares its implementation with [`decode_into`], with the validation
+/// compiled away, which makes it faster for input that is known to be valid,
+/// e.g. because it was produced by [`encode_into`] or checked by [`validate`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Notes
+///
+/// - The input must only contain characters accepted by [`decode_into`]. This
+///   is not required for memory safety, and the method never panics, but for
+///   invalid input the output and the returned length are unspecified, and may
+///   differ between versions of this crate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 5];
+/// let offset = c32::decode_unchecked_into(b"2MAHA", &mut dst)?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub const fn decode_unchecked_into(
+    src: &[u8],
+    dst: &mut [u8],
+) -> Result<usize> {
+    // Assert that the buffer has enough capacity.
+    let capacity = decoded_len(src.len());
+    if dst.len() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.len(),
+        });
+    }
+
+    // Decode the input bytes, and return the amount of bytes written.
+    __internal::de::<{ __internal::UNCHECKED }>(src, 0, src.len(), dst, 0)
+}
+
+/// Decodes Crockford Base32-encoded bytes into a provided buffer, decoding
+/// invalid characters as zero.
+///
+/// This is a best-effort decoder for corrupted input, which keeps the length
+/// implied by the number of characters, so fixed-width fields stay aligned:
+///
+/// - The length is that of [`decode_into`] with every invalid character
+///   substituted with `1`, so an invalid character is never a leading zero.
+/// - The bytes hold the number encoded with every invalid character substituted
+///   with `0`, left-padded with zero bytes to that length.
+///
+/// The bytes are aligned to the end of the input, so a substituted character
+/// only changes the bytes which its 5 bits overlap, and a missing or extra
+/// character shifts all bytes before it.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer, and the number of
+/// substituted characters.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Notes
+///
+/// - The input is processed as bytes, so a non-ASCII character is counted once
+///   for each of its bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 5];
+/// let (offset, substituted) = c32::decode_lossy_into(b"2M!HA", &mut dst)?;
+/// assert_eq!(&dst[..offset], [42, 2, 42]);
+/// assert_eq!(substituted, 1);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub const fn decode_lossy_into(
+    src: &[u8],
+    dst: &mut [u8],
+) -> Result<(usize, usize)> {
+    // Assert that the buffer has enough capacity.
+    let capacity = decoded_len(src.len());
+    if dst.len() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.len(),
+        });
+    }
+
+    // Decode the input bytes, which does not fail for any character.
+    let offset = match __internal::de::<{ __internal::LOSSY }>(
+        src,
+        0,
+        src.len(),
+        dst,
+        0,
+    ) {
+        Ok(offset) => offset,
+        Err(e) => return Err(e),
+    };
+
+    Ok((offset, __internal::count_invalid(src)))
+}
+
+/// Decodes Crockford Base32-encoded bytes into a provided buffer.
+///
+/// # Returns
+///
+/// The decoded bytes, as a slice of the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 5];
+/// let bytes = c32::decode_into_slice(b"2MAHA", &mut dst)?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_into_slice<'a>(
+    src: &[u8],
+    dst: &'a mut [u8],
+) -> Result<&'a [u8]> {
+    let offset = decode_into(src, dst)?;
+    Ok(&dst[..offset])
+}
+
+/// Decodes Crockford Base32-encoded bytes in place.
+///
+/// The decoded bytes overwrite the start of `buf`, which is sound as the
+/// decoded output is never longer than the input. Encoding cannot be done
+/// in place, as the encoded output is longer than the input.
+///
+/// # Returns
+///
+/// The number of decoded bytes at the start of `buf`.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Notes
+///
+/// - The contents of `buf` are unspecified if an error is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let mut buf = *b"2MAHA";
+/// let offset = c32::decode_in_place(&mut buf)?;
+/// assert_eq!(&buf[..offset], [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+pub fn decode_in_place(buf: &mut [u8]) -> Result<usize> {
+    const MASK_8: u16 = 0xFF;
+    const SHIFT_8: u16 = 8;
+
+    let len = buf.len();
+    let mut carry = 0;
+    let mut carry_bits = 0;
+
+    // count leading zeros, before they are overwritten
+    let leading_zeros = leading_zero_chars(buf);
+
+    // Process characters in reverse, writing bytes backwards from the end.
+    // The write position never passes the read position, as every character
+    // produces fewer bits than it occupies.
+    let mut input_pos = len;
+    let mut dst_pos = len;
+    while input_pos > 0 {
+        input_pos -= 1;
+
+        // convert the byte to a map index
+        let byte = buf[input_pos];
+        let index = alphabet::CROCKFORD.value(byte);
+        if index == INVALID {
+            // The characters up to `input_pos` have not been overwritten.
+            let decoded = __internal::decoded_before::<{ __internal::STRICT }>(
+                &alphabet::CROCKFORD,
+                buf,
+                0,
+                len,
+                input_pos,
+            );
+            __internal::clear(&mut buf[dst_pos..]);
+            return Err(Error::InvalidCharacter {
+                char: byte as char,
+                index: input_pos,
+                decoded,
+            });
+        }
+
+        // accumulate bits into carry
+        carry |= u16::from(index) << carry_bits;
+        carry_bits += 5;
+
+        // extract 8-bit chunks
+        while carry_bits >= SHIFT_8 {
+            // write byte from chunk
+            dst_pos -= 1;
+            buf[dst_pos] = (carry & MASK_8) as u8;
+
+            // shift out processed bits
+            carry >>= SHIFT_8;
+            carry_bits -= SHIFT_8;
+        }
+    }
+
+    // process remaining bits
+    if carry_bits > 0 {
+        dst_pos -= 1;
+        buf[dst_pos] = carry as u8;
+    }
+
+    // truncate leading zeros of the value
+    while dst_pos < len && buf[dst_pos] == 0 {
+        dst_pos += 1;
+    }
+
+    // Move the value to the start, after the restored leading zeros.
+    buf.copy_within(dst_pos..len, leading_zeros);
+    buf[..leading_zeros].fill(0);
+
+    Ok(leading_zeros + len - dst_pos)
+}
+
+/// Decodes Base32-encoded bytes into a provided buffer, using a custom
+/// [`Alphabet`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::alphabet;
+///
+/// # let mut dst = [0u8; 8];
+/// let offset = c32::decode_into_with_alphabet(
+///     &alphabet::ZBASE32,
+///     b"pb1sa5dx",
+///     &mut dst,
+/// )?;
+/// assert_eq!(&dst[..offset], b"hello");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Alphabet`]: alphabet::Alphabet
+#[inline]
+pub const fn decode_into_with_alphabet(
+    alphabet: &alphabet::Alphabet,
+    src: &[u8],
+    dst: &mut [u8],
+) -> Result<usize> {
+    // Assert that the buffer has enough capacity.
+    let capacity = decoded_len(src.len());
+    if dst.len() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.len(),
+        });
+    }
+
+    // Decode the input bytes, and return the amount of bytes written.
+    __internal::de_with::<{ __internal::STRICT }>(
+        alphabet,
+        src,
+        0,
+        src.len(),
+        dst,
+        0,
+    )
+}
+
+/// Encodes bytes as Crockford Base32Check into a provided buffer.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 13];
+/// let offset = c32::encode_check_into(&[42, 42, 42], &mut dst, 0)?;
+/// assert_eq!(&dst[..offset], b"0AHA59B9201Z");
+/// assert_eq!(offset, 12);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "check")]
+pub fn encode_check_into(
+    src: &[u8],
+    dst: &mut [u8],
+    version: u8,
+) -> Result<usize> {
+    encode_check_into_with(&alphabet::CROCKFORD, src, dst, version)
+}
+
+/// Encodes bytes as Crockford Base32Check into a provided buffer, with a
+/// [`Version`] that is known to be valid.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::Version;
+///
+/// # let mut dst = [0u8; 13];
+/// let version = Version::try_from(0)?;
+/// let offset =
+///     c32::encode_check_into_versioned(&[42, 42, 42], &mut dst, version)?;
+/// assert_eq!(&dst[..offset], b"0AHA59B9201Z");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "check")]
+pub fn encode_check_into_versioned(
+    src: &[u8],
+    dst: &mut [u8],
+    version: Version,
+) -> Result<usize> {
+    encode_check_into(src, dst, version.get())
+}
+
+/// Encodes bytes as Base32Check into a provided buffer, using a custom
+/// [`Alphabet`].
+///
+/// The checksum is computed over the raw bytes and is independent of the
+/// alphabet; only the version symbol and the payload encoding differ.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::alphabet::Alphabet;
+///
+/// const LOWER: Alphabet = Alphabet::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+///
+/// # let mut dst = [0u8; 13];
+/// let offset =
+///     c32::encode_check_into_with(&LOWER, &[42, 42, 42], &mut dst, 0)?;
+/// assert_eq!(&dst[..offset], b"0aha59b9201z");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Alphabet`]: alphabet::Alphabet
+#[inline]
+#[cfg(feature = "check")]
+pub fn encode_check_into_with(
+    alphabet: &alphabet::Alphabet,
+    src: &[u8],
+    dst: &mut [u8],
+    version: u8,
+) -> Result<usize> {
+    __internal::en_check::<checksum::Sha2Backend, false>(
+        alphabet, src, dst, version,
+    )
+}
+
+/// Decodes Crockford Base32Check-encoded bytes into a provided buffer.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer and the version.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Notes
+///
+/// - The payload is hashed as it is decoded, and the checksum is decoded apart
+///   from it, so the output buffer is left untouched past the returned offset.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 12];
+/// let (offset, version) = c32::decode_check_into(b"0AHA59B9201Z", &mut dst)?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "check")]
+#[allow(clippy::missing_panics_doc)]
+pub fn decode_check_into(src: &[u8], dst: &mut [u8]) -> Result<(usize, u8)> {
+    decode_check_into_with(&alphabet::CROCKFORD, src, dst)
+}
+
+/// Decodes Crockford Base32Check-encoded bytes into a provided buffer, with
+/// the given handling of [`Whitespace`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer and the version.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Notes
+///
+/// - With [`Whitespace::Ignore`], whitespace is also skipped before the version
+///   character, the lengths only count the characters which are not whitespace,
+///   and the `index` of an [`Error::InvalidCharacter`] refers to the original
+///   input.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::Whitespace;
+///
+/// # let mut dst = [0u8; 12];
+/// let (offset, version) = c32::decode_check_into_with_whitespace(
+///     b" 0AHA59\r\nB9201Z",
+///     &mut dst,
+///     Whitespace::Ignore,
+/// )?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "check")]
+pub fn decode_check_into_with_whitespace(
+    src: &[u8],
+    dst: &mut [u8],
+    whitespace: Whitespace,
+) -> Result<(usize, u8)> {
+    match whitespace {
+        Whitespace::Reject => decode_check_into(src, dst),
+        Whitespace::Ignore => __internal::de_check::<
+            __internal::Incremental,
+            false,
+            { __internal::WHITESPACE },
+        >(&alphabet::CROCKFORD, src, dst),
+    }
+}
+
+/// Decodes Crockford Base32Check-encoded bytes into a provided buffer.
+///
+/// # Returns
+///
+/// The version and the decoded payload, as a slice of the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 12];
+/// let (version, bytes) =
+///     c32::decode_check_into_slice(b"0AHA59B9201Z", &mut dst)?;
+/// assert_eq!(bytes, [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "check")]
+pub fn decode_check_into_slice<'a>(
+    src: &[u8],
+    dst: &'a mut [u8],
+) -> Result<(u8, &'a [u8])> {
+    let (offset, version) = decode_check_into(src, dst)?;
+    Ok((version, &dst[..offset]))
+}
+
+/// Decodes Base32Check-encoded bytes into a provided buffer, using a custom
+/// [`Alphabet`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer and the version.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use c32::alphabet::Alphabet;
+///
+/// const LOWER: Alphabet = Alphabet::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+///
+/// # let mut dst = [0u8; 12];
+/// let (offset, version) =
+///     c32::decode_check_into_with(&LOWER, b"0aha59b9201z", &mut dst)?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Alphabet`]: alphabet::Alphabet
+#[inline]
+#[cfg(feature = "check")]
+#[allow(clippy::missing_panics_doc)]
+pub fn decode_check_into_with(
+    alphabet: &alphabet::Alphabet,
+    src: &[u8],
+    dst: &mut [u8],
+) -> Result<(usize, u8)> {
+    __internal::de_check::<__internal::Incremental, false, { __internal::STRICT }>(
+        alphabet, src, dst,
+    )
+}
+
+/// Encodes bytes as Crockford Base32Check into a provided buffer, using the
+/// given [`Sha256Backend`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # #[cfg(feature = "check")] {
+/// use c32::checksum::Sha2Backend;
+///
+/// # let mut dst = [0u8; 13];
+/// let offset = c32::encode_check_into_with_backend::<Sha2Backend>(
+///     &[42, 42, 42],
+///     &mut dst,
+///     0,
+/// )?;
+/// assert_eq!(&dst[..offset], b"0AHA59B9201Z");
+/// # }
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Sha256Backend`]: checksum::Sha256Backend
+#[inline]
+#[cfg(feature = "check-core")]
+pub fn encode_check_into_with_backend<H: checksum::Sha256Backend>(
+    src: &[u8],
+    dst: &mut [u8],
+    version: u8,
+) -> Result<usize> {
+    __internal::en_check::<H, false>(&alphabet::CROCKFORD, src, dst, version)
+}
+
+/// Decodes Crockford Base32Check-encoded bytes into a provided buffer, using
+/// the given [`Sha256Backend`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer and the version.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # #[cfg(feature = "check")] {
+/// use c32::checksum::Sha2Backend;
+///
+/// # let mut dst = [0u8; 12];
+/// let (offset, version) = c32::decode_check_into_with_backend::<Sha2Backend>(
+///     b"0AHA59B9201Z",
+///     &mut dst,
+/// )?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # }
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Sha256Backend`]: checksum::Sha256Backend
+#[inline]
+#[cfg(feature = "check-core")]
+pub fn decode_check_into_with_backend<H: checksum::Sha256Backend>(
+    src: &[u8],
+    dst: &mut [u8],
+) -> Result<(usize, u8)> {
+    __internal::de_check::<__internal::Deferred<H>, false, { __internal::STRICT }>(
+        &alphabet::CROCKFORD,
+        src,
+        dst,
+    )
+}
+
+/// Encodes bytes as prefixed Crockford Base32Check into a provided buffer.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the prefix is not an ASCII character.
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 14];
+/// let offset =
+///     c32::encode_check_prefixed_into(&[42, 42, 42], &mut dst, 'S', 0)?;
+/// assert_eq!(&dst[..offset], b"S0AHA59B9201Z");
+/// assert_eq!(offset, 13);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "check")]
+pub fn encode_check_prefixed_into(
+    src: &[u8],
+    dst: &mut [u8],
+    prefix: char,
+    version: u8,
+) -> Result<usize> {
+    // Assert that the prefix is ASCII.
+    if !prefix.is_ascii() {
+        return Err(Error::InvalidCharacter {
+            char: prefix,
+            index: 0,
+            decoded: 0,
+        });
+    }
+
+    // Assert that the buffer has enough capacity.
+    let capacity = 1 + encoded_check_len(src.len());
+    if dst.len() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.len(),
+        });
+    }
+
+    // Insert the prefix character into the output buffer.
+    dst[0] = prefix as u8;
+
+    // Encode the bytes, version and checksum after the prefix.
+    let written = encode_check_into(src, &mut dst[1..], version)?;
+    Ok(1 + written)
+}
+
+/// Decodes prefixed Crockford Base32Check-encoded bytes into a provided
+/// buffer.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer and the version.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the prefix is not an ASCII character.
+/// - [`Error::MissingPrefix`], the input does not start with the prefix.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 12];
+/// let (offset, version) =
+///     c32::decode_check_prefixed_into(b"S0AHA59B9201Z", &mut dst, 'S')?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "check")]
+pub fn decode_check_prefixed_into(
+    src: &[u8],
+    dst: &mut [u8],
+    prefix: char,
+) -> Result<(usize, u8)> {
+    // Assert that the prefix is ASCII.
+    if !prefix.is_ascii() {
+        return Err(Error::InvalidCharacter {
+            char: prefix,
+            index: 0,
+            decoded: 0,
+        });
+    }
+
+    // Assert that the input starts with the prefix.
+    if src.first() != Some(&(prefix as u8)) {
+        return Err(Error::MissingPrefix {
+            char: prefix,
+            got: src.first().map(|b| *b as char),
+        });
+    }
+
+    // Skip the prefix character and decode the rest.
+    match decode_check_into(&src[1..], dst) {
+        Ok(result) => Ok(result),
+        Err(Error::InvalidCharacter {
+            char,
+            index,
+            decoded,
+        }) => {
+            // This adjusts the index in an 'InvalidCharacter' to account for
+            // the prefix in the original input that we don't decode.
+            Err(Error::InvalidCharacter {
+                char,
+                index: index + 1,
+                decoded,
+            })
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Decodes the leading Crockford Base32-encoded bytes into a provided buffer.
+///
+/// Decoding stops at the first byte that is not part of the alphabet, which
+/// is treated as the end of the input rather than an error.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer and the number of input
+/// bytes consumed.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Notes
+///
+/// - The input following the consumed bytes is left untouched, so a field which
+///   ends at a delimiter can be decoded out of a larger stream, and the stream
+///   can be parsed further from `src[consumed..]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 5];
+/// let (offset, consumed) = c32::decode_partial_into(b"2MAHA?", &mut dst)?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// assert_eq!(consumed, 5);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_partial_into(
+    src: &[u8],
+    dst: &mut [u8],
+) -> Result<(usize, usize)> {
+    // Find the end of the valid input.
+    let consumed = __internal::span(src, 0, src.len());
+
+    // Assert that the buffer has enough capacity.
+    let capacity = decoded_len(consumed);
+    if dst.len() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.len(),
+        });
+    }
+
+    // Decode the valid input bytes into the buffer.
+    let offset =
+        __internal::de::<{ __internal::STRICT }>(src, 0, consumed, dst, 0)?;
+    Ok((offset, consumed))
+}
+
+/// Decodes a Crockford Base32-encoded field from the front of a byte stream.
+///
+/// Decoding stops at the first byte that is not part of the alphabet, such as
+/// a delimiter, which is treated as the end of the field rather than an error.
+///
+/// # Returns
+///
+/// The number of input bytes consumed and the number of bytes written to the
+/// output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Notes
+///
+/// - This is [`decode_partial_into`] with the counts ordered for framing, the
+///   rest of the stream can be parsed further from `src[consumed..]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 5];
+/// let src = b"2MAHA:rest";
+/// let (consumed, written) = c32::decode_prefix(src, &mut dst)?;
+/// assert_eq!(&dst[..written], [42, 42, 42]);
+/// assert_eq!(&src[consumed..], b":rest");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_prefix(src: &[u8], dst: &mut [u8]) -> Result<(usize, usize)> {
+    let (written, consumed) = decode_partial_into(src, dst)?;
+    Ok((consumed, written))
+}
+
+/// Decodes the leading Crockford Base32Check-encoded bytes into a provided
+/// buffer.
+///
+/// Decoding stops at the first byte that is not part of the alphabet, and the
+/// consumed bytes must form a valid check-encoded string.
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer, the version and the
+/// number of input bytes consumed.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InsufficientData`], the consumed input is too short.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 12];
+/// let (offset, version, consumed) =
+///     c32::decode_check_partial_into(b"0AHA59B9201Z?", &mut dst)?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// assert_eq!(consumed, 12);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "check")]
+pub fn decode_check_partial_into(
+    src: &[u8],
+    dst: &mut [u8],
+) -> Result<(usize, u8, usize)> {
+    // Find the end of the valid input.
+    let consumed = __internal::span(src, 0, src.len());
+
+    // Decode and verify the valid input bytes.
+    let (offset, version) = decode_check_into(&src[..consumed], dst)?;
+    Ok((offset, version, consumed))
+}
+
+/// Decodes Crockford Base32-encoded bytes into multiple byte fields.
+///
+/// The fields are written back-to-back into `dst`, and their lengths are
+/// written into `lens`, so the caller can split `dst` accordingly.
+///
+/// # Returns
+///
+/// The number of fields.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::BufferTooSmall`], `lens` cannot hold every field length.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InvalidFrame`], the decoded framing is malformed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 16];
+/// # let mut lens = [0usize; 4];
+/// let count = c32::decode_framed_into(b"258N00081", &mut dst, &mut lens)?;
+/// assert_eq!(count, 3);
+/// assert_eq!(lens[..count], [2, 0, 1]);
+/// assert_eq!(dst[..3], [42, 42, 1]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_framed_into(
+    src: &[u8],
+    dst: &mut [u8],
+    lens: &mut [usize],
+) -> Result<usize> {
+    let len = decode_into(src, dst)?;
+
+    // Validate the framing, and count the fields.
+    let mut count = 0;
+    let mut pos = 0;
+    while pos < len {
+        let (_, end) = __internal::frame(&dst[..len], pos, count)?;
+        count += 1;
+        pos = end;
+    }
+
+    // Assert that every field length can be stored.
+    if lens.len() < count {
+        return Err(Error::BufferTooSmall {
+            min: count,
+            len: lens.len(),
+        });
+    }
+
+    // Strip the length prefixes, moving the fields to the front.
+    let mut offset = 0;
+    let mut pos = 0;
+    let mut field = 0;
+    while pos < len {
+        let (start, end) = __internal::frame(&dst[..len], pos, field)?;
+        dst.copy_within(start..end, offset);
+        lens[field] = end - start;
+        offset += end - start;
+        field += 1;
+        pos = end;
+    }
+
+    Ok(count)
+}
+/// Encodes bytes into padded Crockford Base32 blocks into a provided buffer,
+/// see [`encode_blocks`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 8];
+/// let offset = c32::encode_blocks_into(&[42, 42, 42], &mut dst)?;
+/// assert_eq!(&dst[..offset], b"58N2M===");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn encode_blocks_into(src: &[u8], dst: &mut [u8]) -> Result<usize> {
+    // Assert that the buffer has enough capacity.
+    let capacity = encoded_blocks_len(src.len());
+    if dst.len() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.len(),
+        });
+    }
+
+    for (block, out) in src.chunks(5).zip(dst.chunks_exact_mut(8)) {
+        // Collect the block into a 40-bit big-endian value.
+        let mut value = 0u64;
+        for (i, &byte) in block.iter().enumerate() {
+            value |= u64::from(byte) << (32 - 8 * i);
+        }
+
+        // Write the significant characters, followed by the padding.
+        let chars = __internal::BLOCK_CHARS[block.len()];
+        for (i, char) in out.iter_mut().enumerate() {
+            *char = if i < chars {
+                ALPHABET[((value >> (35 - 5 * i)) & 0x1F) as usize]
+            } else {
+                b'='
+            };
+        }
+    }
+
+    Ok(capacity)
+}
+
+/// Decodes padded Crockford Base32 blocks into a provided buffer, see
+/// [`encode_blocks`].
+///
+/// # Returns
+///
+/// The number of bytes written to the output buffer.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InvalidPadding`], the padding is malformed:
+///   - the input length is not a multiple of 8,
+///   - a character other than `=` follows the first `=`,
+///   - the final block has other than 0, 1, 3, 4 or 6 `=`,
+///   - the unused bits of the last significant character are not zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// # let mut dst = [0u8; 5];
+/// let offset = c32::decode_blocks_into(b"58N2M===", &mut dst)?;
+/// assert_eq!(&dst[..offset], [42, 42, 42]);
+///
+/// let result = c32::decode_blocks_into(b"58N2M=A=", &mut dst);
+/// assert_eq!(result, Err(Error::InvalidPadding { index: 6 }));
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_blocks_into(src: &[u8], dst: &mut [u8]) -> Result<usize> {
+    // Assert that the input consists of complete blocks.
+    if src.len() % 8 != 0 {
+        return Err(Error::InvalidPadding { index: src.len() });
+    }
+
+    // Assert that nothing but padding follows the first `=`.
+    let end = src.iter().position(|&b| b == b'=').unwrap_or(src.len());
+    if let Some(index) = src[end..].iter().position(|&b| b != b'=') {
+        return Err(Error::InvalidPadding { index: end + index });
+    }
+
+    // Assert that the padding maps to a whole number of bytes.
+    let Some(tail) = __internal::BLOCK_CHARS.iter().position(|&chars| {
+        chars == (end % 8) && (chars > 0 || end == src.len())
+    }) else {
+        return Err(Error::InvalidPadding { index: end });
+    };
+
+    // Assert that the buffer has enough capacity.
+    let capacity = end / 8 * 5 + tail;
+    if dst.len() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.len(),
+        });
+    }
+
+    for (n, block) in src[..end].chunks(8).enumerate() {
+        // Collect the significant characters into a 40-bit value.
+        let mut value = 0u64;
+        for (i, &byte) in block.iter().enumerate() {
+            let digit = alphabet::CROCKFORD.value(byte);
+            if digit == INVALID {
+                __internal::clear(&mut dst[..n * 5]);
+                return Err(Error::InvalidCharacter {
+                    char: byte as char,
+                    index: n * 8 + i,
+                    decoded: n * 5 + i * 5 / 8,
+                });
+            }
+            value |= u64::from(digit) << (35 - 5 * i);
+        }
+
+        // Assert that the unused bits of the last character are zero.
+        let len = if block.len() == 8 { 5 } else { tail };
+        if value & ((1 << (40 - 8 * len)) - 1) != 0 {
+            __internal::clear(&mut dst[..n * 5]);
+            return Err(Error::InvalidPadding {
+                index: n * 8 + block.len() - 1,
+            });
+        }
+
+        // The 40-bit value occupies the low 5 of its 8 big-endian bytes.
+        let bytes = value.to_be_bytes();
+        dst[n * 5..n * 5 + len].copy_from_slice(&bytes[3..3 + len]);
+    }
+
+    Ok(capacity)
+}
+
+/// A trait for byte arrays with a stack-allocated encoding.
+///
+/// The capacity [`encoded_len`]`(N)` can not be named in a generic signature
+/// on stable Rust, so this is implemented for the common sizes `16`, `20`,
+/// `32` and `64`, see [`encode_array`] and [`decode_array`].
+///
+/// # Examples
+///
+/// ```rust
+/// use c32::C32Array;
+///
+/// let bytes = [42u8; 20];
+/// let (en, len) = bytes.encode_array();
+/// assert_eq!(en.len(), c32::encoded_len(20));
+/// assert_eq!(<[u8; 20]>::decode_array(&en[..len]), Ok(bytes));
+/// ```
+pub trait C32Array: Sized {
+    /// The array holding the encoded characters.
+    type Encoded: AsRef<[u8]> + Copy;
+
+    /// Encodes the array, returning the characters and their length.
+    fn encode_array(&self) -> (Self::Encoded, usize);
+
+    /// Decodes Crockford Base32-encoded bytes into an array.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::InvalidDataSize`], the input does not decode to exactly `N`
+    ///   bytes.
+    /// - [`Error::InvalidCharacter`], the input contains invalid characters.
+    fn decode_array(src: &[u8]) -> Result<Self>;
+}
+
+macro_rules! impl_c32_array {
+    ($($n:literal),*) => {$(
+        impl C32Array for [u8; $n] {
+            type Encoded = [u8; encoded_len($n)];
+
+            #[inline]
+            fn encode_array(&self) -> (Self::Encoded, usize) {
+                let mut dst = [0u8; encoded_len($n)];
+                let pos = __internal::en(self, 0, $n, &mut dst, 0, None);
+                (dst, pos)
+            }
+
+            #[inline]
+            fn decode_array(src: &[u8]) -> Result<Self> {
+                // Assert that the input decodes to exactly `N` bytes, which
+                // also bounds its length by the scratch capacity.
+                let got = __internal::decoded_len_exact(src);
+                if got != $n {
+                    return Err(Error::InvalidDataSize { expected: $n, got });
+                }
+
+                let mut scratch = [0u8; encoded_len($n)];
+                let pos = decode_into(src, &mut scratch)?;
+
+                let mut dst = [0u8; $n];
+                dst.copy_from_slice(&scratch[..pos]);
+                __internal::wipe(&mut scratch);
+                Ok(dst)
+            }
+        }
+    )*};
+}
+
+impl_c32_array!(16, 20, 32, 64);
+
+/// Encodes a byte array into a stack-allocated array of characters.
+///
+/// # Returns
+///
+/// The encoded characters, and their length.
+///
+/// # Examples
+///
+/// ```rust
+/// let (en, len) = c32::encode_array(&[0xAB; 32]);
+/// assert_eq!(en.len(), c32::encoded_len(32));
+/// assert_eq!(c32::decode_array::<[u8; 32]>(&en[..len]), Ok([0xAB; 32]));
+/// ```
+#[inline]
+#[must_use]
+pub fn encode_array<A: C32Array>(src: &A) -> (A::Encoded, usize) {
+    src.encode_array()
+}
+
+/// Decodes Crockford Base32-encoded bytes into a byte array.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidDataSize`], the input does not decode to exactly `N`
+///   bytes.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de: [u8; 16] = c32::decode_array(b"0000000000000000")?;
+/// assert_eq!(de, [0; 16]);
+///
+/// let result = c32::decode_array::<[u8; 16]>(b"2MAHA");
+/// assert_eq!(
+///     result,
+///     Err(Error::InvalidDataSize {
+///         expected: 16,
+///         got: 3
+///     })
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+pub fn decode_array<A: C32Array>(src: &[u8]) -> Result<A> {
+    A::decode_array(src)
+}
+
+/// Encodes bytes into a Crockford Base32-encoded [`ArrayString`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_arrayvec::<5>(&[42, 42, 42])?;
+/// assert_eq!(en.as_str(), "2MAHA");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// A 20-byte hash fits in [`encoded_len`] characters:
+///
+/// ```rust
+/// # use c32::Error;
+/// const CAP: usize = c32::encoded_len(20);
+///
+/// let en = c32::encode_arrayvec::<CAP>(&[0xAB; 20])?;
+/// let de = c32::decode_arrayvec::<CAP>(en.as_bytes())?;
+/// assert_eq!(de.as_slice(), [0xAB; 20]);
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`ArrayString`]: arrayvec::ArrayString
+#[inline]
+#[cfg(feature = "arrayvec")]
+#[allow(clippy::missing_panics_doc)]
+pub fn encode_arrayvec<const N: usize>(
+    src: &[u8],
+) -> Result<arrayvec::ArrayString<N>> {
+    let mut buffer = [0u8; N];
+    let offset = encode_into(src, &mut buffer)?;
+
+    // This should not panic, as we only write valid ASCII.
+    let mut dst = arrayvec::ArrayString::from_byte_string(&buffer).unwrap();
+    dst.truncate(offset);
+    Ok(dst)
+}
+
+/// Decodes Crockford Base32-encoded bytes into an [`ArrayVec`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_arrayvec::<5>(b"2MAHA")?;
+/// assert_eq!(de.as_slice(), [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`ArrayVec`]: arrayvec::ArrayVec
+#[inline]
+#[cfg(feature = "arrayvec")]
+pub fn decode_arrayvec<const N: usize>(
+    src: &[u8],
+) -> Result<arrayvec::ArrayVec<u8, N>> {
+    let mut buffer = [0u8; N];
+    let offset = decode_into(src, &mut buffer)?;
+    __internal::wipe(&mut buffer[offset..]);
+
+    let mut dst = arrayvec::ArrayVec::from(buffer);
+    __internal::wipe(&mut buffer);
+    dst.truncate(offset);
+    Ok(dst)
+}
+
+/// Encodes bytes into a Crockford Base32Check-encoded [`ArrayString`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let hash160 = [
+///     0xa4, 0x6f, 0xf8, 0x88, 0x86, 0xc2, 0xef, 0x97, 0x62, 0xd9, 0x70, 0xb4,
+///     0xd2, 0xc6, 0x36, 0x78, 0x83, 0x5b, 0xd3, 0x9d,
+/// ];
+///
+/// const CAP: usize = c32::encoded_check_len(20);
+/// let en = c32::encode_check_arrayvec::<CAP>(&hash160, 22)?;
+/// assert_eq!(en.as_str(), "P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`ArrayString`]: arrayvec::ArrayString
+#[inline]
+#[allow(clippy::missing_panics_doc)]
+#[cfg(all(feature = "arrayvec", feature = "check"))]
+pub fn encode_check_arrayvec<const N: usize>(
+    src: &[u8],
+    version: u8,
+) -> Result<arrayvec::ArrayString<N>> {
+    let mut buffer = [0u8; N];
+    let offset = encode_check_into(src, &mut buffer, version)?;
+
+    // This should not panic, as we only write valid ASCII.
+    let mut dst = arrayvec::ArrayString::from_byte_string(&buffer).unwrap();
+    dst.truncate(offset);
+    Ok(dst)
+}
+
+/// Decodes Crockford Base32Check-encoded bytes into an [`ArrayVec`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = b"P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7";
+///
+/// const CAP: usize = c32::decoded_check_len(40);
+/// let (hash160, version) = c32::decode_check_arrayvec::<CAP>(en)?;
+/// assert_eq!(hash160.len(), 20);
+/// assert_eq!(hash160[..4], [0xa4, 0x6f, 0xf8, 0x88]);
+/// assert_eq!(version, 22);
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`ArrayVec`]: arrayvec::ArrayVec
+#[inline]
+#[cfg(all(feature = "arrayvec", feature = "check"))]
+pub fn decode_check_arrayvec<const N: usize>(
+    src: &[u8],
+) -> Result<(arrayvec::ArrayVec<u8, N>, u8)> {
+    let mut buffer = [0u8; N];
+    let (offset, version) = decode_check_into(src, &mut buffer)?;
+    __internal::wipe(&mut buffer[offset..]);
+
+    let mut dst = arrayvec::ArrayVec::from(buffer);
+    __internal::wipe(&mut buffer);
+    dst.truncate(offset);
+    Ok((dst, version))
+}
+
+/// Encodes bytes into a Crockford Base32-encoded [`heapless::String`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_heapless::<5>(&[42, 42, 42])?;
+/// assert_eq!(en.as_str(), "2MAHA");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "heapless")]
+#[allow(clippy::missing_panics_doc)]
+pub fn encode_heapless<const N: usize>(
+    src: &[u8],
+) -> Result<heapless::String<N>> {
+    let mut buffer = [0u8; N];
+    let offset = encode_into(src, &mut buffer)?;
+
+    // This should not panic, as the offset never exceeds `N`.
+    let dst = heapless::Vec::from_slice(&buffer[..offset]).unwrap();
+
+    // This should not panic, as we only write valid ASCII.
+    Ok(heapless::String::from_utf8(dst).unwrap())
+}
+
+/// Decodes Crockford Base32-encoded bytes into a [`heapless::Vec`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_heapless::<5>(b"2MAHA")?;
+/// assert_eq!(de.as_slice(), [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[cfg(feature = "heapless")]
+#[allow(clippy::missing_panics_doc)]
+pub fn decode_heapless<const N: usize>(
+    src: &[u8],
+) -> Result<heapless::Vec<u8, N>> {
+    let mut buffer = [0u8; N];
+    let offset = decode_into(src, &mut buffer)?;
+
+    // This should not panic, as the offset never exceeds `N`.
+    let dst = heapless::Vec::from_slice(&buffer[..offset]).unwrap();
+    __internal::wipe(&mut buffer);
+    Ok(dst)
+}
+
+/// Encodes bytes into a Crockford Base32Check-encoded [`heapless::String`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_check_heapless::<13>(&[42, 42, 42], 0)?;
+/// assert_eq!(en.as_str(), "0AHA59B9201Z");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[allow(clippy::missing_panics_doc)]
+#[cfg(all(feature = "heapless", feature = "check"))]
+pub fn encode_check_heapless<const N: usize>(
+    src: &[u8],
+    version: u8,
+) -> Result<heapless::String<N>> {
+    let mut buffer = [0u8; N];
+    let offset = encode_check_into(src, &mut buffer, version)?;
+
+    // This should not panic, as the offset never exceeds `N`.
+    let dst = heapless::Vec::from_slice(&buffer[..offset]).unwrap();
+
+    // This should not panic, as we only write valid ASCII.
+    Ok(heapless::String::from_utf8(dst).unwrap())
+}
+
+/// Decodes Crockford Base32Check-encoded bytes into a [`heapless::Vec`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (de, version) = c32::decode_check_heapless::<12>(b"0AHA59B9201Z")?;
+/// assert_eq!(de.as_slice(), [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[allow(clippy::missing_panics_doc)]
+#[cfg(all(feature = "heapless", feature = "check"))]
+pub fn decode_check_heapless<const N: usize>(
+    src: &[u8],
+) -> Result<(heapless::Vec<u8, N>, u8)> {
+    let mut buffer = [0u8; N];
+    let (offset, version) = decode_check_into(src, &mut buffer)?;
+
+    // This should not panic, as the offset never exceeds `N`.
+    let dst = heapless::Vec::from_slice(&buffer[..offset]).unwrap();
+    __internal::wipe(&mut buffer);
+    Ok((dst, version))
+}
+
+/// Encodes bytes into a prefixed Crockford Base32Check-encoded
+/// [`heapless::String`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the prefix is not an ASCII character.
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let en = c32::encode_check_prefixed_heapless::<14>(&[42, 42, 42], 'S', 0)?;
+/// assert_eq!(en.as_str(), "S0AHA59B9201Z");
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[allow(clippy::missing_panics_doc)]
+#[cfg(all(feature = "heapless", feature = "check"))]
+pub fn encode_check_prefixed_heapless<const N: usize>(
+    src: &[u8],
+    prefix: char,
+    version: u8,
+) -> Result<heapless::String<N>> {
+    let mut buffer = [0u8; N];
+    let offset = encode_check_prefixed_into(src, &mut buffer, prefix, version)?;
+
+    // This should not panic, as the offset never exceeds `N`.
+    let dst = heapless::Vec::from_slice(&buffer[..offset]).unwrap();
+
+    // This should not panic, as we only write valid ASCII.
+    Ok(heapless::String::from_utf8(dst).unwrap())
+}
+
+/// Decodes prefixed Crockford Base32Check-encoded bytes into a
+/// [`heapless::Vec`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the prefix is not an ASCII character.
+/// - [`Error::MissingPrefix`], the input does not start with the prefix.
+/// - [`Error::BufferTooSmall`], the capacity `N` is insufficient.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InsufficientData`], the input has fewer bytes than required.
+/// - [`Error::InvalidVersion`], the version is 32 or greater.
+/// - [`Error::ChecksumMismatch`], the checksum's do not match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let (de, version) =
+///     c32::decode_check_prefixed_heapless::<12>(b"S0AHA59B9201Z", 'S')?;
+/// assert_eq!(de.as_slice(), [42, 42, 42]);
+/// assert_eq!(version, 0);
+/// # Ok::<(), Error>(())
+/// ```
+#[inline]
+#[allow(clippy::missing_panics_doc)]
+#[cfg(all(feature = "heapless", feature = "check"))]
+pub fn decode_check_prefixed_heapless<const N: usize>(
+    src: &[u8],
+    prefix: char,
+) -> Result<(heapless::Vec<u8, N>, u8)> {
+    let mut buffer = [0u8; N];
+    let (offset, version) =
+        decode_check_prefixed_into(src, &mut buffer, prefix)?;
+
+    // This should not panic, as the offset never exceeds `N`.
+    let dst = heapless::Vec::from_slice(&buffer[..offset]).unwrap();
+    __internal::wipe(&mut buffer);
+    Ok((dst, version))
+}
+
+/// Decodes a Crockford Base32-encoded string into a [`SmallVec`].
+///
+/// Results of up to `N` bytes are stored inline, without allocating.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let de = c32::decode_small::<32>("2MAHA")?;
+/// assert_eq!(de.as_slice(), [42, 42, 42]);
+/// assert!(!de.spilled());
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`SmallVec`]: smallvec::SmallVec
+#[inline]
+#[cfg(feature = "smallvec")]
+pub fn decode_small<const N: usize>(
+    str: &str,
+) -> Result<smallvec::SmallVec<[u8; N]>> {
+    let bytes = str.as_bytes();
+
+    // Allocate the output buffer, inline if possible.
+    let capacity = decoded_len(bytes.len());
+    let mut dst = smallvec::SmallVec::from_elem(0u8, capacity);
+
+    // Decode the input bytes into the buffer.
+    let offset = decode_into(bytes, &mut dst)?;
+    __internal::wipe(&mut dst[offset..]);
+    dst.truncate(offset);
+
+    Ok(dst)
+}
+
+/// Encodes the bytes of a [`Buf`] into a [`BufMut`].
+///
+/// The input may be split across any number of chunks, and is encoded as
+/// it is consumed, without first copying it into a contiguous slice.
+///
+/// # Returns
+///
+/// The number of characters written to `dst`.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], `dst` has less than [`encoded_len`] bytes of
+///   remaining capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use bytes::Buf;
+/// use bytes::BytesMut;
+///
+/// let src = (&[42u8][..]).chain(&[42u8, 42][..]);
+/// let mut dst = BytesMut::new();
+///
+/// let n = c32::encode_buf(src, &mut dst)?;
+/// assert_eq!(&dst[..n], b"2MAHA");
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Buf`]: bytes::Buf
+/// [`BufMut`]: bytes::BufMut
+#[inline]
+#[cfg(feature = "bytes")]
+pub fn encode_buf<B, M>(mut src: B, dst: &mut M) -> Result<usize>
+where
+    B: bytes::Buf,
+    M: bytes::BufMut,
+{
+    // Assert that the output has enough capacity.
+    let capacity = encoded_len(src.remaining());
+    if dst.remaining_mut() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.remaining_mut(),
+        });
+    }
+
+    let mut packer = __stream::Packer::new(src.remaining() as u64);
+    let mut buffer = [0u8; 256];
+    let mut pos = 0;
+    let mut written = 0;
+
+    while src.has_remaining() {
+        let chunk = src.chunk();
+        for &byte in chunk {
+            // Flush the buffer before it could overflow.
+            if pos + 3 > buffer.len() {
+                dst.put_slice(&buffer[..pos]);
+                written += pos;
+                pos = 0;
+            }
+
+            let mut chars = [0u8; 3];
+            let n = packer.push_byte(byte, &mut chars);
+            buffer[pos..pos + n].copy_from_slice(&chars[..n]);
+            pos += n;
+        }
+
+        let len = chunk.len();
+        src.advance(len);
+    }
+
+    dst.put_slice(&buffer[..pos]);
+    Ok(written + pos)
+}
+
+/// Decodes the Crockford Base32-encoded bytes of a [`Buf`] into a [`BufMut`].
+///
+/// The input may be split across any number of chunks, and is decoded as
+/// it is consumed, without first copying it into a contiguous slice.
+///
+/// # Returns
+///
+/// The number of bytes written to `dst`.
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::BufferTooSmall`], `dst` has less than [`decoded_len`] bytes of
+///   remaining capacity.
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+///
+/// # Notes
+///
+/// - An invalid character is reported as by [`decode_into`], and `dst` may
+///   already contain the bytes decoded before the first one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// use bytes::Buf;
+/// use bytes::BytesMut;
+///
+/// let src = (&b"2M"[..]).chain(&b"AHA"[..]);
+/// let mut dst = BytesMut::new();
+///
+/// let n = c32::decode_buf(src, &mut dst)?;
+/// assert_eq!(&dst[..n], [42, 42, 42]);
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Buf`]: bytes::Buf
+/// [`BufMut`]: bytes::BufMut
+#[inline]
+#[cfg(feature = "bytes")]
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+pub fn decode_buf<B, M>(mut src: B, dst: &mut M) -> Result<usize>
+where
+    B: bytes::Buf,
+    M: bytes::BufMut,
+{
+    // Assert that the output has enough capacity.
+    let capacity = decoded_len(src.remaining());
+    if dst.remaining_mut() < capacity {
+        return Err(Error::BufferTooSmall {
+            min: capacity,
+            len: dst.remaining_mut(),
+        });
+    }
+
+    let mut unpacker = __stream::Unpacker::new(src.remaining() as u64);
+    let mut buffer = [0u8; 256];
+    let mut pos = 0;
+    let mut written = 0;
+
+    while src.has_remaining() {
+        let chunk = src.chunk();
+        for &byte in chunk {
+            // Flush the buffer before it could overflow.
+            if pos + 2 > buffer.len() {
+                dst.put_slice(&buffer[..pos]);
+                written += pos;
+                pos = 0;
+            }
+
+            let mut bytes = [0u8; 2];
+            let n = unpacker.push_char(byte, &mut bytes);
+            buffer[pos..pos + n].copy_from_slice(&bytes[..n]);
+            pos += n;
+        }
+
+        let len = chunk.len();
+        src.advance(len);
+    }
+
+    // Assert that the characters are valid.
+    dst.put_slice(&buffer[..pos]);
+    unpacker.check()?;
+    Ok(written + pos)
+}
+
+/// Encodes a [`Uuid`] into a Crockford Base32-encoded string.
+///
+/// # Notes
+///
+/// - Leading zero bytes are encoded as leading `'0'` characters, so the string
+///   always decodes back into all 16 bytes of the [`Uuid`].
+///
+/// # Examples
+///
+/// ```rust
+/// use uuid::Uuid;
+///
+/// let id = Uuid::from_u128(0x0000_2a2a_2a2a_2a2a_2a2a_2a2a_2a2a_2a2a);
+/// let en = c32::encode_uuid(&id);
+/// assert_eq!(en, "00N2MAHA58N2MAHA58N2MAHA");
+/// assert_eq!(c32::decode_uuid(&en)?, id);
+/// # Ok::<(), c32::Error>(())
+/// ```
+///
+/// [`Uuid`]: uuid::Uuid
+#[inline]
+#[must_use]
+#[cfg(feature = "uuid")]
+pub fn encode_uuid(uuid: &uuid::Uuid) -> String {
+    encode(uuid.as_bytes())
+}
+
+/// Decodes a Crockford Base32-encoded string into a [`Uuid`].
+///
+/// # Errors
+///
+/// This method will return an [`Error`] if:
+///
+/// - [`Error::InvalidCharacter`], the input contains invalid characters.
+/// - [`Error::InvalidDataSize`], the input does not decode into 16 bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use c32::Error;
+/// let id = c32::decode_uuid("0000000000000000")?;
+/// assert!(id.is_nil());
+///
+/// let err = c32::decode_uuid("2MAHA").unwrap_err();
+/// assert_eq!(
+///     err,
+///     Error::InvalidDataSize {
+///         expected: 16,
+///         got: 3
+///     }
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Uuid`]: uuid::Uuid
+#[inline]
+#[cfg(feature = "uuid")]
+pub fn decode_uuid(str: &str) -> Result<uuid::Uuid> {
+    let bytes = decode(str)?;
+
+    // Assert that the decoded bytes are exactly 16 bytes.
+    let got = bytes.len();
+    let bytes = <[u8; 16]>::try_from(bytes)
+        .map_err(|_| Error::InvalidDataSize { expected: 16, got })?;
+
+    Ok(uuid::Uuid::from_bytes(bytes))
+}
+
+/// This module provides adapters for streaming IO.
+///
+/// # Notes
+///
+/// - Crockford Base32 encodes its input as a single big-endian number, so the
+///   alignment of the output depends on the total length of the input. The
+///   adapters created with `with_len` know the length up front, and stream
+///   their output as soon as it is available. The adapters created with `new`
+///   accumulate their input, and emit the result on completion.
+#[cfg(feature = "std")]
+pub mod io {
+    use std::io;
+
+    #[cfg(feature = "tokio")]
+    pub use self::__tokio::*;
+    use super::*;
+
+    /// Converts an [`Error`] into an [`io::Error`] for the adapters.
+    #[inline]
+    pub(crate) fn invalid_data(err: Error) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+
+    /// The size of the chunks read from the inner reader.
+    const CHUNK_SIZE: usize = 1024;
+
+    /// A [`Write`] adapter that encodes written bytes into the inner writer.
+    ///
+    /// An adapter created with [`EncodeWriter::with_len`] writes the encoded
+    /// output as soon as it is available. An adapter created with
+    /// [`EncodeWriter::new`] accumulates the input, and writes the output on
+    /// [`EncodeWriter::finish`]. Either way, [`EncodeWriter::finish`] must be
+    /// called to complete the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Write;
+    ///
+    /// use c32::io::EncodeWriter;
+    ///
+    /// let mut writer = EncodeWriter::new(Vec::new());
+    /// writer.write_all(&[42, 42, 42])?;
+    /// assert_eq!(writer.finish()?, b"2MAHA");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`Write`]: io::Write
+    #[derive(Debug)]
+    pub struct EncodeWriter<W> {
+        /// The inner writer.
+        inner: W,
+        /// The encoding state.
+        state: __stream::Encoder,
+        /// The encoded output, which has not been written yet.
+        output: __stream::Drain,
+    }
+
+    impl<W: io::Write> EncodeWriter<W> {
+        /// Creates a new [`EncodeWriter`] wrapping `inner`.
+        ///
+        /// The input is accumulated until [`EncodeWriter::finish`], since
+        /// its length is not known.
+        #[inline]
+        #[must_use]
+        pub fn new(inner: W) -> Self {
+            Self {
+                inner,
+                state: __stream::Encoder::default(),
+                output: __stream::Drain::default(),
+            }
+        }
+
+        /// Creates a new [`EncodeWriter`] for `len` bytes, wrapping `inner`.
+        ///
+        /// The encoded output is written as it becomes available. Writing
+        /// more bytes than declared fails with [`Error::InvalidDataSize`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use std::io::Write;
+        ///
+        /// use c32::io::EncodeWriter;
+        ///
+        /// let mut writer = EncodeWriter::with_len(Vec::new(), 3);
+        /// writer.write_all(&[42, 42])?;
+        /// assert_eq!(writer.get_ref(), b"2MA");
+        /// writer.write_all(&[42])?;
+        /// assert_eq!(writer.finish()?, b"2MAHA");
+        /// # Ok::<(), std::io::Error>(())
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn with_len(inner: W, len: u64) -> Self {
+            Self {
+                inner,
+                state: __stream::Encoder::with_len(len),
+                output: __stream::Drain::default(),
+            }
+        }
+
+        /// Returns a reference to the inner writer.
+        #[inline]
+        #[must_use]
+        pub fn get_ref(&self) -> &W {
+            &self.inner
+        }
+
+        /// Completes the stream, writing the remaining output into the inner
+        /// writer, and returns the inner writer.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an [`io::Error`] if:
+        ///
+        /// - [`io::ErrorKind::InvalidData`], fewer bytes than declared were
+        ///   written, wrapping [`Error::InvalidDataSize`].
+        /// - Writing to the inner writer fails.
+        #[inline]
+        pub fn finish(mut self) -> io::Result<W> {
+            self.state.finish(&mut self.output).map_err(invalid_data)?;
+            self.write_output()?;
+            self.inner.flush()?;
+            Ok(self.inner)
+        }
+
+        /// Writes the pending output into the inner writer.
+        fn write_output(&mut self) -> io::Result<()> {
+            while !self.output.is_empty() {
+                match self.inner.write(self.output.remaining()) {
+                    Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                    Ok(n) => self.output.consume(n),
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl<W: io::Write> io::Write for EncodeWriter<W> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            // Write the output of previous calls first.
+            self.write_output()?;
+
+            self.state
+                .update(buf, &mut self.output)
+                .map_err(invalid_data)?;
+            self.write_output()?;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.write_output()?;
+            self.inner.flush()
+        }
+    }
+
+    /// A [`Read`] adapter that decodes bytes read from the inner reader.
+    ///
+    /// An adapter created with [`DecodeReader::with_len`] returns decoded
+    /// bytes as soon as they are available, and reads no further than the
+    /// declared length. An adapter created with [`DecodeReader::new`] reads
+    /// the inner reader to the end before any decoded bytes are returned.
+    ///
+    /// Decoding errors are returned as [`io::Error`]s of kind
+    /// [`io::ErrorKind::InvalidData`], wrapping an [`Error`] whose index is
+    /// relative to the start of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Read;
+    ///
+    /// use c32::io::DecodeReader;
+    ///
+    /// let mut reader = DecodeReader::new(&b"2MAHA"[..]);
+    /// let mut bytes = Vec::new();
+    /// reader.read_to_end(&mut bytes)?;
+    /// assert_eq!(bytes, [42, 42, 42]);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`Read`]: io::Read
+    #[derive(Debug)]
+    pub struct DecodeReader<R> {
+        /// The inner reader.
+        inner: R,
+        /// The decoding state.
+        state: __stream::Decoder,
+        /// The decoded output, which has not been read yet.
+        output: __stream::Drain,
+        /// Whether the input is complete.
+        done: bool,
+    }
+
+    impl<R: io::Read> DecodeReader<R> {
+        /// Creates a new [`DecodeReader`] wrapping `inner`.
+        ///
+        /// The inner reader is read to the end before any decoded bytes are
+        /// returned, since the length of the input is not known.
+        #[inline]
+        #[must_use]
+        pub fn new(inner: R) -> Self {
+            Self {
+                inner,
+                state: __stream::Decoder::default(),
+                output: __stream::Drain::default(),
+                done: false,
+            }
+        }
+
+        /// Creates a new [`DecodeReader`] for `len` characters, wrapping
+        /// `inner`.
+        ///
+        /// Decoded bytes are returned as they become available. The inner
+        /// reader ending early fails with [`Error::InvalidDataSize`]. An
+        /// invalid character is reported as by [`decode`], once `len`
+        /// characters are read.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use std::io::Read;
+        ///
+        /// use c32::io::DecodeReader;
+        ///
+        /// let mut reader = DecodeReader::with_len(&b"2MAHA;rest"[..], 5);
+        /// let mut bytes = Vec::new();
+        /// reader.read_to_end(&mut bytes)?;
+        /// assert_eq!(bytes, [42, 42, 42]);
+        /// assert_eq!(reader.into_inner(), b";rest");
+        /// # Ok::<(), std::io::Error>(())
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn with_len(inner: R, len: u64) -> Self {
+            Self {
+                inner,
+                state: __stream::Decoder::with_len(len),
+                output: __stream::Drain::default(),
+                done: false,
+            }
+        }
+
+        /// Returns a reference to the inner reader.
+        #[inline]
+        #[must_use]
+        pub fn get_ref(&self) -> &R {
+            &self.inner
+        }
+
+        /// Consumes the adapter, returning the inner reader.
+        #[inline]
+        #[must_use]
+        pub fn into_inner(self) -> R {
+            self.inner
+        }
+    }
+
+    impl<R: io::Read> io::Read for DecodeReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            loop {
+                // Copy the decoded bytes.
+                if !self.output.is_empty() || self.done {
+                    let n = buf.len().min(self.output.remaining().len());
+                    buf[..n].copy_from_slice(&self.output.remaining()[..n]);
+                    self.output.consume(n);
+                    return Ok(n);
+                }
+
+                // Return the error after the bytes decoded before it.
+                if let Some(err) = self.state.error() {
+                    return Err(invalid_data(err));
+                }
+
+                // Read the next chunk, up to the declared length.
+                let want = self.state.remaining().map_or(CHUNK_SIZE, |n| {
+                    usize::try_from(n).unwrap_or(usize::MAX).min(CHUNK_SIZE)
+                });
+                let mut chunk = [0u8; CHUNK_SIZE];
+                let n = match want {
+                    0 => 0,
+                    want => read_chunk(&mut self.inner, &mut chunk[..want])?,
+                };
+
+                if n == 0 {
+                    self.state
+                        .finish(&mut self.output)
+                        .map_err(invalid_data)?;
+                    self.done = true;
+                } else {
+                    self.state.update(&chunk[..n], &mut self.output);
+                }
+            }
+        }
+    }
+
+    /// Encodes bytes read from `reader` as Crockford Base32Check into
+    /// `writer`, using memory bounded by a fixed chunk size.
+    ///
+    /// The reader is read twice: once to compute the checksum and length,
+    /// and once to encode the payload. It is read from its current position
+    /// to the end, and must yield the same bytes after seeking back to that
+    /// position.
+    ///
+    /// # Returns
+    ///
+    /// The number of characters written to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`io::Error`] if:
+    ///
+    /// - [`io::ErrorKind::InvalidInput`], the version is 32 or greater.
+    /// - [`io::ErrorKind::InvalidData`], the reader changed between passes.
+    /// - Reading, seeking or writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    ///
+    /// let mut en = Vec::new();
+    /// let reader = Cursor::new(b"usque ad finem");
+    /// let len = c32::io::encode_check_from_reader(reader, 22, &mut en)?;
+    /// assert_eq!(en, b"P7AWVHENJJ0RB441K6JVK5DNJ7J3V5");
+    /// assert_eq!(len, 30);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "check")]
+    pub fn encode_check_from_reader<R, W>(
+        mut reader: R,
+        version: u8,
+        mut writer: W,
+    ) -> io::Result<u64>
+    where
+        R: io::Read + io::Seek,
+        W: io::Write,
+    {
+        use sha2::Sha256;
+
+        // Assert that the version is valid (< 32).
+        if version >= 32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                Error::InvalidVersion {
+                    expected: "must be < 32",
+                    version,
+                },
+            ));
+        }
+
+        let start = reader.stream_position()?;
+        let mut chunk = [0u8; CHUNK_SIZE];
+
+        // Compute the checksum and length in a first pass.
+        let mut len = 0u64;
+        let mut hasher = Sha256::new().update(&[version]);
+        loop {
+            let n = read_chunk(&mut reader, &mut chunk)?;
+            if n == 0 {
+                break;
+            }
+            hasher = hasher.update(&chunk[..n]);
+            len += n as u64;
+        }
+        let hash = Sha256::new().update(&hasher.finalize()).finalize();
+        let sum = checksum::from_slice(&hash);
+
+        // Write the version character.
+        writer.write_all(&[ALPHABET[version as usize]])?;
+        let mut written = 1;
+
+        // Encode the payload in a second pass.
+        reader.seek(io::SeekFrom::Start(start))?;
+        let mut packer =
+            __stream::Packer::new(len + checksum::BYTE_LENGTH as u64);
+        let mut dst = Vec::with_capacity(encoded_len(CHUNK_SIZE));
+        while packer.remaining() > checksum::BYTE_LENGTH as u64 {
+            let n = read_chunk(&mut reader, &mut chunk)?;
+            let remaining = packer.remaining() - checksum::BYTE_LENGTH as u64;
+            let n = n.min(usize::try_from(remaining).unwrap_or(usize::MAX));
+
+            // Assert that the reader yields the same length.
+            if n == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "reader changed between passes",
+                ));
+            }
+
+            packer.push(&chunk[..n], &mut dst);
+            writer.write_all(&dst)?;
+            written += dst.len() as u64;
+            dst.clear();
+        }
+
+        // Encode the checksum.
+        packer.push(&sum, &mut dst);
+        writer.write_all(&dst)?;
+        written += dst.len() as u64;
+
+        Ok(written)
+    }
+
+    /// Decodes Crockford Base32Check read from `reader` into `writer`,
+    /// verifying the checksum.
+    ///
+    /// The reader is read to the end, and the input is buffered, as its length
+    /// determines the alignment of the decoded bytes. The decoded payload is
+    /// not buffered, see [`decode_check_from_reader_with_len`].
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the number of payload bytes written to `writer` and the
+    /// version byte.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`io::Error`] if:
+    ///
+    /// - [`io::ErrorKind::InvalidData`], wrapping an [`Error`] if:
+    ///   - [`Error::InvalidCharacter`], the input contains invalid characters.
+    ///     The index is the number of bytes consumed before the character.
+    ///   - [`Error::InsufficientData`], the input has fewer bytes than
+    ///     required.
+    ///   - [`Error::ChecksumMismatch`], the checksum does not match. The whole
+    ///     input was consumed and the payload was written.
+    /// - Reading or writing fails.
+    ///
+    /// # Notes
+    ///
+    /// - The payload is written before the checksum is verified, so it must be
+    ///   discarded by the caller if an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut de = Vec::new();
+    /// let reader = &b"P7AWVHENJJ0RB441K6JVK5DNJ7J3V5"[..];
+    /// let (len, version) = c32::io::decode_check_from_reader(reader, &mut de)?;
+    /// assert_eq!(de, b"usque ad finem");
+    /// assert_eq!(len, 14);
+    /// assert_eq!(version, 22);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "check")]
+    pub fn decode_check_from_reader<R, W>(
+        mut reader: R,
+        writer: W,
+    ) -> io::Result<(u64, u8)>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        // Buffer the input to determine its length.
+        let mut src = Vec::new();
+        reader.read_to_end(&mut src)?;
+
+        let len = src.len() as u64;
+        let result = decode_check_from_reader_with_len(&src[..], len, writer);
+        __internal::wipe(&mut src);
+        result
+    }
+
+    /// Decodes `len` bytes of Crockford Base32Check read from `reader` into
+    /// `writer`, verifying the checksum using memory bounded by a fixed chunk
+    /// size.
+    ///
+    /// The reader is read once, and no further than `len` bytes. The payload
+    /// is written to `writer` as it is decoded, while the last 4 decoded bytes
+    /// are held back as the candidate checksum.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the number of payload bytes written to `writer` and the
+    /// version byte.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`io::Error`] if:
+    ///
+    /// - [`io::ErrorKind::InvalidData`], wrapping an [`Error`] if:
+    ///   - [`Error::InvalidCharacter`], the input contains invalid characters.
+    ///     The index is the number of bytes consumed before the character.
+    ///   - [`Error::InsufficientData`], the input has fewer bytes than
+    ///     required.
+    ///   - [`Error::ChecksumMismatch`], the checksum does not match. The whole
+    ///     input was consumed and the payload was written.
+    /// - [`io::ErrorKind::UnexpectedEof`], the reader ended before `len` bytes.
+    /// - Reading or writing fails.
+    ///
+    /// # Notes
+    ///
+    /// - The payload is written before the checksum is verified, so it must be
+    ///   discarded by the caller if an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    ///
+    /// let mut de = Vec::new();
+    /// let reader = Cursor::new(b"P7AWVHENJJ0RB441K6JVK5DNJ7J3V5 rest");
+    /// let (len, version) =
+    ///     c32::io::decode_check_from_reader_with_len(reader, 30, &mut de)?;
+    /// assert_eq!(de, b"usque ad finem");
+    /// assert_eq!(len, 14);
+    /// assert_eq!(version, 22);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "check")]
+    pub fn decode_check_from_reader_with_len<R, W>(
+        mut reader: R,
+        len: u64,
+        mut writer: W,
+    ) -> io::Result<(u64, u8)>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        use sha2::Sha256;
+
+        // Assert that the input contains the minimum amount.
+        if len < 2 {
+            return Err(invalid_data(Error::InsufficientData {
+                min: 2,
+                len: __stream::to_usize(len)?,
+            }));
+        }
+
+        // The first character is the version.
+        let mut byte = [0u8; 1];
+        if read_chunk(&mut reader, &mut byte)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let version = alphabet::CROCKFORD.value(byte[0]);
+        if version == INVALID {
+            return Err(invalid_data(Error::InvalidCharacter {
+                char: byte[0] as char,
+                index: 0,
+                decoded: 0,
+            }));
+        }
+
+        let mut chunk = [0u8; CHUNK_SIZE];
+        let mut consumed = 1u64;
+
+        // The version is followed by the payload and the checksum.
+        let mut unpacker = __stream::Unpacker::new(len - 1);
+        let mut hasher = Sha256::new().update(&[version]);
+
+        // The decoded bytes, of which the last 4 are the candidate checksum.
+        let mut pending =
+            Vec::with_capacity(CHUNK_SIZE + checksum::BYTE_LENGTH);
+        let mut written = 0u64;
+
+        while consumed < len {
+            let max = CHUNK_SIZE
+                .min(usize::try_from(len - consumed).unwrap_or(usize::MAX));
+            let n = read_chunk(&mut reader, &mut chunk[..max])?;
+
+            // Assert that the reader yields the full length.
+            if n == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+
+            for &byte in &chunk[..n] {
+                unpacker.push(byte, &mut pending);
+            }
+            consumed += n as u64;
+
+            // Assert that the characters are valid, once all are known. The
+            // index is relative to the version character.
+            if consumed == len {
+                if let Err(Error::InvalidCharacter {
+                    char,
+                    index,
+                    decoded,
+                }) = unpacker.check()
+                {
+                    return Err(invalid_data(Error::InvalidCharacter {
+                        char,
+                        index: index + 1,
+                        decoded,
+                    }));
+                }
+            }
+
+            // Write everything except the candidate checksum.
+            if pending.len() > checksum::BYTE_LENGTH {
+                let pos = pending.len() - checksum::BYTE_LENGTH;
+                hasher = hasher.update(&pending[..pos]);
+                writer.write_all(&pending[..pos])?;
+                written += pos as u64;
+                pending.drain(..pos);
+            }
+        }
+
+        // Extract the checksum.
+        let Some(sum) = checksum::try_from_slice(&pending) else {
+            return Err(invalid_data(Error::InsufficientData {
+                min: checksum::BYTE_LENGTH,
+                len: pending.len(),
+            }));
+        };
+
+        // Assert that the computed and actual checksums match.
+        let hash = Sha256::new().update(&hasher.finalize()).finalize();
+        let expected = checksum::from_slice(&hash);
+        if expected != sum {
+            return Err(invalid_data(Error::ChecksumMismatch {
+                expected,
+                got: sum,
+                version,
+                len: __stream::to_usize(written)?,
+            }));
+        }
+
+        Ok((written, version))
+    }
+
+    /// Reads into `buf`, retrying on [`io::ErrorKind::Interrupted`].
+    pub(crate) fn read_chunk<R: io::Read>(
+        reader: &mut R,
+        buf: &mut [u8],
+    ) -> io::Result<usize> {
+        loop {
+            match reader.read(buf) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                result => return result,
+            }
+        }
+    }
+
+    /// Validates newline-separated encodings read from `reader`, one line at
+    /// a time.
+    ///
+    /// Each line is checked with [`validate`], after stripping its `\n` or
+    /// `\r\n` terminator, and yielded with its 1-based line number.
+    ///
+    /// # Errors
+    ///
+    /// Each item is an [`io::Error`] if:
+    ///
+    /// - [`io::ErrorKind::InvalidData`], the line is not valid, wrapping the
+    ///   [`Error`] returned by [`validate`].
+    /// - Reading from `reader` fails, after which the iterator ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = "2MAHA\n2M!HA\r\n\n0AHA\n";
+    /// let results: Vec<_> = c32::io::validate_lines(input.as_bytes())
+    ///     .map(|(line, result)| (line, result.is_ok()))
+    ///     .collect();
+    ///
+    /// assert_eq!(results, [(1, true), (2, false), (3, true), (4, true)]);
+    /// ```
+    ///
+    /// [`validate`]: crate::validate
+    #[inline]
+    #[must_use]
+    pub fn validate_lines<R: io::BufRead>(reader: R) -> ValidateLines<R> {
+        ValidateLines {
+            lines: Lines::new(reader),
+        }
+    }
+
+    /// Decodes newline-separated encodings read from `reader`, one line at
+    /// a time.
+    ///
+    /// This is the decoding counterpart of [`validate_lines`].
+    ///
+    /// # Errors
+    ///
+    /// Each item is an [`io::Error`] if:
+    ///
+    /// - [`io::ErrorKind::InvalidData`], the line is not valid, wrapping the
+    ///   [`Error`] returned by [`decode_into`].
+    /// - Reading from `reader` fails, after which the iterator ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = "2MAHA\n2M!HA\n";
+    /// let mut lines = c32::io::decode_lines(input.as_bytes());
+    ///
+    /// let (line, de) = lines.next().unwrap();
+    /// assert_eq!((line, de?), (1, vec![42, 42, 42]));
+    ///
+    /// let (line, de) = lines.next().unwrap();
+    /// assert_eq!(line, 2);
+    /// assert!(de.is_err());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`decode_into`]: crate::decode_into
+    #[inline]
+    #[must_use]
+    pub fn decode_lines<R: io::BufRead>(reader: R) -> DecodeLines<R> {
+        DecodeLines {
+            lines: Lines::new(reader),
+        }
+    }
+
+    /// An iterator over the validated lines of a reader.
+    ///
+    /// This struct is created by [`validate_lines`].
+    #[derive(Debug)]
+    pub struct ValidateLines<R> {
+        /// The lines being validated.
+        lines: Lines<R>,
+    }
+
+    impl<R: io::BufRead> Iterator for ValidateLines<R> {
+        type Item = (usize, io::Result<()>);
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let (number, line) = self.lines.next_line()?;
+            let result =
+                line.and_then(|line| validate(line).map_err(invalid_data));
+            Some((number, result))
+        }
+    }
+
+    impl<R: io::BufRead> core::iter::FusedIterator for ValidateLines<R> {}
+
+    /// An iterator over the decoded lines of a reader.
+    ///
+    /// This struct is created by [`decode_lines`].
+    #[derive(Debug)]
+    pub struct DecodeLines<R> {
+        /// The lines being decoded.
+        lines: Lines<R>,
+    }
+
+    impl<R: io::BufRead> Iterator for DecodeLines<R> {
+        type Item = (usize, io::Result<Vec<u8>>);
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let (number, line) = self.lines.next_line()?;
+            let result = line.and_then(|line| {
+                let mut dst = vec![0u8; decoded_len(line.len())];
+                let offset =
+                    decode_into(line, &mut dst).map_err(invalid_data)?;
+                __internal::wipe(&mut dst[offset..]);
+                dst.truncate(offset);
+                Ok(dst)
+            });
+            Some((number, result))
+        }
+    }
+
+    impl<R: io::BufRead> core::iter::FusedIterator for DecodeLines<R> {}
+
+    /// Reads the lines of a reader into a reused buffer.
+    #[derive(Debug)]
+    struct Lines<R> {
+        /// The inner reader.
+        inner: R,
+        /// The current line, without its terminator.
+        line: Vec<u8>,
+        /// The number of lines read so far.
+        number: usize,
+        /// Whether the end of the input, or an error, was reached.
+        done: bool,
+    }
+
+    impl<R: io::BufRead> Lines<R> {
+        /// Creates a new [`Lines`] wrapping `inner`.
+        fn new(inner: R) -> Self {
+            Self {
+                inner,
+                line: Vec::new(),
+                number: 0,
+                done: false,
+            }
+        }
+
+        /// Reads the next line, returning its 1-based number.
+        fn next_line(&mut self) -> Option<(usize, io::Result<&[u8]>)> {
+            if self.done {
+                return None;
+            }
+
+            self.line.clear();
+            self.number += 1;
+            match self.inner.read_until(b'\n', &mut self.line) {
+                Ok(0) => {
+                    self.done = true;
+                    None
+                }
+                Ok(_) => {
+                    // Strip the '\n' or '\r\n' terminator.
+                    if self.line.last() == Some(&b'\n') {
+                        self.line.pop();
+                        if self.line.last() == Some(&b'\r') {
+                            self.line.pop();
+                        }
+                    }
+                    Some((self.number, Ok(&self.line)))
+                }
+                Err(e) => {
+                    self.done = true;
+                    Some((self.number, Err(e)))
+                }
+            }
+        }
+    }
+
+    /// Async adapters for [`tokio::io`].
+    #[cfg(feature = "tokio")]
+    mod __tokio {
+        use core::pin::Pin;
+        use core::task::ready;
+        use core::task::Context;
+        use core::task::Poll;
+        use std::io;
+
+        use tokio::io::AsyncRead;
+        use tokio::io::AsyncWrite;
+        use tokio::io::ReadBuf;
+
+        use super::invalid_data;
+        use super::CHUNK_SIZE;
+        use crate::__stream;
+
+        /// An [`AsyncWrite`] adapter that encodes written bytes into the
+        /// inner writer.
+        ///
+        /// An adapter created with [`AsyncEncodeWriter::with_len`] writes the
+        /// encoded output as soon as it is available, and accepts no more
+        /// input while the inner writer is pending. An adapter created with
+        /// [`AsyncEncodeWriter::new`] accumulates the input, and writes the
+        /// output on [`poll_shutdown`]. Either way, [`poll_shutdown`] must be
+        /// driven to completion.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # #[tokio::main(flavor = "current_thread")]
+        /// # async fn main() -> std::io::Result<()> {
+        /// use c32::io::AsyncEncodeWriter;
+        /// use tokio::io::AsyncWriteExt;
+        ///
+        /// let mut writer = AsyncEncodeWriter::new(Vec::new());
+        /// writer.write_all(&[42, 42, 42]).await?;
+        /// writer.shutdown().await?;
+        /// assert_eq!(writer.into_inner(), b"2MAHA");
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [`poll_shutdown`]: AsyncWrite::poll_shutdown
+        #[derive(Debug)]
+        pub struct AsyncEncodeWriter<W> {
+            /// The inner writer.
+            inner: W,
+            /// The encoding state.
+            state: __stream::Encoder,
+            /// The encoded output, which has not been written yet.
+            output: __stream::Drain,
+            /// Whether the input is complete.
+            done: bool,
+        }
+
+        impl<W> AsyncEncodeWriter<W> {
+            /// Creates a new [`AsyncEncodeWriter`] wrapping `inner`.
+            ///
+            /// The input is accumulated until [`poll_shutdown`], since its
+            /// length is not known.
+            ///
+            /// [`poll_shutdown`]: AsyncWrite::poll_shutdown
+            #[inline]
+            #[must_use]
+            pub fn new(inner: W) -> Self {
+                Self {
+                    inner,
+                    state: __stream::Encoder::default(),
+                    output: __stream::Drain::default(),
+                    done: false,
+                }
+            }
+
+            /// Creates a new [`AsyncEncodeWriter`] for `len` bytes, wrapping
+            /// `inner`.
+            ///
+            /// The encoded output is written as it becomes available. Writing
+            /// more bytes than declared fails with [`Error::InvalidDataSize`].
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # #[tokio::main(flavor = "current_thread")]
+            /// # async fn main() -> std::io::Result<()> {
+            /// use c32::io::AsyncEncodeWriter;
+            /// use tokio::io::AsyncWriteExt;
+            ///
+            /// let mut writer = AsyncEncodeWriter::with_len(Vec::new(), 3);
+            /// writer.write_all(&[42, 42]).await?;
+            /// writer.flush().await?;
+            /// assert_eq!(writer.get_ref(), b"2MA");
+            /// writer.write_all(&[42]).await?;
+            /// writer.shutdown().await?;
+            /// assert_eq!(writer.into_inner(), b"2MAHA");
+            /// # Ok(())
+            /// # }
+            /// ```
+            ///
+            /// [`Error::InvalidDataSize`]: crate::Error::InvalidDataSize
+            #[inline]
+            #[must_use]
+            pub fn with_len(inner: W, len: u64) -> Self {
+                Self {
+                    inner,
+                    state: __stream::Encoder::with_len(len),
+                    output: __stream::Drain::default(),
+                    done: false,
+                }
+            }
+
+            /// Returns a reference to the inner writer.
+            #[inline]
+            #[must_use]
+            pub fn get_ref(&self) -> &W {
+                &self.inner
+            }
+
+            /// Returns a mutable reference to the inner writer.
+            #[inline]
+            #[must_use]
+            pub fn get_mut(&mut self) -> &mut W {
+                &mut self.inner
+            }
+
+            /// Consumes the adapter, returning the inner writer.
+            #[inline]
+            #[must_use]
+            pub fn into_inner(self) -> W {
+                self.inner
+            }
+        }
+
+        impl<W> AsyncEncodeWriter<W>
+        where
+            W: AsyncWrite + Unpin,
+        {
+            /// Writes the pending output, resuming where a pending write
+            /// stopped.
+            fn poll_output(
+                &mut self,
+                cx: &mut Context<'_>,
+            ) -> Poll<io::Result<()>> {
+                while !self.output.is_empty() {
+                    let n = ready!(Pin::new(&mut self.inner)
+                        .poll_write(cx, self.output.remaining()))?;
+                    if n == 0 {
+                        return Poll::Ready(Err(
+                            io::ErrorKind::WriteZero.into()
+                        ));
+                    }
+                    self.output.consume(n);
+                }
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        impl<W> AsyncWrite for AsyncEncodeWriter<W>
+        where
+            W: AsyncWrite + Unpin,
+        {
+            fn poll_write(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &[u8],
+            ) -> Poll<io::Result<usize>> {
+                let this = self.get_mut();
+
+                // Assert that the writer has not been shut down.
+                if this.done {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::BrokenPipe,
+                        "write after shutdown",
+                    )));
+                }
+
+                // Write the output of previous calls first, which holds back
+                // the input while the inner writer is pending.
+                ready!(this.poll_output(cx))?;
+
+                let n = buf.len().min(CHUNK_SIZE);
+                this.state
+                    .update(&buf[..n], &mut this.output)
+                    .map_err(invalid_data)?;
+                Poll::Ready(Ok(n))
+            }
+
+            fn poll_flush(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+            ) -> Poll<io::Result<()>> {
+                let this = self.get_mut();
+                ready!(this.poll_output(cx))?;
+                Pin::new(&mut this.inner).poll_flush(cx)
+            }
+
+            fn poll_shutdown(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+            ) -> Poll<io::Result<()>> {
+                let this = self.get_mut();
+                if !this.done {
+                    this.state
+                        .finish(&mut this.output)
+                        .map_err(invalid_data)?;
+                    this.done = true;
+                }
+
+                ready!(this.poll_output(cx))?;
+                ready!(Pin::new(&mut this.inner).poll_flush(cx))?;
+                Pin::new(&mut this.inner).poll_shutdown(cx)
+            }
+        }
+
+        /// An [`AsyncRead`] adapter that decodes bytes read from the inner
+        /// reader.
+        ///
+        /// An adapter created with [`AsyncDecodeReader::with_len`] returns
+        /// decoded bytes as soon as they are available, and reads no further
+        /// than the declared length. An adapter created with
+        /// [`AsyncDecodeReader::new`] reads the inner reader to the end
+        /// before any decoded bytes are returned.
+        ///
+        /// Decoding errors are returned as [`io::Error`]s of kind
+        /// [`io::ErrorKind::InvalidData`], wrapping an [`Error`] whose index
+        /// is relative to the start of the stream.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # #[tokio::main(flavor = "current_thread")]
+        /// # async fn main() -> std::io::Result<()> {
+        /// use c32::io::AsyncDecodeReader;
+        /// use tokio::io::AsyncReadExt;
+        ///
+        /// let mut reader = AsyncDecodeReader::new(&b"2MAHA"[..]);
+        /// let mut bytes = Vec::new();
+        /// reader.read_to_end(&mut bytes).await?;
+        /// assert_eq!(bytes, [42, 42, 42]);
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [`Error`]: crate::Error
+        #[derive(Debug)]
+        pub struct AsyncDecodeReader<R> {
+            /// The inner reader.
+            inner: R,
+            /// The decoding state.
+            state: __stream::Decoder,
+            /// The decoded output, which has not been read yet.
+            output: __stream::Drain,
+            /// Whether the input is complete.
+            done: bool,
+        }
+
+        impl<R> AsyncDecodeReader<R> {
+            /// Creates a new [`AsyncDecodeReader`] wrapping `inner`.
+            ///
+            /// The inner reader is read to the end before any decoded bytes
+            /// are returned, since the length of the input is not known.
+            #[inline]
+            #[must_use]
+            pub fn new(inner: R) -> Self {
+                Self {
+                    inner,
+                    state: __stream::Decoder::default(),
+                    output: __stream::Drain::default(),
+                    done: false,
+                }
+            }
+
+            /// Creates a new [`AsyncDecodeReader`] for `len` characters,
+            /// wrapping `inner`.
+            ///
+            /// Decoded bytes are returned as they become available. The inner
+            /// reader ending early fails with [`Error::InvalidDataSize`]. An
+            /// invalid character is reported as by
+            /// [`decode`](crate::decode), once `len` characters are read.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # #[tokio::main(flavor = "current_thread")]
+            /// # async fn main() -> std::io::Result<()> {
+            /// use c32::io::AsyncDecodeReader;
+            /// use tokio::io::AsyncReadExt;
+            ///
+            /// let src = b"2MAHA;rest";
+            /// let mut reader = AsyncDecodeReader::with_len(&src[..], 5);
+            /// let mut bytes = Vec::new();
+            /// reader.read_to_end(&mut bytes).await?;
+            /// assert_eq!(bytes, [42, 42, 42]);
+            /// assert_eq!(reader.into_inner(), b";rest");
+            /// # Ok(())
+            /// # }
+            /// ```
+            ///
+            /// [`Error::InvalidDataSize`]: crate::Error::InvalidDataSize
+            #[inline]
+            #[must_use]
+            pub fn with_len(inner: R, len: u64) -> Self {
+                Self {
+                    inner,
+                    state: __stream::Decoder::with_len(len),
+                    output: __stream::Drain::default(),
+                    done: false,
+                }
+            }
+
+            /// Returns a reference to the inner reader.
+            #[inline]
+            #[must_use]
+            pub fn get_ref(&self) -> &R {
+                &self.inner
+            }
+
+            /// Returns a mutable reference to the inner reader.
+            #[inline]
+            #[must_use]
+            pub fn get_mut(&mut self) -> &mut R {
+                &mut self.inner
+            }
+
+            /// Consumes the adapter, returning the inner reader.
+            #[inline]
+            #[must_use]
+            pub fn into_inner(self) -> R {
+                self.inner
+            }
+        }
+
+        impl<R> AsyncRead for AsyncDecodeReader<R>
+        where
+            R: AsyncRead + Unpin,
+        {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut ReadBuf<'_>,
+            ) -> Poll<io::Result<()>> {
+                let this = self.get_mut();
+
+                loop {
+                    // Copy the decoded bytes.
+                    if !this.output.is_empty() || this.done {
+                        let n =
+                            buf.remaining().min(this.output.remaining().len());
+                        buf.put_slice(&this.output.remaining()[..n]);
+                        this.output.consume(n);
+                        return Poll::Ready(Ok(()));
+                    }
+
+                    // Return the error after the bytes decoded before it.
+                    if let Some(err) = this.state.error() {
+                        return Poll::Ready(Err(invalid_data(err)));
+                    }
+
+                    // Read the next chunk up to the declared length, resuming
+                    // after a pending read.
+                    let want = this.state.remaining().map_or(CHUNK_SIZE, |n| {
+                        usize::try_from(n).unwrap_or(usize::MAX).min(CHUNK_SIZE)
+                    });
+                    let mut chunk = [0u8; CHUNK_SIZE];
+                    let mut read = ReadBuf::new(&mut chunk[..want]);
+                    if want != 0 {
+                        ready!(
+                            Pin::new(&mut this.inner).poll_read(cx, &mut read)
+                        )?;
+                    }
+
+                    if read.filled().is_empty() {
+                        this.state
+                            .finish(&mut this.output)
+                            .map_err(invalid_data)?;
+                        this.done = true;
+                    } else {
+                        this.state.update(read.filled(), &mut this.output);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// This module provides helpers for converting whole files.
+///
+/// # Notes
+///
+/// - The source is streamed through a fixed-size buffer, so the memory used
+///   does not depend on the size of the file.
+/// - The output is written to a temporary file next to the destination, which
+///   is renamed over the destination once complete. If an error is returned,
+///   the destination is left untouched.
+#[cfg(feature = "std")]
+pub mod fs {
+    use alloc::format;
+    use core::sync::atomic::AtomicUsize;
+    use core::sync::atomic::Ordering;
+    use std::ffi::OsString;
+    use std::fs;
+    use std::io;
+    use std::io::Write;
+    use std::path::Path;
+    use std::path::PathBuf;
+    use std::process;
+
+    use super::*;
+
+    /// The size of the chunks read from the source file.
+    const CHUNK_SIZE: usize = 8192;
+
+    /// An error returned by the file helpers.
+    #[derive(Debug)]
+    pub enum FileError {
+        /// Opening, reading, writing or renaming a file failed.
+        Io(io::Error),
+        /// The contents could not be encoded or decoded.
+        C32(Error),
+    }
+
+    impl fmt::Display for FileError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Io(err) => err.fmt(f),
+                Self::C32(err) => err.fmt(f),
+            }
+        }
+    }
+
+    impl error::Error for FileError {
+        fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+            match self {
+                Self::Io(err) => err.source(),
+                Self::C32(err) => err.source(),
+            }
+        }
+    }
+
+    impl From<io::Error> for FileError {
+        /// Converts an [`io::Error`], unwrapping the [`Error`] it may wrap.
+        fn from(err: io::Error) -> Self {
+            match err.get_ref().and_then(|inner| inner.downcast_ref()) {
+                Some(inner) => Self::C32(*inner),
+                None => Self::Io(err),
+            }
+        }
+    }
+
+    impl From<Error> for FileError {
+        fn from(err: Error) -> Self {
+            Self::C32(err)
+        }
+    }
+
+    /// Result type for the file helpers.
+    pub type Result<T> = core::result::Result<T, FileError>;
+
+    /// Encodes the contents of `src` as Crockford Base32 into `dst`.
+    ///
+    /// The output is identical to [`encode`] of the whole file.
+    ///
+    /// # Returns
+    ///
+    /// The number of characters written to `dst`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return a [`FileError::Io`] if opening, reading,
+    /// writing or renaming a file fails, including if `src` shrinks while it
+    /// is being read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let dir = std::env::temp_dir();
+    /// let src = dir.join(format!("c32-doc-{}-encode.in", std::process::id()));
+    /// let dst = dir.join(format!("c32-doc-{}-encode.c32", std::process::id()));
+    ///
+    /// std::fs::write(&src, [42, 42, 42])?;
+    /// let len = c32::fs::encode_file(&src, &dst)?;
+    /// assert_eq!(std::fs::read_to_string(&dst)?, "2MAHA");
+    /// assert_eq!(len, 5);
+    /// # std::fs::remove_file(&src)?;
+    /// # std::fs::remove_file(&dst)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn encode_file(src: &Path, dst: &Path) -> Result<u64> {
+        let mut reader = fs::File::open(src)?;
+        let len = reader.metadata()?.len();
+
+        write_atomic(dst, |writer| {
+            let mut chunk = [0u8; CHUNK_SIZE];
+            let mut packer = __stream::Packer::new(len);
+            let mut en = Vec::with_capacity(encoded_len(CHUNK_SIZE));
+            let mut written = 0u64;
+
+            while packer.remaining() > 0 {
+                let n = crate::io::read_chunk(&mut reader, &mut chunk)?;
+                let n = n.min(
+                    usize::try_from(packer.remaining()).unwrap_or(usize::MAX),
+                );
+
+                // Assert that the file yields its full length.
+                if n == 0 {
+                    return Err(
+                        io::Error::from(io::ErrorKind::UnexpectedEof).into()
+                    );
+                }
+
+                packer.push(&chunk[..n], &mut en);
+                writer.write_all(&en)?;
+                written += en.len() as u64;
+                en.clear();
+            }
+
+            Ok(written)
+        })
+    }
+
+    /// Decodes the Crockford Base32 contents of `src` into `dst`.
+    ///
+    /// The output is identical to [`decode`] of the whole file, so the file
+    /// must not contain anything but the encoding, not even a trailing
+    /// newline.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to `dst`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return a [`FileError`] if:
+    ///
+    /// - [`FileError::C32`], wrapping [`Error::InvalidCharacter`], the file
+    ///   contains invalid characters. The index is the byte offset in `src`.
+    /// - [`FileError::Io`], opening, reading, writing or renaming a file fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let dir = std::env::temp_dir();
+    /// let src = dir.join(format!("c32-doc-{}-decode.c32", std::process::id()));
+    /// let dst = dir.join(format!("c32-doc-{}-decode.out", std::process::id()));
+    ///
+    /// std::fs::write(&src, "2MAHA")?;
+    /// let len = c32::fs::decode_file(&src, &dst)?;
+    /// assert_eq!(std::fs::read(&dst)?, [42, 42, 42]);
+    /// assert_eq!(len, 3);
+    /// # std::fs::remove_file(&src)?;
+    /// # std::fs::remove_file(&dst)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn decode_file(src: &Path, dst: &Path) -> Result<u64> {
+        let mut reader = fs::File::open(src)?;
+        let len = reader.metadata()?.len();
+
+        write_atomic(dst, |writer| {
+            let mut chunk = [0u8; CHUNK_SIZE];
+            let mut unpacker = __stream::Unpacker::new(len);
+            let mut de = Vec::with_capacity(CHUNK_SIZE);
+
+            while unpacker.remaining() > 0 {
+                let n = crate::io::read_chunk(&mut reader, &mut chunk)?;
+                let n = n.min(
+                    usize::try_from(unpacker.remaining()).unwrap_or(usize::MAX),
+                );
+
+                // Assert that the file yields its full length.
+                if n == 0 {
+                    return Err(
+                        io::Error::from(io::ErrorKind::UnexpectedEof).into()
+                    );
+                }
+
+                for &byte in &chunk[..n] {
+                    unpacker.push(byte, &mut de);
+                }
+
+                writer.write_all(&de)?;
+                de.clear();
+            }
+
+            // Assert that the characters are valid.
+            unpacker.check().map_err(FileError::C32)?;
+            Ok(unpacker.decoded())
+        })
+    }
+
+    /// Encodes the contents of `src` as Crockford Base32Check into `dst`.
+    ///
+    /// This reads `src` twice, see [`io::encode_check_from_reader`].
+    ///
+    /// # Returns
+    ///
+    /// The number of characters written to `dst`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return a [`FileError`] if:
+    ///
+    /// - [`FileError::C32`], wrapping [`Error::InvalidVersion`], the version is
+    ///   32 or greater.
+    /// - [`FileError::Io`], opening, reading, writing or renaming a file fails.
+    ///
+    /// [`io::encode_check_from_reader`]: crate::io::encode_check_from_reader
+    #[cfg(feature = "check")]
+    pub fn encode_check_file(
+        src: &Path,
+        dst: &Path,
+        version: u8,
+    ) -> Result<u64> {
+        let reader = fs::File::open(src)?;
+        write_atomic(dst, |writer| {
+            Ok(crate::io::encode_check_from_reader(
+                reader, version, writer,
+            )?)
+        })
+    }
+
+    /// Decodes the Crockford Base32Check contents of `src` into `dst`.
+    ///
+    /// Unlike [`io::decode_check_from_reader`], nothing is written to `dst`
+    /// unless the checksum matches.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the number of bytes written to `dst` and the version byte.
+    ///
+    /// # Errors
+    ///
+    /// This method will return a [`FileError`] if:
+    ///
+    /// - [`FileError::C32`], wrapping:
+    ///   - [`Error::InvalidCharacter`], the file contains invalid characters.
+    ///   - [`Error::InsufficientData`], the file has fewer bytes than required.
+    ///   - [`Error::ChecksumMismatch`], the checksum does not match.
+    /// - [`FileError::Io`], opening, reading, writing or renaming a file fails.
+    ///
+    /// [`io::decode_check_from_reader`]: crate::io::decode_check_from_reader
+    #[cfg(feature = "check")]
+    pub fn decode_check_file(src: &Path, dst: &Path) -> Result<(u64, u8)> {
+        let reader = fs::File::open(src)?;
+        let len = reader.metadata()?.len();
+        write_atomic(dst, |writer| {
+            Ok(crate::io::decode_check_from_reader_with_len(
+                reader, len, writer,
+            )?)
+        })
+    }
+
+    /// Writes to a temporary file next to `dst` with `f`, renaming it to
+    /// `dst` on success and removing it otherwise.
+    fn write_atomic<T, F>(dst: &Path, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut io::BufWriter<fs::File>) -> Result<T>,
+    {
+        let tmp = temp_path(dst)?;
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&tmp)?;
+
+        let result = (|| {
+            let mut writer = io::BufWriter::new(file);
+            let value = f(&mut writer)?;
+            let file = writer
+                .into_inner()
+                .map_err(io::IntoInnerError::into_error)?;
+            file.sync_all()?;
+            fs::rename(&tmp, dst)?;
+            Ok(value)
+        })();
+
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+        result
+    }
+
+    /// Returns a unique temporary path in the directory of `dst`.
+    fn temp_path(dst: &Path) -> io::Result<PathBuf> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let Some(name) = dst.file_name() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "destination has no file name",
+            ));
+        };
+
+        let mut tmp = OsString::from(".");
+        tmp.push(name);
+        tmp.push(format!(
+            ".{}.{}.tmp",
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        Ok(dst.with_file_name(tmp))
+    }
+}
+
+/// This module provides a naive reference implementation.
+///
+/// The input is read as a single big-endian number, which is converted to
+/// base 32 by long division, and back by repeated multiplication. There are
+/// no bit tricks, lookup tables or reversed buffers, so the implementation
+/// can be checked against the specification by reading it.
+///
+/// # Notes
+///
+/// - This is slow, quadratic in the length of the input, and intended for
+///   differential testing only. Do not use it in production.
+///
+/// # Examples
+///
+/// ```rust
+/// use c32::reference;
+///
+/// assert_eq!(reference::encode(&[42, 42, 42]), "2MAHA");
+/// assert_eq!(reference::decode("2MAHA"), Some(vec![42, 42, 42]));
+///
+/// // Assert that the optimized paths agree with the reference.
+/// reference::assert_equivalent(b"usque ad finem");
+/// ```
+#[cfg(feature = "reference")]
+pub mod reference {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use sha2::Sha256;
+
+    /// The Crockford Base32 alphabet, as published by the specification.
+    const ALPHABET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    /// Returns the value of a character, after normalization.
+    #[allow(clippy::cast_possible_truncation)]
+    fn value(char: char) -> Option<u8> {
+        let char = match char.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            char => char,
+        };
+        ALPHABET.find(char).map(|index| index as u8)
+    }
+
+    /// Returns the character of a value.
+    fn symbol(value: u8) -> char {
+        ALPHABET.as_bytes()[value as usize] as char
+    }
+
+    /// Encodes bytes as Crockford Base32.
+    ///
+    /// Every leading zero byte is written as one leading `0`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn encode(bytes: &[u8]) -> String {
+        let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+
+        // The number, as big-endian base 256 digits.
+        let mut number = bytes[zeros..].to_vec();
+
+        // The number, as big-endian base 32 digits.
+        let mut digits = Vec::new();
+        while !number.is_empty() {
+            // Divide the number by 32, by long division.
+            let mut quotient = Vec::new();
+            let mut remainder = 0u32;
+            for &digit in &number {
+                let value = remainder * 256 + u32::from(digit);
+                remainder = value % 32;
+                if !quotient.is_empty() || value / 32 != 0 {
+                    quotient.push((value / 32) as u8);
+                }
+            }
+
+            digits.insert(0, remainder as u8);
+            number = quotient;
+        }
+
+        let mut str = String::new();
+        for _ in 0..zeros {
+            str.push('0');
+        }
+        for digit in digits {
+            str.push(symbol(digit));
+        }
+        str
+    }
+
+    /// Decodes a Crockford Base32 string.
+    ///
+    /// Every leading `0` is read as one leading zero byte.
+    ///
+    /// # Returns
+    ///
+    /// The decoded bytes, or `None` if the string contains a character
+    /// outside of the alphabet and its aliases.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn decode(str: &str) -> Option<Vec<u8>> {
+        let mut digits = Vec::new();
+        for char in str.chars() {
+            digits.push(value(char)?);
+        }
+        let zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+
+        // The number, as big-endian base 256 digits.
+        let mut number: Vec<u8> = Vec::new();
+        for &digit in &digits[zeros..] {
+            // Multiply the number by 32, and add the digit.
+            let mut carry = u32::from(digit);
+            for byte in number.iter_mut().rev() {
+                let value = u32::from(*byte) * 32 + carry;
+                *byte = (value % 256) as u8;
+                carry = value / 256;
+            }
+            if carry != 0 {
+                number.insert(0, carry as u8);
+            }
+        }
+
+        let mut bytes = vec![0u8; zeros];
+        bytes.extend(number);
+        Some(bytes)
+    }
+
+    /// Computes the 4-byte checksum of bytes and a version, which is the
+    /// start of the double SHA-256 hash of the version followed by the bytes.
+    #[must_use]
+    pub fn checksum(bytes: &[u8], version: u8) -> [u8; 4] {
+        let once = Sha256::new().update(&[version]).update(bytes).finalize();
+        let twice = Sha256::new().update(&once).finalize();
+        [twice[0], twice[1], twice[2], twice[3]]
+    }
+
+    /// Encodes bytes as Crockford Base32Check.
+    ///
+    /// # Returns
+    ///
+    /// The version character followed by the encoding of the bytes and their
+    /// checksum, or `None` if the version is 32 or greater.
+    #[must_use]
+    pub fn encode_check(bytes: &[u8], version: u8) -> Option<String> {
+        if version >= 32 {
+            return None;
+        }
+
+        let mut payload = bytes.to_vec();
+        payload.extend(checksum(bytes, version));
+        Some(format!("{}{}", symbol(version), encode(&payload)))
+    }
+
+    /// Decodes a Crockford Base32Check string.
+    ///
+    /// # Returns
+    ///
+    /// The decoded bytes and the version, or `None` if the string is not a
+    /// valid encoding or the checksum does not match.
+    #[must_use]
+    pub fn decode_check(str: &str) -> Option<(Vec<u8>, u8)> {
+        let mut chars = str.chars();
+        let version = value(chars.next()?)?;
+
+        let mut bytes = decode(chars.as_str())?;
+        let sum = bytes.split_off(bytes.len().checked_sub(4)?);
+        (sum == checksum(&bytes, version)).then_some((bytes, version))
+    }
+
+    /// Asserts that the optimized paths agree with the reference on `input`.
+    ///
+    /// The input is encoded and decoded in every mode, with a version
+    /// derived from its length, and decoded itself if it is valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if any of the paths diverge.
+    #[track_caller]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn assert_equivalent(input: &[u8]) {
+        let version = (input.len() % 32) as u8;
+
+        let en = encode(input);
+        assert_eq!(crate::encode(input), en, "encode: {input:?}");
+        assert_eq!(crate::decode(&en).ok(), decode(&en), "decode: {en:?}");
+
+        let en = encode_check(input, version);
+        assert_eq!(
+            crate::encode_check(input, version).ok(),
+            en,
+            "encode_check: {input:?}"
+        );
+        if let Some(en) = en {
+            assert_eq!(
+                crate::decode_check(&en).ok(),
+                decode_check(&en),
+                "decode_check: {en:?}"
+            );
+        }
+
+        if let Ok(str) = core::str::from_utf8(input) {
+            assert_eq!(crate::decode(str).ok(), decode(str), "decode: {str:?}");
+            assert_eq!(
+                crate::decode_check(str).ok(),
+                decode_check(str),
+                "decode_check: {str:?}"
+            );
+        }
+    }
+}
+
+/// Adapters for [`Stream`]s of chunks.
+///
+/// Since the input is encoded as a single number, the alignment of the output
+/// depends on the total length of the input. The adapters created with
+/// `with_len` know the length up front, and yield an item for every chunk of
+/// the inner stream that completes output. The adapters created with `new`
+/// accumulate the chunks, and yield a single item once the inner stream
+/// terminates.
+///
+/// [`Stream`]: futures_core::Stream
+#[cfg(feature = "futures")]
+pub mod stream {
+    use core::pin::Pin;
+    use core::task::ready;
+    use core::task::Context;
+    use core::task::Poll;
+
+    use futures_core::FusedStream;
+    use futures_core::Stream;
+
+    use super::*;
+
+    /// A [`Stream`] adapter that encodes the chunks of the inner stream.
+    ///
+    /// The concatenated items are equal to [`encode`] of the concatenated
+    /// chunks. At least one item is yielded, once the inner stream
+    /// terminates at the latest. An error is yielded after the characters
+    /// encoded before it, and ends the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::stream::C32StreamExt;
+    /// use futures::executor::block_on;
+    /// use futures::stream;
+    /// use futures::TryStreamExt;
+    ///
+    /// let chunks = stream::iter([vec![42], vec![42, 42]]);
+    /// let en: Vec<String> = block_on(chunks.encode_c32().try_collect())?;
+    /// assert_eq!(en, ["2MAHA"]);
+    /// # Ok::<(), c32::Error>(())
+    /// ```
+    #[derive(Debug)]
+    pub struct EncodeStream<S> {
+        /// The inner stream.
+        inner: S,
+        /// The encoding state.
+        state: __stream::Encoder,
+        /// The encoded output, which has not been yielded yet.
+        output: __stream::Drain,
+        /// The error to yield after the output.
+        error: Option<Error>,
+        /// Whether an item has been yielded.
+        yielded: bool,
+        /// Whether the inner stream has terminated, or an error occurred.
+        done: bool,
+    }
+
+    impl<S> EncodeStream<S> {
+        /// Creates a new [`EncodeStream`] wrapping `inner`.
+        ///
+        /// The chunks are accumulated until the inner stream terminates,
+        /// since the length of the input is not known. This holds the whole
+        /// input in memory, and yields nothing before the inner stream ends,
+        /// see [`EncodeStream::with_len`] to stream the output instead.
+        #[inline]
+        #[must_use]
+        pub fn new(inner: S) -> Self {
+            Self::with_state(inner, __stream::Encoder::default())
+        }
+
+        /// Creates a new [`EncodeStream`] for `len` bytes, wrapping `inner`.
+        ///
+        /// Each chunk of the inner stream yields the characters it completes.
+        /// The inner stream yielding more or fewer than `len` bytes fails
+        /// with [`Error::InvalidDataSize`].
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use c32::stream::EncodeStream;
+        /// use futures::executor::block_on;
+        /// use futures::stream;
+        /// use futures::TryStreamExt;
+        ///
+        /// let chunks = stream::iter([vec![42, 42], vec![42]]);
+        /// let en: Vec<String> =
+        ///     block_on(EncodeStream::with_len(chunks, 3).try_collect())?;
+        /// assert_eq!(en, ["2MA", "HA"]);
+        /// # Ok::<(), c32::Error>(())
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn with_len(inner: S, len: u64) -> Self {
+            Self::with_state(inner, __stream::Encoder::with_len(len))
+        }
+
+        /// Creates a new [`EncodeStream`] with the given state.
+        #[inline]
+        fn with_state(inner: S, state: __stream::Encoder) -> Self {
+            Self {
+                inner,
+                state,
+                output: __stream::Drain::default(),
+                error: None,
+                yielded: false,
+                done: false,
+            }
+        }
+
+        /// Returns a reference to the inner stream.
+        #[inline]
+        #[must_use]
+        pub fn get_ref(&self) -> &S {
+            &self.inner
+        }
+
+        /// Consumes the adapter, returning the inner stream.
+        #[inline]
+        #[must_use]
+        pub fn into_inner(self) -> S {
+            self.inner
+        }
+    }
+
+    impl<S> Stream for EncodeStream<S>
+    where
+        S: Stream + Unpin,
+        S::Item: AsRef<[u8]>,
+    {
+        type Item = Result<String>;
+
+        fn poll_next(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+
+            loop {
+                // Yield the output.
+                if !this.output.is_empty() {
+                    this.yielded = true;
+
+                    // This should not panic, as we only push valid ASCII.
+                    let bytes = this.output.take();
+                    return Poll::Ready(Some(Ok(
+                        String::from_utf8(bytes).unwrap()
+                    )));
+                }
+
+                // Yield the error after the characters encoded before it.
+                if let Some(err) = this.error.take() {
+                    this.yielded = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+
+                // Yield at least one item.
+                if this.done {
+                    if this.yielded {
+                        return Poll::Ready(None);
+                    }
+                    this.yielded = true;
+                    return Poll::Ready(Some(Ok(String::new())));
+                }
+
+                let next = ready!(Pin::new(&mut this.inner).poll_next(cx));
+                let result = if let Some(chunk) = next {
+                    this.state.update(chunk.as_ref(), &mut this.output)
+                } else {
+                    this.done = true;
+                    this.state.finish(&mut this.output)
+                };
+
+                if let Err(err) = result {
+                    this.done = true;
+                    this.error = Some(err);
+                }
+            }
+        }
+    }
+
+    impl<S> FusedStream for EncodeStream<S>
+    where
+        S: Stream + Unpin,
+        S::Item: AsRef<[u8]>,
+    {
+        fn is_terminated(&self) -> bool {
+            self.done
+                && self.yielded
+                && self.output.is_empty()
+                && self.error.is_none()
+        }
+    }
+
+    /// A [`Stream`] adapter that decodes the chunks of the inner stream.
+    ///
+    /// The concatenated items are equal to [`decode`] of the concatenated
+    /// chunks. At least one item is yielded, once the inner stream
+    /// terminates at the latest. An error is yielded after the bytes decoded
+    /// before it, and ends the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::stream::C32StreamExt;
+    /// use futures::executor::block_on;
+    /// use futures::stream;
+    /// use futures::StreamExt;
+    ///
+    /// let chunks = stream::iter(["2M", "AHA"]);
+    /// let de: Vec<_> = block_on(chunks.decode_c32().collect());
+    /// assert_eq!(de, [Ok(vec![42, 42, 42])]);
+    /// ```
+    #[derive(Debug)]
+    pub struct DecodeStream<S> {
+        /// The inner stream.
+        inner: S,
+        /// The decoding state.
+        state: __stream::Decoder,
+        /// The decoded output, which has not been yielded yet.
+        output: __stream::Drain,
+        /// Whether an item has been yielded.
+        yielded: bool,
+        /// Whether the last item has been yielded.
+        done: bool,
+    }
+
+    impl<S> DecodeStream<S> {
+        /// Creates a new [`DecodeStream`] wrapping `inner`.
+        ///
+        /// The chunks are accumulated until the inner stream terminates,
+        /// since the length of the input is not known. This holds the whole
+        /// input in memory, and yields nothing before the inner stream ends,
+        /// see [`DecodeStream::with_len`] to stream the output instead.
+        #[inline]
+        #[must_use]
+        pub fn new(inner: S) -> Self {
+            Self::with_state(inner, __stream::Decoder::default())
+        }
+
+        /// Creates a new [`DecodeStream`] for `len` characters, wrapping
+        /// `inner`.
+        ///
+        /// Each chunk of the inner stream yields the bytes it completes. The
+        /// inner stream yielding more or fewer than `len` characters fails
+        /// with [`Error::InvalidDataSize`]. An invalid character is reported
+        /// as by [`decode`], once `len` characters are yielded.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use c32::stream::DecodeStream;
+        /// use futures::executor::block_on;
+        /// use futures::stream;
+        /// use futures::StreamExt;
+        ///
+        /// let chunks = stream::iter(["2MA", "HA"]);
+        /// let de: Vec<_> = block_on(DecodeStream::with_len(chunks, 5).collect());
+        /// assert_eq!(de, [Ok(vec![42]), Ok(vec![42, 42])]);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn with_len(inner: S, len: u64) -> Self {
+            Self::with_state(inner, __stream::Decoder::with_len(len))
+        }
+
+        /// Creates a new [`DecodeStream`] with the given state.
+        #[inline]
+        fn with_state(inner: S, state: __stream::Decoder) -> Self {
+            Self {
+                inner,
+                state,
+                output: __stream::Drain::default(),
+                yielded: false,
+                done: false,
+            }
+        }
+
+        /// Returns a reference to the inner stream.
+        #[inline]
+        #[must_use]
+        pub fn get_ref(&self) -> &S {
+            &self.inner
+        }
+
+        /// Consumes the adapter, returning the inner stream.
+        #[inline]
+        #[must_use]
+        pub fn into_inner(self) -> S {
+            self.inner
+        }
+    }
+
+    impl<S> Stream for DecodeStream<S>
+    where
+        S: Stream + Unpin,
+        S::Item: AsRef<[u8]>,
+    {
+        type Item = Result<Vec<u8>>;
+
+        fn poll_next(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+
+            loop {
+                // Yield the output, and at least one item.
+                if !this.output.is_empty() || (this.done && !this.yielded) {
+                    this.yielded = true;
+                    return Poll::Ready(Some(Ok(this.output.take())));
+                }
+
+                if this.done {
+                    return Poll::Ready(None);
+                }
+
+                // Yield the error after the bytes decoded before it.
+                if let Some(err) = this.state.error() {
+                    this.yielded = true;
+                    this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+
+                let next = ready!(Pin::new(&mut this.inner).poll_next(cx));
+                if let Some(chunk) = next {
+                    this.state.update(chunk.as_ref(), &mut this.output);
+                } else {
+                    this.done = true;
+                    if let Err(err) = this.state.finish(&mut this.output) {
+                        this.yielded = true;
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                }
+            }
+        }
+    }
+
+    impl<S> FusedStream for DecodeStream<S>
+    where
+        S: Stream + Unpin,
+        S::Item: AsRef<[u8]>,
+    {
+        fn is_terminated(&self) -> bool {
+            self.done && self.yielded && self.output.is_empty()
+        }
+    }
+
+    /// An extension trait for encoding and decoding [`Stream`]s of chunks.
+    pub trait C32StreamExt: Stream {
+        /// Encodes the chunks of the stream, see [`EncodeStream::new`].
+        ///
+        /// The whole input is buffered, see [`EncodeStream::with_len`] to
+        /// stream the output instead.
+        #[inline]
+        #[must_use]
+        fn encode_c32(self) -> EncodeStream<Self>
+        where
+            Self: Sized,
+        {
+            EncodeStream::new(self)
+        }
+
+        /// Decodes the chunks of the stream, see [`DecodeStream::new`].
+        ///
+        /// The whole input is buffered, see [`DecodeStream::with_len`] to
+        /// stream the output instead.
+        #[inline]
+        #[must_use]
+        fn decode_c32(self) -> DecodeStream<Self>
+        where
+            Self: Sized,
+        {
+            DecodeStream::new(self)
+        }
+    }
+
+    impl<S: Stream> C32StreamExt for S {}
+}
+
+/// Adapters for the [`embedded_io`] traits.
+///
+/// Since the input is encoded as a single number, the alignment of the output
+/// depends on the total length of the input. The adapters therefore require
+/// the length up front, which allows them to stream without allocating.
+#[cfg(feature = "embedded-io")]
+pub mod embedded {
+    use embedded_io::ErrorKind;
+    use embedded_io::ErrorType;
+    use embedded_io::Read;
+    use embedded_io::Write;
+
+    use super::*;
+
+    /// The size of the internal buffers.
+    const CHUNK_SIZE: usize = 32;
+
+    /// An error returned by the embedded adapters.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum IoError<E> {
+        /// The inner reader or writer failed.
+        Io(E),
+        /// The data could not be encoded or decoded.
+        Codec(Error),
+    }
+
+    impl<E: embedded_io::Error> embedded_io::Error for IoError<E> {
+        fn kind(&self) -> ErrorKind {
+            match self {
+                Self::Io(e) => e.kind(),
+                Self::Codec(_) => ErrorKind::InvalidData,
+            }
+        }
+    }
+
+    /// A [`Write`] adapter that encodes written bytes into the inner writer.
+    ///
+    /// The encoded output is written as soon as it is available, so the
+    /// total length of the input must be known up front. Writing more bytes
+    /// than declared fails with [`Error::InvalidDataSize`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::embedded::EncodeWriter;
+    /// use embedded_io::Write;
+    ///
+    /// let mut dst = [0u8; 5];
+    /// let mut writer = EncodeWriter::new(&mut dst[..], 3);
+    /// writer.write_all(&[42, 42, 42])?;
+    /// writer.finish()?;
+    /// assert_eq!(&dst, b"2MAHA");
+    /// # Ok::<(), c32::embedded::IoError<embedded_io::SliceWriteError>>(())
+    /// ```
+    #[derive(Debug)]
+    pub struct EncodeWriter<W> {
+        /// The inner writer.
+        inner: W,
+        /// The declared length of the input.
+        len: usize,
+        /// The encoding state.
+        packer: __stream::Packer,
+    }
+
+    impl<W: Write> EncodeWriter<W> {
+        /// Creates a new [`EncodeWriter`] for `len` bytes, wrapping `inner`.
+        #[inline]
+        #[must_use]
+        pub fn new(inner: W, len: usize) -> Self {
+            Self {
+                inner,
+                len,
+                packer: __stream::Packer::new(len as u64),
+            }
+        }
+
+        /// Returns a reference to the inner writer.
+        #[inline]
+        #[must_use]
+        pub fn get_ref(&self) -> &W {
+            &self.inner
+        }
+
+        /// Completes the stream, returning the inner writer.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an [`IoError`] if:
+        ///
+        /// - [`Error::InvalidDataSize`], fewer bytes than declared were
+        ///   written.
+        /// - Flushing the inner writer fails.
+        #[allow(clippy::cast_possible_truncation)]
+        pub fn finish(mut self) -> core::result::Result<W, IoError<W::Error>> {
+            // Assert that the declared length was written.
+            let remaining = self.packer.remaining() as usize;
+            if remaining != 0 {
+                return Err(IoError::Codec(Error::InvalidDataSize {
+                    expected: self.len,
+                    got: self.len - remaining,
+                }));
+            }
+
+            self.inner.flush().map_err(IoError::Io)?;
+            Ok(self.inner)
+        }
+    }
+
+    impl<W: Write> ErrorType for EncodeWriter<W> {
+        type Error = IoError<W::Error>;
+    }
+
+    impl<W: Write> Write for EncodeWriter<W> {
+        #[allow(clippy::cast_possible_truncation)]
+        fn write(
+            &mut self,
+            buf: &[u8],
+        ) -> core::result::Result<usize, Self::Error> {
+            // Assert that the declared length is not exceeded.
+            let remaining = self.packer.remaining() as usize;
+            if buf.len() > remaining {
+                return Err(IoError::Codec(Error::InvalidDataSize {
+                    expected: self.len,
+                    got: self.len - remaining + buf.len(),
+                }));
+            }
+
+            let mut chunk = [0u8; CHUNK_SIZE];
+            let mut pos = 0;
+            for &byte in buf {
+                let mut chars = [0u8; 3];
+                let n = self.packer.push_byte(byte, &mut chars);
+                chunk[pos..pos + n].copy_from_slice(&chars[..n]);
+                pos += n;
+
+                // Write the chunk before it can overflow.
+                if pos + chars.len() > CHUNK_SIZE {
+                    self.inner.write_all(&chunk[..pos]).map_err(IoError::Io)?;
+                    pos = 0;
+                }
+            }
+            self.inner.write_all(&chunk[..pos]).map_err(IoError::Io)?;
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> core::result::Result<(), Self::Error> {
+            self.inner.flush().map_err(IoError::Io)
+        }
+    }
+
+    /// A [`Read`] adapter that decodes characters read from the inner reader.
+    ///
+    /// The decoded output is produced as soon as it is available, so the
+    /// total number of characters must be known up front. The inner reader
+    /// ending early fails with [`Error::InvalidDataSize`]. An invalid
+    /// character is reported as by [`decode_into`], once all characters are
+    /// read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::embedded::DecodeReader;
+    /// use embedded_io::Read;
+    ///
+    /// let src = b"2MAHA";
+    /// let mut reader = DecodeReader::new(&src[..], src.len());
+    /// let mut dst = [0u8; 3];
+    /// reader.read_exact(&mut dst).unwrap();
+    /// assert_eq!(dst, [42, 42, 42]);
+    /// ```
+    #[derive(Debug)]
+    pub struct DecodeReader<R> {
+        /// The inner reader.
+        inner: R,
+        /// The declared number of characters.
+        len: usize,
+        /// The number of characters consumed.
+        consumed: usize,
+        /// The decoding state.
+        unpacker: __stream::Unpacker,
+        /// The characters read, but not yet decoded.
+        input: [u8; CHUNK_SIZE],
+        /// The position and length of the characters in `input`.
+        input_pos: usize,
+        input_len: usize,
+        /// The bytes decoded, but not yet read.
+        output: [u8; 2],
+        /// The position and length of the bytes in `output`.
+        output_pos: usize,
+        output_len: usize,
+    }
+
+    impl<R: Read> DecodeReader<R> {
+        /// Creates a new [`DecodeReader`] for `len` characters, wrapping
+        /// `inner`.
+        #[inline]
+        #[must_use]
+        pub fn new(inner: R, len: usize) -> Self {
+            Self {
+                inner,
+                len,
+                consumed: 0,
+                unpacker: __stream::Unpacker::new(len as u64),
+                input: [0u8; CHUNK_SIZE],
+                input_pos: 0,
+                input_len: 0,
+                output: [0u8; 2],
+                output_pos: 0,
+                output_len: 0,
+            }
+        }
+
+        /// Returns a reference to the inner reader.
+        #[inline]
+        #[must_use]
+        pub fn get_ref(&self) -> &R {
+            &self.inner
+        }
+
+        /// Consumes the adapter, returning the inner reader.
+        #[inline]
+        #[must_use]
+        pub fn into_inner(self) -> R {
+            self.inner
+        }
+    }
+
+    impl<R: Read> ErrorType for DecodeReader<R> {
+        type Error = IoError<R::Error>;
+    }
+
+    impl<R: Read> Read for DecodeReader<R> {
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        fn read(
+            &mut self,
+            buf: &mut [u8],
+        ) -> core::result::Result<usize, Self::Error> {
+            let mut n = 0;
+            while n < buf.len() {
+                // Copy the decoded bytes.
+                if self.output_pos < self.output_len {
+                    buf[n] = self.output[self.output_pos];
+                    self.output_pos += 1;
+                    n += 1;
+                    continue;
+                }
+
+                // Read the next chunk, unless bytes can be returned already.
+                if self.input_pos == self.input_len {
+                    let remaining = self.unpacker.remaining() as usize;
+                    if n > 0 {
+                        break;
+                    }
+
+                    // Assert that the characters are valid, once all are
+                    // known.
+                    if remaining == 0 {
+                        self.unpacker.check().map_err(IoError::Codec)?;
+                        break;
+                    }
+
+                    let want = remaining.min(CHUNK_SIZE);
+                    let m = self
+                        .inner
+                        .read(&mut self.input[..want])
+                        .map_err(IoError::Io)?;
+
+                    // Assert that the reader yields the declared length.
+                    if m == 0 {
+                        self.unpacker.check().map_err(IoError::Codec)?;
+                        return Err(IoError::Codec(Error::InvalidDataSize {
+                            expected: self.len,
+                            got: self.consumed,
+                        }));
+                    }
+
+                    self.input_pos = 0;
+                    self.input_len = m;
+                }
+
+                let byte = self.input[self.input_pos];
+                self.input_pos += 1;
+                self.consumed += 1;
+
+                self.output_len =
+                    self.unpacker.push_char(byte, &mut self.output);
+                self.output_pos = 0;
+            }
+
+            Ok(n)
+        }
+    }
+}
+
+/// [`clap`] value parsers for Crockford Base32 arguments.
+///
+/// The parsers decode an argument when the command line is parsed, and
+/// report an invalid argument with a caret under the offending character.
+/// Fixed-size newtypes such as [`C32Text`] implement `FromStr`, and are
+/// supported by `clap::value_parser!` directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use c32::arg::C32CheckValueParser;
+/// use clap::Arg;
+/// use clap::Command;
+///
+/// let cmd = Command::new("app").arg(Arg::new("address").value_parser(
+///     C32CheckValueParser::new().prefix('S').expect_version(22),
+/// ));
+///
+/// let matches = cmd
+///     .clone()
+///     .try_get_matches_from(["app", "SPAHA58QT2DJ9"])
+///     .unwrap();
+/// let (bytes, version) = matches.get_one::<(Vec<u8>, u8)>("address").unwrap();
+/// assert_eq!((&bytes[..], *version), (&[42, 42, 42][..], 22));
+///
+/// let error = cmd
+///     .try_get_matches_from(["app", "SPAH!58QT2DJ9"])
+///     .unwrap_err();
+/// assert!(error.to_string().contains("    SPAH!58QT2DJ9\n        ^"));
+/// ```
+///
+/// [`clap`]: https://docs.rs/clap
+#[cfg(feature = "clap")]
+pub mod arg {
+    use alloc::format;
+    use alloc::string::ToString;
+    use core::fmt::Write;
+    use std::ffi::OsStr;
+
+    use clap::builder::TypedValueParser;
+    use clap::error::ErrorKind;
+    use clap::Arg;
+    use clap::Command;
+
+    use super::*;
+
+    /// A [`TypedValueParser`] for Crockford Base32 arguments, with an
+    /// optional prefix character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::arg::C32ValueParser;
+    /// use clap::Arg;
+    /// use clap::Command;
+    ///
+    /// let cmd = Command::new("app")
+    ///     .arg(Arg::new("key").value_parser(C32ValueParser::new()));
+    ///
+    /// let matches = cmd.try_get_matches_from(["app", "2MAHA"]).unwrap();
+    /// assert_eq!(matches.get_one::<Vec<u8>>("key"), Some(&vec![42, 42, 42]));
+    /// ```
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct C32ValueParser {
+        prefix: Option<char>,
+    }
+
+    impl C32ValueParser {
+        /// Creates a parser for arguments without a prefix.
+        #[inline]
+        #[must_use]
+        pub const fn new() -> Self {
+            Self { prefix: None }
+        }
+
+        /// Requires the arguments to start with the `prefix` character.
+        #[inline]
+        #[must_use]
+        pub const fn prefix(mut self, prefix: char) -> Self {
+            self.prefix = Some(prefix);
+            self
+        }
+    }
+
+    impl TypedValueParser for C32ValueParser {
+        type Value = Vec<u8>;
+
+        fn parse_ref(
+            &self,
+            cmd: &Command,
+            arg: Option<&Arg>,
+            value: &OsStr,
+        ) -> core::result::Result<Self::Value, clap::Error> {
+            let str = to_str(cmd, value)?;
+            let decoded = match self.prefix {
+                Some(prefix) => decode_prefixed(str, prefix),
+                None => decode(str),
+            };
+            decoded.map_err(|err| invalid(cmd, arg, str, &err))
+        }
+    }
+
+    /// A [`TypedValueParser`] for Crockford Base32Check arguments, with an
+    /// optional prefix character and expected version.
+    ///
+    /// The parsed value is the payload and its version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use c32::arg::C32CheckValueParser;
+    /// use clap::Arg;
+    /// use clap::Command;
+    ///
+    /// let cmd = Command::new("app").arg(
+    ///     Arg::new("id")
+    ///         .value_parser(C32CheckValueParser::new().expect_version(0)),
+    /// );
+    ///
+    /// let error = cmd
+    ///     .try_get_matches_from(["app", "PAHA58QT2DJ9"])
+    ///     .unwrap_err();
+    /// assert!(error.to_string().contains("Invalid version 22"));
+    /// ```
+    #[cfg(feature = "check")]
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct C32CheckValueParser {
+        prefix: Option<char>,
+        version: Option<u8>,
+    }
+
+    #[cfg(feature = "check")]
+    impl C32CheckValueParser {
+        /// Creates a parser for arguments without a prefix, of any version.
+        #[inline]
+        #[must_use]
+        pub const fn new() -> Self {
+            Self {
+                prefix: None,
+                version: None,
+            }
+        }
+
+        /// Requires the arguments to start with the `prefix` character.
+        #[inline]
+        #[must_use]
+        pub const fn prefix(mut self, prefix: char) -> Self {
+            self.prefix = Some(prefix);
+            self
+        }
+
+        /// Requires the arguments to be encoded with the `version`.
+        ///
+        /// Arguments of any other version are rejected with
+        /// [`Error::InvalidVersion`].
+        #[inline]
+        #[must_use]
+        pub const fn expect_version(mut self, version: u8) -> Self {
+            self.version = Some(version);
+            self
+        }
+    }
+
+    #[cfg(feature = "check")]
+    impl TypedValueParser for C32CheckValueParser {
+        type Value = (Vec<u8>, u8);
+
+        fn parse_ref(
+            &self,
+            cmd: &Command,
+            arg: Option<&Arg>,
+            value: &OsStr,
+        ) -> core::result::Result<Self::Value, clap::Error> {
+            let str = to_str(cmd, value)?;
+            let decoded = match self.prefix {
+                Some(prefix) => decode_check_prefixed(str, prefix),
+                None => decode_check(str),
+            };
+
+            // Assert that the version matches the expected version.
+            let decoded =
+                decoded.and_then(|(bytes, version)| match self.version {
+                    Some(expected) if expected != version => {
+                        Err(Error::InvalidVersion {
+                            expected: "must match the expected version",
+                            version,
+                        })
+                    }
+                    _ => Ok((bytes, version)),
+                });
+
+            decoded.map_err(|err| invalid(cmd, arg, str, &err))
+        }
+    }
+
+    /// Converts an argument to a `&str`, rejecting invalid UTF-8.
+    fn to_str<'a>(
+        cmd: &Command,
+        value: &'a OsStr,
+    ) -> core::result::Result<&'a str, clap::Error> {
+        value.to_str().ok_or_else(|| {
+            clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd)
+        })
+    }
+
+    /// Builds a [`clap::Error`] for an argument that failed to decode.
+    fn invalid(
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &str,
+        err: &Error,
+    ) -> clap::Error {
+        let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+        let mut message = format!("invalid value '{value}' for '{arg}': {err}");
+
+        // Render a caret under the offending character.
+        let position = match err {
+            Error::MissingPrefix { .. } => Some(0),
+            err => err.invalid_char_position(value),
+        };
+        if let Some(position) = position {
+            let caret = format!("{:>1$}", "^", position + 1);
+            // This should not fail, as writing to a `String` is infallible.
+            let _ = write!(message, "\n\n    {value}\n    {caret}");
+        }
+
+        cmd.clone().error(ErrorKind::ValueValidation, message)
+    }
+}
+
+/// C ABI bindings for the buffer-based API.
+///
+/// The functions write into caller-provided buffers, and never allocate.
+/// They return [`ffi::C32_OK`] on success, or one of the negative
+/// `C32_ERR_*` codes, and write the produced length through an
+/// out-parameter. The required capacities are returned by
+/// [`ffi::c32_encoded_len`] and friends.
+///
+/// A static or dynamic library can be built with:
+///
+/// ```sh
+/// cargo rustc -p c32 --release --features ffi --crate-type staticlib
+/// ```
+///
+/// The C header is generated into `include/c32.h` with `cbindgen`.
+///
+/// # Notes
+///
+/// - The functions do not panic. A panic would abort the process, as it may not
+///   unwind across an `extern "C"` boundary.
+#[cfg(feature = "ffi")]
+pub mod ffi {
+    use core::ffi::c_int;
+
+    use super::*;
+
+    /// The operation succeeded.
+    pub const C32_OK: c_int = 0;
+    /// A required pointer is null.
+    pub const C32_ERR_NULL_POINTER: c_int = -1;
+    /// See [`Error::BufferTooSmall`].
+    pub const C32_ERR_BUFFER_TOO_SMALL: c_int = -2;
+    /// See [`Error::InvalidDataSize`].
+    pub const C32_ERR_INVALID_DATA_SIZE: c_int = -3;
+    /// See [`Error::InvalidCharacter`].
+    pub const C32_ERR_INVALID_CHARACTER: c_int = -4;
+    /// See [`Error::MissingPrefix`].
+    pub const C32_ERR_MISSING_PREFIX: c_int = -5;
+    /// See [`Error::InvalidFrame`].
+    pub const C32_ERR_INVALID_FRAME: c_int = -6;
+    /// See [`Error::OutputTooLarge`].
+    pub const C32_ERR_OUTPUT_TOO_LARGE: c_int = -7;
+    /// See [`Error::InvalidVersion`].
+    pub const C32_ERR_INVALID_VERSION: c_int = -8;
+    /// See [`Error::InsufficientData`].
+    pub const C32_ERR_INSUFFICIENT_DATA: c_int = -9;
+    /// See [`Error::ChecksumMismatch`].
+    pub const C32_ERR_CHECKSUM_MISMATCH: c_int = -10;
+    /// See [`Error::InvalidPadding`].
+    pub const C32_ERR_INVALID_PADDING: c_int = -11;
+    /// See [`Error::AllocationFailed`].
+    pub const C32_ERR_ALLOCATION_FAILED: c_int = -12;
+
+    /// Returns the error code of an [`Error`].
+    #[inline]
+    #[must_use]
+    pub const fn error_code(err: &Error) -> c_int {
+        match err {
+            Error::BufferTooSmall { .. } => C32_ERR_BUFFER_TOO_SMALL,
+            Error::InvalidDataSize { .. } => C32_ERR_INVALID_DATA_SIZE,
+            Error::InvalidCharacter { .. } => C32_ERR_INVALID_CHARACTER,
+            Error::MissingPrefix { .. } => C32_ERR_MISSING_PREFIX,
+            Error::InvalidFrame { .. } => C32_ERR_INVALID_FRAME,
+            Error::OutputTooLarge { .. } => C32_ERR_OUTPUT_TOO_LARGE,
+            Error::InvalidVersion { .. } => C32_ERR_INVALID_VERSION,
+            Error::InsufficientData { .. } => C32_ERR_INSUFFICIENT_DATA,
+            Error::ChecksumMismatch { .. } => C32_ERR_CHECKSUM_MISMATCH,
+            Error::InvalidPadding { .. } => C32_ERR_INVALID_PADDING,
+            Error::AllocationFailed { .. } => C32_ERR_ALLOCATION_FAILED,
+        }
+    }
+
+    /// Returns the maximum encoded length of `n` bytes.
+    #[no_mangle]
+    pub extern "C" fn c32_encoded_len(n: usize) -> usize {
+        encoded_len(n)
+    }
+
+    /// Returns the maximum Base32Check-encoded length of `n` bytes.
+    #[no_mangle]
+    pub extern "C" fn c32_encoded_check_len(n: usize) -> usize {
+        encoded_check_len(n)
+    }
+
+    /// Returns the maximum decoded length of `n` characters.
+    #[no_mangle]
+    pub extern "C" fn c32_decoded_len(n: usize) -> usize {
+        decoded_len(n)
+    }
+
+    /// Returns the maximum Base32Check-decoded length of `n` characters.
+    #[no_mangle]
+    pub extern "C" fn c32_decoded_check_len(n: usize) -> usize {
+        decoded_check_len(n)
+    }
+
+    /// Encodes `src_len` bytes at `src` into Crockford Base32.
+    ///
+    /// The characters are written to `out`, and their number to `out_len`.
+    ///
+    /// # Safety
+    ///
+    /// - `src` must be valid for reads of `src_len` bytes, or may be null if
+    ///   `src_len` is zero.
+    /// - `out` must be valid for writes of `out_cap` bytes, or may be null if
+    ///   `out_cap` is zero.
+    /// - `out_len` must be valid for writes, or null.
+    /// - The buffers must not overlap.
+    #[no_mangle]
+    pub unsafe extern "C" fn c32_encode(
+        src: *const u8,
+        src_len: usize,
+        out: *mut u8,
+        out_cap: usize,
+        out_len: *mut usize,
+    ) -> c_int {
+        let (Some(src), Some(out)) =
+            (input(src, src_len), output(out, out_cap))
+        else {
+            return C32_ERR_NULL_POINTER;
+        };
+        finish(encode_into(src, out), out_len)
+    }
+
+    /// Decodes `src_len` Crockford Base32 characters at `src`.
+    ///
+    /// The bytes are written to `out`, and their number to `out_len`.
+    ///
+    /// # Safety
+    ///
+    /// See [`c32_encode`].
+    #[no_mangle]
+    pub unsafe extern "C" fn c32_decode(
+        src: *const u8,
+        src_len: usize,
+        out: *mut u8,
+        out_cap: usize,
+        out_len: *mut usize,
+    ) -> c_int {
+        let (Some(src), Some(out)) =
+            (input(src, src_len), output(out, out_cap))
+        else {
+            return C32_ERR_NULL_POINTER;
+        };
+        finish(decode_into(src, out), out_len)
+    }
+
+    /// Encodes `src_len` bytes at `src` into Crockford Base32Check, with the
+    /// given `version`.
+    ///
+    /// The characters are written to `out`, and their number to `out_len`.
+    ///
+    /// # Safety
+    ///
+    /// See [`c32_encode`].
+    #[no_mangle]
+    pub unsafe extern "C" fn c32_encode_check(
+        src: *const u8,
+        src_len: usize,
+        version: u8,
+        out: *mut u8,
+        out_cap: usize,
+        out_len: *mut usize,
+    ) -> c_int {
+        let (Some(src), Some(out)) =
+            (input(src, src_len), output(out, out_cap))
+        else {
+            return C32_ERR_NULL_POINTER;
+        };
+        finish(encode_check_into(src, out, version), out_len)
+    }
+
+    /// Decodes `src_len` Crockford Base32Check characters at `src`.
+    ///
+    /// The payload is written to `out`, its length to `out_len`, and the
+    /// version to `version`.
+    ///
+    /// # Safety
+    ///
+    /// See [`c32_encode`], and `version` must be valid for writes, or null.
+    #[no_mangle]
+    pub unsafe extern "C" fn c32_decode_check(
+        src: *const u8,
+        src_len: usize,
+        out: *mut u8,
+        out_cap: usize,
+        out_len: *mut usize,
+        version: *mut u8,
+    ) -> c_int {
+        let (Some(src), Some(out)) =
+            (input(src, src_len), output(out, out_cap))
+        else {
+            return C32_ERR_NULL_POINTER;
+        };
+
+        match decode_check_into(src, out) {
+            Ok((len, v)) => {
+                if !version.is_null() {
+                    *version = v;
+                }
+                finish(Ok(len), out_len)
+            }
+            Err(err) => error_code(&err),
+        }
+    }
+
+    /// Creates the input slice, or returns `None` for a null pointer.
+    #[inline]
+    unsafe fn input<'a>(ptr: *const u8, len: usize) -> Option<&'a [u8]> {
+        match (ptr.is_null(), len) {
+            (_, 0) => Some(&[]),
+            (true, _) => None,
+            (false, _) => Some(slice::from_raw_parts(ptr, len)),
+        }
+    }
+
+    /// Creates the output slice, or returns `None` for a null pointer.
+    #[inline]
+    unsafe fn output<'a>(ptr: *mut u8, len: usize) -> Option<&'a mut [u8]> {
+        match (ptr.is_null(), len) {
+            (_, 0) => Some(&mut []),
+            (true, _) => None,
+            (false, _) => Some(slice::from_raw_parts_mut(ptr, len)),
+        }
+    }
+
+    /// Writes the length of a successful result to `out_len`, and returns
+    /// the status code.
+    #[inline]
+    unsafe fn finish(result: Result<usize>, out_len: *mut usize) -> c_int {
+        match result {
+            Ok(len) => {
+                if !out_len.is_null() {
+                    *out_len = len;
+                }
+                C32_OK
+            }
+            Err(err) => error_code(&err),
+        }
+    }
+}
+
+/// Private module containing the streaming codec state.
+///
+/// This is shared by all IO adapters, so that their behavior is identical.
+#[allow(dead_code)]
+mod __stream {
+    use super::*;
+
+    /// Encodes bytes pushed in chunks.
+    ///
+    /// If the length of the input is known, the bytes are packed as they are
+    /// pushed. Otherwise, the input is accumulated and encoded by
+    /// [`Encoder::finish`].
+    #[derive(Debug, Default)]
+    #[cfg(feature = "alloc")]
+    pub(crate) struct Encoder {
+        /// The accumulated input, if the length is not known.
+        input: Vec<u8>,
+        /// The encoding state and the declared length, if known.
+        packer: Option<(Packer, u64)>,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Encoder {
+        /// Creates a new [`Encoder`] for an input of `len` bytes.
+        #[inline]
+        #[must_use]
+        pub(crate) fn with_len(len: u64) -> Self {
+            Self {
+                input: Vec::new(),
+                packer: Some((Packer::new(len), len)),
+            }
+        }
+
+        /// Pushes bytes, appending the finished characters to `out`.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an [`Error`] if:
+        ///
+        /// - [`Error::InvalidDataSize`], more bytes than declared are pushed.
+        #[inline]
+        pub(crate) fn update(
+            &mut self,
+            bytes: &[u8],
+            out: &mut Drain,
+        ) -> Result<()> {
+            let Some((packer, len)) = &mut self.packer else {
+                self.input.extend_from_slice(bytes);
+                return Ok(());
+            };
+
+            // Assert that the declared length is not exceeded.
+            let remaining = packer.remaining();
+            if bytes.len() as u64 > remaining {
+                return Err(Error::InvalidDataSize {
+                    expected: to_usize(*len)?,
+                    got: to_usize(*len - remaining + bytes.len() as u64)?,
+                });
+            }
+
+            packer.push(bytes, out.buffer());
+            Ok(())
+        }
+
+        /// Completes the input, appending the remaining characters to `out`.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an [`Error`] if:
+        ///
+        /// - [`Error::InvalidDataSize`], fewer bytes than declared were pushed.
+        #[inline]
+        pub(crate) fn finish(&mut self, out: &mut Drain) -> Result<()> {
+            let Some((packer, len)) = &self.packer else {
+                let input = core::mem::take(&mut self.input);
+                out.buffer().extend_from_slice(encode(input).as_bytes());
+                return Ok(());
+            };
+
+            // Assert that the declared length was pushed.
+            let remaining = packer.remaining();
+            if remaining != 0 {
+                return Err(Error::InvalidDataSize {
+                    expected: to_usize(*len)?,
+                    got: to_usize(*len - remaining)?,
+                });
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Decodes characters pushed in chunks.
+    ///
+    /// If the number of characters is known, they are unpacked as they are
+    /// pushed. Otherwise, the input is accumulated and decoded by
+    /// [`Decoder::finish`].
+    #[derive(Debug, Default)]
+    #[cfg(feature = "alloc")]
+    pub(crate) struct Decoder {
+        /// The accumulated input, if the length is not known.
+        input: Vec<u8>,
+        /// The decoding state and the declared length, if known.
+        unpacker: Option<(Unpacker, u64)>,
+        /// The error which stopped decoding.
+        error: Option<Error>,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Decoder {
+        /// Creates a new [`Decoder`] for an input of `len` characters.
+        #[inline]
+        #[must_use]
+        pub(crate) fn with_len(len: u64) -> Self {
+            Self {
+                input: Vec::new(),
+                unpacker: Some((Unpacker::new(len), len)),
+                error: None,
+            }
+        }
+
+        /// Returns the number of characters which are still expected, if
+        /// the length is known.
+        #[inline]
+        #[must_use]
+        pub(crate) fn remaining(&self) -> Option<u64> {
+            self.unpacker
+                .as_ref()
+                .map(|(unpacker, _)| unpacker.remaining())
+        }
+
+        /// Returns the error which stopped decoding, if any.
+        ///
+        /// The bytes decoded before the error are appended to the output
+        /// first, so adapters return them before the error.
+        #[inline]
+        #[must_use]
+        pub(crate) fn error(&self) -> Option<Error> {
+            self.error
+        }
+
+        /// Pushes characters, appending the finished bytes to `out`.
+        ///
+        /// No more bytes are produced after an invalid character, which is
+        /// reported once the declared length is pushed, or by
+        /// [`Decoder::finish`]. Decoding stops once more characters than
+        /// declared are pushed, see [`Decoder::error`].
+        #[inline]
+        pub(crate) fn update(&mut self, bytes: &[u8], out: &mut Drain) {
+            if self.error.is_some() {
+                return;
+            }
+
+            let Some((unpacker, len)) = &mut self.unpacker else {
+                self.input.extend_from_slice(bytes);
+                return;
+            };
+
+            if let Err(e) = unpack(unpacker, *len, bytes, out) {
+                self.error = Some(e);
+            }
+        }
+
+        /// Completes the input, appending the remaining bytes to `out`.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an [`Error`] if:
+        ///
+        /// - [`Error::InvalidCharacter`], the input contains invalid
+        ///   characters.
+        /// - [`Error::InvalidDataSize`], the number of characters differs from
+        ///   the declared length.
+        #[inline]
+        pub(crate) fn finish(&mut self, out: &mut Drain) -> Result<()> {
+            if let Some(error) = self.error {
+                return Err(error);
+            }
+
+            let Some((unpacker, len)) = &self.unpacker else {
+                let mut input = core::mem::take(&mut self.input);
+
+                // Allocate the output buffer.
+                let capacity = decoded_len(input.len());
+                let mut dst = vec![0u8; capacity];
+
+                // Decode the input bytes into the buffer.
+                let result = decode_into(&input, &mut dst);
+                __internal::wipe(&mut input);
+                let offset = result?;
+                out.buffer().extend_from_slice(&dst[..offset]);
+                __internal::wipe(&mut dst);
+
+                return Ok(());
+            };
+
+            // Assert that the characters are valid, and that the declared
+            // length was pushed.
+            unpacker.check()?;
+            let remaining = unpacker.remaining();
+            if remaining != 0 {
+                return Err(Error::InvalidDataSize {
+                    expected: to_usize(*len)?,
+                    got: to_usize(*len - remaining)?,
+                });
+            }
+
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Drop for Decoder {
+        fn drop(&mut self) {
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut self.input);
+        }
+    }
+
+    /// Output that has been produced, but not yet consumed.
+    #[derive(Debug, Default)]
+    #[cfg(feature = "alloc")]
+    pub(crate) struct Drain {
+        /// The produced bytes.
+        bytes: Vec<u8>,
+        /// The amount of consumed bytes.
+        pos: usize,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Drain {
+        /// Returns the buffer to append produced bytes to.
+        ///
+        /// Once all bytes have been consumed, the buffer is reused.
+        #[inline]
+        pub(crate) fn buffer(&mut self) -> &mut Vec<u8> {
+            if self.pos != 0 && self.is_empty() {
+                __internal::wipe(&mut self.bytes);
+                self.bytes.clear();
+                self.pos = 0;
+            }
+            &mut self.bytes
+        }
+
+        /// Returns the bytes which have not been consumed.
+        #[inline]
+        #[must_use]
+        pub(crate) fn remaining(&self) -> &[u8] {
+            &self.bytes[self.pos..]
+        }
+
+        /// Marks `n` bytes as consumed.
+        #[inline]
+        pub(crate) fn consume(&mut self, n: usize) {
+            self.pos = (self.pos + n).min(self.bytes.len());
+        }
+
+        /// Returns `true` if all bytes have been consumed.
+        #[inline]
+        #[must_use]
+        pub(crate) fn is_empty(&self) -> bool {
+            self.pos == self.bytes.len()
+        }
+
+        /// Takes the bytes which have not been consumed, leaving the
+        /// [`Drain`] empty.
+        #[inline]
+        #[must_use]
+        pub(crate) fn take(&mut self) -> Vec<u8> {
+            let mut bytes = core::mem::take(&mut self.bytes);
+            __internal::wipe(&mut bytes[..self.pos]);
+            bytes.drain(..self.pos);
+            self.pos = 0;
+            bytes
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Drop for Drain {
+        fn drop(&mut self) {
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut self.bytes);
+        }
+    }
+
+    /// Encodes a byte stream of known length, most significant byte first.
+    ///
+    /// Knowing the length up front fixes the alignment of the 5-bit groups,
+    /// so characters can be produced as soon as their bits are available.
+    /// The output is identical to [`encode`] of the whole stream.
+    #[derive(Debug)]
+    pub(crate) struct Packer {
+        /// The bits which have not been written yet.
+        carry: u16,
+        /// The number of bits in `carry`.
+        carry_bits: u32,
+        /// The size of the next group in bits.
+        group_bits: u32,
+        /// The number of bytes which have not been pushed yet.
+        remaining: u64,
+        /// Whether only zero bytes have been pushed.
+        zeros: bool,
+        /// Whether a non-zero character has been written.
+        started: bool,
+    }
+
+    impl Packer {
+        /// Creates a new [`Packer`] for a stream of `len` bytes.
+        #[inline]
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub(crate) fn new(len: u64) -> Self {
+            // The most significant group holds the bits left over by the
+            // groups aligned to the end of the stream.
+            let group_bits = match (len % 5 * 8 % 5) as u32 {
+                0 => 5,
+                bits => bits,
+            };
+
+            Self {
+                carry: 0,
+                carry_bits: 0,
+                group_bits,
+                remaining: len,
+                zeros: true,
+                started: false,
+            }
+        }
+
+        /// Returns the number of bytes which have not been pushed yet.
+        #[inline]
+        #[must_use]
+        pub(crate) fn remaining(&self) -> u64 {
+            self.remaining
+        }
+
+        /// Pushes bytes, appending the finished characters to `dst`.
+        ///
+        /// # Notes
+        ///
+        /// - At most [`Packer::remaining`] bytes may be pushed.
+        #[inline]
+        #[cfg(feature = "alloc")]
+        pub(crate) fn push(&mut self, bytes: &[u8], dst: &mut Vec<u8>) {
+            let mut chars = [0u8; 3];
+            for &byte in bytes {
+                let n = self.push_byte(byte, &mut chars);
+                dst.extend_from_slice(&chars[..n]);
+            }
+        }
+
+        /// Pushes a byte, writing the finished characters to `dst`.
+        ///
+        /// # Returns
+        ///
+        /// The number of characters written to `dst`.
+        ///
+        /// # Notes
+        ///
+        /// - At most [`Packer::remaining`] bytes may be pushed.
+        #[inline]
+        pub(crate) fn push_byte(
+            &mut self,
+            byte: u8,
+            dst: &mut [u8; 3],
+        ) -> usize {
+            let mut n = 0;
+            self.remaining -= 1;
+
+            // Write a zero symbol for every leading zero byte.
+            if self.zeros {
+                if byte == 0 {
+                    dst[n] = ALPHABET[0];
+                    n += 1;
+                } else {
+                    self.zeros = false;
+                }
+            }
+
+            // accumulate bits into carry
+            self.carry = (self.carry << 8) | u16::from(byte);
+            self.carry_bits += 8;
+
+            // extract groups, most significant first
+            while self.carry_bits >= self.group_bits {
+                self.carry_bits -= self.group_bits;
+                let index = (self.carry >> self.carry_bits) & 0x1F;
+                self.carry &= (1 << self.carry_bits) - 1;
+                self.group_bits = 5;
+
+                // skip zero groups before the first non-zero group
+                if index != 0 || self.started {
+                    self.started = true;
+                    dst[n] = ALPHABET[index as usize];
+                    n += 1;
+                }
+            }
+
+            n
+        }
+    }
+
+    /// Decodes a character stream of known length, most significant first.
+    ///
+    /// Knowing the length up front fixes the alignment of the output bytes,
+    /// so bytes can be produced as soon as their bits are available. The
+    /// output is identical to [`decode`] of the whole stream.
+    ///
+    /// Once an invalid character is pushed, no more bytes are produced, but
+    /// the remaining characters are still checked, so [`Unpacker::check`]
+    /// reports the same error as [`decode`].
+    #[derive(Debug)]
+    pub(crate) struct Unpacker {
+        /// The bits which have not been written yet.
+        carry: u16,
+        /// The number of bits in `carry`.
+        carry_bits: u32,
+        /// The size of the next byte in bits.
+        byte_bits: u32,
+        /// The number of characters of the stream.
+        len: u64,
+        /// The number of characters which have not been pushed yet.
+        remaining: u64,
+        /// Whether only zero characters have been pushed.
+        zeros: bool,
+        /// The number of leading zero characters.
+        zero_chars: u64,
+        /// The bits of the most significant non-zero character, which counts
+        /// as 5 bits if it is invalid.
+        top: u32,
+        /// Whether a non-zero byte has been written.
+        started: bool,
+        /// The number of bytes which have been written.
+        decoded: u64,
+        /// The last invalid character and its index, if any.
+        invalid: Option<(u8, u64)>,
+    }
+
+    impl Unpacker {
+        /// Creates a new [`Unpacker`] for a stream of `len` characters.
+        #[inline]
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub(crate) fn new(len: u64) -> Self {
+            // The most significant byte holds the bits left over by the
+            // bytes aligned to the end of the stream.
+            let byte_bits = match (len % 8 * 5 % 8) as u32 {
+                0 => 8,
+                bits => bits,
+            };
+
+            Self {
+                carry: 0,
+                carry_bits: 0,
+                byte_bits,
+                len,
+                remaining: len,
+                zeros: true,
+                zero_chars: 0,
+                top: 0,
+                started: false,
+                decoded: 0,
+                invalid: None,
+            }
+        }
+
+        /// Returns the number of characters which have not been pushed yet.
+        #[inline]
+        #[must_use]
+        pub(crate) fn remaining(&self) -> u64 {
+            self.remaining
+        }
+
+        /// Returns the number of bytes which have been written.
+        #[inline]
+        #[must_use]
+        pub(crate) fn decoded(&self) -> u64 {
+            self.decoded
+        }
+
+        /// Returns the error for the last invalid character pushed, if any.
+        ///
+        /// # Errors
+        ///
+        /// This method will return an [`Error`] if:
+        ///
+        /// - [`Error::InvalidCharacter`], a character is invalid. The index is
+        ///   relative to the start of the stream, and the error is identical to
+        ///   that of [`decode`], once all characters are pushed.
+        #[inline]
+        pub(crate) fn check(&self) -> Result<()> {
+            let Some((byte, index)) = self.invalid else {
+                return Ok(());
+            };
+
+            let index = to_usize(index)?;
+            Err(Error::InvalidCharacter {
+                char: byte as char,
+                index,
+                decoded: __internal::decoded_from(
+                    to_usize(self.len)?,
+                    index,
+                    to_usize(self.zero_chars)?,
+                    self.top as usize,
+                ),
+            })
+        }
+
+        /// Pushes a character, appending the finished bytes to `dst`.
+        ///
+        /// # Notes
+        ///
+        /// - At most [`Unpacker::remaining`] characters may be pushed.
+        #[inline]
+        #[cfg(feature = "alloc")]
+        pub(crate) fn push(&mut self, byte: u8, dst: &mut Vec<u8>) {
+            let mut bytes = [0u8; 2];
+            let n = self.push_char(byte, &mut bytes);
+            dst.extend_from_slice(&bytes[..n]);
+        }
+
+        /// Pushes a character, writing the finished bytes to `dst`.
+        ///
+        /// # Returns
+        ///
+        /// The number of bytes written to `dst`, which is zero once an
+        /// invalid character has been pushed, see [`Unpacker::check`].
+        ///
+        /// # Notes
+        ///
+        /// - At most [`Unpacker::remaining`] characters may be pushed.
+        #[inline]
+        pub(crate) fn push_char(
+            &mut self,
+            byte: u8,
+            dst: &mut [u8; 2],
+        ) -> usize {
+            let value = alphabet::CROCKFORD.value(byte);
+            if value != INVALID && self.invalid.is_none() {
+                return self.push_value(value, dst);
+            }
+
+            // An invalid character before any non-zero one is the most
+            // significant character.
+            if value == INVALID {
+                if self.zeros {
+                    self.zeros = false;
+                    self.top = 5;
+                }
+                self.invalid = Some((byte, self.len - self.remaining));
+            }
+            self.remaining -= 1;
+            0
+        }
+
+        /// Pushes a 5-bit value, writing the finished bytes to `dst`.
+        ///
+        /// # Returns
+        ///
+        /// The number of bytes written to `dst`.
+        ///
+        /// # Notes
+        ///
+        /// - At most [`Unpacker::remaining`] values may be pushed.
+        #[inline]
+        #[allow(clippy::cast_possible_truncation)]
+        pub(crate) fn push_value(
+            &mut self,
+            value: u8,
+            dst: &mut [u8; 2],
+        ) -> usize {
+            let mut n = 0;
+            self.remaining -= 1;
+
+            // Write a zero byte for every leading zero character.
+            if self.zeros {
+                if value == 0 {
+                    dst[n] = 0;
+                    n += 1;
+                    self.zero_chars += 1;
+                } else {
+                    self.zeros = false;
+                    self.top = 8 - value.leading_zeros();
+                }
+            }
+
+            // accumulate bits into carry
+            self.carry = (self.carry << 5) | u16::from(value & 0x1F);
+            self.carry_bits += 5;
+
+            // extract bytes, most significant first
+            while self.carry_bits >= self.byte_bits {
+                self.carry_bits -= self.byte_bits;
+                let byte = (self.carry >> self.carry_bits) as u8;
+                self.carry &= (1 << self.carry_bits) - 1;
+                self.byte_bits = 8;
+
+                // skip zero bytes before the first non-zero byte
+                if byte != 0 || self.started {
+                    self.started = true;
+                    dst[n] = byte;
+                    n += 1;
+                }
+            }
+
+            self.decoded += n as u64;
+            n
+        }
+    }
+
+    /// Unpacks characters of a stream of `len` characters into `out`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::InvalidCharacter`], a character is invalid, once all `len`
+    ///   characters are pushed, see [`Unpacker::check`].
+    /// - [`Error::InvalidDataSize`], more than `len` characters are pushed.
+    #[cfg(feature = "alloc")]
+    fn unpack(
+        unpacker: &mut Unpacker,
+        len: u64,
+        bytes: &[u8],
+        out: &mut Drain,
+    ) -> Result<()> {
+        // Assert that the declared length is not exceeded.
+        let remaining = unpacker.remaining();
+        if bytes.len() as u64 > remaining {
+            return Err(Error::InvalidDataSize {
+                expected: to_usize(len)?,
+                got: to_usize(len - remaining + bytes.len() as u64)?,
+            });
+        }
+
+        for &byte in bytes {
+            unpacker.push(byte, out.buffer());
+        }
+
+        // Assert that the characters are valid, once all are known.
+        if unpacker.remaining() == 0 {
+            unpacker.check()?;
+        }
+
+        Ok(())
+    }
+
+    /// Converts a stream position or count to `usize`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an [`Error`] if:
+    ///
+    /// - [`Error::OutputTooLarge`], `n` does not fit in `usize`, which is only
+    ///   possible on targets narrower than 64 bits. The `got` field is
+    ///   saturated to [`usize::MAX`].
+    #[inline]
+    pub(crate) fn to_usize(n: u64) -> Result<usize> {
+        usize::try_from(n).map_err(|_| Error::OutputTooLarge {
+            max: usize::MAX,
+            got: usize::MAX,
+        })
+    }
+
+    /// Decodes a Base32Check payload into `dst`, holding back the checksum.
+    ///
+    /// The payload is unpacked one character at a time, and the last 4
+    /// decoded bytes are kept in a fixed-size window, so the checksum never
+    /// reaches `dst`. The bytes leaving the window are written to `dst`, and
+    /// passed to `feed` in runs as they are produced.
+    ///
+    /// # Returns
+    ///
+    /// The number of payload bytes written to `dst` and the checksum.
+    ///
+    /// # Notes
+    ///
+    /// - The output buffer must be properly sized.
+    /// - An invalid character is reported as by the in-memory decoder, which
+    ///   scans in reverse, so the error matches that of [`decode`].
+    /// - `POLICY` is either [`__internal::STRICT`] or
+    ///   [`__internal::WHITESPACE`], see [`__internal::de_with`].
+    #[cfg(feature = "check-core")]
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn unpack_check<F: FnMut(&[u8]), const POLICY: u8>(
+        alphabet: &alphabet::Alphabet,
+        payload: &[u8],
+        dst: &mut [u8],
+        mut feed: F,
+    ) -> Result<(usize, checksum::Checksum)> {
+        /// The number of payload bytes passed to `feed` at once.
+        const RUN: usize = 64;
+
+        let len = __internal::significant_len::<POLICY>(payload);
+        let mut unpacker = Unpacker::new(len as u64);
+
+        // The last decoded bytes, which form the candidate checksum.
+        let mut window = [0u8; checksum::BYTE_LENGTH];
+        let mut filled = 0;
+
+        let mut offset = 0;
+        let mut passed = 0;
+        for &byte in payload {
+            // Assert that the character is valid.
+            let value = alphabet.value(byte);
+            if value == INVALID {
+                if POLICY == __internal::WHITESPACE
+                    && __internal::is_whitespace(byte)
+                {
+                    continue;
+                }
+
+                // Clear the bytes written so far, as `de_with` only clears its
+                // own.
+                __internal::clear(&mut dst[..offset]);
+                let result = __internal::de_with::<POLICY>(
+                    alphabet,
+                    payload,
+                    0,
+                    payload.len(),
+                    dst,
+                    0,
+                );
+
+                // This should not panic, as the payload is invalid.
+                return Err(result.unwrap_err());
+            }
+
+            let mut bytes = [0u8; 2];
+            let n = unpacker.push_value(value, &mut bytes);
+            for &byte in &bytes[..n] {
+                if filled < checksum::BYTE_LENGTH {
+                    window[filled] = byte;
+                    filled += 1;
+                } else {
+                    dst[offset] = window[0];
+                    offset += 1;
+                    window.rotate_left(1);
+                    window[checksum::BYTE_LENGTH - 1] = byte;
+                }
+            }
+
+            if offset - passed >= RUN {
+                feed(&dst[passed..offset]);
+                passed = offset;
+            }
+        }
+        feed(&dst[passed..offset]);
+
+        // Assert that the payload contains a checksum.
+        if filled < checksum::BYTE_LENGTH {
+            return Err(Error::InsufficientData {
+                min: checksum::BYTE_LENGTH,
+                len: filled,
+            });
+        }
+
+        Ok((offset, window))
+    }
+
+    /// Verifies Base32Check-encoded bytes without allocating.
+    ///
+    /// The payload is unpacked one character at a time, and all but the last
+    /// 4 decoded bytes are fed to the hasher, which leaves the candidate
+    /// checksum in a fixed-size window.
+    ///
+    /// # Returns
+    ///
+    /// The version byte of the verified input.
+    #[cfg(feature = "check")]
+    pub(crate) fn verify_check_with(
+        alphabet: &alphabet::Alphabet,
+        src: &[u8],
+    ) -> Result<u8> {
+        use sha2::Sha256;
+
+        // Assert that the input contains the minimum amount.
+        if src.len() < 2 {
+            return Err(Error::InsufficientData {
+                min: 2,
+                len: src.len(),
+            });
+        }
+
+        let mut version = None;
+        let mut unpacker = Unpacker::new(src.len() as u64 - 1);
+        let mut hasher = Sha256::new();
+
+        // The last decoded bytes, which form the candidate checksum.
+        let mut window = [0u8; checksum::BYTE_LENGTH];
+        let mut filled = 0;
+
+        for (index, &byte) in src.iter().enumerate() {
+            // Assert that the character is valid.
+            let value = alphabet.value(byte);
+            if value == INVALID {
+                return Err(Error::InvalidCharacter {
+                    char: byte as char,
+                    index,
+                    decoded: to_usize(unpacker.decoded())?,
+                });
+            }
+
+            // The first character is the version, followed by the payload.
+            if version.is_none() {
+                hasher = hasher.update(&[value]);
+                version = Some(value);
+                continue;
+            }
+
+            let mut bytes = [0u8; 2];
+            let n = unpacker.push_value(value, &mut bytes);
+            for &byte in &bytes[..n] {
+                if filled < checksum::BYTE_LENGTH {
+                    window[filled] = byte;
+                    filled += 1;
+                } else {
+                    hasher = hasher.update(&window[..1]);
+                    window.rotate_left(1);
+                    window[checksum::BYTE_LENGTH - 1] = byte;
+                }
+            }
+        }
+
+        // Assert that the payload contains a checksum.
+        if filled < checksum::BYTE_LENGTH {
+            return Err(Error::InsufficientData {
+                min: checksum::BYTE_LENGTH,
+                len: filled,
+            });
+        }
 
-    // Encode the input bytes, and return the amount of bytes written.
-    let offset = __internal::en(src, 0, src.len(), &mut dst[..], 0, None);
-    Ok(offset)
+        // Assert that the computed and actual checksums match.
+        let hash = Sha256::new().update(&hasher.finalize()).finalize();
+        let expected = checksum::from_slice(&hash);
+        if expected != window {
+            return Err(Error::ChecksumMismatch {
+                expected,
+                got: window,
+                version: version.unwrap(),
+                len: to_usize(unpacker.decoded())? - checksum::BYTE_LENGTH,
+            });
+        }
+
+        // This should not panic, as the input contains min. 2 characters.
+        Ok(version.unwrap())
+    }
 }
 
-/// Decodes Crockford Base32-encoded bytes into a provided buffer.
-///
-/// # Returns
-///
-/// The number of bytes written to the output buffer.
-///
-/// # Errors
-///
-/// This method will return an [`Error`] if:
-///
-/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
-/// - [`Error::InvalidCharacter`], the input contains invalid characters.
-///
-/// # Examples
-///
-/// ```rust
-/// # use c32::Error;
-/// # let mut dst = [0u8; 5];
-/// let offset = c32::decode_into(b"2MAHA", &mut dst)?;
-/// assert_eq!(&dst[..offset], [42, 42, 42]);
-/// assert_eq!(offset, 3);
-/// # Ok::<(), Error>(())
-/// ```
-#[inline]
-pub fn decode_into(src: &[u8], dst: &mut [u8]) -> Result<usize> {
-    // Assert that the buffer has enough capacity.
-    let capacity = decoded_len(src.len());
-    if dst.len() < capacity {
-        return Err(Error::BufferTooSmall {
-            min: capacity,
-            len: dst.len(),
-        });
+/// Private module containing internal methods.
+#[allow(dead_code)]
+mod __internal {
+    use alphabet::Alphabet;
+
+    use super::*;
+
+    /// Encodes bytes into a Crockford Base32-encoded string.
+    ///
+    /// This is the uninstrumented body of [`encode`].
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub(crate) fn encode_string(src: &[u8]) -> String {
+        // Allocate the output buffer.
+        let capacity = encoded_len(src.len());
+        let mut dst = vec![0u8; capacity];
+
+        // This should not panic, as we allocate enough space.
+        let offset = encode_into(src, &mut dst).unwrap();
+        dst.truncate(offset);
+        dst.shrink_to_fit();
+
+        // This should not panic, as we only push valid ASCII.
+        String::from_utf8(dst).unwrap()
     }
 
-    // Encode the input bytes, and return the amount of bytes written.
-    __internal::de(src, 0, src.len(), dst, 0)
-}
+    /// Decodes a Crockford Base32-encoded string.
+    ///
+    /// This is the uninstrumented body of [`decode`].
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub(crate) fn decode_vec(str: &str) -> Result<Vec<u8>> {
+        let bytes = str.as_bytes();
 
-/// Encodes bytes as Crockford Base32Check into a provided buffer.
-///
-/// # Returns
-///
-/// The number of bytes written to the output buffer.
-///
-/// # Errors
-///
-/// This method will return an [`Error`] if:
-///
-/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
-/// - [`Error::InvalidVersion`], the version is 32 or greater.
-///
-/// # Examples
-///
-/// ```rust
-/// # use c32::Error;
-/// # let mut dst = [0u8; 13];
-/// let offset = c32::encode_check_into(&[42, 42, 42], &mut dst, 0)?;
-/// assert_eq!(&dst[..offset], b"0AHA59B9201Z");
-/// assert_eq!(offset, 12);
-/// # Ok::<(), Error>(())
-/// ```
-#[inline]
-#[cfg(feature = "check")]
-pub fn encode_check_into(
-    src: &[u8],
-    dst: &mut [u8],
-    version: u8,
-) -> Result<usize> {
-    // Assert that the buffer has enough capacity.
-    let capacity = encoded_check_len(src.len());
-    if dst.len() < capacity {
-        return Err(Error::BufferTooSmall {
-            min: capacity,
-            len: dst.len(),
-        });
+        // Reject invalid input before allocating the output buffer.
+        validate(bytes)?;
+
+        // Allocate the output buffer.
+        let capacity = decoded_len(bytes.len());
+        let mut dst = vec![0u8; capacity];
+
+        // Decode the input bytes into the buffer.
+        let offset = decode_into(bytes, &mut dst)?;
+        wipe(&mut dst[offset..]);
+        dst.truncate(offset);
+
+        Ok(dst)
     }
 
-    // Assert that the version is valid (< 32).
-    if version >= 32 {
-        return Err(Error::InvalidVersion {
-            expected: "must be < 32",
-            version,
-        });
+    /// Encodes bytes into a Crockford Base32Check-encoded string.
+    ///
+    /// This is the uninstrumented body of [`encode_check`].
+    #[inline]
+    #[cfg(all(feature = "alloc", feature = "check"))]
+    pub(crate) fn encode_check_string(
+        src: &[u8],
+        version: u8,
+    ) -> Result<String> {
+        // Allocate the output string.
+        let capacity = encoded_check_len(src.len());
+        let mut dst = vec![0u8; capacity];
+
+        // This should not panic, as we allocate enough space.
+        let offset = encode_check_into(src, &mut dst, version)?;
+        dst.truncate(offset);
+        dst.shrink_to_fit();
+
+        // This should not panic, as we only push valid ASCII.
+        Ok(String::from_utf8(dst).unwrap())
     }
 
-    // Insert the version character into the output buffer.
-    let mut offset = 0;
-    dst[offset] = ALPHABET[version as usize];
-    offset += 1;
+    /// Decodes a Crockford Base32Check-encoded string.
+    ///
+    /// This is the uninstrumented body of [`decode_check`].
+    #[inline]
+    #[cfg(all(feature = "alloc", feature = "check"))]
+    pub(crate) fn decode_check_vec(str: &str) -> Result<(Vec<u8>, u8)> {
+        let bytes = str.as_bytes();
 
-    // Compute the checksum for the input bytes and version.
-    let sum = checksum::compute(src, version);
+        // Allocate the output buffer.
+        let capacity = decoded_check_len(bytes.len());
+        let mut dst = vec![0u8; capacity];
 
-    // Encode the bytes and checksum.
-    offset +=
-        __internal::en(src, 0, src.len(), &mut dst[offset..], 0, Some(sum));
+        // Decode the input bytes into the buffer.
+        let (offset, version) = decode_check_into(bytes, &mut dst)?;
+        wipe(&mut dst[offset..]);
+        dst.truncate(offset);
 
-    Ok(offset)
-}
+        Ok((dst, version))
+    }
 
-/// Decodes Crockford Base32Check-encoded bytes into a provided buffer.
-///
-/// # Returns
-///
-/// The number of bytes written to the output buffer and the version.
-///
-/// # Errors
-///
-/// This method will return an [`Error`] if:
-///
-/// - [`Error::InvalidCharacter`], the input contains invalid characters.
-/// - [`Error::InsufficientData`], the input has fewer bytes than required.
-/// - [`Error::InvalidVersion`], the version is 32 or greater.
-/// - [`Error::ChecksumMismatch`], the checksum's do not match.
-/// - [`Error::BufferTooSmall`], the output buffer lacks capacity.
-///
-/// # Examples
-///
-/// ```rust
-/// # use c32::Error;
-/// # let mut dst = [0u8; 12];
-/// let (offset, version) = c32::decode_check_into(b"0AHA59B9201Z", &mut dst)?;
-/// assert_eq!(&dst[..offset], [42, 42, 42]);
-/// assert_eq!(version, 0);
-/// # Ok::<(), Error>(())
-/// ```
-#[inline]
-#[cfg(feature = "check")]
-#[allow(clippy::missing_panics_doc)]
-pub fn decode_check_into(src: &[u8], dst: &mut [u8]) -> Result<(usize, u8)> {
-    // Assert that the buffer has enough capacity.
-    let capacity = decoded_check_len(src.len());
-    if dst.len() < capacity {
-        return Err(Error::BufferTooSmall {
-            min: capacity,
-            len: dst.len(),
-        });
+    /// Allocates a zeroed buffer of `len` bytes, returning an error instead
+    /// of aborting if the allocation fails.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub(crate) fn try_zeroed(len: usize) -> Result<Vec<u8>> {
+        let mut dst = Vec::new();
+        dst.try_reserve_exact(len)
+            .map_err(|_| Error::AllocationFailed { requested: len })?;
+        dst.resize(len, 0);
+        Ok(dst)
     }
 
-    // Assert that the input bytes contain the minimum amount.
-    if src.len() < 2 {
-        return Err(Error::InsufficientData {
-            min: 2,
-            len: src.len(),
-        });
+    /// Encodes bytes into a Crockford Base32-encoded string, with an optional
+    /// prefix, without aborting on allocation failure.
+    ///
+    /// This is the body of [`encode_fallible`] and
+    /// [`encode_prefixed_fallible`].
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub(crate) fn try_encode_string(
+        src: &[u8],
+        prefix: Option<char>,
+    ) -> Result<String> {
+        let prefix_len = prefix.map_or(0, char::len_utf8);
+
+        // Allocate the output buffer, an overflowing capacity is requested
+        // as `usize::MAX`, which always fails.
+        let capacity = checked_encoded_len(src.len())
+            .unwrap_or(usize::MAX)
+            .saturating_add(prefix_len);
+        let mut dst = try_zeroed(capacity)?;
+
+        // Write the prefix and encode the input bytes after it.
+        if let Some(prefix) = prefix {
+            prefix.encode_utf8(&mut dst);
+        }
+        let offset = encode_into(src, &mut dst[prefix_len..])?;
+        dst.truncate(prefix_len + offset);
+
+        // This should not panic, as we only push valid UTF-8.
+        Ok(String::from_utf8(dst).unwrap())
+    }
+
+    /// Decodes a Crockford Base32-encoded string, without aborting on
+    /// allocation failure.
+    ///
+    /// This is the body of [`decode_fallible`].
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub(crate) fn try_decode_vec(str: &str) -> Result<Vec<u8>> {
+        let bytes = str.as_bytes();
+
+        // Reject invalid input before allocating the output buffer.
+        validate(bytes)?;
+
+        // Allocate the output buffer.
+        let mut dst = try_zeroed(decoded_len(bytes.len()))?;
+
+        // Decode the input bytes into the buffer.
+        let offset = decode_into(bytes, &mut dst)?;
+        wipe(&mut dst[offset..]);
+        dst.truncate(offset);
+
+        Ok(dst)
     }
 
-    // This should not panic, as the check above ensures enough bytes.
-    let (tag, payload) = src.split_first().unwrap();
+    /// Encodes bytes into a Crockford Base32Check-encoded string, with an
+    /// optional prefix, without aborting on allocation failure.
+    ///
+    /// This is the body of [`encode_check_fallible`] and
+    /// [`encode_check_prefixed_fallible`].
+    #[inline]
+    #[cfg(all(feature = "alloc", feature = "check"))]
+    pub(crate) fn try_encode_check_string(
+        src: &[u8],
+        prefix: Option<char>,
+        version: u8,
+    ) -> Result<String> {
+        let prefix_len = prefix.map_or(0, char::len_utf8);
+
+        // Allocate the output buffer, an overflowing capacity is requested
+        // as `usize::MAX`, which always fails.
+        let capacity = checked_encoded_check_len(src.len())
+            .unwrap_or(usize::MAX)
+            .saturating_add(prefix_len);
+        let mut dst = try_zeroed(capacity)?;
+
+        // Write the prefix and encode the input bytes after it.
+        if let Some(prefix) = prefix {
+            prefix.encode_utf8(&mut dst);
+        }
+        let offset = encode_check_into(src, &mut dst[prefix_len..], version)?;
+        dst.truncate(prefix_len + offset);
 
-    // Decode the version byte.
-    let mut buffer = [0u8; 1];
-    let _ = __internal::de(&[*tag], 0, 1, &mut buffer, 0)?;
-    let version = buffer[0];
+        // This should not panic, as we only push valid UTF-8.
+        Ok(String::from_utf8(dst).unwrap())
+    }
 
-    // Assert that the recovered version is valid. (< 32).
-    if version >= 32 {
-        return Err(Error::InvalidVersion {
-            expected: "must be < 32",
-            version,
-        });
+    /// Decodes a Crockford Base32Check-encoded string, without aborting on
+    /// allocation failure.
+    ///
+    /// This is the body of [`decode_check_fallible`].
+    #[inline]
+    #[cfg(all(feature = "alloc", feature = "check"))]
+    pub(crate) fn try_decode_check_vec(str: &str) -> Result<(Vec<u8>, u8)> {
+        let bytes = str.as_bytes();
+
+        // Allocate the output buffer.
+        let mut dst = try_zeroed(decoded_check_len(bytes.len()))?;
+
+        // Decode the input bytes into the buffer.
+        let (offset, version) = decode_check_into(bytes, &mut dst)?;
+        wipe(&mut dst[offset..]);
+        dst.truncate(offset);
+
+        Ok((dst, version))
     }
 
-    // Decode the remaining bytes into the output buffer.
-    let mut offset = match __internal::de(payload, 0, payload.len(), dst, 0) {
-        Ok(pos) => pos,
-        Err(Error::InvalidCharacter { char, index }) => {
-            return Err(Error::InvalidCharacter {
-                char,
-                index: index + 1,
+    /// Decodes the rest of a string that starts with `prefix` with `de`.
+    ///
+    /// # Notes
+    ///
+    /// - The `index` of an [`Error::InvalidCharacter`] is adjusted to account
+    ///   for the prefix in the original string.
+    #[inline]
+    pub(crate) fn decode_after_prefix<T>(
+        str: &str,
+        prefix: char,
+        de: impl FnOnce(&str) -> Result<T>,
+    ) -> Result<T> {
+        // Assert that the string starts with the prefix.
+        let Some(rest) = str.strip_prefix(prefix) else {
+            return Err(Error::MissingPrefix {
+                char: prefix,
+                got: str.chars().next(),
             });
+        };
+
+        de(rest).map_err(|e| match e {
+            Error::InvalidCharacter {
+                char,
+                index,
+                decoded,
+            } => Error::InvalidCharacter {
+                char,
+                index: index + prefix.len_utf8(),
+                decoded,
+            },
+            e => e,
+        })
+    }
+
+    /// Encodes a byte slice into Crockford Base32.
+    ///
+    /// # Notes
+    ///
+    /// - The output buffer must be properly sized.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn en(
+        src: &[u8],
+        src_offset: usize,
+        src_len: usize,
+        dst: &mut [u8],
+        dst_offset: usize,
+        checksum: Option<[u8; 4]>,
+    ) -> usize {
+        en_with(
+            &alphabet::CROCKFORD,
+            src,
+            src_offset,
+            src_len,
+            dst,
+            dst_offset,
+            checksum,
+        )
+    }
+
+    /// Computes the exact encoded length of `src`, followed by `checksum`.
+    ///
+    /// # Notes
+    ///
+    /// - Leading zero bytes of `src`, and of `checksum` if `src` is all zeros,
+    ///   are encoded as zero characters, matching [`en_with`].
+    #[inline]
+    #[must_use]
+    pub(crate) const fn exact_len(
+        src: &[u8],
+        checksum: Option<[u8; 4]>,
+    ) -> usize {
+        // count leading zeros
+        let mut leading_zeros = leading_zero_bytes(src);
+
+        // count significant bits
+        let mut bits = 0;
+        if leading_zeros < src.len() {
+            bits = (src.len() - leading_zeros) * 8
+                - src[leading_zeros].leading_zeros() as usize;
+            if checksum.is_some() {
+                bits += 32;
+            }
+        } else if let Some(sum) = checksum {
+            let i = leading_zero_bytes(&sum);
+            if i < sum.len() {
+                bits = (sum.len() - i) * 8 - sum[i].leading_zeros() as usize;
+            }
+            leading_zeros += i;
         }
-        Err(e) => return Err(e),
-    };
 
-    // Extract the checksum.
-    offset -= checksum::BYTE_LENGTH;
-    let sum =
-        checksum::from_slice(&dst[offset..offset + checksum::BYTE_LENGTH]);
+        leading_zeros + bits.div_ceil(5)
+    }
 
-    // Compute the expected checksum.
-    let expected = checksum::compute(&dst[..offset], version);
+    /// Returns the exact decoded length of `src` without `overhead` bytes, if
+    /// it exceeds `max`.
+    ///
+    /// # Notes
+    ///
+    /// - The exact computation is skipped when the upper bound of
+    ///   [`decoded_len`] is already within `max`.
+    /// - With [`WHITESPACE`], the whitespace is not counted.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn exceeds_limit<const POLICY: u8>(
+        src: &[u8],
+        overhead: usize,
+        max: usize,
+    ) -> Option<usize> {
+        let len = significant_len::<POLICY>(src);
+        if decoded_len(len).saturating_sub(overhead) <= max {
+            return None;
+        }
 
-    // Assert that the computed and actual checksums match.
-    if !__internal::memcmp(&expected, &sum, checksum::BYTE_LENGTH) {
-        return Err(Error::ChecksumMismatch { expected, got: sum });
+        let exact = if POLICY == WHITESPACE {
+            decoded_before::<POLICY>(
+                &alphabet::CROCKFORD,
+                src,
+                0,
+                src.len(),
+                src.len(),
+            )
+        } else {
+            decoded_len_exact(src)
+        };
+
+        let got = exact.saturating_sub(overhead);
+        if got > max {
+            return Some(got);
+        }
+        None
     }
 
-    Ok((offset, version))
-}
+    /// Computes the exact decoded length of `src`.
+    ///
+    /// # Notes
+    ///
+    /// - Leading zero characters are decoded as zero bytes, matching
+    ///   [`de_with`]. An invalid character is counted as 5 significant bits.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub(crate) const fn decoded_len_exact(src: &[u8]) -> usize {
+        let leading_zeros = leading_zero_chars(src);
+        if leading_zeros == src.len() {
+            return leading_zeros;
+        }
+
+        // count significant bits
+        let value = alphabet::CROCKFORD.value(src[leading_zeros]);
+        let top = if value == INVALID {
+            5
+        } else {
+            8 - value.leading_zeros() as usize
+        };
+        let bits = (src.len() - leading_zeros - 1) * 5 + top;
 
-/// Private module containing internal methods.
-#[allow(dead_code)]
-mod __internal {
-    use super::*;
+        leading_zeros + bits.div_ceil(8)
+    }
 
-    /// Encodes a byte slice into Crockford Base32.
+    /// Encodes a byte slice using the provided alphabet.
     ///
     /// # Notes
     ///
     /// - The output buffer must be properly sized.
     #[inline]
     #[must_use]
-    pub(crate) const fn en(
+    pub(crate) const fn en_with(
+        alphabet: &Alphabet,
         src: &[u8],
         src_offset: usize,
         src_len: usize,
@@ -1924,6 +14555,11 @@ mod __internal {
             leading_zeros += 1;
         }
 
+        // count leading zeros of the checksum, if the input is all zeros
+        if let (true, Some(sum)) = (leading_zeros == src_len, checksum) {
+            leading_zeros += leading_zero_bytes(&sum);
+        }
+
         // process checksum if provided
         if let Some(sum) = checksum {
             let mut checksum_pos = sum.len();
@@ -1937,7 +14573,7 @@ mod __internal {
                 // extract 5-bit chunks
                 while carry_bits >= SHIFT_5 {
                     // write character from chunk
-                    dst[dst_pos] = ALPHABET[(carry & MASK_5) as usize];
+                    dst[dst_pos] = alphabet.char((carry & MASK_5) as usize);
                     dst_pos += 1;
 
                     // shift out processed bytes
@@ -1959,7 +14595,7 @@ mod __internal {
             // extract 5-bit chunks
             while carry_bits >= SHIFT_5 {
                 // write character from chunk
-                dst[dst_pos] = ALPHABET[(carry & MASK_5) as usize];
+                dst[dst_pos] = alphabet.char((carry & MASK_5) as usize);
                 dst_pos += 1;
 
                 // shift out processed bits
@@ -1970,47 +14606,654 @@ mod __internal {
 
         // process remaining bits
         if carry_bits > 0 && carry > 0 {
-            dst[dst_pos] = ALPHABET[(carry & MASK_5) as usize];
+            dst[dst_pos] = alphabet.char((carry & MASK_5) as usize);
+            dst_pos += 1;
+        }
+
+        // truncate trailing zeros
+        while dst_pos > dst_offset && dst[dst_pos - 1] == alphabet.char(0) {
+            dst_pos -= 1;
+        }
+
+        // restore leading zeros from input
+        let mut j = 0;
+        while j < leading_zeros {
+            dst[dst_pos] = alphabet.char(0);
             dst_pos += 1;
+            j += 1;
+        }
+
+        // reverse the buffer
+        if dst_pos > dst_offset {
+            let mut lhs = dst_offset;
+            let mut rhs = dst_pos - 1;
+            while lhs < rhs {
+                let temp = dst[lhs];
+                dst[lhs] = dst[rhs];
+                dst[rhs] = temp;
+                lhs += 1;
+                rhs -= 1;
+            }
+        }
+
+        dst_pos - dst_offset
+    }
+
+    /// Decodes Crockford Base32-encoded bytes.
+    ///
+    /// # Notes
+    ///
+    /// - The output buffer must be properly sized.
+    /// - See [`de_with`] for the `POLICY` parameter.
+    #[inline]
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub(crate) const fn de<const POLICY: u8>(
+        src: &[u8],
+        src_offset: usize,
+        src_len: usize,
+        dst: &mut [u8],
+        dst_offset: usize,
+    ) -> Result<usize> {
+        de_with::<POLICY>(
+            &alphabet::CROCKFORD,
+            src,
+            src_offset,
+            src_len,
+            dst,
+            dst_offset,
+        )
+    }
+
+    /// Encodes bytes as Base32Check, using the given [`Sha256Backend`].
+    ///
+    /// # Generics
+    ///
+    /// * `SYMBOL` - Whether the checksum is computed over the version
+    ///   character, see [`checksum::compute_over_symbol`], rather than the
+    ///   numeric version.
+    ///
+    /// [`Sha256Backend`]: checksum::Sha256Backend
+    #[inline]
+    #[cfg(feature = "check-core")]
+    pub(crate) fn en_check<H: checksum::Sha256Backend, const SYMBOL: bool>(
+        alphabet: &Alphabet,
+        src: &[u8],
+        dst: &mut [u8],
+        version: u8,
+    ) -> Result<usize> {
+        // Assert that the buffer has enough capacity.
+        let capacity = encoded_check_len(src.len());
+        if dst.len() < capacity {
+            return Err(Error::BufferTooSmall {
+                min: capacity,
+                len: dst.len(),
+            });
+        }
+
+        // Assert that the version is valid (< 32).
+        if version >= 32 {
+            return Err(Error::InvalidVersion {
+                expected: "must be < 32",
+                version,
+            });
+        }
+
+        // Insert the version character into the output buffer.
+        let mut offset = 0;
+        let symbol = alphabet.as_bytes()[version as usize];
+        dst[offset] = symbol;
+        offset += 1;
+
+        // Compute the checksum for the input bytes and version.
+        let tag = if SYMBOL { symbol } else { version };
+        let sum = checksum::compute_with_backend::<H>(src, tag);
+
+        // Encode the bytes and checksum.
+        offset += __internal::en_with(
+            alphabet,
+            src,
+            0,
+            src.len(),
+            &mut dst[offset..],
+            0,
+            Some(sum),
+        );
+
+        Ok(offset)
+    }
+
+    /// A checksum computation over a Base32Check payload, see [`de_check`].
+    #[cfg(feature = "check-core")]
+    pub(crate) trait CheckHasher {
+        /// Starts the computation over the version `tag`.
+        fn new(tag: u8) -> Self;
+
+        /// Feeds the next run of the payload, as it is decoded.
+        fn update(&mut self, bytes: &[u8]);
+
+        /// Finishes the computation, given the whole decoded `payload`.
+        fn finish(self, payload: &[u8]) -> checksum::Checksum;
+    }
+
+    /// A [`CheckHasher`] that computes the checksum with the given
+    /// [`Sha256Backend`] once the payload is decoded.
+    ///
+    /// [`Sha256Backend`]: checksum::Sha256Backend
+    #[cfg(feature = "check-core")]
+    pub(crate) struct Deferred<H> {
+        /// The version tag.
+        tag: u8,
+        /// The backend computing the checksum.
+        backend: marker::PhantomData<H>,
+    }
+
+    #[cfg(feature = "check-core")]
+    impl<H: checksum::Sha256Backend> CheckHasher for Deferred<H> {
+        #[inline]
+        fn new(tag: u8) -> Self {
+            Self {
+                tag,
+                backend: marker::PhantomData,
+            }
+        }
+
+        #[inline]
+        fn update(&mut self, _: &[u8]) {}
+
+        #[inline]
+        fn finish(self, payload: &[u8]) -> checksum::Checksum {
+            checksum::compute_with_backend::<H>(payload, self.tag)
+        }
+    }
+
+    /// A [`CheckHasher`] that hashes the payload as it is decoded, using the
+    /// built-in SHA-256 implementation.
+    #[cfg(feature = "check")]
+    pub(crate) struct Incremental(sha2::Sha256);
+
+    #[cfg(feature = "check")]
+    impl CheckHasher for Incremental {
+        #[inline]
+        fn new(tag: u8) -> Self {
+            Self(sha2::Sha256::new().update(&[tag]))
+        }
+
+        #[inline]
+        fn update(&mut self, bytes: &[u8]) {
+            let hasher = core::mem::replace(&mut self.0, sha2::Sha256::new());
+            self.0 = hasher.update(bytes);
+        }
+
+        #[inline]
+        fn finish(self, _: &[u8]) -> checksum::Checksum {
+            let hash =
+                sha2::Sha256::new().update(&self.0.finalize()).finalize();
+            checksum::from_slice(&hash)
+        }
+    }
+
+    /// Decodes Base32Check bytes, computing the checksum with `C`.
+    ///
+    /// The payload is decoded directly into the output buffer, while the
+    /// checksum is held back in a local array, so the output buffer is left
+    /// untouched past the returned offset.
+    ///
+    /// # Generics
+    ///
+    /// * `C` - The [`CheckHasher`], either [`Incremental`] for the built-in
+    ///   implementation, or [`Deferred`] for a [`Sha256Backend`].
+    /// * `SYMBOL` - Whether the checksum is computed over the canonical version
+    ///   character of the alphabet, rather than the numeric version.
+    /// * `POLICY` - Either [`STRICT`] or [`WHITESPACE`], see [`de_with`].
+    ///
+    /// [`Sha256Backend`]: checksum::Sha256Backend
+    #[inline]
+    #[cfg(feature = "check-core")]
+    #[allow(clippy::missing_panics_doc)]
+    pub(crate) fn de_check<
+        C: CheckHasher,
+        const SYMBOL: bool,
+        const POLICY: u8,
+    >(
+        alphabet: &Alphabet,
+        src: &[u8],
+        dst: &mut [u8],
+    ) -> Result<(usize, u8)> {
+        let len = significant_len::<POLICY>(src);
+
+        // Assert that the buffer has enough capacity.
+        let capacity = decoded_check_len(len);
+        if dst.len() < capacity {
+            return Err(Error::BufferTooSmall {
+                min: capacity,
+                len: dst.len(),
+            });
+        }
+
+        // Assert that the input bytes contain the minimum amount.
+        if len < 2 {
+            return Err(Error::InsufficientData { min: 2, len });
+        }
+
+        // Skip the whitespace before the version character. This should not
+        // panic, as the check above ensures enough bytes.
+        let start = if POLICY == WHITESPACE {
+            src.iter().position(|&byte| !is_whitespace(byte)).unwrap()
+        } else {
+            0
+        };
+        let (tag, payload) = src[start..].split_first().unwrap();
+
+        // Decode the version byte.
+        let mut buffer = [0u8; 1];
+        if let Err(Error::InvalidCharacter { char, decoded, .. }) =
+            __internal::de_with::<STRICT>(
+                alphabet,
+                &[*tag],
+                0,
+                1,
+                &mut buffer,
+                0,
+            )
+        {
+            return Err(Error::InvalidCharacter {
+                char,
+                index: start,
+                decoded,
+            });
+        }
+        let version = buffer[0];
+
+        // Assert that the recovered version is valid. (< 32).
+        if version >= 32 {
+            return Err(Error::InvalidVersion {
+                expected: "must be < 32",
+                version,
+            });
+        }
+
+        // Start the checksum computation over the version.
+        let tag = if SYMBOL {
+            alphabet.as_bytes()[version as usize]
+        } else {
+            version
+        };
+        let mut hasher = C::new(tag);
+
+        // Decode the payload into the output buffer, and the checksum apart.
+        let (offset, mut sum) = match __stream::unpack_check::<_, POLICY>(
+            alphabet,
+            payload,
+            dst,
+            |run| hasher.update(run),
+        ) {
+            Ok(result) => result,
+            Err(Error::InvalidCharacter {
+                char,
+                index,
+                decoded,
+            }) => {
+                return Err(Error::InvalidCharacter {
+                    char,
+                    index: start + 1 + index,
+                    decoded,
+                });
+            }
+            Err(e) => return Err(e),
+        };
+
+        // Assert that the computed and actual checksums match.
+        let mut expected = hasher.finish(&dst[..offset]);
+        if expected != sum {
+            __internal::clear(&mut dst[..offset]);
+            return Err(Error::ChecksumMismatch {
+                expected,
+                got: sum,
+                version,
+                len: offset,
+            });
+        }
+
+        // Clear the checksum scratch.
+        __internal::wipe(&mut expected);
+        __internal::wipe(&mut sum);
+
+        Ok((offset, version))
+    }
+
+    /// Splits the checksum off the bytes decoded into `dst[..pos]`, and
+    /// verifies it against the payload.
+    ///
+    /// The checksum bytes are cleared from `dst`, so that nothing but the
+    /// payload remains in the buffer.
+    ///
+    /// # Returns
+    ///
+    /// The length of the payload.
+    #[inline]
+    #[cfg(feature = "check")]
+    pub(crate) const fn split_check(
+        dst: &mut [u8],
+        pos: usize,
+        version: u8,
+    ) -> Result<usize> {
+        // Assert that the decoded bytes contain a checksum.
+        if pos < checksum::BYTE_LENGTH {
+            return Err(Error::InsufficientData {
+                min: checksum::BYTE_LENGTH,
+                len: pos,
+            });
+        }
+        let len = pos - checksum::BYTE_LENGTH;
+
+        // Move the checksum out of the buffer.
+        let mut sum = [0u8; checksum::BYTE_LENGTH];
+        memcpy(&mut sum, 0, dst, len, checksum::BYTE_LENGTH);
+        let mut i = len;
+        while i < pos {
+            dst[i] = 0;
+            i += 1;
+        }
+
+        // Assert that the computed and actual checksums match.
+        let (payload, _) = dst.split_at(len);
+        let expected = checksum::compute(payload, version);
+        if !checksum_eq(expected, sum) {
+            return Err(Error::ChecksumMismatch {
+                expected,
+                got: sum,
+                version,
+                len,
+            });
+        }
+
+        Ok(len)
+    }
+
+    /// Encodes `src` into a [`Buffer`] array, after the optional prefix and
+    /// version characters, appending the checksum if a version is given.
+    ///
+    /// This is the shared body of the [`Buffer`] encoding methods, which only
+    /// allocate the array, so that it is not monomorphized per size `N`.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to `dst`.
+    #[inline(never)]
+    pub(crate) const fn buffer_en(
+        src: &[u8],
+        dst: &mut [u8],
+        prefix: Option<char>,
+        version: Option<u8>,
+    ) -> Result<usize> {
+        // Assert that the buffer has enough capacity.
+        let capacity = match version {
+            #[cfg(feature = "check")]
+            Some(_) => encoded_check_len(src.len()),
+            _ => encoded_len(src.len()),
+        } + prefix.is_some() as usize;
+        if dst.len() < capacity {
+            return Err(Error::BufferTooSmall {
+                min: capacity,
+                len: dst.len(),
+            });
+        }
+
+        let mut offset = 0;
+
+        // Prepend the prefix character, asserting that it is ASCII.
+        if let Some(prefix) = prefix {
+            if !prefix.is_ascii() {
+                return Err(Error::InvalidCharacter {
+                    char: prefix,
+                    index: 0,
+                    decoded: 0,
+                });
+            }
+            dst[offset] = prefix as u8;
+            offset += 1;
+        }
+
+        // Prepend the version character, asserting that it is valid (< 32).
+        #[cfg(feature = "check")]
+        if let Some(version) = version {
+            if version >= 32 {
+                return Err(Error::InvalidVersion {
+                    expected: "must be < 32",
+                    version,
+                });
+            }
+            dst[offset] = ALPHABET[version as usize];
+            offset += 1;
+        }
+
+        // Compute the checksum.
+        let sum = match version {
+            #[cfg(feature = "check")]
+            Some(version) => Some(checksum::compute(src, version)),
+            _ => None,
+        };
+
+        // Encode the input (and checksum) to the buffer.
+        Ok(en(src, 0, src.len(), dst, offset, sum) + offset)
+    }
+
+    /// Decodes `src` into a [`Buffer`] array, after the optional prefix, and
+    /// splits off the version and checksum if `check` is set.
+    ///
+    /// This is the shared body of the [`Buffer`] decoding methods, which only
+    /// allocate the array, so that it is not monomorphized per size `N`.
+    ///
+    /// # Returns
+    ///
+    /// The length of the payload in `dst`, and the version (or `0`).
+    #[inline(never)]
+    pub(crate) const fn buffer_de(
+        src: &[u8],
+        dst: &mut [u8],
+        prefix: Option<char>,
+        check: bool,
+    ) -> Result<(usize, u8)> {
+        let mut offset = 0;
+
+        if let Some(prefix) = prefix {
+            // Assert that the prefix is ASCII.
+            if !prefix.is_ascii() {
+                return Err(Error::InvalidCharacter {
+                    char: prefix,
+                    index: 0,
+                    decoded: 0,
+                });
+            }
+
+            // Assert that the input is not empty.
+            if src.is_empty() {
+                return Err(Error::MissingPrefix {
+                    char: prefix,
+                    got: None,
+                });
+            }
+
+            // Assert that the string starts with the prefix, which the
+            // checked variants only do after the length checks.
+            if !check && src[0] != prefix as u8 {
+                return Err(Error::MissingPrefix {
+                    char: prefix,
+                    got: Some(src[0] as char),
+                });
+            }
+            offset += 1;
+        }
+
+        // Assert that the buffer has enough capacity.
+        let capacity = match check {
+            #[cfg(feature = "check")]
+            true => decoded_check_len(src.len() - offset),
+            _ => decoded_len(src.len() - offset),
+        };
+        if dst.len() < capacity {
+            return Err(Error::BufferTooSmall {
+                min: capacity,
+                len: dst.len(),
+            });
+        }
+
+        #[cfg(not(feature = "check"))]
+        let version = 0;
+        #[cfg(feature = "check")]
+        let mut version = 0;
+        #[cfg(feature = "check")]
+        if check {
+            // Assert that the input bytes contain the minimum amount.
+            if src.len() < offset + 2 {
+                return Err(Error::InsufficientData {
+                    min: offset + 2,
+                    len: src.len(),
+                });
+            }
+
+            // Assert that the string starts with the prefix.
+            if let Some(prefix) = prefix {
+                if src[0] != prefix as u8 {
+                    return Err(Error::MissingPrefix {
+                        char: prefix,
+                        got: Some(src[0] as char),
+                    });
+                }
+            }
+
+            // Extract the version byte.
+            let mut buffer = [0u8; 1];
+            if let Err(err) = de::<STRICT>(src, offset, 1, &mut buffer, 0) {
+                return Err(err);
+            }
+
+            // Assert that the version is valid (< 32).
+            version = buffer[0];
+            if version >= 32 {
+                return Err(Error::InvalidVersion {
+                    expected: "must be < 32",
+                    version,
+                });
+            }
+            offset += 1;
+        }
+
+        // Decode the remaining bytes into the buffer.
+        let pos = match de::<STRICT>(src, offset, src.len() - offset, dst, 0) {
+            Ok(pos) => pos,
+            Err(Error::InvalidCharacter {
+                char,
+                index,
+                decoded,
+            }) => {
+                return Err(Error::InvalidCharacter {
+                    char,
+                    index: index + offset,
+                    decoded,
+                });
+            }
+            Err(e) => return Err(e),
+        };
+
+        // Split off and verify the checksum.
+        #[cfg(feature = "check")]
+        if check {
+            return match split_check(dst, pos, version) {
+                Ok(pos) => Ok((pos, version)),
+                Err(err) => Err(err),
+            };
         }
 
-        // truncate trailing zeros
-        while dst_pos > dst_offset && dst[dst_pos - 1] == ALPHABET[0] {
-            dst_pos -= 1;
-        }
+        Ok((pos, version))
+    }
 
-        // restore leading zeros from input
-        let mut j = 0;
-        while j < leading_zeros {
-            dst[dst_pos] = ALPHABET[0];
-            dst_pos += 1;
-            j += 1;
+    /// Unwraps the result of [`buffer_en`], panicking with a message for
+    /// each error, as the const methods of [`Buffer`] do.
+    #[inline]
+    #[track_caller]
+    pub(crate) const fn expect_buffer_en(result: Result<usize>) -> usize {
+        match result {
+            Ok(pos) => pos,
+            Err(Error::BufferTooSmall { .. }) => {
+                panic!("Size 'N' is too small")
+            }
+            Err(Error::InvalidCharacter { .. }) => {
+                panic!("Prefix must be an ASCII character")
+            }
+            Err(_) => panic!("Version must be < 32"),
         }
+    }
 
-        // reverse the buffer
-        if dst_pos > dst_offset {
-            let mut lhs = dst_offset;
-            let mut rhs = dst_pos - 1;
-            while lhs < rhs {
-                let temp = dst[lhs];
-                dst[lhs] = dst[rhs];
-                dst[rhs] = temp;
-                lhs += 1;
-                rhs -= 1;
+    /// Unwraps the result of [`buffer_de`], panicking with a message for
+    /// each error, as the const methods of [`Buffer`] do.
+    #[inline]
+    #[track_caller]
+    pub(crate) const fn expect_buffer_de(
+        result: Result<(usize, u8)>,
+    ) -> (usize, u8) {
+        match result {
+            Ok(result) => result,
+            Err(Error::BufferTooSmall { .. }) => {
+                panic!("Size 'N' is too small")
+            }
+            Err(Error::InvalidCharacter { .. }) => {
+                panic!("Input must not contain invalid characters")
+            }
+            Err(Error::MissingPrefix { .. }) => {
+                panic!("Input must start with prefix")
             }
+            #[cfg(feature = "check-core")]
+            Err(Error::InsufficientData { min: 4, .. }) => {
+                panic!("Input must contain a checksum")
+            }
+            #[cfg(feature = "check-core")]
+            Err(Error::InsufficientData { .. }) => {
+                panic!("Input is too short")
+            }
+            #[cfg(feature = "check-core")]
+            Err(Error::InvalidVersion { .. }) => {
+                panic!("Version must be < 32")
+            }
+            Err(_) => panic!("Checksum mismatch"),
         }
-
-        dst_pos - dst_offset
     }
 
-    /// Decodes Crockford Base32-encoded bytes.
+    /// Rejects invalid characters, see [`de_with`].
+    pub(crate) const STRICT: u8 = 0;
+
+    /// Masks invalid characters to 5 bits, see [`de_with`].
+    pub(crate) const UNCHECKED: u8 = 1;
+
+    /// Substitutes invalid characters with zero, see [`de_with`].
+    pub(crate) const LOSSY: u8 = 2;
+
+    /// Skips ASCII whitespace, and rejects other invalid characters, see
+    /// [`de_with`].
+    pub(crate) const WHITESPACE: u8 = 3;
+
+    /// Decodes bytes using the provided alphabet.
+    ///
+    /// # Generics
+    ///
+    /// * `POLICY` - The handling of invalid characters:
+    ///   - [`STRICT`] returns [`Error::InvalidCharacter`] for the last one.
+    ///   - [`UNCHECKED`] compiles the checks away, and masks the values to 5
+    ///     bits, so an invalid character decodes as some valid symbol, and the
+    ///     output is bounded as for valid input.
+    ///   - [`LOSSY`] decodes them as `0`, and pads the output to the length of
+    ///     the input with them substituted with `1`, see [`lossy_len`]. This
+    ///     never fails.
+    ///   - [`WHITESPACE`] skips ASCII space, tab, CR and LF, and otherwise
+    ///     behaves as [`STRICT`], reporting the index into the input.
     ///
     /// # Notes
     ///
     /// - The output buffer must be properly sized.
     #[inline]
     #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
-    pub(crate) const fn de(
+    pub(crate) const fn de_with<const POLICY: u8>(
+        alphabet: &Alphabet,
         src: &[u8],
         src_offset: usize,
         src_len: usize,
@@ -2025,33 +15268,67 @@ mod __internal {
         let mut dst_pos = dst_offset;
 
         // count leading zeros
-        let mut leading_zeros = 0;
-        while leading_zeros < src_len
-            && src[src_offset + leading_zeros] == ALPHABET[0]
-        {
-            leading_zeros += 1;
-        }
+        let leading_zeros =
+            leading_zeros_with::<POLICY>(alphabet, src, src_offset, src_len);
 
-        // process characters in reverse
+        // Process blocks of 8 characters in reverse, which decode to exactly
+        // 5 bytes. Valid values are below 32, so the block contains an
+        // invalid character if and only if their union is the sentinel, in
+        // which case the scalar loop below locates it.
         let mut input_pos = src_offset + src_len;
+        while input_pos - src_offset >= 8 {
+            let mut block = 0u64;
+            let mut union = 0u8;
+
+            let mut k = 0;
+            while k < 8 {
+                let byte = src[input_pos - 1 - k];
+                let value = value_with::<POLICY>(alphabet, byte);
+                block |= (value as u64) << (5 * k);
+                union |= value;
+                k += 1;
+            }
+            if (POLICY == STRICT || POLICY == WHITESPACE) && union == INVALID {
+                break;
+            }
+
+            let mut k = 0;
+            while k < 5 {
+                dst[dst_pos] = (block >> (8 * k)) as u8;
+                dst_pos += 1;
+                k += 1;
+            }
+            input_pos -= 8;
+        }
+
+        // process remaining characters in reverse
         while input_pos > src_offset {
             input_pos -= 1;
 
-            // fetch the byte
+            // convert the byte to a map index
             let byte = src[input_pos];
-            if byte >= 128 {
-                return Err(Error::InvalidCharacter {
-                    char: byte as char,
-                    index: input_pos - src_offset,
-                });
+            if POLICY == WHITESPACE && is_whitespace(byte) {
+                continue;
             }
+            let index = value_with::<POLICY>(alphabet, byte);
+            if (POLICY == STRICT || POLICY == WHITESPACE) && index == INVALID {
+                // Clear the bytes written so far, in every build.
+                let mut j = dst_offset;
+                while j < dst_pos {
+                    dst[j] = 0;
+                    j += 1;
+                }
 
-            // convert the byte to a map index
-            let index = BYTE_MAP[byte as usize];
-            if index < 0 {
                 return Err(Error::InvalidCharacter {
                     char: byte as char,
                     index: input_pos - src_offset,
+                    decoded: decoded_before::<POLICY>(
+                        alphabet,
+                        src,
+                        src_offset,
+                        src_len,
+                        input_pos - src_offset,
+                    ),
                 });
             }
 
@@ -2082,6 +15359,16 @@ mod __internal {
             dst_pos -= 1;
         }
 
+        // pad substituted high-order zeros
+        if POLICY == LOSSY {
+            let len =
+                lossy_len(alphabet, src, src_offset, src_len, leading_zeros);
+            while dst_pos - dst_offset < len {
+                dst[dst_pos] = 0;
+                dst_pos += 1;
+            }
+        }
+
         // restore leading zeros from input
         let mut j = 0;
         while j < leading_zeros {
@@ -2106,6 +15393,429 @@ mod __internal {
         Ok(dst_pos - dst_offset)
     }
 
+    /// Maps a byte to its value under `POLICY`, see [`de_with`].
+    #[inline]
+    #[must_use]
+    const fn value_with<const POLICY: u8>(alphabet: &Alphabet, byte: u8) -> u8 {
+        let value = alphabet.value(byte);
+        if POLICY == UNCHECKED {
+            value & 0x1F
+        } else if POLICY == LOSSY && value == INVALID {
+            0
+        } else {
+            value
+        }
+    }
+
+    /// Counts the leading zero characters under `POLICY`, see [`de_with`].
+    #[inline]
+    #[must_use]
+    const fn leading_zeros_with<const POLICY: u8>(
+        alphabet: &Alphabet,
+        src: &[u8],
+        src_offset: usize,
+        src_len: usize,
+    ) -> usize {
+        let mut leading_zeros = 0;
+        let mut i = 0;
+        while i < src_len {
+            let byte = src[src_offset + i];
+            i += 1;
+            if POLICY == WHITESPACE && is_whitespace(byte) {
+                continue;
+            }
+            if alphabet.value(byte) != 0 {
+                break;
+            }
+            leading_zeros += 1;
+        }
+        leading_zeros
+    }
+
+    /// Computes the length of the output under [`LOSSY`], before the
+    /// `leading_zeros` are restored, see [`de_with`].
+    ///
+    /// This is the length of the input with every invalid character
+    /// substituted with `1`, which only affects the most significant one.
+    #[inline]
+    #[must_use]
+    const fn lossy_len(
+        alphabet: &Alphabet,
+        src: &[u8],
+        src_offset: usize,
+        src_len: usize,
+        leading_zeros: usize,
+    ) -> usize {
+        if leading_zeros == src_len {
+            return 0;
+        }
+
+        let value = match alphabet.value(src[src_offset + leading_zeros]) {
+            INVALID => 1,
+            value => value,
+        };
+        let top = 8 - value.leading_zeros() as usize;
+        let bits = (src_len - leading_zeros - 1) * 5 + top;
+        bits.div_ceil(8)
+    }
+
+    /// Encodes the unsigned number `value` into all of `dst`, left-padded
+    /// with the zero symbol.
+    ///
+    /// # Notes
+    ///
+    /// - The bits of `value` above `5 * dst.len()` are discarded.
+    #[inline]
+    pub(crate) fn en_fixed(mut value: u128, dst: &mut [u8]) {
+        for byte in dst.iter_mut().rev() {
+            *byte = ALPHABET[(value & 0x1F) as usize];
+            value >>= 5;
+        }
+    }
+
+    /// Decodes an unsigned number of `bytes` bytes from exactly
+    /// [`encoded_len`]`(bytes)` symbols, see [`en_fixed`].
+    ///
+    /// # Notes
+    ///
+    /// - The number of bytes must be at most 16.
+    #[inline]
+    pub(crate) fn de_fixed(src: &[u8], bytes: usize) -> Result<u128> {
+        validate(src)?;
+
+        // Assert that the input has the fixed width.
+        let width = encoded_len(bytes);
+        if src.len() != width {
+            return Err(Error::InvalidDataSize {
+                expected: width,
+                got: src.len(),
+            });
+        }
+
+        // Assert that the top symbol only carries the bits of the value, as
+        // the input would otherwise decode to more than `bytes` bytes.
+        let top_bits = bytes * 8 - 5 * (width - 1);
+        if BYTE_MAP[src[0] as usize] >> top_bits != 0 {
+            return Err(Error::OutputTooLarge {
+                max: bytes,
+                got: decoded_len_exact(src),
+            });
+        }
+
+        let mut value = 0u128;
+        for &byte in src {
+            value = (value << 5) | u128::from(BYTE_MAP[byte as usize]);
+        }
+        Ok(value)
+    }
+
+    /// Reads the field starting at `pos` from framed bytes.
+    ///
+    /// # Returns
+    ///
+    /// The start and end offsets of the field's bytes.
+    #[inline]
+    pub(crate) fn frame(
+        src: &[u8],
+        pos: usize,
+        field: usize,
+    ) -> Result<(usize, usize)> {
+        let mut len = 0usize;
+        let mut shift = 0;
+        let mut i = pos;
+
+        loop {
+            // Assert that the length prefix is complete.
+            if i >= src.len() {
+                return Err(Error::InvalidFrame {
+                    field,
+                    reason: "truncated length prefix",
+                });
+            }
+
+            let byte = src[i];
+            i += 1;
+
+            // Assert that the length fits into a usize.
+            let value = (byte & 0x7F) as usize;
+            if shift >= usize::BITS || value > (usize::MAX >> shift) {
+                return Err(Error::InvalidFrame {
+                    field,
+                    reason: "length prefix overflows",
+                });
+            }
+            len |= value << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                // Assert that the length prefix is minimal.
+                if byte == 0 && i - pos > 1 {
+                    return Err(Error::InvalidFrame {
+                        field,
+                        reason: "non-canonical length prefix",
+                    });
+                }
+                break;
+            }
+        }
+
+        // Assert that the field fits into the remaining bytes.
+        if len > src.len() - i {
+            return Err(Error::InvalidFrame {
+                field,
+                reason: "length exceeds the input",
+            });
+        }
+
+        Ok((i, i + len))
+    }
+
+    /// Counts the leading Crockford Base32 characters in a byte slice.
+    ///
+    /// # Notes
+    ///
+    /// - The input slice must be properly sized.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn span(
+        src: &[u8],
+        src_offset: usize,
+        src_len: usize,
+    ) -> usize {
+        let mut i = 0;
+        while i < src_len {
+            if !is_valid(src[src_offset + i]) {
+                break;
+            }
+            i += 1;
+        }
+        i
+    }
+
+    /// The number of significant characters in a block, by the number of
+    /// bytes in the block.
+    pub(crate) const BLOCK_CHARS: [usize; 6] = [0, 2, 4, 5, 7, 8];
+
+    /// Overwrites the bytes a failed call wrote to the caller's buffer with
+    /// zeros.
+    ///
+    /// # Notes
+    ///
+    /// - Unlike [`wipe`], this clears the buffer in every build, so that the
+    ///   contents seen by the caller do not depend on the `zeroize` feature.
+    #[inline]
+    pub(crate) fn clear(buf: &mut [u8]) {
+        buf.fill(0);
+    }
+
+    /// Overwrites a buffer with zeros, if the `zeroize` feature is enabled.
+    ///
+    /// # Notes
+    ///
+    /// - Without the feature, this is a no-op.
+    #[inline]
+    #[allow(unused_variables)]
+    pub(crate) fn wipe(buf: &mut [u8]) {
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(buf);
+    }
+
+    /// Builds the Crockford decoding map from the alphabet.
+    ///
+    /// # Notes
+    ///
+    /// - Lowercase characters decode like their uppercase counterparts, and
+    ///   `I`, `L` and `O` are accepted as aliases for `1`, `1` and `0`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn crockford_map(chars: &[u8; 32]) -> [u8; 256] {
+        let mut map = [INVALID; 256];
+
+        let mut i = 0;
+        while i < chars.len() {
+            let byte = chars[i];
+            map[byte as usize] = i as u8;
+            map[byte.to_ascii_lowercase() as usize] = i as u8;
+            i += 1;
+        }
+
+        // map the lenient aliases
+        let mut j = 0;
+        while j < 3 {
+            let (alias, value) = [(b'I', 1), (b'L', 1), (b'O', 0)][j];
+            map[alias as usize] = value;
+            map[alias.to_ascii_lowercase() as usize] = value;
+            j += 1;
+        }
+
+        map
+    }
+
+    /// Checks whether a byte is a Crockford Base32 character.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn is_valid(byte: u8) -> bool {
+        BYTE_MAP[byte as usize] != INVALID
+    }
+
+    /// Checks whether a byte is ASCII space, tab, CR or LF.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn is_whitespace(byte: u8) -> bool {
+        matches!(byte, b' ' | b'\t' | b'\r' | b'\n')
+    }
+
+    /// Counts the bytes of `src` which are decoded under `POLICY`, see
+    /// [`de_with`].
+    #[inline]
+    #[must_use]
+    pub(crate) const fn significant_len<const POLICY: u8>(src: &[u8]) -> usize {
+        if POLICY != WHITESPACE {
+            return src.len();
+        }
+
+        let mut len = 0;
+        let mut i = 0;
+        while i < src.len() {
+            if !is_whitespace(src[i]) {
+                len += 1;
+            }
+            i += 1;
+        }
+        len
+    }
+
+    /// Counts the leading output bytes which the characters before `index`
+    /// decode to, see [`Error::InvalidCharacter`].
+    ///
+    /// This is the number of bytes a streaming decoder writes before it stops
+    /// at `index`, computed from the length of the input, the leading zero
+    /// characters and the most significant non-zero character, which counts
+    /// as 5 bits if it is invalid.
+    ///
+    /// # Notes
+    ///
+    /// - The `index` is relative to `src_offset`, and must not exceed
+    ///   `src_len`, an `index` of `src_len` counts the whole output.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn decoded_before<const POLICY: u8>(
+        alphabet: &Alphabet,
+        src: &[u8],
+        src_offset: usize,
+        src_len: usize,
+        index: usize,
+    ) -> usize {
+        // Count the significant characters, in total and before `index`, the
+        // leading zeros and the bits of the most significant character.
+        let mut len = 0;
+        let mut before = 0;
+        let mut zeros = 0;
+        let mut top = 0;
+        let mut i = 0;
+        while i < src_len {
+            let byte = src[src_offset + i];
+            if !(POLICY == WHITESPACE && is_whitespace(byte)) {
+                if top == 0 {
+                    match alphabet.value(byte) {
+                        0 => zeros += 1,
+                        INVALID => top = 5,
+                        value => top = 8 - value.leading_zeros() as usize,
+                    }
+                }
+                if i < index {
+                    before += 1;
+                }
+                len += 1;
+            }
+            i += 1;
+        }
+
+        decoded_from(len, before, zeros, top)
+    }
+
+    /// Counts the leading output bytes which the first `before` of `len`
+    /// significant characters decode to, see [`decoded_before`].
+    ///
+    /// # Notes
+    ///
+    /// - `zeros` is the number of leading zero characters, and `top` the bits
+    ///   of the most significant non-zero character.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn decoded_from(
+        len: usize,
+        before: usize,
+        zeros: usize,
+        top: usize,
+    ) -> usize {
+        // Every leading zero character decodes to a zero byte.
+        if zeros == len {
+            return before;
+        }
+
+        // The bytes are aligned to the end of the input, so the first one
+        // holds the bits left over by the others.
+        let first = match len % 8 * 5 % 8 {
+            0 => 8,
+            bits => bits,
+        };
+
+        // Count the bytes completed by the characters before `index`, less
+        // the high-order zero bytes, which are not part of the output.
+        let bits = (len - zeros - 1) * 5 + top;
+        let skipped = (5 * len + 8 - first) / 8 - bits.div_ceil(8);
+        let complete = (5 * before + 8 - first) / 8;
+
+        let zeros = if before < zeros { before } else { zeros };
+        zeros + complete.saturating_sub(skipped)
+    }
+
+    /// Returns the index of the last invalid character.
+    ///
+    /// # Notes
+    ///
+    /// - Valid input is checked in fixed-size chunks without branching, so that
+    ///   the scan can be vectorized.
+    #[inline]
+    #[must_use]
+    pub(crate) fn rfind_invalid(src: &[u8]) -> Option<usize> {
+        // Check whether the Crockford alphabet contains the byte, which is
+        // equivalent to `is_valid`, but without the table lookup.
+        #[inline]
+        fn is_alphabet(byte: u8) -> bool {
+            let lower = byte | 0x20;
+            let is_digit = byte.wrapping_sub(b'0') < 10;
+            let is_letter = lower.wrapping_sub(b'a') < 26 && lower != b'u';
+            is_digit | is_letter
+        }
+
+        // Skip the search if every chunk is valid.
+        let all_valid = src.chunks(64).all(|chunk| {
+            chunk.iter().fold(true, |acc, &b| acc & is_alphabet(b))
+        });
+        if all_valid {
+            return None;
+        }
+
+        src.iter().rposition(|&byte| !is_valid(byte))
+    }
+
+    /// Counts the invalid characters.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn count_invalid(src: &[u8]) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < src.len() {
+            if !is_valid(src[i]) {
+                count += 1;
+            }
+            i += 1;
+        }
+        count
+    }
+
     /// Copies `n` bytes from `src` to `dst`.
     ///
     /// # Notes
@@ -2126,21 +15836,19 @@ mod __internal {
         }
     }
 
-    /// Compares `n` bytes between `lhs` and `rhs`.
+    /// Compares two checksums in a `const` context.
     ///
     /// # Notes
     ///
-    /// - Both slices must be properly sized.
+    /// - Array equality is not `const` on stable, so the checksums are compared
+    ///   as integers.
     #[inline]
     #[must_use]
-    pub(crate) const fn memcmp(lhs: &[u8], rhs: &[u8], n: usize) -> bool {
-        let mut i = 0;
-        while i < n {
-            if lhs[i] != rhs[i] {
-                return false;
-            }
-            i += 1;
-        }
-        true
+    #[cfg(feature = "check")]
+    pub(crate) const fn checksum_eq(
+        lhs: checksum::Checksum,
+        rhs: checksum::Checksum,
+    ) -> bool {
+        u32::from_ne_bytes(lhs) == u32::from_ne_bytes(rhs)
     }
 }
@@ -0,0 +1,80 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+// A smoke test for the C ABI, linked against the static library:
+//
+//   cargo rustc -p c32 --release --features ffi --crate-type staticlib
+//   cc -I c32/include c32/tests/ffi/smoke.c target/release/libc32.a -o smoke
+//   ./smoke
+
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "c32.h"
+
+static void test_encode_decode(void) {
+    const uint8_t bytes[] = {42, 42, 42};
+    uint8_t en[16];
+    uint8_t de[16];
+    size_t len = 0;
+
+    assert(c32_encoded_len(sizeof bytes) <= sizeof en);
+    assert(c32_encode(bytes, sizeof bytes, en, sizeof en, &len) == C32_OK);
+    assert(len == 5 && memcmp(en, "2MAHA", 5) == 0);
+
+    assert(c32_decode(en, len, de, sizeof de, &len) == C32_OK);
+    assert(len == sizeof bytes && memcmp(de, bytes, len) == 0);
+}
+
+static void test_encode_decode_check(void) {
+    const uint8_t bytes[] = {42, 42, 42};
+    uint8_t en[32];
+    uint8_t de[32];
+    uint8_t version = 0;
+    size_t len = 0;
+
+    assert(c32_encode_check(bytes, sizeof bytes, 0, en, sizeof en, &len) ==
+           C32_OK);
+    assert(len == 12 && memcmp(en, "0AHA59B9201Z", 12) == 0);
+
+    assert(c32_decode_check(en, len, de, sizeof de, &len, &version) ==
+           C32_OK);
+    assert(len == sizeof bytes && memcmp(de, bytes, len) == 0);
+    assert(version == 0);
+
+    en[1] = 'B';
+    assert(c32_decode_check(en, 12, de, sizeof de, &len, &version) ==
+           C32_ERR_CHECKSUM_MISMATCH);
+}
+
+static void test_errors(void) {
+    const uint8_t bytes[] = {42, 42, 42};
+    uint8_t out[16];
+    size_t len = 0;
+
+    assert(c32_encode(bytes, sizeof bytes, out, 2, &len) ==
+           C32_ERR_BUFFER_TOO_SMALL);
+    assert(c32_decode((const uint8_t *)"2M!HA", 5, out, sizeof out, &len) ==
+           C32_ERR_INVALID_CHARACTER);
+    assert(c32_encode_check(bytes, sizeof bytes, 32, out, sizeof out, &len) ==
+           C32_ERR_INVALID_VERSION);
+    assert(c32_encode(NULL, 3, out, sizeof out, &len) ==
+           C32_ERR_NULL_POINTER);
+
+    // Assert that empty input accepts null pointers.
+    assert(c32_encode(NULL, 0, NULL, 0, &len) == C32_OK && len == 0);
+}
+
+int main(void) {
+    test_encode_decode();
+    test_encode_decode_check();
+    test_errors();
+    printf("ok\n");
+    return 0;
+}
@@ -0,0 +1,128 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+#![cfg(feature = "cli")]
+
+use assert_cmd::Command;
+
+/// Returns a [`Command`] for the `c32` binary.
+fn c32() -> Command {
+    Command::cargo_bin("c32").unwrap()
+}
+
+#[test]
+fn test_cli_encode_decode() {
+    c32()
+        .arg("encode")
+        .write_stdin([42, 42, 42])
+        .assert()
+        .success()
+        .stdout("2MAHA\n");
+
+    c32()
+        .arg("decode")
+        .write_stdin("2MAHA\n")
+        .assert()
+        .success()
+        .stdout(&[42u8, 42, 42][..]);
+}
+
+#[test]
+fn test_cli_prefix_and_lower() {
+    c32()
+        .args(["encode", "--prefix", "S", "--lower"])
+        .write_stdin([42, 42, 42])
+        .assert()
+        .success()
+        .stdout("S2maha\n");
+
+    c32()
+        .args(["decode", "--prefix", "S"])
+        .write_stdin("S2maha")
+        .assert()
+        .success()
+        .stdout(&[42u8, 42, 42][..]);
+
+    c32()
+        .args(["decode", "--prefix", "S"])
+        .write_stdin("P2MAHA")
+        .assert()
+        .code(2)
+        .stderr("c32: Expected prefix 'S', found 'P'\n");
+}
+
+#[test]
+fn test_cli_check() {
+    c32()
+        .args(["check-encode", "--version", "22", "--prefix", "S"])
+        .write_stdin("usque ad finem")
+        .assert()
+        .success()
+        .stdout("SP7AWVHENJJ0RB441K6JVK5DNJ7J3V5\n");
+
+    c32()
+        .args(["check-decode", "--expect-version", "22", "--prefix", "S"])
+        .write_stdin("SP7AWVHENJJ0RB441K6JVK5DNJ7J3V5\n")
+        .assert()
+        .success()
+        .stdout("usque ad finem");
+}
+
+#[test]
+fn test_cli_file() {
+    let path = std::env::temp_dir().join("c32-test-cli-file.txt");
+    std::fs::write(&path, "P7AWVHENJJ0RB441K6JVK5DNJ7J3V5\r\n").unwrap();
+
+    c32()
+        .arg("check-decode")
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout("usque ad finem");
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_cli_exit_codes() {
+    // Assert that invalid input reports the character index.
+    c32()
+        .arg("decode")
+        .write_stdin("2M!HA")
+        .assert()
+        .code(2)
+        .stderr("c32: Invalid character '!' at position 2\n");
+
+    c32()
+        .args(["check-decode", "--prefix", "S"])
+        .write_stdin("SP7AWVHENJJ0RB4!1K6JVK5DNJ7J3V5")
+        .assert()
+        .code(2)
+        .stderr("c32: Invalid character '!' at position 15\n");
+
+    c32()
+        .args(["check-encode", "--version", "32"])
+        .write_stdin("usque ad finem")
+        .assert()
+        .code(2)
+        .stdout("");
+
+    c32()
+        .args(["check-decode", "--expect-version", "21"])
+        .write_stdin("P7AWVHENJJ0RB441K6JVK5DNJ7J3V5")
+        .assert()
+        .code(2)
+        .stderr("c32: Invalid version 22: must match the expected version\n");
+
+    // Assert that checksum failures are distinguished.
+    c32()
+        .arg("check-decode")
+        .write_stdin("P7AWVHENJJ0RB441K6JVK5DNJ7J3V6")
+        .assert()
+        .code(3);
+}
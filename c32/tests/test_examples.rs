@@ -0,0 +1,167 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+#![cfg(all(feature = "std", feature = "check"))]
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+use std::process::Stdio;
+
+/// The unit vectors, as the hex input, the encoding and the version 0
+/// check encoding.
+const VECTORS: [(&str, &str, &str); 11] = [
+    ("", "", "0A0DR2R"),
+    ("01", "1", "04C407K6"),
+    ("0102", "82", "0108TZKWMK"),
+    ("010203", "20G3", "0820FVT6NE0"),
+    ("01020304", "G40R4", "020G30HV8M1Y1"),
+    ("0102030405", "4106105", "0G40R40QP9HXK8"),
+    ("0000000000", "00000", "0000001CKYF1A"),
+    ("0001000100", "0G0080", "00200100M9A7ZE"),
+    ("0000010000", "002000", "0008003VTB5H8"),
+    ("0001000000", "0G0000", "00200003Y4CA34"),
+    ("0001000001", "0G0001", "00200005F1PN5G"),
+];
+
+/// Returns the path of an example binary.
+///
+/// The examples are built by `cargo test` next to the `deps` directory of
+/// the test binary, unless the test targets are filtered.
+fn example(name: &str) -> PathBuf {
+    let mut path = std::env::current_exe().unwrap();
+    path.pop();
+    if path.ends_with("deps") {
+        path.pop();
+    }
+    path.join("examples").join(name)
+}
+
+/// Runs the `c32` example with `args`.
+fn c32(args: &[&str]) -> Output {
+    Command::new(example("c32")).args(args).output().unwrap()
+}
+
+/// Runs the `c32` example with `args`, writing `stdin` to its input.
+fn c32_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(example("c32"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+/// Asserts that the output succeeded with `stdout`.
+fn assert_stdout(output: &Output, stdout: &str) {
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), stdout);
+}
+
+/// Asserts that the output failed with `code` and `stderr`.
+fn assert_stderr(output: &Output, code: i32, stderr: &str) {
+    assert_eq!(output.status.code(), Some(code), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), stderr);
+}
+
+#[test]
+fn test_example_unit_vectors() {
+    for (hex, en, check) in VECTORS {
+        assert_stdout(&c32(&["encode", hex]), &format!("{en}\n"));
+        assert_stdout(&c32(&["decode", en]), &format!("{hex}\n"));
+
+        let output = c32(&["check-encode", "--version", "0", hex]);
+        assert_stdout(&output, &format!("{check}\n"));
+        assert_stdout(&c32(&["check-decode", check]), &format!("0 {hex}\n"));
+    }
+}
+
+#[test]
+fn test_example_stdin() {
+    assert_stdout(&c32_stdin(&["encode"], "2a2a2a\n"), "2MAHA\n");
+    assert_stdout(&c32_stdin(&["decode"], "2MAHA\n"), "2a2a2a\n");
+}
+
+#[test]
+fn test_example_prefix_and_lower() {
+    let output = c32(&["encode", "--prefix", "S", "--lower", "2a2a2a"]);
+    assert_stdout(&output, "S2maha\n");
+
+    let output =
+        c32(&["check-encode", "--prefix", "€", "--version", "22", "2a2a2a"]);
+    assert_stdout(&output, "€PAHA58QT2DJ9\n");
+
+    let output = c32(&["check-decode", "--prefix", "€", "€pAHA58QT2DJ9"]);
+    assert_stdout(&output, "22 2a2a2a\n");
+}
+
+#[test]
+fn test_example_error_carets() {
+    let output = c32(&["decode", "2M!HA"]);
+    assert_stderr(
+        &output,
+        13,
+        "c32: Invalid character '!' at position 2 [C32_E_INVALID_CHARACTER]\n    \
+         2M!HA\n      ^\n",
+    );
+
+    // Assert that the caret accounts for a multi-byte prefix.
+    let output = c32(&["check-decode", "--prefix", "€", "€PAH!58QT2DJ9"]);
+    assert_stderr(
+        &output,
+        13,
+        "c32: Invalid character '!' at position 6 [C32_E_INVALID_CHARACTER]\n    \
+         €PAH!58QT2DJ9\n        ^\n",
+    );
+
+    let output = c32(&["decode", "--prefix", "S", "P2MAHA"]);
+    assert_stderr(
+        &output,
+        14,
+        "c32: Expected prefix 'S', found 'P' [C32_E_MISSING_PREFIX]\n    \
+         P2MAHA\n    ^\n",
+    );
+}
+
+#[test]
+fn test_example_exit_codes() {
+    let cases: [(&[&str], i32); 6] = [
+        (&["bogus"], 2),
+        (&["encode", "zz"], 2),
+        (&["check-encode", "00"], 2),
+        (&["check-encode", "--version", "32", "00"], 17),
+        (&["check-decode", "P"], 18),
+        (&["check-decode", "PAHA58QT2DJ8"], 19),
+    ];
+    for (args, code) in cases {
+        assert_eq!(c32(args).status.code(), Some(code), "{args:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn test_example_id_gen() {
+    let output = Command::new(example("id_gen")).arg("3").output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ids: Vec<&str> = stdout.lines().collect();
+    assert_eq!(ids.len(), 3);
+    for id in ids {
+        assert_eq!(c32::decode(id).unwrap().len(), 16);
+    }
+}
@@ -0,0 +1,194 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+//! The checked-in regression corpus in `tests/corpus`, shared by the fuzz
+//! targets and the `test_corpus` test.
+//!
+//! Every input is run through all decode entry points by [`render`], and
+//! stored next to a `<name>.expected` file with the rendered results.
+
+#![allow(dead_code)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::env;
+use std::ffi::OsStr;
+use std::fmt::Debug;
+use std::fmt::Write;
+use std::fs;
+use std::hash::Hasher;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use c32::en;
+use c32::Buffer;
+use c32::Mode;
+
+/// The extension of the files holding the expected results.
+pub const EXPECTED: &str = "expected";
+
+/// The variable that makes [`export`] add the inputs of a fuzz target to the
+/// corpus.
+pub const EXPORT: &str = "C32_CORPUS_EXPORT";
+
+/// Returns the directory of the corpus.
+pub fn dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus")
+}
+
+/// Appends the result of an entry point to `out`.
+fn line<T: Debug>(out: &mut String, name: &str, result: T) {
+    let _ = writeln!(out, "{name}: {result:?}");
+}
+
+/// Runs `input` through all decode entry points, rendering the results one
+/// per line.
+pub fn render(input: &[u8]) -> String {
+    let mut out = String::new();
+    let mut dst = vec![0u8; input.len() + 8];
+    let mut lens = [0usize; 8];
+
+    line(&mut out, "validate", c32::validate(input));
+    let result = c32::decode_into(input, &mut dst).map(|n| &dst[..n]);
+    line(&mut out, "decode_into", result);
+    let result = c32::decode_check_into(input, &mut dst)
+        .map(|(n, version)| (dst[..n].to_vec(), version));
+    line(&mut out, "decode_check_into", result);
+    let result = c32::decode_check_into_slice(input, &mut dst)
+        .map(|(version, bytes)| (version, bytes.to_vec()));
+    line(&mut out, "decode_check_into_slice", result);
+    let result = c32::decode_partial_into(input, &mut dst)
+        .map(|(n, consumed)| (dst[..n].to_vec(), consumed));
+    line(&mut out, "decode_partial_into", result);
+    let result = c32::decode_lossy_into(input, &mut dst)
+        .map(|(n, substituted)| (dst[..n].to_vec(), substituted));
+    line(&mut out, "decode_lossy_into", result);
+    let result = c32::decode_framed_into(input, &mut dst, &mut lens);
+    line(&mut out, "decode_framed_into", result);
+    let result = c32::decode_blocks_into(input, &mut dst).map(|n| &dst[..n]);
+    line(&mut out, "decode_blocks_into", result);
+
+    let mut buf = input.to_vec();
+    let result = c32::decode_in_place(&mut buf).map(|n| &buf[..n]);
+    line(&mut out, "decode_in_place", result);
+
+    let result = Buffer::<32>::try_decode(input).map(|b| b.as_bytes().to_vec());
+    line(&mut out, "Buffer::try_decode", result);
+    let result = Buffer::<32, true>::try_decode(input, 'S')
+        .map(|b| b.as_bytes().to_vec());
+    line(&mut out, "Buffer::try_decode (prefixed)", result);
+    let result = Buffer::<32, false, en::Check>::try_decode(input)
+        .map(|(b, version)| (b.as_bytes().to_vec(), version));
+    line(&mut out, "Buffer::try_decode (check)", result);
+    let result = Buffer::<32, true, en::Check>::try_decode(input, 'S')
+        .map(|(b, version)| (b.as_bytes().to_vec(), version));
+    line(&mut out, "Buffer::try_decode (check, prefixed)", result);
+
+    // The remaining entry points take a string.
+    let Ok(str) = std::str::from_utf8(input) else {
+        return out;
+    };
+
+    line(&mut out, "decode", c32::decode(str));
+    line(&mut out, "decode_owned", c32::decode_owned(str.to_owned()));
+    line(&mut out, "decode_bounded", c32::decode_bounded(str, 16));
+    line(&mut out, "decode_le", c32::decode_le(str));
+    line(&mut out, "decode_lossy", c32::decode_lossy(str));
+    line(&mut out, "decode_partial", c32::decode_partial(str));
+    line(&mut out, "decode_framed", c32::decode_framed(str));
+    line(&mut out, "decode_blocks", c32::decode_blocks(str));
+    line(&mut out, "decode_small", c32::decode_small::<16>(str));
+    line(&mut out, "decode_uuid", c32::decode_uuid(str));
+    let result = c32::decode_prefixed(str, 'S');
+    line(&mut out, "decode_prefixed", result);
+    line(&mut out, "peek_version", c32::peek_version(str));
+    line(&mut out, "decode_check", c32::decode_check(str));
+    let result = c32::decode_check_bounded(str, 16);
+    line(&mut out, "decode_check_bounded", result);
+    let result = c32::decode_check_with_version(str, 22);
+    line(&mut out, "decode_check_with_version", result);
+    let result = c32::decode_check_full(str);
+    line(&mut out, "decode_check_full", result);
+    let result = c32::decode_check_partial(str);
+    line(&mut out, "decode_check_partial", result);
+    let result = c32::decode_check_symbol(str);
+    line(&mut out, "decode_check_symbol", result);
+    let result = c32::decode_check_prefixed(str, 'S');
+    line(&mut out, "decode_check_prefixed", result);
+
+    let result =
+        Buffer::<32>::try_decode_str(str).map(|b| b.as_bytes().to_vec());
+    line(&mut out, "Buffer::try_decode_str", result);
+    let result = Buffer::<32, false, en::Check>::try_decode_str(str)
+        .map(|(b, version)| (b.as_bytes().to_vec(), version));
+    line(&mut out, "Buffer::try_decode_str (check)", result);
+
+    for mode in [
+        Mode::Default,
+        Mode::Prefixed('S'),
+        Mode::Check { version: 22 },
+        Mode::CheckPrefixed {
+            prefix: 'S',
+            version: 22,
+        },
+    ] {
+        let result = c32::decode_mode(str, mode);
+        line(&mut out, &format!("decode_mode ({mode:?})"), result);
+    }
+
+    out
+}
+
+/// Returns the inputs of the corpus, sorted by name.
+pub fn inputs() -> Vec<PathBuf> {
+    let mut paths: Vec<_> = fs::read_dir(dir())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension() != Some(OsStr::new(EXPECTED)))
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Returns the path of the expected results of an input.
+pub fn expected(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap().to_os_string();
+    name.push(".");
+    name.push(EXPECTED);
+    path.with_file_name(name)
+}
+
+/// Adds `input` to the corpus, with its rendered results.
+///
+/// The file is named after a hash of its contents, so adding the same input
+/// twice is a no-op. The input is written before it is rendered, so an input
+/// that panics is kept without an expected file.
+pub fn add(input: &[u8]) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(input);
+    let path = dir().join(format!("fuzz-{:016x}", hasher.finish()));
+
+    fs::write(&path, input).unwrap();
+    fs::write(expected(&path), render(input)).unwrap();
+    path
+}
+
+/// Adds `input` to the corpus if [`EXPORT`] is set, see [`add`].
+///
+/// Fuzz targets call this first, so replaying a minimized find adds it:
+///
+/// ```sh
+/// C32_CORPUS_EXPORT=1 cargo fuzz run decode_invalid \
+///     fuzz/artifacts/decode_invalid/crash-1234
+/// ```
+pub fn export(input: &[u8]) {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    if *ENABLED.get_or_init(|| env::var_os(EXPORT).is_some()) {
+        add(input);
+    }
+}
@@ -0,0 +1,41 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+mod corpus;
+
+/// The characters of the alphabet.
+const ALPHABET: &[u8; 32] = c32::alphabet::CROCKFORD.as_bytes();
+
+/// The characters injected into otherwise valid input.
+const INVALID: &[u8] = b"!-_ \t\nUuIiLlOo*\x00\xc3\xa9\xff";
+
+fuzz_target!(|bytes: &[u8]| {
+    corpus::export(bytes);
+
+    // Maps most bytes to alphabet characters, so the invalid characters are
+    // reached deep inside otherwise valid input.
+    let input: Vec<u8> = bytes
+        .iter()
+        .map(|&b| match b {
+            0..=0xDF => ALPHABET[usize::from(b % 32)],
+            _ => INVALID[usize::from(b - 0xE0) % INVALID.len()],
+        })
+        .collect();
+    corpus::export(&input);
+
+    // Fuzzes all decoding paths, asserting that none of them panic.
+    let _ = corpus::render(bytes);
+    let _ = corpus::render(&input);
+
+    // Fuzzes the optimized paths against the reference implementation.
+    c32::reference::assert_equivalent(&input);
+
+    // Asserts that validation agrees with decoding.
+    let mut dst = vec![0u8; input.len()];
+    let result = c32::decode_into(&input, &mut dst);
+    match c32::validate(&input) {
+        Ok(()) => assert!(result.is_ok()),
+        Err(err) => assert_eq!(result, Err(err)),
+    }
+});
@@ -2,7 +2,20 @@
 
 use libfuzzer_sys::fuzz_target;
 
+mod corpus;
+
 fuzz_target!(|bytes: &[u8]| {
+    corpus::export(bytes);
+
+    // Fuzzes the decoding paths with arbitrary input.
+    if let Ok(str) = core::str::from_utf8(bytes) {
+        let _ = c32::decode(str);
+        let _ = c32::decode_check(str);
+    }
+
+    // Fuzzes the optimized paths against the reference implementation.
+    c32::reference::assert_equivalent(bytes);
+
     // Skip empty bytes.
     if bytes.is_empty() {
         return;
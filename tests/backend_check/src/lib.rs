@@ -0,0 +1,66 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+//! Compile test for `#![no_std]` targets using only `[feature = "check-core"]`,
+//! with a user-provided SHA-256 backend and without the `sha2` dependency.
+//!
+//! This crate must build on its own, as features are unified across the
+//! packages of a single build, e.g.:
+//!
+//! ```text
+//! cargo build -p backend_check --target thumbv6m-none-eabi
+//! ```
+
+#![no_std]
+
+use c32::checksum::Sha256Backend;
+use c32::Result;
+
+/// A backend for a hardware SHA-256 peripheral.
+pub struct Peripheral;
+
+extern "C" {
+    /// Feeds bytes to the peripheral.
+    fn sha256_update(data: *const u8, len: usize);
+
+    /// Writes the digest of the fed bytes, and resets the peripheral.
+    fn sha256_finish(out: *mut u8);
+}
+
+impl Sha256Backend for Peripheral {
+    fn digest(data_parts: &[&[u8]]) -> [u8; 32] {
+        let mut hash = [0u8; 32];
+        unsafe {
+            for part in data_parts {
+                sha256_update(part.as_ptr(), part.len());
+            }
+            sha256_finish(hash.as_mut_ptr());
+        }
+        hash
+    }
+}
+
+/// Round-trips bytes through the check-encoded buffer APIs.
+///
+/// # Errors
+///
+/// Returns the [`Error`](c32::Error) of the first failing call.
+pub fn round_trip(src: &[u8; 20], version: u8) -> Result<bool> {
+    let mut en = [0u8; c32::encoded_check_len(20)];
+    let epos = c32::encode_check_into_with_backend::<Peripheral>(
+        src, &mut en, version,
+    )?;
+
+    let mut de = [0u8; c32::decoded_check_len(c32::encoded_check_len(20))];
+    let (dpos, dver) = c32::decode_check_into_with_backend::<Peripheral>(
+        &en[..epos],
+        &mut de,
+    )?;
+
+    Ok(&de[..dpos] == src && dver == version)
+}
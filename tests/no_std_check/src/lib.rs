@@ -0,0 +1,87 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+//! Compile test for `#![no_std]` targets using only `[feature = "check"]`,
+//! `[feature = "defmt"]` and `[feature = "serde"]`.
+//!
+//! This crate must build for targets without `std` or `alloc`, e.g.:
+//!
+//! ```text
+//! cargo build -p no_std_check --target thumbv6m-none-eabi
+//! cargo build -p no_std_check --target riscv32imc-unknown-none-elf
+//! ```
+//!
+//! Neither `c32` nor its `sha2` or `serde` dependencies may enable their
+//! `std` or `alloc` features here, which CI asserts via `cargo tree`.
+
+#![no_std]
+
+use c32::en::Check;
+use c32::Buffer;
+use c32::Result;
+
+/// A check-encoded [`Buffer`] computed in a constant context.
+pub const ENCODED: Buffer<13, false, Check> =
+    Buffer::<13, false, Check>::encode(&[42, 42, 42], 0);
+
+/// A prefixed check-encoded [`Buffer`] computed in a constant context.
+pub const ENCODED_PREFIXED: Buffer<14, true, Check> =
+    Buffer::<14, true, Check>::encode(&[42, 42, 42], 'S', 0);
+
+/// Computes a checksum in a constant context.
+pub const CHECKSUM: c32::checksum::Checksum =
+    c32::checksum::compute(&[42, 42, 42], 0);
+
+/// Round-trips bytes through the check-encoded buffer APIs.
+///
+/// # Errors
+///
+/// Returns the [`Error`](c32::Error) of the first failing call.
+pub fn round_trip(src: &[u8; 20], version: u8) -> Result<bool> {
+    let mut en = [0u8; c32::encoded_check_len(20)];
+    let epos = c32::encode_check_into(src, &mut en, version)?;
+
+    let mut de = [0u8; c32::decoded_check_len(c32::encoded_check_len(20))];
+    let (dpos, dver) = c32::decode_check_into(&en[..epos], &mut de)?;
+
+    Ok(&de[..dpos] == src && dver == version)
+}
+
+/// Round-trips bytes through the prefixed check-encoded buffer APIs.
+///
+/// # Errors
+///
+/// Returns the [`Error`](c32::Error) of the first failing call.
+pub fn round_trip_prefixed(
+    src: &[u8; 20],
+    prefix: char,
+    version: u8,
+) -> Result<bool> {
+    let mut en = [0u8; 1 + c32::encoded_check_len(20)];
+    let epos = c32::encode_check_prefixed_into(src, &mut en, prefix, version)?;
+
+    let mut de = [0u8; 1 + c32::encoded_check_len(20)];
+    let (dpos, dver) =
+        c32::decode_check_prefixed_into(&en[..epos], &mut de, prefix)?;
+
+    Ok(&de[..dpos] == src && dver == version)
+}
+
+/// Decodes a check-encoded [`Buffer`] at runtime.
+///
+/// # Errors
+///
+/// Returns the [`Error`](c32::Error) of [`Buffer::try_decode`].
+pub fn decode_buffer(src: &[u8]) -> Result<(Buffer<32, false, Check>, u8)> {
+    Buffer::<32, false, Check>::try_decode(src)
+}
+
+/// Logs a decoding error through `defmt`, without formatting it first.
+pub fn log_error(err: &c32::Error) {
+    defmt::warn!("decode failed: {}", err);
+}
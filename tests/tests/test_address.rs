@@ -0,0 +1,144 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::address::decode_address;
+use c32::address::encode_address_versioned;
+use c32::address::from_version;
+use c32::address::version;
+use c32::address::AddressKind;
+use c32::address::Network;
+use c32::address::ADDR_VERSION_MAINNET_MULTISIG;
+use c32::address::ADDR_VERSION_MAINNET_SINGLESIG;
+use c32::address::ADDR_VERSION_TESTNET_MULTISIG;
+use c32::address::ADDR_VERSION_TESTNET_SINGLESIG;
+use c32::Error;
+
+mod __internal {
+    pub const HASH160: [u8; 20] = [
+        0xa4, 0x6f, 0xf8, 0x88, 0x86, 0xc2, 0xef, 0x97, 0x62, 0xd9, 0x70, 0xb4,
+        0xd2, 0xc6, 0x36, 0x78, 0x83, 0x5b, 0xd3, 0x9d,
+    ];
+
+    /// Published Stacks addresses and their network, kind and 'hash160'.
+    pub const VECTORS: &[(
+        &str,
+        super::Network,
+        super::AddressKind,
+        [u8; 20],
+    )] = &[
+        (
+            "SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7",
+            super::Network::Mainnet,
+            super::AddressKind::SingleSig,
+            HASH160,
+        ),
+        (
+            "SM2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKQVX8X0G",
+            super::Network::Mainnet,
+            super::AddressKind::MultiSig,
+            HASH160,
+        ),
+        (
+            "SP000000000000000000002Q6VF78",
+            super::Network::Mainnet,
+            super::AddressKind::SingleSig,
+            [0; 20],
+        ),
+        (
+            "ST000000000000000000002AMW42H",
+            super::Network::Testnet,
+            super::AddressKind::SingleSig,
+            [0; 20],
+        ),
+    ];
+}
+
+use __internal::*;
+
+#[test]
+fn test_address_vectors() {
+    for (addr, network, kind, hash160) in VECTORS {
+        let en = encode_address_versioned(*network, *kind, hash160);
+        assert_eq!(en, *addr);
+
+        let de = decode_address(addr).unwrap();
+        assert_eq!(de, (*network, *kind, *hash160));
+    }
+}
+
+#[test]
+fn test_address_versions() {
+    let parts = [
+        (
+            Network::Mainnet,
+            AddressKind::SingleSig,
+            ADDR_VERSION_MAINNET_SINGLESIG,
+        ),
+        (
+            Network::Mainnet,
+            AddressKind::MultiSig,
+            ADDR_VERSION_MAINNET_MULTISIG,
+        ),
+        (
+            Network::Testnet,
+            AddressKind::SingleSig,
+            ADDR_VERSION_TESTNET_SINGLESIG,
+        ),
+        (
+            Network::Testnet,
+            AddressKind::MultiSig,
+            ADDR_VERSION_TESTNET_MULTISIG,
+        ),
+    ];
+
+    for (network, kind, expected) in parts {
+        assert_eq!(version(network, kind), expected);
+        assert_eq!(from_version(expected), Some((network, kind)));
+
+        // Assert that the round-trip preserves the network and kind.
+        let addr = encode_address_versioned(network, kind, &HASH160);
+        assert_eq!(decode_address(&addr).unwrap(), (network, kind, HASH160));
+    }
+
+    for v in (0..32).filter(|v| from_version(*v).is_none()) {
+        assert_eq!(from_version(v), None);
+    }
+}
+
+#[test]
+fn test_address_unknown_version() {
+    // Version 0 is valid c32check, but not a known address version.
+    let addr = c32::encode_check_prefixed(HASH160, 'S', 0).unwrap();
+    let result = decode_address(&addr);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidVersion { version: 0, .. })
+    ));
+}
+
+#[test]
+fn test_address_invalid_length() {
+    let addr = c32::encode_check_prefixed([0u8; 19], 'S', 22).unwrap();
+    let result = decode_address(&addr);
+    assert_eq!(
+        result,
+        Err(Error::InvalidDataSize {
+            expected: 20,
+            got: 19
+        })
+    );
+}
+
+#[test]
+fn test_address_missing_prefix() {
+    let result = decode_address("P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7");
+    assert!(matches!(
+        result,
+        Err(Error::MissingPrefix { char: 'S', .. })
+    ));
+}
@@ -0,0 +1,217 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::alphabet::Alphabet;
+use c32::alphabet::{self};
+use c32::Error;
+
+const LOWER: Alphabet = Alphabet::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+
+#[test]
+fn test_alphabet_crockford_default() {
+    assert_eq!(Alphabet::default(), alphabet::CROCKFORD);
+    assert_eq!(
+        alphabet::CROCKFORD.as_bytes(),
+        b"0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Alphabet characters must be unique")]
+fn test_alphabet_new_duplicate() {
+    let _ = Alphabet::new(b"0023456789ABCDEFGHJKMNPQRSTVWXYZ");
+}
+
+#[test]
+#[should_panic(expected = "Alphabet characters must be ASCII")]
+fn test_alphabet_new_non_ascii() {
+    let _ = Alphabet::new(b"\xFF123456789ABCDEFGHJKMNPQRSTVWXYZ");
+}
+
+#[test]
+fn test_check_with_crockford() {
+    let bytes = [42, 42, 42];
+    for version in 0..32 {
+        let en = c32::encode_check_with(&alphabet::CROCKFORD, bytes, version)
+            .unwrap();
+        assert_eq!(en, c32::encode_check(bytes, version).unwrap());
+
+        let de = c32::decode_check_with(&alphabet::CROCKFORD, &en).unwrap();
+        assert_eq!(de, (bytes.to_vec(), version));
+    }
+}
+
+#[test]
+fn test_check_with_custom() {
+    let bytes = [0, 0, 1, 2, 3, 255];
+    for version in 0..32 {
+        let en = c32::encode_check_with(&LOWER, bytes, version).unwrap();
+        let upper = c32::encode_check(bytes, version).unwrap();
+        assert_eq!(en, upper.to_ascii_lowercase());
+
+        let de = c32::decode_check_with(&LOWER, &en).unwrap();
+        assert_eq!(de, (bytes.to_vec(), version));
+    }
+}
+
+#[test]
+fn test_check_with_custom_rejects_default() {
+    // Uppercase characters are not part of the custom alphabet.
+    let result = c32::decode_check_with(&LOWER, "0AHA59B9201Z");
+    assert_eq!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: 'Z',
+            index: 11,
+            decoded: 6,
+        })
+    );
+}
+
+#[test]
+fn test_check_with_custom_checksum_mismatch() {
+    let en = c32::encode_check_with(&LOWER, [42, 42, 42], 0).unwrap();
+
+    // Replace the last character with another valid one.
+    let mut bytes = en.into_bytes();
+    let last = bytes.len() - 1;
+    bytes[last] = if bytes[last] == b'0' { b'1' } else { b'0' };
+    let tampered = String::from_utf8(bytes).unwrap();
+
+    let result = c32::decode_check_with(&LOWER, &tampered);
+    assert!(matches!(result, Err(Error::ChecksumMismatch { .. })));
+}
+
+#[test]
+fn test_check_into_with_custom() {
+    let mut en = [0u8; 13];
+    let offset =
+        c32::encode_check_into_with(&LOWER, &[42, 42, 42], &mut en, 0).unwrap();
+    assert_eq!(&en[..offset], b"0aha59b9201z");
+
+    let mut de = [0u8; 12];
+    let (pos, version) =
+        c32::decode_check_into_with(&LOWER, &en[..offset], &mut de).unwrap();
+    assert_eq!(&de[..pos], [42, 42, 42]);
+    assert_eq!(version, 0);
+}
+
+#[test]
+fn test_alphabet_zbase32() {
+    assert_eq!(
+        alphabet::ZBASE32.as_bytes(),
+        b"ybndrfg8ejkmcpqxot1uwisza345h769"
+    );
+}
+
+#[test]
+fn test_zbase32_reference() {
+    // z-base-32 vectors for inputs where the bit stream and the big-endian
+    // packing agree (multiples of 5 bytes with a leading byte >= 0x08).
+    let vectors: [(&[u8], &[u8]); 5] = [
+        (b"hello", b"pb1sa5dx"),
+        (b"0123456789", b"gyaurc3wgw5dqqb3"),
+        (&[0xF0, 0xBF, 0xC7, 0x00, 0x01], b"6n9hqyyb"),
+        (&[0xD4, 0x7A, 0x04, 0x10, 0x20], b"4t7yerby"),
+        (&[0xFF; 5], b"99999999"),
+    ];
+
+    for (bytes, expected) in vectors {
+        let mut en = [0u8; 16];
+        let offset =
+            c32::encode_into_with_alphabet(&alphabet::ZBASE32, bytes, &mut en)
+                .unwrap();
+        assert_eq!(&en[..offset], expected);
+
+        let mut de = [0u8; 16];
+        let pos = c32::decode_into_with_alphabet(
+            &alphabet::ZBASE32,
+            &en[..offset],
+            &mut de,
+        )
+        .unwrap();
+        assert_eq!(&de[..pos], bytes);
+    }
+}
+
+#[test]
+fn test_zbase32_rejects_crockford() {
+    let mut de = [0u8; 8];
+    let result = c32::decode_into_with_alphabet(
+        &alphabet::ZBASE32,
+        b"pb1sa5dX",
+        &mut de,
+    );
+    assert_eq!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: 'X',
+            index: 7,
+            decoded: 4,
+        })
+    );
+}
+
+#[test]
+fn test_with_alphabet_crockford() {
+    let bytes = [0, 0, 1, 2, 3, 42, 255];
+
+    let mut en = [0u8; 16];
+    let mut expected = [0u8; 16];
+    let offset =
+        c32::encode_into_with_alphabet(&alphabet::CROCKFORD, &bytes, &mut en)
+            .unwrap();
+    let len = c32::encode_into(&bytes, &mut expected).unwrap();
+    assert_eq!(&en[..offset], &expected[..len]);
+
+    let mut de = [0u8; 16];
+    let pos = c32::decode_into_with_alphabet(
+        &alphabet::CROCKFORD,
+        &en[..offset],
+        &mut de,
+    )
+    .unwrap();
+    assert_eq!(&de[..pos], bytes);
+}
+
+#[test]
+fn test_with_alphabet_const() {
+    const EN: ([u8; 8], usize) = {
+        let mut dst = [0u8; 8];
+        match c32::encode_into_with_alphabet(
+            &alphabet::ZBASE32,
+            b"hello",
+            &mut dst,
+        ) {
+            Ok(offset) => (dst, offset),
+            Err(_) => panic!("failed to encode"),
+        }
+    };
+    assert_eq!(&EN.0[..EN.1], b"pb1sa5dx");
+
+    const DE: ([u8; 8], usize) = {
+        let mut dst = [0u8; 8];
+        match c32::decode_into_with_alphabet(
+            &alphabet::ZBASE32,
+            b"pb1sa5dx",
+            &mut dst,
+        ) {
+            Ok(offset) => (dst, offset),
+            Err(_) => panic!("failed to decode"),
+        }
+    };
+    assert_eq!(&DE.0[..DE.1], b"hello");
+}
+
+#[test]
+fn test_with_alphabet_buffer_too_small() {
+    let mut dst = [0u8; 4];
+    let result =
+        c32::encode_into_with_alphabet(&alphabet::ZBASE32, b"hello", &mut dst);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 8, len: 4 }));
+}
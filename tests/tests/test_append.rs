@@ -0,0 +1,61 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::encode;
+use c32::encode_append;
+use rand::Rng;
+
+#[test]
+fn test_append_empty() {
+    let mut out = String::new();
+    encode_append([], &mut out);
+    assert_eq!(out, "");
+
+    encode_append([0, 0], &mut out);
+    assert_eq!(out, "00");
+}
+
+#[test]
+fn test_append_prefix() {
+    let mut out = String::from("prefix ünïcödé:");
+    encode_append([42, 42, 42], &mut out);
+
+    // Assert that the prefix is left intact.
+    assert_eq!(out, "prefix ünïcödé:2MAHA");
+}
+
+#[test]
+fn test_append_capacity() {
+    let mut out = String::with_capacity(1024);
+    let ptr = out.as_ptr();
+
+    for _ in 0..64 {
+        encode_append([42, 42, 42], &mut out);
+    }
+
+    // Assert that the existing capacity is reused.
+    assert_eq!(out, "2MAHA".repeat(64));
+    assert_eq!(out.as_ptr(), ptr);
+}
+
+#[test]
+fn test_append_rand() {
+    let mut rng = rand::rng();
+    let mut out = String::new();
+    let mut expected = String::new();
+
+    for _ in 0..256 {
+        let len = rng.random_range(0..64);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+
+        encode_append(&bytes, &mut out);
+        expected.push_str(&encode(&bytes));
+        assert_eq!(out, expected);
+    }
+}
@@ -0,0 +1,107 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_array;
+use c32::encode;
+use c32::encode_array;
+use c32::encoded_len;
+use c32::C32Array;
+use c32::Error;
+
+/// A `hash160` payload, as found in Stacks addresses.
+const HASH160: [u8; 20] = [
+    0xA4, 0x6F, 0xF8, 0x88, 0x86, 0xC2, 0xEF, 0x97, 0x62, 0xD9, 0x70, 0xB4,
+    0xD2, 0xC6, 0x3D, 0x89, 0xB3, 0xD8, 0x5C, 0x58,
+];
+
+/// A 32-byte key.
+const KEY: [u8; 32] = [
+    0x1F, 0x8B, 0x3C, 0x52, 0xE0, 0x04, 0x9A, 0x6D, 0x77, 0x21, 0xC8, 0x5E,
+    0xB3, 0x90, 0x0F, 0x46, 0xDA, 0x2B, 0x81, 0x65, 0xFC, 0x38, 0x4E, 0xA7,
+    0x12, 0x99, 0xC0, 0x5B, 0x6E, 0xF3, 0x0A, 0xD4,
+];
+
+#[test]
+fn test_array_hash160() {
+    let (en, len) = encode_array(&HASH160);
+
+    // Assert that the capacity is computed from the input size.
+    assert_eq!(en.len(), encoded_len(20));
+    assert_eq!(&en[..len], encode(HASH160).as_bytes());
+
+    let de: [u8; 20] = decode_array(&en[..len]).unwrap();
+    assert_eq!(de, HASH160);
+}
+
+#[test]
+fn test_array_key() {
+    let (en, len) = encode_array(&KEY);
+    assert_eq!(en.len(), encoded_len(32));
+    assert_eq!(&en[..len], encode(KEY).as_bytes());
+
+    let de: [u8; 32] = decode_array(&en[..len]).unwrap();
+    assert_eq!(de, KEY);
+}
+
+#[test]
+fn test_array_leading_zeros() {
+    let mut bytes = [0u8; 20];
+    bytes[19] = 1;
+
+    let (en, len) = encode_array(&bytes);
+    assert_eq!(&en[..len], b"00000000000000000001");
+    assert_eq!(decode_array::<[u8; 20]>(&en[..len]), Ok(bytes));
+
+    let (en, len) = encode_array(&[0u8; 32]);
+    assert_eq!(&en[..len], [b'0'; 32]);
+    assert_eq!(decode_array::<[u8; 32]>(&en[..len]), Ok([0; 32]));
+}
+
+#[test]
+fn test_array_sizes() {
+    assert_eq!(<[u8; 16]>::encode_array(&[0xFF; 16]).1, encoded_len(16));
+    assert_eq!(<[u8; 20]>::encode_array(&[0xFF; 20]).1, encoded_len(20));
+    assert_eq!(<[u8; 32]>::encode_array(&[0xFF; 32]).1, encoded_len(32));
+    assert_eq!(<[u8; 64]>::encode_array(&[0xFF; 64]).1, encoded_len(64));
+}
+
+#[test]
+fn test_array_invalid_size() {
+    let en = encode(KEY);
+    assert_eq!(
+        decode_array::<[u8; 20]>(en.as_bytes()),
+        Err(Error::InvalidDataSize {
+            expected: 20,
+            got: 32
+        })
+    );
+
+    // Assert that long inputs are rejected before decoding.
+    let en = encode([0xFF; 64]);
+    assert_eq!(
+        decode_array::<[u8; 32]>(en.as_bytes()),
+        Err(Error::InvalidDataSize {
+            expected: 32,
+            got: 64
+        })
+    );
+}
+
+#[test]
+fn test_array_invalid_character() {
+    let (mut en, len) = encode_array(&HASH160);
+    en[3] = b'!';
+    assert!(matches!(
+        decode_array::<[u8; 20]>(&en[..len]),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 3,
+            ..
+        })
+    ));
+}
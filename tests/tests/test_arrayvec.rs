@@ -0,0 +1,119 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_arrayvec;
+use c32::decode_check_arrayvec;
+use c32::encode;
+use c32::encode_arrayvec;
+use c32::encode_check;
+use c32::encode_check_arrayvec;
+use c32::Error;
+use c32::Result;
+
+mod __internal {
+    use super::*;
+
+    /// A test helper for `[feature = "arrayvec"]`.
+    pub fn test_arrayvec<const N: usize>(bytes: &[u8]) -> Result<()> {
+        let en = encode_arrayvec::<N>(bytes)?;
+        assert_eq!(en.as_str(), encode(bytes));
+        let de = decode_arrayvec::<N>(en.as_bytes())?;
+        assert_eq!(de.as_slice(), bytes);
+        Ok(())
+    }
+
+    /// A test helper for `[feature = "arrayvec", feature = "check"]`.
+    pub fn test_check_arrayvec<const N: usize>(bytes: &[u8]) -> Result<()> {
+        let en = encode_check_arrayvec::<N>(bytes, 22)?;
+        assert_eq!(en.as_str(), encode_check(bytes, 22)?);
+        let (de, version) = decode_check_arrayvec::<N>(en.as_bytes())?;
+        assert_eq!(de.as_slice(), bytes);
+        assert_eq!(version, 22);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_arrayvec_empty() {
+    __internal::test_arrayvec::<0>(&[]).unwrap();
+}
+
+#[test]
+fn test_arrayvec_ascending() {
+    __internal::test_arrayvec::<8>(&[1, 2, 3, 4, 5]).unwrap();
+}
+
+#[test]
+fn test_arrayvec_leading_zeros() {
+    __internal::test_arrayvec::<10>(&[0, 0, 1, 0, 0, 1]).unwrap();
+}
+
+#[test]
+fn test_arrayvec_all_zeros() {
+    __internal::test_arrayvec::<7>(&[0, 0, 0, 0]).unwrap();
+}
+
+#[test]
+fn test_arrayvec_hello_world() {
+    __internal::test_arrayvec::<64>(b"hello world").unwrap();
+}
+
+#[test]
+fn test_arrayvec_encode_buffer_too_small() {
+    let result = encode_arrayvec::<4>(&[42, 42, 42]);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 4 }));
+}
+
+#[test]
+fn test_arrayvec_decode_buffer_too_small() {
+    let result = decode_arrayvec::<4>(b"2MAHA");
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 4 }));
+}
+
+#[test]
+fn test_arrayvec_decode_invalid_character() {
+    let result = decode_arrayvec::<8>(b"2MAHU");
+    assert_eq!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: 'U',
+            index: 4,
+            decoded: 2,
+        })
+    );
+}
+
+#[test]
+fn test_check_arrayvec_empty() {
+    __internal::test_check_arrayvec::<8>(&[]).unwrap();
+}
+
+#[test]
+fn test_check_arrayvec_hash160() {
+    __internal::test_check_arrayvec::<40>(&[0xAB; 20]).unwrap();
+    __internal::test_check_arrayvec::<40>(&[0; 20]).unwrap();
+}
+
+#[test]
+fn test_check_arrayvec_buffer_too_small() {
+    let result = encode_check_arrayvec::<39>(&[0xAB; 20], 22);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 40, len: 39 }));
+
+    let en = encode_check([0xAB; 20], 22).unwrap();
+    let result = decode_check_arrayvec::<39>(en.as_bytes());
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 40, len: 39 }));
+}
+
+#[test]
+fn test_check_arrayvec_invalid_version() {
+    let result = encode_check_arrayvec::<40>(&[0xAB; 20], 32);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidVersion { version: 32, .. })
+    ));
+}
@@ -0,0 +1,149 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::checksum;
+use c32::checksum::RingBackend;
+use c32::checksum::Sha256Backend;
+use c32::checksum::Sha2Backend;
+use c32::decode_check_into_with_backend;
+use c32::decode_check_with_backend;
+use c32::encode_check_into_with_backend;
+use c32::encode_check_with_backend;
+use c32::Error;
+
+mod __internal {
+    use sha2_rustcrypto::Digest;
+    use sha2_rustcrypto::Sha256;
+
+    use super::*;
+
+    /// A user-provided backend, using the `sha2` crate.
+    pub struct RustCryptoBackend;
+
+    impl Sha256Backend for RustCryptoBackend {
+        fn digest(data_parts: &[&[u8]]) -> [u8; 32] {
+            let mut hasher = Sha256::new();
+            for part in data_parts {
+                hasher.update(part);
+            }
+            hasher.finalize().into()
+        }
+    }
+
+    /// Published Base32Check vectors, as `(bytes, version, encoded)`.
+    pub const VECTORS: &[(&[u8], u8, &str)] = &[
+        (&[42, 42, 42], 0, "0AHA59B9201Z"),
+        (b"usque ad finem", 22, "P7AWVHENJJ0RB441K6JVK5DNJ7J3V5"),
+        (&[0; 20], 22, "P000000000000000000002Q6VF78"),
+        (&[0; 20], 26, "T000000000000000000002AMW42H"),
+        (
+            &[
+                0xa4, 0x6f, 0xf8, 0x88, 0x86, 0xc2, 0xef, 0x97, 0x62, 0xd9,
+                0x70, 0xb4, 0xd2, 0xc6, 0x36, 0x78, 0x83, 0x5b, 0xd3, 0x9d,
+            ],
+            22,
+            "P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7",
+        ),
+    ];
+
+    /// Asserts that a backend reproduces the published vectors.
+    pub fn assert_vectors<H: Sha256Backend>() {
+        for &(bytes, version, encoded) in VECTORS {
+            let en = encode_check_with_backend::<H, _>(bytes, version).unwrap();
+            assert_eq!(en, encoded);
+
+            let de = decode_check_with_backend::<H>(encoded).unwrap();
+            assert_eq!(de, (bytes.to_vec(), version));
+        }
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_backend_vectors() {
+    assert_vectors::<Sha2Backend>();
+    assert_vectors::<RingBackend>();
+    assert_vectors::<RustCryptoBackend>();
+}
+
+#[test]
+fn test_backend_digest() {
+    let parts: &[&[u8]] = &[b"usque ", b"ad ", b"", b"finem"];
+    let expected = Sha2Backend::digest(&[b"usque ad finem"]);
+    assert_eq!(Sha2Backend::digest(parts), expected);
+    assert_eq!(RingBackend::digest(parts), expected);
+    assert_eq!(RustCryptoBackend::digest(parts), expected);
+}
+
+#[test]
+fn test_backend_checksum() {
+    let bytes = [0, 0, 1, 2, 3, 42, 255];
+    for version in 0..32 {
+        let sum = checksum::compute(&bytes, version);
+        assert_eq!(
+            checksum::compute_with_backend::<RingBackend>(&bytes, version),
+            sum
+        );
+        assert_eq!(
+            checksum::compute_with_backend::<RustCryptoBackend>(
+                &bytes, version
+            ),
+            sum
+        );
+    }
+}
+
+#[test]
+fn test_backend_default_unchanged() {
+    // Assert that enabling other backends does not alter the default path.
+    for &(bytes, version, encoded) in VECTORS {
+        assert_eq!(c32::encode_check(bytes, version).unwrap(), encoded);
+        assert_eq!(
+            encode_check_with_backend::<RingBackend, _>(bytes, version),
+            c32::encode_check(bytes, version)
+        );
+    }
+}
+
+#[test]
+fn test_backend_into() {
+    let mut en = [0u8; 32];
+    let mut de = [0u8; 32];
+
+    let pos =
+        encode_check_into_with_backend::<RingBackend>(&[42; 3], &mut en, 0)
+            .unwrap();
+    assert_eq!(&en[..pos], b"0AHA59B9201Z");
+
+    let (pos, version) = decode_check_into_with_backend::<RustCryptoBackend>(
+        &en[..pos],
+        &mut de,
+    )
+    .unwrap();
+    assert_eq!((&de[..pos], version), (&[42u8; 3][..], 0));
+}
+
+#[test]
+fn test_backend_errors() {
+    assert_eq!(
+        encode_check_with_backend::<RingBackend, _>([42], 32),
+        Err(Error::InvalidVersion {
+            expected: "must be < 32",
+            version: 32
+        })
+    );
+    assert!(matches!(
+        decode_check_with_backend::<RingBackend>("0AHA59B9201Y"),
+        Err(Error::ChecksumMismatch { version: 0, .. })
+    ));
+    assert!(matches!(
+        decode_check_with_backend::<RustCryptoBackend>("0"),
+        Err(Error::InsufficientData { .. })
+    ));
+}
@@ -0,0 +1,169 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+//! Interop tests against the Crockford variant of the `base32` crate.
+//!
+//! The `base32` crate encodes bytes in blocks, the same as RFC 4648 but with
+//! the Crockford alphabet and without padding. The block mode of this crate,
+//! [`c32::encode_blocks`], agrees with it up to the padding. The default
+//! mode, [`c32::encode`], reads the bytes as a single number instead, so the
+//! outputs only coincide in the cases asserted below.
+
+use std::fs;
+
+use base32::Alphabet;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    use super::*;
+
+    /// Encodes bytes with the `base32` crate.
+    pub fn encode(bytes: &[u8]) -> String {
+        base32::encode(Alphabet::Crockford, bytes)
+    }
+
+    /// Decodes a string with the `base32` crate.
+    pub fn decode(str: &str) -> Option<Vec<u8>> {
+        base32::decode(Alphabet::Crockford, str)
+    }
+
+    /// Asserts that the block mode agrees with the `base32` crate.
+    pub fn assert_blocks(bytes: &[u8]) {
+        let padded = c32::encode_blocks(bytes);
+        let unpadded = padded.trim_end_matches('=');
+        assert_eq!(unpadded, encode(bytes));
+
+        // Assert that both decode the encoding to the same bytes.
+        assert_eq!(c32::decode_blocks(&padded).unwrap(), bytes);
+        assert_eq!(decode(unpadded).unwrap(), bytes);
+    }
+
+    /// Returns the sample files, split into lines.
+    pub fn samples() -> Vec<Vec<u8>> {
+        let mut lines = Vec::new();
+        for name in ["c32_m_100x32b", "c32_m_100x64b", "c32_m_100x128b"] {
+            let input = fs::read(format!("../samples/{name}.in")).unwrap();
+            lines.extend(input.split(|&b| b == b'\n').map(<[u8]>::to_vec));
+        }
+        lines
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_base32_blocks_rand() {
+    let mut rng = rand::rng();
+    for _ in 0..1024 {
+        let len = rng.random_range(0..64);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        assert_blocks(&bytes);
+    }
+}
+
+#[test]
+fn test_base32_blocks_samples() {
+    for line in samples() {
+        assert_blocks(&line);
+    }
+
+    let input = fs::read("../samples/c32_s_64k.in").unwrap();
+    assert_blocks(&input);
+}
+
+#[test]
+fn test_base32_blocks_zeros() {
+    // In block mode, zero bytes are ordinary bits on both sides.
+    for len in 0..16 {
+        assert_blocks(&vec![0; len]);
+    }
+}
+
+#[test]
+fn test_base32_numeric_aligned() {
+    let mut rng = rand::rng();
+    for _ in 0..1024 {
+        let len = rng.random_range(1..13) * 5;
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        bytes[0] |= 0x08;
+
+        // A number of `5n` bytes fills exactly `8n` symbols, so the numeric
+        // and the block encoding coincide if the top symbol is non-zero.
+        assert_eq!(c32::encode(&bytes), encode(&bytes));
+    }
+}
+
+#[test]
+fn test_base32_numeric_unaligned() {
+    // A number is aligned to its least significant bit, whereas blocks are
+    // aligned to the most significant bit and zero-filled at the end.
+    assert_eq!(c32::encode([42, 42, 42]), "2MAHA");
+    assert_eq!(encode(&[42, 42, 42]), "58N2M");
+    assert_eq!(c32::encode(b"usque ad"), "7AWVHENJJ0RB4");
+    assert_eq!(encode(b"usque ad"), "ENSQ2XB541GP8");
+    assert_eq!(c32::encode(b"ad"), "RB4");
+    assert_eq!(encode(b"ad"), "C5J0");
+}
+
+#[test]
+fn test_base32_numeric_zeros() {
+    // Every leading zero byte is a single `0` symbol in numeric mode, while
+    // blocks encode the zero bits like any other.
+    assert_eq!(c32::encode([0]), "0");
+    assert_eq!(encode(&[0]), "00");
+    assert_eq!(c32::encode([0, 0, 0, 0, 0]), "00000");
+    assert_eq!(encode(&[0, 0, 0, 0, 0]), "00000000");
+
+    // The top symbol of an aligned number is dropped if it is zero.
+    assert_eq!(c32::encode([1, 2, 3, 4, 5]), "4106105");
+    assert_eq!(encode(&[1, 2, 3, 4, 5]), "04106105");
+}
+
+#[test]
+fn test_base32_decode_aliases() {
+    // Both read lowercase symbols and the `O`, `I` and `L` aliases.
+    for (alias, canonical) in [("58n2m", "58N2M"), ("O4IL", "0411")] {
+        assert_eq!(decode(alias), decode(canonical));
+        assert_eq!(
+            c32::decode_blocks(&format!("{alias}====")),
+            c32::decode_blocks(&format!("{canonical}===="))
+        );
+    }
+
+    // Neither reads the excluded `U`.
+    assert_eq!(decode("58U2M"), None);
+    assert!(matches!(
+        c32::decode_blocks("58U2M==="),
+        Err(Error::InvalidCharacter {
+            char: 'U',
+            index: 2,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_base32_decode_strict() {
+    // The `base32` crate discards the unused bits of the last symbol, while
+    // the block mode rejects every non-canonical encoding.
+    assert_eq!(decode("58N2N"), Some(vec![42, 42, 42]));
+    assert_eq!(
+        c32::decode_blocks("58N2N==="),
+        Err(Error::InvalidPadding { index: 4 })
+    );
+
+    // The `base32` crate reads unpadded blocks, the block mode does not.
+    assert_eq!(decode("58N2M"), Some(vec![42, 42, 42]));
+    assert_eq!(
+        c32::decode_blocks("58N2M"),
+        Err(Error::InvalidPadding { index: 5 })
+    );
+}
@@ -0,0 +1,111 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_blocks;
+use c32::decode_blocks_into;
+use c32::decoded_blocks_len;
+use c32::encode_blocks;
+use c32::encode_blocks_into;
+use c32::encoded_blocks_len;
+use c32::Error;
+
+#[test]
+fn test_blocks_legal_padding() {
+    // Assert that every legal padding count maps to the exact byte count.
+    let vectors: [(&str, &[u8]); 6] = [
+        ("", &[]),
+        ("58======", &[42]),
+        ("58N0====", &[42, 42]),
+        ("58N2M===", &[42, 42, 42]),
+        ("58N2MAG=", &[42, 42, 42, 42]),
+        ("58N2MAHA", &[42, 42, 42, 42, 42]),
+    ];
+    for (en, de) in vectors {
+        assert_eq!(encode_blocks(de), en);
+        assert_eq!(decode_blocks(en).unwrap(), de);
+    }
+}
+
+#[test]
+fn test_blocks_roundtrip() {
+    for len in 0..=64 {
+        let bytes: Vec<u8> =
+            (0..len).map(|i| (i as u8).wrapping_mul(97)).collect();
+        let en = encode_blocks(&bytes);
+        assert_eq!(en.len(), encoded_blocks_len(len));
+        assert_eq!(decode_blocks(&en).unwrap(), bytes);
+    }
+
+    // Assert that leading zero bytes are preserved.
+    assert_eq!(encode_blocks(&[0, 0, 0, 0, 0, 1]), "0000000004======");
+    assert_eq!(
+        decode_blocks("0000000004======").unwrap(),
+        [0, 0, 0, 0, 0, 1]
+    );
+}
+
+#[test]
+fn test_blocks_illegal_padding() {
+    let cases = [
+        // Incomplete final block.
+        ("58N2M==", 7),
+        ("58N2MAH", 7),
+        // Illegal padding counts of 2, 5 and 7.
+        ("58N2MA==", 6),
+        ("58N=====", 3),
+        ("5=======", 1),
+        // A full block of padding.
+        ("58N2MAHA========", 8),
+        // A character after the first `=`.
+        ("58N2M=A=", 6),
+        ("58N2M==A", 7),
+        ("58======58======", 8),
+        // Non-zero unused bits in the last character.
+        ("59======", 1),
+        ("58N2MAH=", 6),
+    ];
+    for (en, index) in cases {
+        assert_eq!(
+            decode_blocks(en),
+            Err(Error::InvalidPadding { index }),
+            "{en}"
+        );
+    }
+}
+
+#[test]
+fn test_blocks_invalid_character() {
+    assert_eq!(
+        decode_blocks("58N2MAHA58!2M==="),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 10,
+            decoded: 6,
+        })
+    );
+}
+
+#[test]
+fn test_blocks_into_buffer_too_small() {
+    let mut dst = [0u8; 7];
+    assert_eq!(
+        encode_blocks_into(&[42], &mut dst),
+        Err(Error::BufferTooSmall { min: 8, len: 7 })
+    );
+
+    let mut dst = [0u8; 2];
+    assert_eq!(
+        decode_blocks_into(b"58N2M===", &mut dst),
+        Err(Error::BufferTooSmall { min: 3, len: 2 })
+    );
+
+    // Assert that the exact decoded length suffices.
+    let mut dst = [0u8; 3];
+    assert_eq!(decode_blocks_into(b"58N2M===", &mut dst), Ok(3));
+    assert!(decoded_blocks_len(8) >= 3);
+}
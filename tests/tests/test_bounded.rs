@@ -0,0 +1,103 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode;
+use c32::decode_bounded;
+use c32::encode;
+use c32::Error;
+use rand::Rng;
+
+#[test]
+fn test_bounded_limits() {
+    let bytes = [0xA5u8; 64];
+    let en = encode(bytes);
+
+    // Assert that the limit is inclusive.
+    assert_eq!(decode_bounded(&en, 65).unwrap(), bytes);
+    assert_eq!(decode_bounded(&en, 64).unwrap(), bytes);
+    assert_eq!(
+        decode_bounded(&en, 63),
+        Err(Error::OutputTooLarge { max: 63, got: 64 })
+    );
+}
+
+#[test]
+fn test_bounded_empty() {
+    assert_eq!(decode_bounded("", 0).unwrap(), [0u8; 0]);
+    assert_eq!(decode_bounded("0", 1).unwrap(), [0]);
+    assert_eq!(
+        decode_bounded("0", 0),
+        Err(Error::OutputTooLarge { max: 0, got: 1 })
+    );
+}
+
+#[test]
+fn test_bounded_aliases() {
+    // The lenient aliases decode to zero bytes, like `0`.
+    assert_eq!(decode("OO1").unwrap(), [0, 0, 1]);
+    assert_eq!(decode_bounded("OO1", 3).unwrap(), [0, 0, 1]);
+    assert_eq!(
+        decode_bounded("00O1", 3),
+        Err(Error::OutputTooLarge { max: 3, got: 4 })
+    );
+}
+
+#[test]
+fn test_bounded_invalid_character() {
+    let result = decode_bounded("2M!HA", 8);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 2,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_bounded_rand() {
+    let mut rng = rand::rng();
+    for _ in 0..512 {
+        let len = rng.random_range(0..64);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..4).min(len);
+        bytes[..zeros].fill(0);
+
+        let en = encode(&bytes);
+        assert_eq!(decode_bounded(&en, len).unwrap(), bytes);
+        if len > 0 {
+            assert_eq!(
+                decode_bounded(&en, len - 1),
+                Err(Error::OutputTooLarge {
+                    max: len - 1,
+                    got: len
+                })
+            );
+        }
+    }
+
+    // Assert that arbitrary input is bounded by its decoded length.
+    let chars = b"0123456789ABCDEFGHJKMNPQRSTVWXYZOoIil";
+    for _ in 0..512 {
+        let len = rng.random_range(0..32);
+        let str: String = (0..len)
+            .map(|_| chars[rng.random_range(0..chars.len())] as char)
+            .collect();
+
+        let de = decode(&str).unwrap();
+        assert_eq!(decode_bounded(&str, de.len()).unwrap(), de);
+        if !de.is_empty() {
+            let result = decode_bounded(&str, de.len() - 1);
+            assert!(
+                matches!(result, Err(Error::OutputTooLarge { got, .. }) if got == de.len())
+            );
+        }
+    }
+}
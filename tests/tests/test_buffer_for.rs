@@ -0,0 +1,64 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::buffer_for;
+use c32::en::Check;
+use c32::encoded_check_len;
+use c32::encoded_len;
+use c32::Buffer;
+
+#[test]
+fn test_buffer_required_for() {
+    for n in [0, 1, 3, 20, 512, 51_300] {
+        assert_eq!(Buffer::<0>::required_for(n), encoded_len(n));
+        assert_eq!(Buffer::<0, true>::required_for(n), encoded_len(n) + 1);
+        assert_eq!(
+            Buffer::<0, false, Check>::required_for(n),
+            encoded_check_len(n)
+        );
+        assert_eq!(
+            Buffer::<0, true, Check>::required_for(n),
+            1 + encoded_len(n + 4) + 1
+        );
+    }
+
+    // Assert that the size does not depend on the size of the buffer.
+    assert_eq!(Buffer::<64>::required_for(3), Buffer::<0>::required_for(3));
+}
+
+#[test]
+fn test_buffer_for_roundtrip() {
+    const BYTES: [u8; 3] = [42, 42, 42];
+
+    const EN: buffer_for!(3) = <buffer_for!(3)>::encode(&BYTES);
+    const DE: buffer_for!(3) = <buffer_for!(3)>::decode(EN.as_bytes());
+    assert_eq!((EN.as_str(), DE.as_bytes()), ("2MAHA", &BYTES[..]));
+
+    type Prefixed = buffer_for!(prefixed, 3);
+    const EN_P: Prefixed = Prefixed::encode(&BYTES, 'S');
+    const DE_P: Prefixed = Prefixed::decode(EN_P.as_bytes(), 'S');
+    assert_eq!((EN_P.as_str(), DE_P.as_bytes()), ("S2MAHA", &BYTES[..]));
+
+    type Checked = buffer_for!(check, 3);
+    const EN_C: Checked = Checked::encode(&BYTES, 0);
+    const DE_C: (Checked, u8) = Checked::decode(EN_C.as_bytes());
+    assert_eq!(EN_C.as_str(), "0AHA59B9201Z");
+    assert_eq!((DE_C.0.as_bytes(), DE_C.1), (&BYTES[..], 0));
+
+    type Id = buffer_for!(check, prefixed, 3);
+    const EN_CP: Id = Id::encode(&BYTES, 'S', 0);
+    const DE_CP: (Id, u8) = Id::decode(EN_CP.as_bytes(), 'S');
+    assert_eq!(EN_CP.as_str(), "S0AHA59B9201Z");
+    assert_eq!((DE_CP.0.as_bytes(), DE_CP.1), (&BYTES[..], 0));
+}
+
+#[test]
+fn test_buffer_for_too_small() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("ui/buffer_*.rs");
+}
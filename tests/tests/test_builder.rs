@@ -0,0 +1,85 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::fs;
+
+use c32::encode_sortable;
+use c32::encoded_len;
+use c32::C32Builder;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    use super::*;
+
+    /// Pushes `input` in random-sized chunks, and compares the output with a
+    /// single-shot fixed-width encode.
+    pub fn test_chunks<const CAP: usize>(input: &[u8]) {
+        let mut rng = rand::rng();
+        let mut builder = C32Builder::<CAP>::new();
+
+        let mut pos = 0;
+        while pos < input.len() {
+            let len = rng.random_range(0..=(input.len() - pos).min(97));
+            builder.push_bytes(&input[pos..pos + len]).unwrap();
+            pos += len;
+        }
+
+        let expected =
+            encode_sortable(input, encoded_len(input.len())).unwrap();
+        assert_eq!(builder.finish().as_str(), expected);
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_builder_sample_32b() {
+    let input = fs::read("../samples/c32_m_100x32b.in").unwrap();
+    test_chunks::<8192>(&input);
+}
+
+#[test]
+fn test_builder_sample_64b() {
+    let input = fs::read("../samples/c32_m_100x64b.in").unwrap();
+    test_chunks::<16384>(&input);
+}
+
+#[test]
+fn test_builder_leading_zeros() {
+    test_chunks::<32>(&[0, 0, 0, 1, 2, 3]);
+    test_chunks::<32>(&[0; 16]);
+    test_chunks::<32>(&[]);
+}
+
+#[test]
+fn test_builder_matches_encode() {
+    // Without leading zero bytes, the output equals `encode` when the top
+    // symbol is non-zero.
+    let mut builder = C32Builder::<16>::new();
+    builder.push_bytes(b"usque").unwrap();
+    builder.push_bytes(b" ad").unwrap();
+    assert_eq!(builder.finish().as_str(), c32::encode(b"usque ad"));
+}
+
+#[test]
+fn test_builder_overflow() {
+    let mut builder = C32Builder::<8>::new();
+    assert_eq!(builder.remaining_capacity(), 5);
+
+    builder.push_bytes(&[1, 2, 3]).unwrap();
+    assert_eq!(builder.remaining_capacity(), 2);
+
+    let result = builder.push_bytes(&[4, 5, 6]);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 10, len: 8 }));
+
+    // Assert that a failed push leaves the builder untouched.
+    builder.push_bytes(&[4, 5]).unwrap();
+    assert_eq!(builder.remaining_capacity(), 0);
+    assert_eq!(builder.finish().as_str(), "04106105");
+}
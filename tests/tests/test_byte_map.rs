@@ -0,0 +1,116 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::alphabet;
+use c32::decode_into;
+use c32::version_from_char;
+use c32::Error;
+
+mod __internal {
+    /// Builds the expected decoding map from the Crockford specification.
+    pub fn expected_map() -> [Option<u8>; 256] {
+        let mut map = [None; 256];
+
+        for (value, &char) in
+            c32::alphabet::CROCKFORD.as_bytes().iter().enumerate()
+        {
+            map[char as usize] = Some(value as u8);
+            map[char.to_ascii_lowercase() as usize] = Some(value as u8);
+        }
+
+        // 'O' is read as '0', 'I' and 'L' are read as '1'.
+        for (alias, value) in [(b'O', 0), (b'I', 1), (b'L', 1)] {
+            map[alias as usize] = Some(value);
+            map[alias.to_ascii_lowercase() as usize] = Some(value);
+        }
+
+        map
+    }
+
+    /// The hand-written ASCII decoding map of `c32 0.6`.
+    #[rustfmt::skip]
+    pub const LEGACY_MAP: [i8; 128] = [
+        -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+        -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+        -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, -1,
+        -1, -1, -1, -1, -1, -1, 10, 11, 12, 13, 14, 15, 16, 17, 1, 18, 19, 1, 20,
+        21, 0, 22, 23, 24, 25, 26, -1, 27, 28, 29, 30, 31, -1, -1, -1, -1, -1, -1,
+        10, 11, 12, 13, 14, 15, 16, 17, 1, 18, 19, 1, 20, 21, 0, 22, 23, 24, 25,
+        26, -1, 27, 28, 29, 30, 31, -1, -1, -1, -1, -1,
+    ];
+
+    /// Decodes a single character into its value.
+    pub fn decode_char(byte: u8) -> Option<u8> {
+        let mut dst = [0u8; 1];
+        match c32::decode_into(&[byte], &mut dst) {
+            Ok(1) => Some(dst[0]),
+            // A zero value decodes to no bytes, unless it is a leading '0'.
+            Ok(_) => Some(0),
+            Err(_) => None,
+        }
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_byte_map_matches_alphabet() {
+    let expected = expected_map();
+
+    // Assert that every byte decodes to the value of the specification.
+    for byte in 0..=255u8 {
+        assert_eq!(
+            decode_char(byte),
+            expected[byte as usize],
+            "byte {byte:#04x}"
+        );
+    }
+}
+
+#[test]
+fn test_byte_map_matches_legacy_map() {
+    // Assert that the generated map is identical to the hand-written one.
+    for byte in 0..=255u8 {
+        let legacy = LEGACY_MAP
+            .get(byte as usize)
+            .and_then(|&value| u8::try_from(value).ok());
+        assert_eq!(decode_char(byte), legacy, "byte {byte:#04x}");
+    }
+}
+
+#[test]
+fn test_byte_map_version_from_char() {
+    let expected = expected_map();
+
+    for byte in 0..128u8 {
+        assert_eq!(version_from_char(byte as char), expected[byte as usize]);
+    }
+    assert_eq!(version_from_char('Ö'), None);
+}
+
+#[test]
+fn test_byte_map_spec() {
+    let expected = expected_map();
+
+    // Assert the specification itself, independent of the alphabet constant.
+    assert_eq!(
+        alphabet::CROCKFORD.as_bytes(),
+        b"0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+    );
+    assert_eq!(
+        expected.iter().filter(|v| v.is_some()).count(),
+        32 * 2 - 10 + 6
+    );
+    for byte in [b'U', b'u', b'!', b' ', 0x80, 0xFF] {
+        assert_eq!(expected[byte as usize], None);
+        assert!(matches!(
+            decode_into(&[byte], &mut [0u8; 1]),
+            Err(Error::InvalidCharacter { index: 0, .. })
+        ));
+    }
+}
@@ -0,0 +1,167 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use bytes::Buf;
+use bytes::BytesMut;
+use c32::decode;
+use c32::decode_buf;
+use c32::encode;
+use c32::encode_buf;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    use super::*;
+
+    /// A [`Buf`] that yields its bytes in chunks of at most `max` bytes.
+    pub struct Chunked<'a> {
+        pub bytes: &'a [u8],
+        pub max: usize,
+    }
+
+    impl Buf for Chunked<'_> {
+        fn remaining(&self) -> usize {
+            self.bytes.len()
+        }
+
+        fn chunk(&self) -> &[u8] {
+            &self.bytes[..self.bytes.len().min(self.max)]
+        }
+
+        fn advance(&mut self, cnt: usize) {
+            self.bytes = &self.bytes[cnt..];
+        }
+    }
+
+    /// A test helper for `[feature = "bytes"]`.
+    pub fn test_buf(bytes: &[u8], max: usize) {
+        let expected = encode(bytes);
+
+        let mut en = BytesMut::new();
+        let n = encode_buf(Chunked { bytes, max }, &mut en).unwrap();
+        assert_eq!(n, expected.len());
+        assert_eq!(&en[..], expected.as_bytes());
+
+        let mut de = BytesMut::new();
+        let n = decode_buf(Chunked { bytes: &en, max }, &mut de).unwrap();
+        assert_eq!(n, bytes.len());
+        assert_eq!(&de[..], bytes);
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_buf_empty() {
+    test_buf(&[], 1);
+}
+
+#[test]
+fn test_buf_chunk_boundaries() {
+    // Assert that every chunk size splits the 5-byte groups differently.
+    let bytes: Vec<u8> = (0..=255).collect();
+    for max in 1..=11 {
+        test_buf(&bytes, max);
+        test_buf(&bytes[..7], max);
+    }
+}
+
+#[test]
+fn test_buf_leading_zeros() {
+    for max in 1..=4 {
+        test_buf(&[0, 0, 0, 1, 2], max);
+        test_buf(&[0; 9], max);
+    }
+}
+
+#[test]
+fn test_buf_chain() {
+    let bytes = b"hello world";
+    let src = (&bytes[..3]).chain(&bytes[3..4]).chain(&bytes[4..]);
+
+    let mut dst = Vec::new();
+    let n = encode_buf(src, &mut dst).unwrap();
+    assert_eq!(n, dst.len());
+    assert_eq!(dst, encode(bytes).as_bytes());
+
+    let src = (&dst[..5]).chain(&dst[5..]);
+    let mut de = Vec::new();
+    decode_buf(src, &mut de).unwrap();
+    assert_eq!(de, bytes);
+}
+
+#[test]
+fn test_buf_random() {
+    let mut rng = rand::rng();
+    for _ in 0..128 {
+        let len = rng.random_range(0..1024);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        test_buf(&bytes, rng.random_range(1..64));
+    }
+}
+
+#[test]
+fn test_buf_large() {
+    // Assert that the output is flushed correctly past the staging buffer.
+    let bytes = vec![0xA5; 4096];
+    test_buf(&bytes, 1000);
+    test_buf(&bytes, 4096);
+}
+
+#[test]
+fn test_buf_buffer_too_small() {
+    let mut dst = [0u8; 4];
+    let result = encode_buf(&[42u8, 42, 42][..], &mut &mut dst[..]);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 4 }));
+
+    let result = decode_buf(&b"2MAHA"[..], &mut &mut dst[..]);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 4 }));
+
+    // Assert that exactly enough capacity succeeds.
+    let mut dst = [0u8; 5];
+    let n = encode_buf(&[42u8, 42, 42][..], &mut &mut dst[..]).unwrap();
+    assert_eq!(&dst[..n], b"2MAHA");
+}
+
+#[test]
+fn test_buf_invalid_character() {
+    let src = Chunked {
+        bytes: b"2MA!HA",
+        max: 2,
+    };
+    let mut dst = Vec::new();
+    let result = decode_buf(src, &mut dst);
+    assert_eq!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 3,
+            decoded: 2,
+        })
+    );
+
+    // Assert that the same input is rejected by `decode`.
+    assert!(decode("2MA!HA").is_err());
+
+    // Assert that the last invalid character is reported, as by `decode`.
+    let src = Chunked {
+        bytes: "é2M!HA".as_bytes(),
+        max: 2,
+    };
+    let result = decode_buf(src, &mut Vec::new());
+    assert_eq!(result.map(|_| ()), decode("é2M!HA").map(|_| ()));
+    assert!(matches!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 4,
+            ..
+        })
+    ));
+}
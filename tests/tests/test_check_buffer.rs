@@ -0,0 +1,109 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::check_buffer_len;
+use c32::en::Check;
+use c32::Buffer;
+use c32::CheckBuffer20;
+use c32::CheckBuffer32;
+use c32::PrefixedCheckBuffer20;
+use c32::PrefixedCheckBuffer32;
+
+const HASH: [u8; 20] = [
+    0xa4, 0x6f, 0xf8, 0x88, 0x86, 0xc2, 0xef, 0x97, 0x62, 0xd9, 0x70, 0xb4,
+    0xd2, 0xc6, 0x36, 0x78, 0x83, 0x5b, 0xd3, 0x9d,
+];
+
+mod __internal {
+    use super::*;
+
+    /// Returns the size `N` of a check-encoded [`Buffer`].
+    pub fn capacity<const N: usize, const P: bool>(
+        _: &Buffer<N, P, Check>,
+    ) -> usize {
+        N
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_check_buffer_len() {
+    for m in [0, 1, 3, 20, 32, 512] {
+        assert_eq!(
+            check_buffer_len(m, false),
+            Buffer::<0, false, Check>::required_for(m)
+        );
+        assert_eq!(
+            check_buffer_len(m, true),
+            Buffer::<0, true, Check>::required_for(m)
+        );
+    }
+
+    // Assert the sizes of the aliases.
+    let en = CheckBuffer20::encode_payload(&HASH, 22);
+    assert_eq!(capacity(&en), 40);
+    let en = PrefixedCheckBuffer20::encode_payload(&HASH, 'S', 22);
+    assert_eq!(capacity(&en), 41);
+    let en = CheckBuffer32::encode_payload(&[0; 32], 22);
+    assert_eq!(capacity(&en), 59);
+    let en = PrefixedCheckBuffer32::encode_payload(&[0; 32], 'S', 22);
+    assert_eq!(capacity(&en), 60);
+}
+
+#[test]
+fn test_check_buffer_20_roundtrip() {
+    const EN: CheckBuffer20 = CheckBuffer20::encode_payload(&HASH, 22);
+    assert_eq!(EN.as_str(), "P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7");
+
+    let (de, version) = CheckBuffer20::try_decode_str(EN.as_str()).unwrap();
+    assert_eq!((de.as_bytes(), version), (&HASH[..], 22));
+
+    const EN_P: PrefixedCheckBuffer20 =
+        PrefixedCheckBuffer20::encode_payload(&HASH, 'S', 22);
+    assert_eq!(EN_P.as_str(), "SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7");
+
+    let (de, version) =
+        PrefixedCheckBuffer20::try_decode_str(EN_P.as_str(), 'S').unwrap();
+    assert_eq!((de.as_bytes(), version), (&HASH[..], 22));
+}
+
+#[test]
+fn test_check_buffer_32_roundtrip() {
+    let bytes = [0xAB; 32];
+
+    let en = CheckBuffer32::encode_payload(&bytes, 0);
+    let (de, version) = CheckBuffer32::try_decode_str(en.as_str()).unwrap();
+    assert_eq!((de.as_bytes(), version), (&bytes[..], 0));
+
+    let en = PrefixedCheckBuffer32::encode_payload(&bytes, 'S', 0);
+    let (de, version) =
+        PrefixedCheckBuffer32::try_decode_str(en.as_str(), 'S').unwrap();
+    assert_eq!((de.as_bytes(), version), (&bytes[..], 0));
+}
+
+#[test]
+fn test_check_buffer_generic() {
+    // Assert that the aliases match the generic buffers.
+    for version in [0, 22, 31] {
+        let en = CheckBuffer20::encode_payload(&HASH, version);
+        let generic = Buffer::<40, false, Check>::encode(&HASH, version);
+        assert_eq!(en.as_str(), generic.as_str());
+        assert_eq!(en.as_str(), c32::encode_check(HASH, version).unwrap());
+
+        let en = PrefixedCheckBuffer32::encode_payload(&[7; 32], 'S', version);
+        let generic = Buffer::<60, true, Check>::encode(&[7; 32], 'S', version);
+        assert_eq!(en.as_str(), generic.as_str());
+    }
+}
+
+#[test]
+fn test_check_buffer_wrong_size() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("ui/check_buffer_*.rs");
+}
@@ -0,0 +1,154 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::alphabet::Alphabet;
+use c32::checksum::RingBackend;
+use c32::checksum::Sha2Backend;
+use c32::decode_check_into;
+use c32::decode_check_into_with;
+use c32::decode_check_into_with_backend;
+use c32::en::Check;
+use c32::encode_check;
+use c32::Buffer;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    /// The byte the output buffers are pre-filled with.
+    pub const SENTINEL: u8 = 0xAA;
+
+    /// Published Base32Check vectors, as `(bytes, version, encoded)`.
+    pub const VECTORS: &[(&[u8], u8, &str)] = &[
+        (&[42, 42, 42], 0, "0AHA59B9201Z"),
+        (b"usque ad finem", 22, "P7AWVHENJJ0RB441K6JVK5DNJ7J3V5"),
+        (&[0; 20], 22, "P000000000000000000002Q6VF78"),
+        (&[0; 20], 26, "T000000000000000000002AMW42H"),
+        (
+            &[
+                0xa4, 0x6f, 0xf8, 0x88, 0x86, 0xc2, 0xef, 0x97, 0x62, 0xd9,
+                0x70, 0xb4, 0xd2, 0xc6, 0x36, 0x78, 0x83, 0x5b, 0xd3, 0x9d,
+            ],
+            22,
+            "P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7",
+        ),
+    ];
+
+    /// Asserts that `decode` leaves the buffer untouched past the payload.
+    pub fn assert_untouched<F>(encoded: &str, bytes: &[u8], version: u8, f: F)
+    where
+        F: Fn(&[u8], &mut [u8]) -> c32::Result<(usize, u8)>,
+    {
+        let mut dst = vec![SENTINEL; encoded.len() + 8];
+        let (offset, got) = f(encoded.as_bytes(), &mut dst).unwrap();
+        assert_eq!((&dst[..offset], got), (bytes, version));
+        assert!(dst[offset..].iter().all(|&b| b == SENTINEL));
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_check_into_vectors() {
+    for &(bytes, version, encoded) in VECTORS {
+        assert_untouched(encoded, bytes, version, decode_check_into);
+    }
+}
+
+#[test]
+fn test_check_into_backends() {
+    for &(bytes, version, encoded) in VECTORS {
+        assert_untouched(
+            encoded,
+            bytes,
+            version,
+            decode_check_into_with_backend::<Sha2Backend>,
+        );
+        assert_untouched(
+            encoded,
+            bytes,
+            version,
+            decode_check_into_with_backend::<RingBackend>,
+        );
+    }
+}
+
+#[test]
+fn test_check_into_alphabet() {
+    const LOWER: Alphabet = Alphabet::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+    assert_untouched("0aha59b9201z", &[42, 42, 42], 0, |src, dst| {
+        decode_check_into_with(&LOWER, src, dst)
+    });
+}
+
+#[test]
+fn test_check_into_rand() {
+    let mut rng = rand::rng();
+    for _ in 0..512 {
+        let len = rng.random_range(0..256);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+
+        // Cover leading zero bytes, which decode ahead of the number.
+        let zeros = rng.random_range(0..4).min(len);
+        bytes[..zeros].fill(0);
+
+        let version = rng.random_range(0..32);
+        let encoded = encode_check(&bytes, version).unwrap();
+        assert_untouched(&encoded, &bytes, version, decode_check_into);
+    }
+}
+
+#[test]
+fn test_check_into_errors() {
+    let mut dst = [SENTINEL; 32];
+
+    // Assert that the last invalid character is reported, as in `decode`.
+    assert!(matches!(
+        decode_check_into(b"0!HA59B92!1Z", &mut dst),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 9,
+            ..
+        })
+    ));
+    assert!(matches!(
+        decode_check_into(b"0AHA", &mut dst),
+        Err(Error::InsufficientData { min: 4, len: 2 })
+    ));
+    assert!(matches!(
+        decode_check_into(b"0AHA59B9201Y", &mut dst),
+        Err(Error::ChecksumMismatch {
+            version: 0,
+            len: 3,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_check_into_buffer_checksum() {
+    // Assert that the const decoding verifies the checksum.
+    let result = Buffer::<12, false, Check>::try_decode(b"0AHA59B9201Y");
+    assert!(matches!(
+        result,
+        Err(Error::ChecksumMismatch {
+            version: 0,
+            len: 3,
+            ..
+        })
+    ));
+
+    let result = Buffer::<14, true, Check>::try_decode(b"S0AHA59B9201Y", 'S');
+    assert!(matches!(result, Err(Error::ChecksumMismatch { .. })));
+
+    let result = Buffer::<4, false, Check>::try_decode(b"0AHA");
+    assert!(matches!(
+        result,
+        Err(Error::InsufficientData { min: 4, len: 2 })
+    ));
+}
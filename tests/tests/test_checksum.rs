@@ -0,0 +1,75 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::checksum;
+use c32::Error;
+use c32::Version;
+
+#[test]
+fn test_checksum_try_from_slice() {
+    let bytes = [1, 2, 3, 4, 5];
+    for len in 0..=5 {
+        let sum = checksum::try_from_slice(&bytes[..len]);
+        if len < checksum::BYTE_LENGTH {
+            assert_eq!(sum, None);
+        } else {
+            assert_eq!(sum, Some([1, 2, 3, 4]));
+        }
+    }
+}
+
+#[test]
+fn test_checksum_try_from_slice_const() {
+    const SUM: Option<checksum::Checksum> =
+        checksum::try_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(SUM, Some([1, 2, 3, 4]));
+
+    const NONE: Option<checksum::Checksum> = checksum::try_from_slice(&[1]);
+    assert_eq!(NONE, None);
+}
+
+#[test]
+fn test_checksum_from_slice() {
+    assert_eq!(checksum::from_slice(&[1, 2, 3, 4]), [1, 2, 3, 4]);
+    assert_eq!(checksum::from_slice(&[1, 2, 3, 4, 5]), [1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "Slice must contain min. 4 bytes")]
+fn test_checksum_from_slice_short() {
+    let _ = checksum::from_slice(&[1, 2]);
+}
+
+#[test]
+fn test_checksum_compute_versioned() {
+    let bytes = [0, 0, 1, 2, 3, 42, 255];
+    for version in 0..32 {
+        let v = Version::new(version).unwrap();
+        assert_eq!(
+            checksum::compute_versioned(&bytes, v),
+            checksum::compute(&bytes, version)
+        );
+    }
+}
+
+#[test]
+fn test_version_bounds() {
+    assert_eq!(Version::new(0).map(Version::get), Some(0));
+    assert_eq!(Version::new(31).map(Version::get), Some(31));
+    assert_eq!(Version::new(32), None);
+    assert_eq!(Version::new(255), None);
+
+    assert_eq!(
+        Version::try_from(32),
+        Err(Error::InvalidVersion {
+            expected: "must be < 32",
+            version: 32
+        })
+    );
+    assert_eq!(u8::from(Version::try_from(31).unwrap()), 31);
+}
@@ -0,0 +1,127 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::arg::C32CheckValueParser;
+use c32::arg::C32ValueParser;
+use c32::C32Text;
+use clap::error::ErrorKind;
+use clap::value_parser;
+use clap::Arg;
+use clap::Command;
+
+mod __internal {
+    use super::*;
+
+    /// Builds a command with a single `value` argument.
+    pub fn command(arg: Arg) -> Command {
+        Command::new("app").arg(arg.required(true))
+    }
+
+    /// Returns the rendered error of parsing `value`.
+    pub fn error(cmd: Command, value: &str) -> (ErrorKind, String) {
+        let err = cmd.try_get_matches_from(["app", value]).unwrap_err();
+        (err.kind(), err.to_string())
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_clap_plain() {
+    let cmd = command(Arg::new("value").value_parser(C32ValueParser::new()));
+    let matches = cmd.try_get_matches_from(["app", "0020G3"]).unwrap();
+    assert_eq!(
+        matches.get_one::<Vec<u8>>("value"),
+        Some(&vec![0, 0, 1, 2, 3])
+    );
+}
+
+#[test]
+fn test_clap_prefixed() {
+    let parser = C32ValueParser::new().prefix('€');
+    let cmd = command(Arg::new("value").value_parser(parser));
+    let matches = cmd.clone().try_get_matches_from(["app", "€2MAHA"]).unwrap();
+    assert_eq!(matches.get_one::<Vec<u8>>("value"), Some(&vec![42, 42, 42]));
+
+    // Assert that the caret is placed by char, not by byte.
+    let (kind, message) = error(cmd.clone(), "€2M!HA");
+    assert_eq!(kind, ErrorKind::ValueValidation);
+    assert!(message.contains("Invalid character '!' at position 5"));
+    assert!(message.contains("\n    €2M!HA\n       ^\n"));
+
+    let (_, message) = error(cmd, "2MAHA");
+    assert!(message.contains("Expected prefix '€', found '2'"));
+    assert!(message.contains("\n    2MAHA\n    ^\n"));
+}
+
+#[test]
+fn test_clap_check() {
+    let parser = C32CheckValueParser::new().prefix('S');
+    let cmd = command(Arg::new("value").value_parser(parser));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["app", "SPAHA58QT2DJ9"])
+        .unwrap();
+    assert_eq!(
+        matches.get_one::<(Vec<u8>, u8)>("value"),
+        Some(&(vec![42, 42, 42], 22))
+    );
+
+    let (kind, message) = error(cmd, "SPAHA58QT2DJ8");
+    assert_eq!(kind, ErrorKind::ValueValidation);
+    assert!(message.contains("Checksum mismatch"));
+    assert!(!message.contains('^'));
+}
+
+#[test]
+fn test_clap_expect_version() {
+    let parser = C32CheckValueParser::new().expect_version(22);
+    let cmd = command(Arg::new("value").value_parser(parser));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["app", "PAHA58QT2DJ9"])
+        .unwrap();
+    assert_eq!(
+        matches.get_one::<(Vec<u8>, u8)>("value"),
+        Some(&(vec![42, 42, 42], 22))
+    );
+
+    let (kind, message) = error(cmd, "0AHA59B9201Z");
+    assert_eq!(kind, ErrorKind::ValueValidation);
+    assert!(
+        message.contains("Invalid version 0: must match the expected version")
+    );
+}
+
+#[test]
+fn test_clap_from_str() {
+    // Assert that the newtypes are supported via `FromStr`.
+    let arg = Arg::new("value").value_parser(value_parser!(C32Text<3>));
+    let cmd = command(arg);
+    let matches = cmd.clone().try_get_matches_from(["app", "2MAHA"]).unwrap();
+    assert_eq!(
+        matches.get_one::<C32Text<3>>("value"),
+        Some(&C32Text::new([42, 42, 42]))
+    );
+
+    let (kind, message) = error(cmd, "2MAHA2");
+    assert_eq!(kind, ErrorKind::ValueValidation);
+    assert!(message.contains("Invalid data size"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_clap_invalid_utf8() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let cmd = command(Arg::new("value").value_parser(C32ValueParser::new()));
+    let args = [OsStr::new("app"), OsStr::from_bytes(b"2M\xffHA")];
+    let err = cmd.try_get_matches_from(args).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidUtf8);
+}
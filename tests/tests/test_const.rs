@@ -8,6 +8,7 @@
 
 use c32::en::Check;
 use c32::Buffer;
+use c32::Error;
 
 mod __internal {
     /// A test helper for default features.
@@ -501,3 +502,229 @@ __internal::const_test_check_prefixed! {
     VERSION = 0,
     EXPECTED = "S00200005F1PN5G"
 }
+
+#[test]
+fn test_eq_str() {
+    const EN: Buffer<5> = Buffer::<5>::encode(&[42, 42, 42]);
+    assert_eq!(EN, "2MAHA");
+    assert_eq!("2MAHA", EN);
+    assert_eq!(EN, *"2MAHA");
+    assert_eq!(*"2MAHA", EN);
+    assert_ne!(EN, "2MAH");
+}
+
+#[test]
+fn test_check_prefixed_eq_str() {
+    const EN: Buffer<14, true, Check> =
+        Buffer::<14, true, Check>::encode(&[42, 42, 42], 'S', 0);
+    assert_eq!(EN, "S0AHA59B9201Z");
+    assert_eq!("S0AHA59B9201Z", EN);
+}
+
+#[test]
+fn test_macro() {
+    const EMPTY: &str = c32::c32!(b"");
+    assert_eq!(EMPTY, "");
+
+    const ZEROS: &str = c32::c32!(&[0, 0, 1, 2, 3]);
+    assert_eq!(ZEROS, "0020G3");
+
+    const SAMPLE: &str = c32::c32!(b"usque ad finem");
+    assert_eq!(SAMPLE, c32::encode(b"usque ad finem"));
+}
+
+#[test]
+fn test_macro_check() {
+    const EMPTY: &str = c32::c32_check!(b"", 0);
+    assert_eq!(EMPTY, "0A0DR2R");
+
+    const SAMPLE: &str = c32::c32_check!(b"usque ad finem", 22);
+    assert_eq!(SAMPLE, "P7AWVHENJJ0RB441K6JVK5DNJ7J3V5");
+
+    const MAX: &str = c32::c32_check!(&[42, 42, 42], 31);
+    assert_eq!(MAX, c32::encode_check([42, 42, 42], 31).unwrap());
+}
+
+#[test]
+fn test_str() {
+    const EN: Buffer<24> = Buffer::<24>::encode_str("usque ad finem");
+    assert_eq!(EN, c32::encode(b"usque ad finem").as_str());
+
+    const DE: Buffer<24> = Buffer::<24>::decode_str(EN.as_str());
+    assert_eq!(DE.as_bytes(), b"usque ad finem");
+
+    let en = Buffer::<24>::try_encode_str("usque ad finem").unwrap();
+    assert_eq!(en.as_str(), EN.as_str());
+    let de = Buffer::<24>::try_decode_str(en.as_str()).unwrap();
+    assert_eq!(de.as_bytes(), b"usque ad finem");
+
+    let result = Buffer::<4>::try_encode_str("***");
+    assert_eq!(result.err(), Some(Error::BufferTooSmall { min: 5, len: 4 }));
+    let result = Buffer::<5>::try_decode_str("2MAH!");
+    assert_eq!(
+        result.err(),
+        Some(Error::InvalidCharacter {
+            char: '!',
+            index: 4,
+            decoded: 2,
+        })
+    );
+}
+
+#[test]
+fn test_prefixed_str() {
+    const DE: Buffer<6, true> = Buffer::<6, true>::decode_str("S2MAHA", 'S');
+    assert_eq!(DE.as_bytes(), [42, 42, 42]);
+
+    let de = Buffer::<6, true>::try_decode_str("S2MAHA", 'S').unwrap();
+    assert_eq!(de.as_bytes(), [42, 42, 42]);
+
+    let result = Buffer::<6, true>::try_decode_str("2MAHA", 'S');
+    assert!(matches!(result, Err(Error::MissingPrefix { .. })));
+}
+
+#[test]
+fn test_check_str() {
+    const RESULT: (Buffer<12, false, Check>, u8) =
+        Buffer::<12, false, Check>::decode_str("0AHA59B9201Z");
+    assert_eq!(RESULT.0.as_bytes(), [42, 42, 42]);
+    assert_eq!(RESULT.1, 0);
+
+    let (de, version) =
+        Buffer::<12, false, Check>::try_decode_str("0AHA59B9201Z").unwrap();
+    assert_eq!(de.as_bytes(), [42, 42, 42]);
+    assert_eq!(version, 0);
+}
+
+#[test]
+fn test_check_prefixed_str() {
+    const RESULT: (Buffer<14, true, Check>, u8) =
+        Buffer::<14, true, Check>::decode_str("S0AHA59B9201Z", 'S');
+    assert_eq!(RESULT.0.as_bytes(), [42, 42, 42]);
+    assert_eq!(RESULT.1, 0);
+
+    let (de, version) =
+        Buffer::<14, true, Check>::try_decode_str("S0AHA59B9201Z", 'S')
+            .unwrap();
+    assert_eq!(de.as_bytes(), [42, 42, 42]);
+    assert_eq!(version, 0);
+
+    // Assert that an empty input is rejected, rather than underflowing.
+    let result = Buffer::<14, true, Check>::try_decode_str("", 'S');
+    assert_eq!(
+        result.err(),
+        Some(Error::MissingPrefix {
+            char: 'S',
+            got: None
+        })
+    );
+
+    // Assert that a non-ASCII prefix is rejected.
+    let result = Buffer::<14, true, Check>::try_decode_str("é0AHA", 'é');
+    assert_eq!(
+        result.err(),
+        Some(Error::InvalidCharacter {
+            char: 'é',
+            index: 0,
+            decoded: 0,
+        })
+    );
+}
+
+#[test]
+fn test_check_const_checksum() {
+    // Assert that the checksum is verified during const evaluation.
+    const VALID: bool =
+        Buffer::<12, false, Check>::try_decode(b"0AHA59B9201Z").is_ok();
+    const INVALID: bool = matches!(
+        Buffer::<12, false, Check>::try_decode(b"0AHA59B9201Y"),
+        Err(Error::ChecksumMismatch { .. })
+    );
+    const PREFIXED: bool = matches!(
+        Buffer::<14, true, Check>::try_decode(b"S0AHA59B9201Y", 'S'),
+        Err(Error::ChecksumMismatch { .. })
+    );
+    const _: () = assert!(VALID, "valid checksum rejected");
+    const _: () = assert!(INVALID, "invalid checksum accepted");
+    const _: () = assert!(PREFIXED, "invalid checksum accepted");
+}
+
+#[test]
+fn test_buffer_sizes_agree() {
+    // Assert that the buffers of all sizes share the same results, including
+    // the order in which the errors are reported.
+    for src in ["S0AHA59B9201Z", "S0AHA59B9201Y", "S!", "X0AHA", "S0A!A", ""] {
+        let src = src.as_bytes();
+        let a = Buffer::<13, true, Check>::try_decode(src, 'S')
+            .map(|(de, version)| (de.as_bytes().to_vec(), version));
+        let b = Buffer::<40, true, Check>::try_decode(src, 'S')
+            .map(|(de, version)| (de.as_bytes().to_vec(), version));
+        assert_eq!(a, b);
+
+        let a = Buffer::<13, true>::try_decode(src, 'S')
+            .map(|de| de.as_bytes().to_vec());
+        let b = Buffer::<40, true>::try_decode(src, 'S')
+            .map(|de| de.as_bytes().to_vec());
+        assert_eq!(a, b);
+    }
+
+    // Assert that the prefix is checked after the length of the input.
+    assert_eq!(
+        Buffer::<14, true, Check>::try_decode(b"X0", 'S').err(),
+        Some(Error::InsufficientData { min: 3, len: 2 })
+    );
+    assert_eq!(
+        Buffer::<14, true, Check>::try_decode(b"X0AHA", 'S').err(),
+        Some(Error::MissingPrefix {
+            char: 'S',
+            got: Some('X')
+        })
+    );
+}
+
+#[test]
+fn test_transcode() {
+    const EN: Buffer<24> = Buffer::<24>::encode(b"usque ad finem");
+    const CHECK: Buffer<32, false, Check> =
+        match Buffer::<32, false, Check>::from_default(&EN, 22) {
+            Ok(buffer) => buffer,
+            Err(_) => panic!("transcoding failed"),
+        };
+    const BACK: (Buffer<24>, u8) = match Buffer::<24>::from_check(&CHECK) {
+        Ok(result) => result,
+        Err(_) => panic!("transcoding failed"),
+    };
+    assert_eq!(CHECK.as_str(), "P7AWVHENJJ0RB441K6JVK5DNJ7J3V5");
+    assert_eq!(BACK.0.as_str(), EN.as_str());
+    assert_eq!(BACK.1, 22);
+
+    // Assert that leading zero bytes are kept.
+    let en = Buffer::<8>::encode(&[0, 0, 42]);
+    let check = Buffer::<16, false, Check>::from_default(&en, 0).unwrap();
+    assert_eq!(check, c32::encode_check([0, 0, 42], 0).unwrap().as_str());
+    let (back, _) = Buffer::<8>::from_check(&check).unwrap();
+    assert_eq!(back.as_str(), en.as_str());
+}
+
+#[test]
+fn test_transcode_errors() {
+    let en = Buffer::<5>::encode(&[42, 42, 42]);
+    assert_eq!(
+        Buffer::<12, false, Check>::from_default(&en, 0).err(),
+        Some(Error::BufferTooSmall { min: 13, len: 12 })
+    );
+    assert!(matches!(
+        Buffer::<13, false, Check>::from_default(&en, 32),
+        Err(Error::InvalidVersion { version: 32, .. })
+    ));
+
+    let check = Buffer::<13, false, Check>::encode(&[42, 42, 42], 0);
+    assert_eq!(
+        Buffer::<4>::from_check(&check).err(),
+        Some(Error::BufferTooSmall { min: 5, len: 4 })
+    );
+    assert_eq!(
+        Buffer::<5>::from_check(&Buffer::<13, false, Check>::EMPTY).err(),
+        Some(Error::InsufficientData { min: 2, len: 0 })
+    );
+}
@@ -0,0 +1,67 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+//! Regression tests over the checked-in corpus in `tests/corpus`.
+//!
+//! Every file is run through all decode entry points, asserting that none of
+//! them panic. If a sibling `<name>.expected` file exists, the rendered
+//! results must match it exactly.
+//!
+//! - `C32_CORPUS_BLESS=1` rewrites the `.expected` files from the current
+//!   results, after a deliberate change of behavior.
+//! - `C32_CORPUS_ADD=<paths>` adds the given files, e.g. minimized fuzz
+//!   artifacts, to the corpus with a rendered `.expected` file:
+//!
+//! ```sh
+//! C32_CORPUS_ADD=fuzz/artifacts/roundtrip/crash-1234 \
+//!     cargo test -p tests --test test_corpus -- --ignored corpus_add
+//! ```
+//!
+//! The corpus helpers are shared with the fuzz targets, which export their
+//! inputs with `C32_CORPUS_EXPORT=1`.
+
+use std::env;
+use std::fs;
+
+#[path = "../../fuzz/targets/corpus.rs"]
+mod corpus;
+
+use corpus::*;
+
+#[test]
+fn test_corpus() {
+    let bless = env::var_os("C32_CORPUS_BLESS").is_some();
+
+    let inputs = inputs();
+    assert!(!inputs.is_empty());
+
+    for path in inputs {
+        let input = fs::read(&path).unwrap();
+        let result = render(&input);
+
+        // Assert the exact results, if they are recorded.
+        let expected_path = expected(&path);
+        if let Ok(expected) = fs::read_to_string(&expected_path) {
+            if bless {
+                fs::write(&expected_path, &result).unwrap();
+            } else {
+                assert_eq!(result, expected, "{}", path.display());
+            }
+        }
+    }
+}
+
+#[test]
+#[ignore = "adds the files in C32_CORPUS_ADD to the corpus"]
+fn corpus_add() {
+    let paths = env::var_os("C32_CORPUS_ADD").unwrap_or_default();
+    for path in env::split_paths(&paths) {
+        let input = fs::read(&path).unwrap();
+        println!("{} -> {}", path.display(), add(&input).display());
+    }
+}
@@ -0,0 +1,104 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::damage_position_hint;
+use c32::suggest_corrections;
+
+mod __internal {
+    pub const VECTORS: &[&str] = &[
+        "P7AWVHENJJ0RB441K6JVK5DNJ7J3V5",
+        "P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7",
+        "P000000000000000000002Q6VF78",
+    ];
+
+    /// Replaces the character at `index` with a different valid character.
+    pub fn corrupt(s: &str, index: usize) -> String {
+        let mut bytes = s.as_bytes().to_vec();
+        bytes[index] = match bytes[index] {
+            b'Z' => b'0',
+            b'9' => b'A',
+            byte => byte + 1,
+        };
+        String::from_utf8(bytes).unwrap()
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_suggest_substitutions() {
+    for vector in VECTORS {
+        for (index, char) in vector.char_indices() {
+            let corrupted = corrupt(vector, index);
+            let suggestions = suggest_corrections(&corrupted, usize::MAX);
+
+            // Assert that the corrupted position is always suggested.
+            assert!(
+                suggestions.contains(&(index, char)),
+                "{corrupted}: {suggestions:?} does not contain {:?}",
+                (index, char)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_suggest_transpositions() {
+    for vector in VECTORS {
+        let bytes = vector.as_bytes();
+        for index in 0..bytes.len() - 1 {
+            if bytes[index] == bytes[index + 1] {
+                continue;
+            }
+
+            let mut swapped = bytes.to_vec();
+            swapped.swap(index, index + 1);
+            let swapped = String::from_utf8(swapped).unwrap();
+
+            let suggestions = suggest_corrections(&swapped, usize::MAX);
+            assert!(suggestions.contains(&(index, bytes[index] as char)));
+        }
+    }
+}
+
+#[test]
+fn test_suggest_invalid_character() {
+    let suggestions = suggest_corrections("P7AWVHENJJ0RB441K6JUK5DNJ7J3V5", 8);
+    assert_eq!(suggestions, [(19, 'V')]);
+}
+
+#[test]
+fn test_suggest_bounds() {
+    // Assert that valid input yields no suggestions.
+    for vector in VECTORS {
+        assert!(suggest_corrections(vector, usize::MAX).is_empty());
+    }
+
+    // Assert that the number of suggestions is bounded.
+    let corrupted = corrupt(VECTORS[0], 10);
+    assert!(suggest_corrections(&corrupted, 0).is_empty());
+    assert_eq!(suggest_corrections(&corrupted, 1).len(), 1);
+
+    // Assert that input longer than 64 characters is not searched.
+    let long = c32::encode_check([42u8; 64], 0).unwrap();
+    let corrupted = corrupt(&long, 10);
+    assert!(long.len() > 64);
+    assert!(suggest_corrections(&corrupted, usize::MAX).is_empty());
+}
+
+#[test]
+fn test_damage_position_hint() {
+    assert_eq!(damage_position_hint(VECTORS[0]), None);
+    assert_eq!(damage_position_hint(&corrupt(VECTORS[0], 3)), None);
+    assert_eq!(
+        damage_position_hint("P7AWVHENJJ0RB441K6JUK5DNJ7J3V5"),
+        Some(19)
+    );
+    assert_eq!(damage_position_hint("P7AW-VHENJ"), Some(4));
+    assert_eq!(damage_position_hint("P7AWVé"), Some(5));
+}
@@ -0,0 +1,130 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode;
+use c32::decode_bounded;
+use c32::decode_check;
+use c32::decode_check_bounded;
+use c32::encode;
+use c32::encode_check;
+use c32::Decoder;
+use c32::Error;
+use c32::Whitespace;
+use rand::Rng;
+
+#[test]
+fn test_decoder_default() {
+    let decoder = Decoder::new();
+    assert_eq!(decoder, Decoder::default());
+
+    assert_eq!(decoder.decode("2MAHA"), decode("2MAHA"));
+    assert_eq!(decoder.decode("2M AHA"), decode("2M AHA"));
+    assert_eq!(
+        decoder.decode_check("0AHA59B9201Z"),
+        decode_check("0AHA59B9201Z")
+    );
+
+    let mut dst = [0u8; 5];
+    assert_eq!(decoder.decode_into(b"2MAHA", &mut dst), Ok(3));
+    assert_eq!(dst[..3], [42, 42, 42]);
+}
+
+#[test]
+fn test_decoder_whitespace() {
+    let decoder = Decoder::new().whitespace(Whitespace::Ignore);
+    assert_eq!(decoder.decode(" 2M\tAH\r\nA "), Ok(vec![42, 42, 42]));
+
+    // Assert that the index refers to the original input.
+    assert!(matches!(
+        decoder.decode("2M A!A"),
+        Err(Error::InvalidCharacter { index: 4, .. })
+    ));
+
+    let (bytes, version) = decoder.decode_check(" 0 AHA59B\n9201Z").unwrap();
+    assert_eq!(bytes, [42, 42, 42]);
+    assert_eq!(version, 0);
+}
+
+#[test]
+fn test_decoder_max_len() {
+    let decoder = Decoder::new().max_len(3);
+    assert_eq!(decoder.decode("2MAHA"), Ok(vec![42, 42, 42]));
+    assert_eq!(
+        decoder.decode("0002MAHA"),
+        Err(Error::OutputTooLarge { max: 3, got: 6 })
+    );
+
+    let mut dst = [0u8; 16];
+    assert_eq!(
+        decoder.decode_into(b"2MAHA4", &mut dst),
+        Err(Error::OutputTooLarge { max: 3, got: 4 })
+    );
+
+    // Assert that the maximum applies to the payload of check-encoded input.
+    assert_eq!(
+        decoder.decode_check_into(b"0AHA59B9201Z", &mut dst),
+        Ok((3, 0))
+    );
+    let en = encode_check([42; 4], 0).unwrap();
+    assert_eq!(
+        decoder.decode_check(&en),
+        Err(Error::OutputTooLarge { max: 3, got: 4 })
+    );
+}
+
+#[test]
+fn test_decoder_max_len_whitespace() {
+    let decoder = Decoder::new().whitespace(Whitespace::Ignore).max_len(3);
+
+    // Assert that whitespace is not counted towards the maximum.
+    let padded = format!("{}2MAHA\n", " ".repeat(64));
+    assert_eq!(decoder.decode(&padded), Ok(vec![42, 42, 42]));
+    assert_eq!(
+        decoder.decode(" 2MA\r\nHA4 "),
+        Err(Error::OutputTooLarge { max: 3, got: 4 })
+    );
+
+    // Assert that whitespace before the version is skipped.
+    let en = encode_check([42; 4], 0).unwrap();
+    let padded = format!("\t {en}\n");
+    assert_eq!(
+        decoder.decode_check(&padded),
+        Err(Error::OutputTooLarge { max: 3, got: 4 })
+    );
+    let result = decoder.decode_check("\t 0AHA 59B9201Z\n");
+    assert_eq!(result, Ok((vec![42, 42, 42], 0)));
+}
+
+#[test]
+fn test_decoder_matches_bounded() {
+    let mut rng = rand::rng();
+    for _ in 0..256 {
+        let len = rng.random_range(0..48);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..=len);
+        bytes[..zeros].fill(0);
+        let max = rng.random_range(0..48);
+
+        let en = encode(&bytes);
+        let decoder = Decoder::new().max_len(max);
+        assert_eq!(decoder.decode(&en), decode_bounded(&en, max));
+
+        // Assert that interleaved whitespace does not change the result.
+        let spaced = en.chars().flat_map(|c| [c, ' ']).collect::<String>();
+        let decoder = decoder.whitespace(Whitespace::Ignore);
+        assert_eq!(decoder.decode(&spaced), decode_bounded(&en, max));
+
+        let en = encode_check(&bytes, 22).unwrap();
+        let spaced = en.chars().flat_map(|c| ['\n', c]).collect::<String>();
+        assert_eq!(
+            decoder.decode_check(&spaced),
+            decode_check_bounded(&en, max)
+        );
+    }
+}
@@ -0,0 +1,227 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode;
+use c32::decode_check;
+use c32::Error;
+
+mod __internal {
+    use core::fmt;
+
+    use c32::Error;
+
+    /// A fixed-capacity string, which fails instead of growing.
+    pub struct FixedString<const N: usize> {
+        pub bytes: [u8; N],
+        pub len: usize,
+    }
+
+    impl<const N: usize> FixedString<N> {
+        pub fn new() -> Self {
+            Self {
+                bytes: [0u8; N],
+                len: 0,
+            }
+        }
+
+        pub fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl<const N: usize> fmt::Write for FixedString<N> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            if end > N {
+                return Err(fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    /// The messages this crate uses for `InvalidFrame::reason`.
+    pub const REASONS: [&str; 4] = [
+        "truncated length prefix",
+        "length prefix overflows",
+        "non-canonical length prefix",
+        "length exceeds the input",
+    ];
+
+    /// The messages this crate uses for `InvalidVersion::expected`.
+    pub const EXPECTED: [&str; 3] = [
+        "must be < 32",
+        "must be a known Stacks address version",
+        "must match the expected version",
+    ];
+
+    /// Returns every variant with maximal field values.
+    pub fn maximal_errors() -> Vec<Error> {
+        let mut errors = vec![
+            Error::BufferTooSmall {
+                min: usize::MAX,
+                len: usize::MAX,
+            },
+            Error::InvalidDataSize {
+                expected: usize::MAX,
+                got: usize::MAX,
+            },
+            Error::InvalidCharacter {
+                char: '\u{10FFFF}',
+                index: usize::MAX,
+                decoded: usize::MAX,
+            },
+            Error::MissingPrefix {
+                char: '\u{10FFFF}',
+                got: Some('\u{10FFFF}'),
+            },
+            Error::MissingPrefix {
+                char: '\u{10FFFF}',
+                got: None,
+            },
+            Error::OutputTooLarge {
+                max: usize::MAX,
+                got: usize::MAX,
+            },
+            Error::InvalidPadding { index: usize::MAX },
+            Error::AllocationFailed {
+                requested: usize::MAX,
+            },
+            Error::InsufficientData {
+                min: usize::MAX,
+                len: usize::MAX,
+            },
+            Error::ChecksumMismatch {
+                expected: [255; 4],
+                got: [255; 4],
+                version: u8::MAX,
+                len: usize::MAX,
+            },
+        ];
+
+        for reason in REASONS {
+            errors.push(Error::InvalidFrame {
+                field: usize::MAX,
+                reason,
+            });
+        }
+
+        for expected in EXPECTED {
+            errors.push(Error::InvalidVersion {
+                expected,
+                version: u8::MAX,
+            });
+        }
+
+        errors
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_display_max_len() {
+    for error in maximal_errors() {
+        // Assert that every message fits into a buffer of the bound.
+        let mut message = FixedString::<{ Error::MAX_DISPLAY_LEN }>::new();
+        error.write_to(&mut message).unwrap();
+        assert!(message.len <= Error::MAX_DISPLAY_LEN);
+
+        let display = error.to_string();
+        assert!(display.len() <= Error::MAX_DISPLAY_LEN, "{display}");
+    }
+}
+
+#[test]
+fn test_display_matches_write_to() {
+    for error in maximal_errors() {
+        let mut message = FixedString::<{ Error::MAX_DISPLAY_LEN }>::new();
+        error.write_to(&mut message).unwrap();
+        assert_eq!(message.as_str(), error.to_string());
+    }
+}
+
+#[test]
+fn test_display_messages() {
+    let error = decode("2MA!A").unwrap_err();
+    assert_eq!(error.to_string(), "Invalid character '!' at position 3");
+
+    let error = decode_check("0AHA59B9201A").unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Checksum mismatch: expected "));
+
+    let error = Error::MissingPrefix {
+        char: 'S',
+        got: Some('T'),
+    };
+    assert_eq!(error.to_string(), "Expected prefix 'S', found 'T'");
+
+    let error = Error::MissingPrefix {
+        char: 'S',
+        got: None,
+    };
+    assert_eq!(error.to_string(), "Expected prefix 'S', found nothing");
+
+    let error = Error::ChecksumMismatch {
+        expected: [0, 1, 0xAB, 0xFF],
+        got: [255; 4],
+        version: 0,
+        len: 0,
+    };
+    assert_eq!(
+        error.to_string(),
+        "Checksum mismatch: expected 0001abff, got ffffffff"
+    );
+}
+
+#[test]
+fn test_display_write_to_overflow() {
+    // Assert that a sink which is too small reports an error.
+    let error = Error::BufferTooSmall { min: 10, len: 5 };
+    let mut message = FixedString::<8>::new();
+    assert!(error.write_to(&mut message).is_err());
+}
+
+#[test]
+fn test_display_invalid_version() {
+    // Assert the exact messages, which downstream snapshots may depend on.
+    let error = c32::encode_check([42], 32).unwrap_err();
+    assert_eq!(error.to_string(), "Invalid version 32: must be < 32");
+
+    let error = c32::Version::try_from(255).unwrap_err();
+    assert_eq!(error.to_string(), "Invalid version 255: must be < 32");
+
+    let hash160 = [0u8; 20];
+    let en = c32::encode_check_prefixed(hash160, 'S', 0).unwrap();
+    let error = c32::address::decode_address(&en).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid version 0: must be a known Stacks address version"
+    );
+
+    let error = Error::InvalidVersion {
+        expected: "must match the expected version",
+        version: 22,
+    };
+    assert_eq!(
+        error.to_string(),
+        "Invalid version 22: must match the expected version"
+    );
+}
+
+#[test]
+fn test_display_invalid_version_match() {
+    // Assert that the version is bound like the former tuple form.
+    let error = c32::encode_check([42], 40).unwrap_err();
+    let Error::InvalidVersion { version, .. } = error else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert_eq!(version, 40);
+}
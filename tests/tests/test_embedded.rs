@@ -0,0 +1,202 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::convert::Infallible;
+use std::fs;
+
+use c32::embedded::DecodeReader;
+use c32::embedded::EncodeWriter;
+use c32::embedded::IoError;
+use c32::Error;
+use embedded_io::ErrorType;
+use embedded_io::Read;
+use embedded_io::Write;
+use rand::Rng;
+
+mod __internal {
+    use super::*;
+
+    /// An in-memory reader and writer, transferring `step` bytes at a time.
+    pub struct Memory {
+        pub bytes: Vec<u8>,
+        pub pos: usize,
+        pub step: usize,
+    }
+
+    impl Memory {
+        pub fn new(bytes: &[u8], step: usize) -> Self {
+            Self {
+                bytes: bytes.to_vec(),
+                pos: 0,
+                step,
+            }
+        }
+    }
+
+    impl ErrorType for Memory {
+        type Error = Infallible;
+    }
+
+    impl Read for Memory {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let n = buf.len().min(self.step).min(self.bytes.len() - self.pos);
+            buf[..n].copy_from_slice(&self.bytes[self.pos..self.pos + n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    impl Write for Memory {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            let n = buf.len().min(self.step);
+            self.bytes.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    /// Encodes `src` through an [`EncodeWriter`], `chunk` bytes at a time.
+    pub fn encode(src: &[u8], chunk: usize, step: usize) -> Vec<u8> {
+        let mut writer = EncodeWriter::new(Memory::new(&[], step), src.len());
+        for part in src.chunks(chunk) {
+            writer.write_all(part).unwrap();
+        }
+        writer.finish().unwrap().bytes
+    }
+
+    /// Decodes `src` through a [`DecodeReader`], `chunk` bytes at a time.
+    pub fn decode(
+        src: &[u8],
+        chunk: usize,
+        step: usize,
+    ) -> Result<Vec<u8>, IoError<Infallible>> {
+        let mut reader = DecodeReader::new(Memory::new(src, step), src.len());
+        let mut dst = Vec::new();
+        let mut buf = vec![0u8; chunk];
+        loop {
+            let n = reader.read(&mut buf)?;
+            if n == 0 {
+                return Ok(dst);
+            }
+            dst.extend_from_slice(&buf[..n]);
+        }
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_embedded_roundtrip() {
+    let mut rng = rand::rng();
+    for len in 0..128 {
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..=len);
+        bytes[..zeros].fill(0);
+
+        let chunk = rng.random_range(1..=16);
+        let step = rng.random_range(1..=16);
+
+        let en = encode(&bytes, chunk, step);
+        assert_eq!(en, c32::encode(&bytes).as_bytes());
+
+        let de = decode(&en, chunk, step).unwrap();
+        assert_eq!(de, bytes);
+    }
+}
+
+#[test]
+fn test_embedded_sample() {
+    let input = fs::read("../samples/c32_s_64k.in").unwrap();
+
+    let en = encode(&input, 1000, 7);
+    assert_eq!(en, c32::encode(&input).as_bytes());
+
+    let de = decode(&en, 1000, 7).unwrap();
+    assert_eq!(de, input);
+}
+
+#[test]
+fn test_embedded_invalid_character() {
+    let result = decode(b"2MAH!A", 4, 4);
+    assert_eq!(
+        result,
+        Err(IoError::Codec(Error::InvalidCharacter {
+            char: '!',
+            index: 4,
+            decoded: 2,
+        }))
+    );
+}
+
+#[test]
+fn test_embedded_invalid_character_matches_decode_into() {
+    let mut rng = rand::rng();
+    for len in 1..128 {
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..=len);
+        bytes[..zeros].fill(0);
+
+        let mut en = c32::encode(&bytes).into_bytes();
+        let index = rng.random_range(0..en.len());
+        en[index] = b'!';
+        let index = rng.random_range(0..en.len());
+        en[index] = b'*';
+
+        // Assert that the streaming decoder reports the same character and
+        // `decoded` count as the in-memory decoder.
+        let mut dst = vec![0u8; en.len()];
+        let expected = c32::decode_into(&en, &mut dst).unwrap_err();
+        let result = decode(&en, 16, 16);
+        assert_eq!(result, Err(IoError::Codec(expected)));
+    }
+}
+
+#[test]
+fn test_embedded_invalid_data_size() {
+    // Assert that the reader fails if the input ends early.
+    let mut reader = DecodeReader::new(Memory::new(b"2MA", 8), 5);
+    let mut buf = [0u8; 8];
+    let result = reader.read_exact(&mut buf).map_err(|e| match e {
+        embedded_io::ReadExactError::Other(e) => e,
+        embedded_io::ReadExactError::UnexpectedEof => unreachable!(),
+    });
+    assert_eq!(
+        result,
+        Err(IoError::Codec(Error::InvalidDataSize {
+            expected: 5,
+            got: 3
+        }))
+    );
+
+    // Assert that the writer rejects more bytes than declared.
+    let mut writer = EncodeWriter::new(Memory::new(&[], 8), 2);
+    let result = writer.write(&[42, 42, 42]);
+    assert_eq!(
+        result,
+        Err(IoError::Codec(Error::InvalidDataSize {
+            expected: 2,
+            got: 3
+        }))
+    );
+
+    // Assert that the writer rejects fewer bytes than declared.
+    writer.write_all(&[42]).unwrap();
+    let result = writer.finish().map(|_| ());
+    assert_eq!(
+        result,
+        Err(IoError::Codec(Error::InvalidDataSize {
+            expected: 2,
+            got: 1
+        }))
+    );
+}
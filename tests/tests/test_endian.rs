@@ -0,0 +1,61 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode;
+use c32::decode_le;
+use c32::encode;
+use c32::encode_le;
+use c32::Result;
+
+mod __internal {
+    use super::*;
+
+    /// A test helper for big-endian and little-endian encoding/decoding.
+    pub fn test_endian(bytes: &[u8], be: &str, le: &str) -> Result<()> {
+        assert_eq!(encode(bytes), be);
+        assert_eq!(decode(be)?, bytes);
+
+        assert_eq!(encode_le(bytes), le);
+        assert_eq!(decode_le(le)?, bytes);
+
+        let mut reversed = bytes.to_vec();
+        reversed.reverse();
+        assert_eq!(encode_le(bytes), encode(&reversed));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_endian_empty() {
+    __internal::test_endian(&[], "", "").unwrap();
+}
+
+#[test]
+fn test_endian_ascending() {
+    __internal::test_endian(&[1, 2, 3], "20G3", "60G1").unwrap();
+}
+
+#[test]
+fn test_endian_leading_zeros() {
+    __internal::test_endian(&[0, 0, 1], "001", "2000").unwrap();
+}
+
+#[test]
+fn test_endian_trailing_zeros() {
+    __internal::test_endian(&[1, 0, 0], "2000", "001").unwrap();
+}
+
+#[test]
+fn test_endian_zero_two_ones_end() {
+    __internal::test_endian(&[0, 1, 0, 0, 1], "0G0001", "4000080").unwrap();
+}
+
+#[test]
+fn test_endian_palindrome() {
+    __internal::test_endian(&[42, 42, 42], "2MAHA", "2MAHA").unwrap();
+}
@@ -10,13 +10,18 @@ use c32::decode;
 use c32::decode_check;
 use c32::decode_check_into;
 use c32::decode_check_prefixed;
+use c32::decode_check_prefixed_into;
 use c32::decode_into;
 use c32::decode_prefixed;
+use c32::en::Check;
 use c32::encode_check;
 use c32::encode_check_into;
 use c32::encode_check_prefixed;
+use c32::encode_check_prefixed_into;
 use c32::encode_into;
+use c32::Buffer;
 use c32::Error;
+use c32::ErrorCode;
 
 mod __internal {
     /// A test helper for [`Error::BufferTooSmall`] errors.
@@ -36,7 +41,7 @@ mod __internal {
             let result = $fn;
             assert!(matches!(
                 result,
-                Err(Error::InvalidCharacter { char, index }) if char == $char && index == $index
+                Err(Error::InvalidCharacter { char, index, .. }) if char == $char && index == $index
             ));
         };
     }
@@ -78,13 +83,7 @@ mod __internal {
     macro_rules! assert_checksum_mismatch {
         ($fn:expr) => {
             let result = $fn;
-            assert!(matches!(
-                result,
-                Err(Error::ChecksumMismatch {
-                    expected: _,
-                    got: _
-                })
-            ));
+            assert!(matches!(result, Err(Error::ChecksumMismatch { .. })));
         };
     }
 
@@ -131,6 +130,27 @@ fn test_error_decode_into_invalid_character() {
     __internal::assert_invalid_character!(result, '!', 0);
 }
 
+#[test]
+fn test_error_decode_into_invalid_character_blocks() {
+    let mut output = [0u8; 64];
+    for index in 0..41 {
+        let mut input = [b'Z'; 41];
+        input[index] = b'!';
+
+        // Assert that every position is located, inside or outside blocks.
+        // The first byte holds 5 bits, as 41 characters hold 205 bits.
+        let result = decode_into(&input, &mut output);
+        assert_eq!(
+            result,
+            Err(Error::InvalidCharacter {
+                char: '!',
+                index,
+                decoded: (index * 5 + 3) / 8,
+            })
+        );
+    }
+}
+
 #[test]
 fn test_error_decode_invalid_character() {
     let result = decode("!MAHA");
@@ -195,13 +215,13 @@ fn test_error_encode_check_into_invalid_version() {
 
 #[test]
 fn test_error_encode_check_invalid_version() {
-    let result = encode_check(&[1, 2, 3], 32);
+    let result = encode_check([1, 2, 3], 32);
     __internal::assert_invalid_version!(result, 32);
 }
 
 #[test]
 fn test_error_encode_check_prefixed_invalid_version() {
-    let result = encode_check_prefixed(&[1, 2, 3], 'S', 32);
+    let result = encode_check_prefixed([1, 2, 3], 'S', 32);
     __internal::assert_invalid_version!(result, 32);
 }
 
@@ -212,6 +232,19 @@ fn test_error_decode_check_into_insufficient_data() {
     __internal::assert_insufficient_data!(result, 2, 1);
 }
 
+#[test]
+fn test_error_decode_check_into_short_checksum() {
+    let mut output = [0u8; 8];
+    let result = decode_check_into(b"01", &mut output);
+    __internal::assert_insufficient_data!(result, 4, 1);
+}
+
+#[test]
+fn test_error_decode_check_short_checksum() {
+    let result = decode_check("0ZZZ");
+    __internal::assert_insufficient_data!(result, 4, 2);
+}
+
 #[test]
 fn test_error_decode_check_insufficient_data() {
     let result = decode_check("0");
@@ -242,3 +275,343 @@ fn test_error_decode_check_prefixed_checksum_mismatch() {
     let result = decode_check_prefixed("S0820FVT6NE1", 'S');
     __internal::assert_checksum_mismatch!(result);
 }
+
+#[test]
+fn test_error_encode_check_prefixed_into_buffer_too_small() {
+    let mut output = [0u8; 13];
+    let result = encode_check_prefixed_into(&[1, 2, 3], &mut output, 'S', 0);
+    __internal::assert_buffer_too_small!(result, 14, 13);
+}
+
+#[test]
+fn test_error_decode_check_prefixed_into_missing_prefix() {
+    let mut output = [0u8; 16];
+    let result = decode_check_prefixed_into(b"0AHA59B9201Z", &mut output, 'S');
+    __internal::assert_missing_prefix!(result, 'S', Some('0'));
+}
+
+#[test]
+fn test_error_decode_check_prefixed_into_empty() {
+    let mut output = [0u8; 16];
+    let result = decode_check_prefixed_into(b"", &mut output, 'S');
+    __internal::assert_missing_prefix!(result, 'S', None);
+}
+
+#[test]
+fn test_error_decode_check_prefixed_into_invalid_character() {
+    let mut output = [0u8; 16];
+    let result = decode_check_prefixed_into(b"S0AHA59B!201Z", &mut output, 'S');
+    __internal::assert_invalid_character!(result, '!', 8);
+}
+
+#[test]
+fn test_error_buffer_try_encode_buffer_too_small() {
+    let result = Buffer::<4>::try_encode(&[42, 42, 42]);
+    __internal::assert_buffer_too_small!(result, 5, 4);
+
+    // Assert that a generic caller can handle the error at runtime.
+    fn encode<const N: usize>(src: &[u8; 3]) -> Result<Buffer<N>, Error> {
+        Buffer::<N>::try_encode(src)
+    }
+
+    __internal::assert_buffer_too_small!(encode::<0>(&[42, 42, 42]), 5, 0);
+    assert_eq!(encode::<5>(&[42, 42, 42]).unwrap().as_str(), "2MAHA");
+}
+
+#[test]
+fn test_error_buffer_try_encode_prefixed_buffer_too_small() {
+    let result = Buffer::<5, true>::try_encode(&[42, 42, 42], 'S');
+    __internal::assert_buffer_too_small!(result, 6, 5);
+
+    let result = Buffer::<6, true>::try_encode(&[42, 42, 42], 'S');
+    assert_eq!(result.unwrap().as_str(), "S2MAHA");
+}
+
+#[test]
+fn test_error_buffer_try_encode_check_buffer_too_small() {
+    let result = Buffer::<5, false, Check>::try_encode(&[42, 42, 42], 0);
+    __internal::assert_buffer_too_small!(result, 13, 5);
+
+    let result = Buffer::<13, false, Check>::try_encode(&[42, 42, 42], 0);
+    assert_eq!(result.unwrap().as_str(), "0AHA59B9201Z");
+}
+
+#[test]
+fn test_error_buffer_try_encode_check_prefixed_buffer_too_small() {
+    let result = Buffer::<13, true, Check>::try_encode(&[42, 42, 42], 'S', 0);
+    __internal::assert_buffer_too_small!(result, 14, 13);
+
+    let result = Buffer::<14, true, Check>::try_encode(&[42, 42, 42], 'S', 0);
+    assert_eq!(result.unwrap().as_str(), "S0AHA59B9201Z");
+}
+
+#[test]
+fn test_error_decode_invalid_character_matches_decode_into() {
+    let inputs = [
+        "<html><body>Not Found</body></html>",
+        "{\"error\":\"not found\",\"status\":404}",
+        "2MAHA!2MAHA?2MAHA",
+        "ünïcödé",
+        "2MAHAü",
+    ];
+
+    for input in inputs {
+        let mut output = vec![0u8; input.len()];
+        let expected = decode_into(input.as_bytes(), &mut output).unwrap_err();
+        assert_eq!(decode(input).unwrap_err(), expected);
+    }
+}
+
+#[test]
+fn test_error_decode_every_byte_matches_decode_into() {
+    for byte in 0..=255u8 {
+        let input = [b'2', byte, b'A'];
+        let Ok(input) = core::str::from_utf8(&input) else {
+            continue;
+        };
+
+        let mut output = [0u8; 3];
+        let expected = decode_into(input.as_bytes(), &mut output).map(|_| ());
+        assert_eq!(decode(input).map(|_| ()), expected);
+    }
+}
+
+#[test]
+fn test_error_checksum_mismatch_context() {
+    // "usque ad finem", with the last character altered.
+    let en = "P7AWVHENJJ0RB441K6JVK5DNJ7J3V6";
+    let expected = (22, 14);
+
+    let result = decode_check(en);
+    assert!(matches!(
+        result,
+        Err(Error::ChecksumMismatch { version, len, .. }) if (version, len) == expected
+    ));
+
+    let mut output = [0u8; 32];
+    let result = decode_check_into(en.as_bytes(), &mut output);
+    assert!(matches!(
+        result,
+        Err(Error::ChecksumMismatch { version, len, .. }) if (version, len) == expected
+    ));
+
+    let result = decode_check_prefixed(&format!("S{en}"), 'S');
+    assert!(matches!(
+        result,
+        Err(Error::ChecksumMismatch { version, len, .. }) if (version, len) == expected
+    ));
+
+    let token = c32::scan::find_tokens(en, 0).next().unwrap();
+    assert!(matches!(
+        token.validate_check(),
+        Err(Error::ChecksumMismatch { version, len, .. }) if (version, len) == expected
+    ));
+}
+
+#[test]
+fn test_error_invalid_character_context() {
+    // The leading "2M" decodes into 1 byte, as the first holds 1 bit.
+    let result = decode("2M!HA");
+    let expected = Error::InvalidCharacter {
+        char: '!',
+        index: 2,
+        decoded: 1,
+    };
+    assert_eq!(result, Err(expected));
+
+    let mut output = [0u8; 8];
+    assert_eq!(decode_into(b"2M!HA", &mut output), Err(expected));
+    assert_eq!(
+        decode_prefixed("S2M!HA", 'S'),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 3,
+            decoded: 1,
+        })
+    );
+
+    // The leading 13 payload characters decode into 8 bytes.
+    let result = decode_check("P7AWVHENJJ0RB4!1K6JVK5DNJ7J3V5");
+    let expected = Error::InvalidCharacter {
+        char: '!',
+        index: 14,
+        decoded: 8,
+    };
+    assert_eq!(result.err(), Some(expected));
+
+    let mut output = [0u8; 32];
+    let result =
+        decode_check_into(b"P7AWVHENJJ0RB4!1K6JVK5DNJ7J3V5", &mut output);
+    assert_eq!(result, Err(expected));
+
+    let result = decode_check_prefixed_into(
+        b"SP7AWVHENJJ0RB4!1K6JVK5DNJ7J3V5",
+        &mut output,
+        'S',
+    );
+    assert_eq!(
+        result.err(),
+        Some(Error::InvalidCharacter {
+            char: '!',
+            index: 15,
+            decoded: 8,
+        })
+    );
+
+    // The version character is decoded first.
+    let result = decode_check("!7AWVHENJJ0RB441K6JVK5DNJ7J3V5");
+    assert!(matches!(
+        result,
+        Err(Error::InvalidCharacter {
+            index: 0,
+            decoded: 0,
+            ..
+        })
+    ));
+
+    // The characters before the last one decode into all but 1 byte.
+    let result = Buffer::<5>::try_decode(b"2MAH!");
+    assert!(matches!(
+        result,
+        Err(Error::InvalidCharacter {
+            index: 4,
+            decoded: 2,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_error_non_exhaustive() {
+    let describe = |e: Error| match e {
+        Error::InvalidCharacter { char, .. } => format!("bad {char}"),
+        _ => String::from("other"),
+    };
+    assert_eq!(describe(decode("2M!HA").unwrap_err()), "bad !");
+    assert_eq!(describe(Error::BufferTooSmall { min: 1, len: 0 }), "other");
+}
+
+#[test]
+fn test_error_into_io_error_kind() {
+    use std::io::ErrorKind;
+
+    let cases = [
+        (decode("2M!HA").unwrap_err(), ErrorKind::InvalidData),
+        (
+            decode_check("0G40R40QP9HXK9").unwrap_err(),
+            ErrorKind::InvalidData,
+        ),
+        (decode_check("0").unwrap_err(), ErrorKind::InvalidData),
+        (
+            Error::InvalidDataSize {
+                expected: 16,
+                got: 2,
+            },
+            ErrorKind::InvalidData,
+        ),
+        (
+            Error::InvalidFrame {
+                field: 0,
+                reason: "",
+            },
+            ErrorKind::InvalidData,
+        ),
+        (encode_check([42], 32).unwrap_err(), ErrorKind::InvalidInput),
+        (
+            decode_prefixed("2MAHA", 'S').unwrap_err(),
+            ErrorKind::InvalidInput,
+        ),
+        (
+            Error::BufferTooSmall { min: 8, len: 2 },
+            ErrorKind::WriteZero,
+        ),
+        (Error::OutputTooLarge { max: 2, got: 3 }, ErrorKind::Other),
+        (
+            Error::AllocationFailed { requested: 64 },
+            ErrorKind::OutOfMemory,
+        ),
+    ];
+
+    for (error, kind) in cases {
+        let io = std::io::Error::from(error);
+        assert_eq!(io.kind(), kind, "{error:?}");
+    }
+}
+
+#[test]
+fn test_error_into_io_error_source() {
+    let error = decode("2M!HA").unwrap_err();
+    let io: std::io::Error = error.into();
+    assert_eq!(io.to_string(), error.to_string());
+
+    // Assert that the typed error is reachable through the source chain.
+    let inner = io.get_ref().and_then(|e| e.downcast_ref::<Error>());
+    assert_eq!(inner, Some(&error));
+
+    // Assert that `?` converts into an `io::Error`.
+    let load = || -> std::io::Result<Vec<u8>> { Ok(decode("2M!HA")?) };
+    let inner = load().unwrap_err().into_inner().unwrap();
+    assert_eq!(inner.downcast_ref::<Error>(), Some(&error));
+}
+
+#[test]
+fn test_error_code() {
+    let errors = [
+        Error::BufferTooSmall { min: 0, len: 0 },
+        Error::InvalidDataSize {
+            expected: 0,
+            got: 0,
+        },
+        decode("2M!HA").unwrap_err(),
+        decode_prefixed("2MAHA", 'S').unwrap_err(),
+        Error::InvalidVersion {
+            expected: "",
+            version: 0,
+        },
+        Error::InsufficientData { min: 0, len: 0 },
+        Error::ChecksumMismatch {
+            expected: [0; 4],
+            got: [0; 4],
+            version: 0,
+            len: 0,
+        },
+        Error::InvalidPadding { index: 0 },
+        Error::AllocationFailed { requested: 0 },
+    ];
+    let codes = errors.map(|e| (e.code(), e.name()));
+    assert_eq!(
+        codes,
+        [
+            (1, "C32_E_BUFFER_TOO_SMALL"),
+            (2, "C32_E_INVALID_DATA_SIZE"),
+            (3, "C32_E_INVALID_CHARACTER"),
+            (4, "C32_E_MISSING_PREFIX"),
+            (7, "C32_E_INVALID_VERSION"),
+            (8, "C32_E_INSUFFICIENT_DATA"),
+            (9, "C32_E_CHECKSUM_MISMATCH"),
+            (10, "C32_E_INVALID_PADDING"),
+            (11, "C32_E_ALLOCATION_FAILED"),
+        ]
+    );
+
+    // Assert that the codes round-trip through `u16`.
+    for error in errors {
+        let code = ErrorCode::from(&error);
+        assert_eq!(ErrorCode::try_from(error.code()), Ok(code));
+        assert_eq!(u16::from(code), error.code());
+        assert_eq!(code.name(), error.name());
+    }
+}
+
+#[test]
+fn test_error_code_unassigned() {
+    assert_eq!(ErrorCode::try_from(0), Err(0));
+    assert_eq!(ErrorCode::try_from(12), Err(12));
+    assert_eq!(ErrorCode::try_from(u16::MAX), Err(u16::MAX));
+
+    // Assert that every assigned code maps to a unique name.
+    let names: std::collections::HashSet<_> = (1..=11)
+        .map(|code| ErrorCode::try_from(code).unwrap().name())
+        .collect();
+    assert_eq!(names.len(), 11);
+}
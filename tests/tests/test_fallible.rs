@@ -0,0 +1,183 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::checked_encoded_check_len;
+use c32::checked_encoded_len;
+use c32::decode_check_fallible;
+use c32::decode_check_prefixed_fallible;
+use c32::decode_fallible;
+use c32::decode_prefixed_fallible;
+use c32::encode_check_fallible;
+use c32::encode_check_prefixed_fallible;
+use c32::encode_fallible;
+use c32::encode_prefixed_fallible;
+use c32::Error;
+
+mod __internal {
+    use std::alloc::GlobalAlloc;
+    use std::alloc::Layout;
+    use std::alloc::System;
+    use std::cell::Cell;
+
+    thread_local! {
+        /// The largest allocation permitted on the current thread.
+        static LIMIT: Cell<usize> = const { Cell::new(usize::MAX) };
+    }
+
+    /// An allocator which fails allocations above a per-thread limit.
+    pub struct Limited;
+
+    // SAFETY: This forwards to `System`, or fails by returning null.
+    unsafe impl GlobalAlloc for Limited {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let limit = LIMIT.try_with(Cell::get).unwrap_or(usize::MAX);
+            if layout.size() > limit {
+                return std::ptr::null_mut();
+            }
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+        }
+    }
+
+    /// Runs `f` with allocations above `limit` bytes failing.
+    pub fn with_limit<T>(limit: usize, f: impl FnOnce() -> T) -> T {
+        LIMIT.with(|cell| cell.set(limit));
+        let result = f();
+        LIMIT.with(|cell| cell.set(usize::MAX));
+        result
+    }
+}
+
+use __internal::*;
+
+#[global_allocator]
+static ALLOCATOR: Limited = Limited;
+
+#[test]
+fn test_fallible_matches_infallible() {
+    let bytes = [0, 0, 42, 42, 42, 255];
+    let en = c32::encode(bytes);
+
+    assert_eq!(encode_fallible(bytes).unwrap(), en);
+    assert_eq!(decode_fallible(&en).unwrap(), bytes);
+    assert_eq!(
+        encode_prefixed_fallible(bytes, '€').unwrap(),
+        c32::encode_prefixed(bytes, '€')
+    );
+    assert_eq!(
+        decode_prefixed_fallible(&format!("€{en}"), '€').unwrap(),
+        bytes
+    );
+
+    let en = c32::encode_check(bytes, 22).unwrap();
+    assert_eq!(encode_check_fallible(bytes, 22).unwrap(), en);
+    assert_eq!(decode_check_fallible(&en).unwrap(), (bytes.to_vec(), 22));
+    assert_eq!(
+        encode_check_prefixed_fallible(bytes, 'S', 22).unwrap(),
+        format!("S{en}")
+    );
+    assert_eq!(
+        decode_check_prefixed_fallible(&format!("S{en}"), 'S').unwrap(),
+        (bytes.to_vec(), 22)
+    );
+}
+
+#[test]
+fn test_fallible_errors() {
+    assert!(matches!(
+        decode_fallible("2M!HA"),
+        Err(Error::InvalidCharacter { index: 2, .. })
+    ));
+    assert!(matches!(
+        decode_prefixed_fallible("€2M!HA", '€'),
+        Err(Error::InvalidCharacter { index: 5, .. })
+    ));
+    assert_eq!(
+        decode_prefixed_fallible("2MAHA", 'P'),
+        Err(Error::MissingPrefix {
+            char: 'P',
+            got: Some('2'),
+        })
+    );
+    assert_eq!(
+        decode_check_prefixed_fallible("", 'S'),
+        Err(Error::MissingPrefix {
+            char: 'S',
+            got: None,
+        })
+    );
+    assert!(matches!(
+        encode_check_fallible([42], 32),
+        Err(Error::InvalidVersion { version: 32, .. })
+    ));
+}
+
+#[test]
+fn test_fallible_allocation_failed() {
+    let bytes = [42u8; 64];
+    let en = c32::encode(bytes);
+    let check = c32::encode_check(bytes, 22).unwrap();
+
+    // The results are asserted outside of the limit, as a failed assertion
+    // allocates while panicking.
+    let results = with_limit(96, || {
+        [
+            encode_fallible(bytes).map(String::into_bytes),
+            encode_prefixed_fallible(bytes, '€').map(String::into_bytes),
+            decode_fallible(&en),
+            encode_check_fallible(bytes, 22).map(String::into_bytes),
+            decode_check_fallible(&check).map(|(bytes, _)| bytes),
+        ]
+    });
+    assert_eq!(
+        results.map(Result::unwrap_err),
+        [
+            Error::AllocationFailed { requested: 103 },
+            Error::AllocationFailed { requested: 106 },
+            Error::AllocationFailed {
+                requested: en.len()
+            },
+            Error::AllocationFailed { requested: 110 },
+            Error::AllocationFailed {
+                requested: check.len(),
+            },
+        ]
+    );
+
+    // Assert that invalid input is rejected before allocating.
+    let result = with_limit(4, || decode_fallible("2M!HA"));
+    assert!(matches!(
+        result,
+        Err(Error::InvalidCharacter { index: 2, .. })
+    ));
+}
+
+#[test]
+fn test_fallible_capacity_overflow() {
+    assert_eq!(checked_encoded_len(0), Some(0));
+    assert_eq!(checked_encoded_len(5), Some(8));
+    assert_eq!(checked_encoded_len(usize::MAX), None);
+    assert_eq!(checked_encoded_check_len(usize::MAX - 3), None);
+
+    // Assert that the checked length agrees with `encoded_len`.
+    for n in 0..256 {
+        assert_eq!(checked_encoded_len(n), Some(c32::encoded_len(n)));
+        assert_eq!(
+            checked_encoded_check_len(n),
+            Some(c32::encoded_check_len(n))
+        );
+    }
+
+    // Assert that the largest representable length does not overflow.
+    let max = usize::MAX / 8 * 5;
+    assert_eq!(checked_encoded_len(max), Some(usize::MAX / 8 * 8));
+    assert_eq!(checked_encoded_len(max + 5), None);
+}
@@ -0,0 +1,140 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::ptr;
+
+use c32::ffi::*;
+use c32::Error;
+
+#[test]
+fn test_ffi_encode_decode() {
+    let bytes = [42u8, 42, 42];
+    let mut en = [0u8; 16];
+    let mut de = [0u8; 16];
+    let mut len = 0;
+
+    let code = unsafe {
+        c32_encode(bytes.as_ptr(), 3, en.as_mut_ptr(), en.len(), &mut len)
+    };
+    assert_eq!(code, C32_OK);
+    assert_eq!(&en[..len], b"2MAHA");
+    assert_eq!(len, c32_encoded_len(3));
+
+    let code = unsafe {
+        c32_decode(en.as_ptr(), len, de.as_mut_ptr(), de.len(), &mut len)
+    };
+    assert_eq!(code, C32_OK);
+    assert_eq!(&de[..len], bytes);
+}
+
+#[test]
+fn test_ffi_encode_decode_check() {
+    let bytes = [42u8, 42, 42];
+    let mut en = [0u8; 32];
+    let mut de = [0u8; 32];
+    let mut len = 0;
+    let mut version = 0xFF;
+
+    let code = unsafe {
+        c32_encode_check(bytes.as_ptr(), 3, 22, en.as_mut_ptr(), 32, &mut len)
+    };
+    assert_eq!(code, C32_OK);
+    assert_eq!(&en[..len], b"PAHA58QT2DJ9");
+
+    let code = unsafe {
+        c32_decode_check(
+            en.as_ptr(),
+            len,
+            de.as_mut_ptr(),
+            32,
+            &mut len,
+            &mut version,
+        )
+    };
+    assert_eq!(code, C32_OK);
+    assert_eq!((&de[..len], version), (&bytes[..], 22));
+}
+
+#[test]
+fn test_ffi_errors() {
+    let bytes = [42u8, 42, 42];
+    let mut out = [0u8; 16];
+    let mut len = usize::MAX;
+
+    let code =
+        unsafe { c32_encode(bytes.as_ptr(), 3, out.as_mut_ptr(), 2, &mut len) };
+    assert_eq!(code, C32_ERR_BUFFER_TOO_SMALL);
+
+    let code = unsafe {
+        c32_decode(b"2M!HA".as_ptr(), 5, out.as_mut_ptr(), 16, &mut len)
+    };
+    assert_eq!(code, C32_ERR_INVALID_CHARACTER);
+
+    let code = unsafe {
+        c32_decode_check(
+            b"0AHA59B9201Y".as_ptr(),
+            12,
+            out.as_mut_ptr(),
+            16,
+            &mut len,
+            ptr::null_mut(),
+        )
+    };
+    assert_eq!(code, C32_ERR_CHECKSUM_MISMATCH);
+
+    // Assert that the length is not written on failure.
+    assert_eq!(len, usize::MAX);
+}
+
+#[test]
+fn test_ffi_null_pointers() {
+    let mut out = [0u8; 16];
+    let mut len = usize::MAX;
+
+    let code =
+        unsafe { c32_encode(ptr::null(), 3, out.as_mut_ptr(), 16, &mut len) };
+    assert_eq!(code, C32_ERR_NULL_POINTER);
+    let code = unsafe {
+        c32_decode(b"2MAHA".as_ptr(), 5, ptr::null_mut(), 16, &mut len)
+    };
+    assert_eq!(code, C32_ERR_NULL_POINTER);
+
+    // Assert that null pointers are accepted for empty buffers.
+    let code =
+        unsafe { c32_encode(ptr::null(), 0, ptr::null_mut(), 0, &mut len) };
+    assert_eq!((code, len), (C32_OK, 0));
+    let code = unsafe {
+        c32_decode(ptr::null(), 0, ptr::null_mut(), 0, ptr::null_mut())
+    };
+    assert_eq!(code, C32_OK);
+}
+
+#[test]
+fn test_ffi_error_code() {
+    let codes = [
+        error_code(&Error::BufferTooSmall { min: 0, len: 0 }),
+        error_code(&Error::InvalidVersion {
+            expected: "",
+            version: 0,
+        }),
+        error_code(&Error::ChecksumMismatch {
+            expected: [0; 4],
+            got: [0; 4],
+            version: 0,
+            len: 0,
+        }),
+    ];
+    assert_eq!(
+        codes,
+        [
+            C32_ERR_BUFFER_TOO_SMALL,
+            C32_ERR_INVALID_VERSION,
+            C32_ERR_CHECKSUM_MISMATCH
+        ]
+    );
+}
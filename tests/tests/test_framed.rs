@@ -0,0 +1,148 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_framed;
+use c32::decode_framed_into;
+use c32::decoded_len;
+use c32::encode_framed;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    use super::*;
+
+    /// Generates up to 5 random fields, including empty ones.
+    pub fn fields(rng: &mut impl Rng) -> Vec<Vec<u8>> {
+        let count = rng.random_range(0..=5);
+        (0..count)
+            .map(|_| {
+                let len = match rng.random_range(0..4) {
+                    0 => 0,
+                    1 => rng.random_range(1..8),
+                    2 => rng.random_range(120..140),
+                    _ => rng.random_range(0..400),
+                };
+                let mut field = vec![0u8; len];
+                rng.fill(&mut field[..]);
+                field
+            })
+            .collect()
+    }
+
+    /// Asserts that decoding `bytes` as framed fields fails with `reason`.
+    pub fn assert_invalid_frame(bytes: &[u8], field: usize, reason: &str) {
+        let encoded = c32::encode(bytes);
+        let expected = Error::InvalidFrame {
+            field,
+            reason: match reason {
+                "truncated" => "truncated length prefix",
+                "exceeds" => "length exceeds the input",
+                "overflows" => "length prefix overflows",
+                _ => "non-canonical length prefix",
+            },
+        };
+
+        assert_eq!(decode_framed(&encoded), Err(expected));
+
+        let mut dst = vec![0u8; decoded_len(encoded.len())];
+        let mut lens = [0usize; 8];
+        let result =
+            decode_framed_into(encoded.as_bytes(), &mut dst, &mut lens);
+        assert_eq!(result, Err(expected));
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_framed_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..500 {
+        let fields = fields(&mut rng);
+        let refs: Vec<&[u8]> = fields.iter().map(Vec::as_slice).collect();
+
+        let en = encode_framed(&refs);
+        let de = decode_framed(&en).unwrap();
+        assert_eq!(de, fields);
+    }
+}
+
+#[test]
+fn test_framed_into_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..500 {
+        let fields = fields(&mut rng);
+        let refs: Vec<&[u8]> = fields.iter().map(Vec::as_slice).collect();
+        let en = encode_framed(&refs);
+
+        let mut dst = vec![0u8; decoded_len(en.len())];
+        let mut lens = [0usize; 5];
+        let count =
+            decode_framed_into(en.as_bytes(), &mut dst, &mut lens).unwrap();
+        assert_eq!(count, fields.len());
+
+        let mut offset = 0;
+        for (field, len) in fields.iter().zip(&lens[..count]) {
+            assert_eq!(&dst[offset..offset + len], field.as_slice());
+            offset += len;
+        }
+    }
+}
+
+#[test]
+fn test_framed_empty() {
+    assert_eq!(encode_framed(&[]), "");
+    assert_eq!(decode_framed(""), Ok(vec![]));
+
+    // A single empty field still produces a length prefix.
+    let en = encode_framed(&[&[]]);
+    assert_eq!(en, "0");
+    assert_eq!(decode_framed(&en), Ok(vec![vec![]]));
+}
+
+#[test]
+fn test_framed_invalid_frame() {
+    // The length prefix ends before its final byte.
+    assert_invalid_frame(&[0x80], 0, "truncated");
+    assert_invalid_frame(&[1, 42, 0xFF], 1, "truncated");
+
+    // The length exceeds the remaining bytes.
+    assert_invalid_frame(&[5, 1, 2], 0, "exceeds");
+    assert_invalid_frame(&[0, 0x81, 0x01, 1], 1, "exceeds");
+
+    // The length does not fit into a usize.
+    let mut bytes = vec![0xFF; 10];
+    bytes.push(0x7F);
+    assert_invalid_frame(&bytes, 0, "overflows");
+
+    // The length prefix has a redundant trailing byte.
+    assert_invalid_frame(&[0x80, 0x00], 0, "non-canonical");
+}
+
+#[test]
+fn test_framed_into_lens_too_small() {
+    let en = encode_framed(&[&[1], &[2], &[3]]);
+    let mut dst = vec![0u8; decoded_len(en.len())];
+    let mut lens = [0usize; 2];
+
+    let result = decode_framed_into(en.as_bytes(), &mut dst, &mut lens);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 3, len: 2 }));
+}
+
+#[test]
+fn test_framed_invalid_character() {
+    let result = decode_framed("0!");
+    assert_eq!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 1,
+            decoded: 1,
+        })
+    );
+}
@@ -0,0 +1,186 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use c32::fs::decode_check_file;
+use c32::fs::decode_file;
+use c32::fs::encode_check_file;
+use c32::fs::encode_file;
+use c32::fs::FileError;
+use c32::Error;
+
+mod __internal {
+    use super::*;
+
+    /// A temporary directory, removed when dropped.
+    pub struct TempDir(pub PathBuf);
+
+    impl TempDir {
+        pub fn new(name: &str) -> Self {
+            let path = std::env::temp_dir()
+                .join(format!("c32-{}-{name}", std::process::id()));
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        pub fn path(&self, name: &str) -> PathBuf {
+            self.0.join(name)
+        }
+
+        /// Returns the names of the files in the directory.
+        pub fn entries(&self) -> Vec<String> {
+            let mut names: Vec<_> = fs::read_dir(&self.0)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+                .collect();
+            names.sort();
+            names
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    pub fn sample() -> &'static Path {
+        Path::new("../samples/c32_s_4m.in")
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_fs_sample() {
+    let dir = TempDir::new("fs-sample");
+    let (en, de) = (dir.path("sample.c32"), dir.path("sample.out"));
+    let input = fs::read(sample()).unwrap();
+
+    let len = encode_file(sample(), &en).unwrap();
+    assert_eq!(len, c32::encoded_len(input.len()) as u64);
+    assert_eq!(fs::read_to_string(&en).unwrap(), c32::encode(&input));
+
+    let len = decode_file(&en, &de).unwrap();
+    assert_eq!(len, input.len() as u64);
+    assert_eq!(fs::read(&de).unwrap(), input);
+}
+
+#[test]
+fn test_fs_sample_check() {
+    let dir = TempDir::new("fs-sample-check");
+    let (en, de) = (dir.path("sample.c32"), dir.path("sample.out"));
+    let input = fs::read(sample()).unwrap();
+
+    encode_check_file(sample(), &en, 22).unwrap();
+    let expected = c32::encode_check(&input, 22).unwrap();
+    assert_eq!(fs::read_to_string(&en).unwrap(), expected);
+
+    let (len, version) = decode_check_file(&en, &de).unwrap();
+    assert_eq!((len, version), (input.len() as u64, 22));
+    assert_eq!(fs::read(&de).unwrap(), input);
+}
+
+#[test]
+fn test_fs_leading_zeros() {
+    let dir = TempDir::new("fs-leading-zeros");
+    let (src, en, de) = (dir.path("in"), dir.path("en"), dir.path("de"));
+
+    for bytes in [&[][..], &[0], &[0, 0, 1, 2, 3], &[0; 16]] {
+        fs::write(&src, bytes).unwrap();
+        encode_file(&src, &en).unwrap();
+        assert_eq!(fs::read_to_string(&en).unwrap(), c32::encode(bytes));
+        decode_file(&en, &de).unwrap();
+        assert_eq!(fs::read(&de).unwrap(), bytes);
+    }
+}
+
+#[test]
+fn test_fs_overwrite() {
+    let dir = TempDir::new("fs-overwrite");
+    let (src, dst) = (dir.path("in"), dir.path("out"));
+    fs::write(&src, [42, 42, 42]).unwrap();
+    fs::write(&dst, "previous contents").unwrap();
+
+    // Assert that the destination is replaced, leaving no temporary file.
+    encode_file(&src, &dst).unwrap();
+    assert_eq!(fs::read_to_string(&dst).unwrap(), "2MAHA");
+    assert_eq!(dir.entries(), ["in", "out"]);
+}
+
+#[test]
+fn test_fs_invalid_character() {
+    let dir = TempDir::new("fs-invalid-character");
+    let (src, dst) = (dir.path("in"), dir.path("out"));
+    fs::write(&src, "2MAHA\n").unwrap();
+    fs::write(&dst, "previous contents").unwrap();
+
+    // Assert that the destination is untouched on failure.
+    let err = decode_file(&src, &dst).unwrap_err();
+    assert!(matches!(
+        err,
+        FileError::C32(Error::InvalidCharacter {
+            char: '\n',
+            index: 5,
+            ..
+        })
+    ));
+    assert_eq!(fs::read_to_string(&dst).unwrap(), "previous contents");
+    assert_eq!(dir.entries(), ["in", "out"]);
+
+    // Assert that the last invalid character is reported, as by `decode`.
+    fs::write(&src, "2M!HA\n").unwrap();
+    let err = decode_file(&src, &dst).unwrap_err();
+    let FileError::C32(err) = err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(Err(err), c32::decode("2M!HA\n"));
+}
+
+#[test]
+fn test_fs_check_errors() {
+    let dir = TempDir::new("fs-check-errors");
+    let (src, dst) = (dir.path("in"), dir.path("out"));
+
+    // Assert that the errors wrapped by the reader functions are unwrapped.
+    fs::write(&src, [42]).unwrap();
+    let err = encode_check_file(&src, &dst, 32).unwrap_err();
+    assert!(matches!(err, FileError::C32(Error::InvalidVersion { .. })));
+
+    fs::write(&src, "PAHA58QT2DJ8").unwrap();
+    let err = decode_check_file(&src, &dst).unwrap_err();
+    assert!(matches!(
+        err,
+        FileError::C32(Error::ChecksumMismatch { .. })
+    ));
+
+    // Assert that no payload is written before the checksum is verified.
+    assert_eq!(dir.entries(), ["in"]);
+}
+
+#[test]
+fn test_fs_io_errors() {
+    let dir = TempDir::new("fs-io-errors");
+    let missing = dir.path("missing");
+
+    let err = encode_file(&missing, &dir.path("out")).unwrap_err();
+    assert!(
+        matches!(err, FileError::Io(e) if e.kind() == io::ErrorKind::NotFound)
+    );
+
+    fs::write(dir.path("in"), [42]).unwrap();
+    let err = encode_file(&dir.path("in"), &missing.join("out")).unwrap_err();
+    assert!(
+        matches!(err, FileError::Io(e) if e.kind() == io::ErrorKind::NotFound)
+    );
+    assert_eq!(dir.entries(), ["in"]);
+}
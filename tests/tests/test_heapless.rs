@@ -0,0 +1,133 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_check_heapless;
+use c32::decode_check_prefixed_heapless;
+use c32::decode_heapless;
+use c32::encode;
+use c32::encode_check;
+use c32::encode_check_heapless;
+use c32::encode_check_prefixed;
+use c32::encode_check_prefixed_heapless;
+use c32::encode_heapless;
+use c32::Error;
+use c32::Result;
+
+mod __internal {
+    use super::*;
+
+    /// A test helper for `[feature = "heapless"]`.
+    pub fn test_heapless<const N: usize>(bytes: &[u8]) -> Result<()> {
+        let en = encode_heapless::<N>(bytes)?;
+        assert_eq!(en.as_str(), encode(bytes));
+        let de = decode_heapless::<N>(en.as_bytes())?;
+        assert_eq!(de.as_slice(), bytes);
+        Ok(())
+    }
+
+    /// A test helper for `[feature = "heapless", feature = "check"]`.
+    pub fn test_heapless_check<const N: usize>(bytes: &[u8]) -> Result<()> {
+        let en = encode_check_heapless::<N>(bytes, 22)?;
+        assert_eq!(en.as_str(), encode_check(bytes, 22)?);
+        let (de, version) = decode_check_heapless::<N>(en.as_bytes())?;
+        assert_eq!(de.as_slice(), bytes);
+        assert_eq!(version, 22);
+
+        let en = encode_check_prefixed_heapless::<N>(bytes, 'S', 22)?;
+        assert_eq!(en.as_str(), encode_check_prefixed(bytes, 'S', 22)?);
+        let (de, version) =
+            decode_check_prefixed_heapless::<N>(en.as_bytes(), 'S')?;
+        assert_eq!(de.as_slice(), bytes);
+        assert_eq!(version, 22);
+        Ok(())
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_heapless_empty() {
+    test_heapless::<0>(&[]).unwrap();
+}
+
+#[test]
+fn test_heapless_leading_zeros() {
+    test_heapless::<10>(&[0, 0, 1, 0, 0, 1]).unwrap();
+}
+
+#[test]
+fn test_heapless_hello_world() {
+    test_heapless::<64>(b"hello world").unwrap();
+}
+
+#[test]
+fn test_heapless_check_hello_world() {
+    test_heapless_check::<64>(b"hello world").unwrap();
+}
+
+#[test]
+fn test_heapless_exact_capacity() {
+    // Assert that a capacity of exactly the required size succeeds.
+    test_heapless::<5>(&[42, 42, 42]).unwrap();
+
+    let en = encode_check_heapless::<13>(&[42, 42, 42], 0).unwrap();
+    assert_eq!(en.as_str(), "0AHA59B9201Z");
+    let (de, _) = decode_check_heapless::<12>(en.as_bytes()).unwrap();
+    assert_eq!(de.as_slice(), [42, 42, 42]);
+
+    let en = encode_check_prefixed_heapless::<14>(&[42, 42, 42], 'S', 0);
+    assert_eq!(en.unwrap().as_str(), "S0AHA59B9201Z");
+    let de = decode_check_prefixed_heapless::<12>(b"S0AHA59B9201Z", 'S');
+    assert_eq!(de.unwrap().0.as_slice(), [42, 42, 42]);
+}
+
+#[test]
+fn test_heapless_one_too_small() {
+    let result = encode_heapless::<4>(&[42, 42, 42]);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 4 }));
+
+    let result = decode_heapless::<4>(b"2MAHA");
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 4 }));
+
+    let result = encode_check_heapless::<12>(&[42, 42, 42], 0);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 13, len: 12 }));
+
+    let result = decode_check_heapless::<11>(b"0AHA59B9201Z");
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 12, len: 11 }));
+
+    let result = encode_check_prefixed_heapless::<13>(&[42, 42, 42], 'S', 0);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 14, len: 13 }));
+
+    let result = decode_check_prefixed_heapless::<11>(b"S0AHA59B9201Z", 'S');
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 12, len: 11 }));
+}
+
+#[test]
+fn test_heapless_errors() {
+    let result = decode_heapless::<8>(b"2MAHU");
+    assert_eq!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: 'U',
+            index: 4,
+            decoded: 2,
+        })
+    );
+
+    let result = encode_check_heapless::<16>(&[42], 32);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidVersion { version: 32, .. })
+    ));
+
+    let result = decode_check_prefixed_heapless::<16>(b"X0AHA59B9201Z", 'S');
+    assert!(matches!(
+        result,
+        Err(Error::MissingPrefix { char: 'S', .. })
+    ));
+}
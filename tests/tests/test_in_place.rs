@@ -0,0 +1,174 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::fs;
+
+use c32::decode;
+use c32::decode_in_place;
+use c32::decode_into;
+use c32::decode_owned;
+use c32::decoded_len;
+use c32::encode;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    use std::alloc::GlobalAlloc;
+    use std::alloc::Layout;
+    use std::alloc::System;
+    use std::cell::Cell;
+
+    use super::*;
+
+    thread_local! {
+        /// The number of allocations made by the current thread.
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// An allocator that counts the allocations of each thread.
+    pub struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.with(|n| n.set(n.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+        }
+    }
+
+    /// Returns the number of allocations made by `f` on this thread.
+    pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+        let before = ALLOCATIONS.with(Cell::get);
+        let result = f();
+        let after = ALLOCATIONS.with(Cell::get);
+        (result, after - before)
+    }
+
+    /// Asserts that decoding `src` in place matches `decode_into`.
+    pub fn test_in_place(src: &[u8]) {
+        let mut dst = vec![0u8; decoded_len(src.len())];
+        let expected = decode_into(src, &mut dst).map(|n| dst[..n].to_vec());
+
+        let mut buf = src.to_vec();
+        let result = decode_in_place(&mut buf).map(|n| buf[..n].to_vec());
+        assert_eq!(result, expected);
+    }
+}
+
+use __internal::*;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_in_place_unit() {
+    for src in [
+        "",
+        "0",
+        "00",
+        "1",
+        "01",
+        "2MAHA",
+        "0020G3",
+        "80",
+        "ZZZZZZZZ",
+        "000000000000",
+        "7ZZZZZZZZZZZZZZZ",
+        "2mahaiLo",
+    ] {
+        test_in_place(src.as_bytes());
+    }
+}
+
+#[test]
+fn test_in_place_random() {
+    let mut rng = rand::rng();
+    for _ in 0..10_000 {
+        let len = rng.random_range(0..=64);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..=len);
+        bytes[..zeros].fill(0);
+
+        let en = encode(&bytes);
+        test_in_place(en.as_bytes());
+
+        let mut buf = en.into_bytes();
+        let n = decode_in_place(&mut buf).unwrap();
+        assert_eq!(&buf[..n], bytes);
+    }
+}
+
+#[test]
+fn test_in_place_sample() {
+    let input = fs::read("../samples/c32_s_64k.in").unwrap();
+    let mut buf = encode(&input).into_bytes();
+    let n = decode_in_place(&mut buf).unwrap();
+    assert_eq!(&buf[..n], input);
+}
+
+#[test]
+fn test_in_place_invalid_character() {
+    let mut buf = *b"2MA!A?A";
+    let result = decode_in_place(&mut buf);
+    assert_eq!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: '?',
+            index: 5,
+            decoded: 2,
+        })
+    );
+    test_in_place(b"2MA!A?A");
+    test_in_place("2MAHAü".as_bytes());
+}
+
+#[test]
+fn test_in_place_decode_owned() {
+    for src in ["", "0", "00", "2MAHA", "0002MAHA", "2maha", "ZZZZZZZZ"] {
+        // Assert that the owned path matches the borrowed path.
+        assert_eq!(decode_owned(String::from(src)), decode(src));
+    }
+
+    let input = fs::read("../samples/c32_s_64k.in").unwrap();
+    let en = encode(&input);
+    assert_eq!(decode_owned(en.clone()).unwrap(), input);
+    assert_eq!(decode(&en).unwrap(), input);
+
+    let result = decode_owned(String::from("2MA!A"));
+    assert!(matches!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 3,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_in_place_decode_owned_does_not_allocate() {
+    let en = encode([42u8; 64]);
+    let ptr = en.as_ptr();
+
+    // Assert that the owned path reuses the allocation of the string.
+    let (result, allocations) = count_allocations(|| decode_owned(en));
+    let de = result.unwrap();
+    assert_eq!(de, [42u8; 64]);
+    assert_eq!(de.as_ptr(), ptr);
+    assert_eq!(allocations, 0);
+
+    // Assert that the counter observes the borrowed path.
+    let en = encode([42u8; 64]);
+    let (result, allocations) = count_allocations(|| decode(&en));
+    assert_eq!(result.unwrap(), [42u8; 64]);
+    assert!(allocations > 0);
+}
@@ -0,0 +1,268 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::io;
+use std::io::Read;
+use std::io::Write;
+
+use c32::io::DecodeReader;
+use c32::io::EncodeWriter;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    use super::*;
+
+    /// A reader which returns at most `max` bytes per read.
+    pub struct Chunked<'a> {
+        pub bytes: &'a [u8],
+        pub max: usize,
+    }
+
+    impl Read for Chunked<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(self.max).min(self.bytes.len());
+            buf[..n].copy_from_slice(&self.bytes[..n]);
+            self.bytes = &self.bytes[n..];
+            Ok(n)
+        }
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_io_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        let len = rng.random_range(0..2000);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        if len > 0 && rng.random_bool(0.5) {
+            bytes[0] = 0;
+        }
+
+        // Encode in random-sized chunks.
+        let mut writer = EncodeWriter::new(Vec::new());
+        let mut pos = 0;
+        while pos < len {
+            let n = rng.random_range(1..=(len - pos));
+            writer.write_all(&bytes[pos..pos + n]).unwrap();
+            pos += n;
+        }
+        let encoded = writer.finish().unwrap();
+        assert_eq!(encoded, c32::encode(&bytes).as_bytes());
+
+        // Decode in random-sized chunks, including mid-group boundaries.
+        let max = rng.random_range(1..=9);
+        let mut reader = DecodeReader::new(Chunked {
+            bytes: &encoded,
+            max,
+        });
+        let mut decoded = Vec::new();
+        let mut buf = [0u8; 7];
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            decoded.extend_from_slice(&buf[..n]);
+        }
+        assert_eq!(decoded, bytes);
+    }
+}
+
+#[test]
+fn test_io_partial_group() {
+    // A trailing partial group is valid.
+    for encoded in ["", "1", "2MAHA", "0020G3"] {
+        let mut reader = DecodeReader::new(Chunked {
+            bytes: encoded.as_bytes(),
+            max: 1,
+        });
+        let mut decoded = Vec::new();
+        reader.read_to_end(&mut decoded).unwrap();
+        assert_eq!(decoded, c32::decode(encoded).unwrap());
+    }
+}
+
+#[test]
+fn test_io_invalid_character() {
+    let mut encoded = c32::encode([42; 100]).into_bytes();
+    encoded[77] = b'!';
+
+    let mut reader = DecodeReader::new(Chunked {
+        bytes: &encoded,
+        max: 3,
+    });
+    let mut decoded = Vec::new();
+    let err = reader.read_to_end(&mut decoded).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "Invalid character '!' at position 77");
+
+    let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+    assert_eq!(
+        *inner,
+        Error::InvalidCharacter {
+            char: '!',
+            index: 77,
+            decoded: 48,
+        }
+    );
+}
+
+#[test]
+fn test_io_roundtrip_known_length() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        let len = rng.random_range(0..2000);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..=len.min(4));
+        bytes[..zeros].fill(0);
+        let expected = c32::encode(&bytes);
+
+        // Assert that the output is written as the input arrives.
+        let mut writer = EncodeWriter::with_len(Vec::new(), len as u64);
+        let mut pos = 0;
+        while pos < len {
+            let n = rng.random_range(1..=(len - pos));
+            writer.write_all(&bytes[pos..pos + n]).unwrap();
+            pos += n;
+            assert!(expected.as_bytes().starts_with(writer.get_ref()));
+        }
+        let encoded = writer.finish().unwrap();
+        assert_eq!(encoded, expected.as_bytes());
+
+        // Assert that the reader stops at the declared length.
+        let mut input = encoded.clone();
+        input.extend_from_slice(b";rest");
+        let max = rng.random_range(1..=9);
+        let mut reader = DecodeReader::with_len(
+            Chunked { bytes: &input, max },
+            encoded.len() as u64,
+        );
+        let mut decoded = Vec::new();
+        reader.read_to_end(&mut decoded).unwrap();
+        assert_eq!(decoded, bytes);
+        assert_eq!(reader.into_inner().bytes, b";rest");
+    }
+}
+
+#[test]
+fn test_io_invalid_character_known_length() {
+    let mut encoded = c32::encode([42; 100]).into_bytes();
+    encoded[77] = b'!';
+
+    let mut reader = DecodeReader::with_len(
+        Chunked {
+            bytes: &encoded,
+            max: 3,
+        },
+        encoded.len() as u64,
+    );
+
+    // Assert that the bytes before the invalid character are returned.
+    let mut decoded = Vec::new();
+    let err = reader.read_to_end(&mut decoded).unwrap_err();
+    assert_eq!(decoded.len(), 48);
+
+    let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+    assert_eq!(
+        *inner,
+        Error::InvalidCharacter {
+            char: '!',
+            index: 77,
+            decoded: 48,
+        }
+    );
+}
+
+#[test]
+fn test_io_invalid_character_matches_decode() {
+    // Decodes `input` with both readers, returning the errors.
+    fn errors(input: &[u8], max: usize) -> (Error, Error) {
+        let bytes = Chunked { bytes: input, max };
+        let err = DecodeReader::new(bytes)
+            .read_to_end(&mut Vec::new())
+            .unwrap_err();
+        let new = *err.into_inner().unwrap().downcast::<Error>().unwrap();
+
+        let bytes = Chunked { bytes: input, max };
+        let err = DecodeReader::with_len(bytes, input.len() as u64)
+            .read_to_end(&mut Vec::new())
+            .unwrap_err();
+        let with_len = *err.into_inner().unwrap().downcast::<Error>().unwrap();
+        (new, with_len)
+    }
+
+    // Assert that the last invalid character is reported, as by `decode`.
+    let input = "é2MAHA".as_bytes();
+    let expected = Error::InvalidCharacter {
+        char: '©',
+        index: 1,
+        decoded: 1,
+    };
+    assert_eq!(c32::decode_into(input, &mut [0u8; 8]), Err(expected));
+    assert_eq!(errors(input, 1), (expected, expected));
+
+    let mut rng = rand::rng();
+    for _ in 0..500 {
+        let len = rng.random_range(1..64);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..=len.min(4));
+        bytes[..zeros].fill(0);
+        let mut input = c32::encode(&bytes).into_bytes();
+
+        // Replace up to 3 characters with invalid ones.
+        for _ in 0..rng.random_range(1..=3) {
+            let index = rng.random_range(0..input.len());
+            input[index] = b"!U*\xff"[rng.random_range(0..4)];
+        }
+
+        let mut dst = vec![0u8; input.len()];
+        let expected = c32::decode_into(&input, &mut dst).unwrap_err();
+        let max = rng.random_range(1..=9);
+        assert_eq!(errors(&input, max), (expected, expected), "{input:?}");
+    }
+}
+
+#[test]
+fn test_io_invalid_data_size() {
+    // Assert that the writer rejects more bytes than declared.
+    let mut writer = EncodeWriter::with_len(Vec::new(), 2);
+    let err = writer.write_all(&[42, 42, 42]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // Assert that the writer rejects fewer bytes than declared.
+    let mut writer = EncodeWriter::with_len(Vec::new(), 2);
+    writer.write_all(&[42]).unwrap();
+    let err = writer.finish().unwrap_err();
+    let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+    assert_eq!(
+        *inner,
+        Error::InvalidDataSize {
+            expected: 2,
+            got: 1
+        }
+    );
+
+    // Assert that the reader fails if the input ends early.
+    let mut reader = DecodeReader::with_len(&b"2MA"[..], 5);
+    let mut decoded = Vec::new();
+    let err = reader.read_to_end(&mut decoded).unwrap_err();
+    let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+    assert_eq!(
+        *inner,
+        Error::InvalidDataSize {
+            expected: 5,
+            got: 3
+        }
+    );
+}
@@ -0,0 +1,160 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_bounded;
+use c32::decode_check_bounded;
+use c32::encode;
+use c32::encode_check;
+use c32::Error;
+
+mod __internal {
+    use std::alloc::GlobalAlloc;
+    use std::alloc::Layout;
+    use std::alloc::System;
+    use std::cell::Cell;
+
+    thread_local! {
+        /// The number of allocations made by the current thread.
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// An allocator that counts the allocations of each thread.
+    pub struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.with(|n| n.set(n.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+        }
+    }
+
+    /// Returns the number of allocations made by `f` on this thread.
+    pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+        let before = ALLOCATIONS.with(Cell::get);
+        let result = f();
+        let after = ALLOCATIONS.with(Cell::get);
+        (result, after - before)
+    }
+}
+
+use __internal::*;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_limit_decode() {
+    let bytes = [0xA5u8; 64];
+    let en = encode(bytes);
+
+    // Assert that the limit is inclusive.
+    assert_eq!(decode_bounded(&en, 64).unwrap(), bytes);
+    assert_eq!(
+        decode_bounded(&en, 63),
+        Err(Error::OutputTooLarge { max: 63, got: 64 })
+    );
+    assert_eq!(
+        decode_bounded(&en, 8),
+        Err(Error::OutputTooLarge { max: 8, got: 64 })
+    );
+}
+
+#[test]
+fn test_limit_decode_leading_zeros() {
+    let en = encode([0u8; 16]);
+    assert_eq!(decode_bounded(&en, 16).unwrap(), [0u8; 16]);
+    assert_eq!(
+        decode_bounded(&en, 15),
+        Err(Error::OutputTooLarge { max: 15, got: 16 })
+    );
+}
+
+#[test]
+fn test_limit_decode_check() {
+    let bytes = [0xA5u8; 20];
+    let en = encode_check(bytes, 22).unwrap();
+
+    // Assert that the limit applies to the payload only.
+    let (de, version) = decode_check_bounded(&en, 20).unwrap();
+    assert_eq!((de.as_slice(), version), (&bytes[..], 22));
+    assert_eq!(
+        decode_check_bounded(&en, 19),
+        Err(Error::OutputTooLarge { max: 19, got: 20 })
+    );
+    assert_eq!(
+        decode_check_bounded(&en, 0),
+        Err(Error::OutputTooLarge { max: 0, got: 20 })
+    );
+
+    // Assert that short input is left to the checksum validation.
+    assert!(matches!(
+        decode_check_bounded("0", 0),
+        Err(Error::InsufficientData { .. })
+    ));
+}
+
+#[test]
+fn test_limit_rejection_does_not_allocate() {
+    let huge = "Z".repeat(1 << 20);
+
+    let (result, allocations) =
+        count_allocations(|| decode_bounded(&huge, 1024));
+    assert!(matches!(
+        result,
+        Err(Error::OutputTooLarge { max: 1024, .. })
+    ));
+    assert_eq!(allocations, 0);
+
+    let (result, allocations) =
+        count_allocations(|| decode_check_bounded(&huge, 1024));
+    assert!(matches!(
+        result,
+        Err(Error::OutputTooLarge { max: 1024, .. })
+    ));
+    assert_eq!(allocations, 0);
+
+    // Assert that the counter observes the accepted path.
+    let (result, allocations) =
+        count_allocations(|| decode_bounded("2MAHA", 3));
+    assert_eq!(result.unwrap(), [42, 42, 42]);
+    assert_eq!(allocations, 1);
+}
+
+#[test]
+fn test_limit_precedence() {
+    // Assert that the length is checked before the characters.
+    assert_eq!(
+        decode_bounded("2M!HA", 2),
+        Err(Error::OutputTooLarge { max: 2, got: 3 })
+    );
+    assert!(matches!(
+        decode_bounded("2M!HA", 3),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 2,
+            ..
+        })
+    ));
+
+    assert_eq!(
+        decode_check_bounded("0AH!59B9201Z", 2),
+        Err(Error::OutputTooLarge { max: 2, got: 3 })
+    );
+    assert!(matches!(
+        decode_check_bounded("0AH!59B9201Z", 3),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 3,
+            ..
+        })
+    ));
+}
@@ -0,0 +1,147 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::PathBuf;
+
+use c32::io::decode_lines;
+use c32::io::validate_lines;
+use c32::Error;
+
+mod __internal {
+    use super::*;
+
+    /// A temporary file, removed when dropped.
+    pub struct TempFile(pub PathBuf);
+
+    impl TempFile {
+        pub fn new(name: &str, contents: &[u8]) -> Self {
+            let path = std::env::temp_dir()
+                .join(format!("c32-{}-{name}", std::process::id()));
+            fs::write(&path, contents).unwrap();
+            Self(path)
+        }
+
+        pub fn open(&self) -> BufReader<fs::File> {
+            BufReader::new(fs::File::open(&self.0).unwrap())
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    /// Extracts the [`Error`] wrapped by an [`io::Error`].
+    pub fn codec_error(err: &io::Error) -> Error {
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        *err.get_ref().unwrap().downcast_ref::<Error>().unwrap()
+    }
+
+    /// A reader that fails after yielding its first line.
+    pub struct Failing(pub bool);
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            unreachable!()
+        }
+    }
+
+    impl BufRead for Failing {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            if self.0 {
+                return Err(io::ErrorKind::BrokenPipe.into());
+            }
+            Ok(b"2MAHA\n")
+        }
+
+        fn consume(&mut self, _: usize) {
+            self.0 = true;
+        }
+    }
+}
+
+use __internal::*;
+
+const INPUT: &[u8] = b"2MAHA\n2M!HA\n\n0000\r\nDECAF\xFF\nZZZZZZZZ";
+
+#[test]
+fn test_validate_lines_file() {
+    let file = TempFile::new("validate", INPUT);
+    let results: Vec<_> = validate_lines(file.open()).collect();
+    assert_eq!(results.len(), 6);
+
+    let numbers: Vec<_> = results.iter().map(|(n, _)| *n).collect();
+    assert_eq!(numbers, [1, 2, 3, 4, 5, 6]);
+
+    let valid: Vec<_> = results.iter().map(|(_, r)| r.is_ok()).collect();
+    assert_eq!(valid, [true, false, true, true, false, true]);
+
+    // Assert that the failures report the offending character.
+    let err = results[1].1.as_ref().unwrap_err();
+    assert!(matches!(
+        codec_error(err),
+        Error::InvalidCharacter {
+            char: '!',
+            index: 2,
+            ..
+        }
+    ));
+
+    let err = results[4].1.as_ref().unwrap_err();
+    assert!(matches!(
+        codec_error(err),
+        Error::InvalidCharacter { index: 5, .. }
+    ));
+}
+
+#[test]
+fn test_decode_lines_file() {
+    let file = TempFile::new("decode", INPUT);
+    let results: Vec<_> = decode_lines(file.open())
+        .map(|(n, r)| (n, r.ok()))
+        .collect();
+
+    assert_eq!(
+        results,
+        [
+            (1, Some(vec![42, 42, 42])),
+            (2, None),
+            (3, Some(vec![])),
+            (4, Some(vec![0, 0, 0, 0])),
+            (5, None),
+            (6, Some(vec![0xFF; 5])),
+        ]
+    );
+}
+
+#[test]
+fn test_lines_empty() {
+    assert!(validate_lines(&b""[..]).next().is_none());
+    assert!(decode_lines(&b""[..]).next().is_none());
+}
+
+#[test]
+fn test_lines_io_error() {
+    let mut lines = validate_lines(Failing(false));
+
+    let (number, result) = lines.next().unwrap();
+    assert_eq!(number, 1);
+    assert!(result.is_ok());
+
+    // Assert that the error is reported on its line, and ends the iterator.
+    let (number, result) = lines.next().unwrap();
+    assert_eq!(number, 2);
+    assert_eq!(result.unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+    assert!(lines.next().is_none());
+}
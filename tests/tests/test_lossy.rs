@@ -0,0 +1,168 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode;
+use c32::decode_lossy;
+use c32::decode_lossy_into;
+use c32::encode;
+use c32::leading_zero_bytes;
+use c32::Error;
+use rand::Rng;
+
+/// Replaces the characters at `positions` with `!`.
+fn corrupt(str: &str, positions: &[usize]) -> String {
+    let mut bytes = str.as_bytes().to_vec();
+    for &pos in positions {
+        bytes[pos] = b'!';
+    }
+    String::from_utf8(bytes).unwrap()
+}
+
+#[test]
+fn test_lossy_single() {
+    let (bytes, substituted) = decode_lossy("2M!HA");
+    assert_eq!(bytes, decode("2M0HA").unwrap());
+    assert_eq!(substituted, [2]);
+}
+
+#[test]
+fn test_lossy_multiple() {
+    let en = encode([0xAB; 20]);
+    let corrupted = corrupt(&en, &[3, 7, 8, 31]);
+
+    let (bytes, substituted) = decode_lossy(&corrupted);
+    assert_eq!(substituted, [3, 7, 8, 31]);
+    assert_eq!(bytes.len(), 20);
+    assert_eq!(bytes, decode(&corrupted.replace('!', "0")).unwrap());
+}
+
+#[test]
+fn test_lossy_middle() {
+    // Assert that an extra character is decoded in place, shifting the
+    // bytes before it.
+    let (bytes, substituted) = decode_lossy("2MA!HA");
+    assert_eq!(bytes, decode("2MA0HA").unwrap());
+    assert_eq!(bytes, [5, 69, 2, 42]);
+    assert_eq!(substituted, [3]);
+
+    // Assert that a corrupted character only changes the bytes its 5 bits
+    // overlap, counted from the end of the input.
+    let original: Vec<u8> = (0..20).map(|i| i * 13 + 7).collect();
+    let en = encode(&original);
+    for pos in 1..en.len() - 1 {
+        let (bytes, substituted) = decode_lossy(&corrupt(&en, &[pos]));
+        assert_eq!(substituted, [pos]);
+        assert_eq!(bytes.len(), original.len());
+
+        let lo = (en.len() - 1 - pos) * 5;
+        let overlapped = (lo / 8)..=((lo + 4) / 8);
+        for (i, (&a, &b)) in
+            bytes.iter().rev().zip(original.iter().rev()).enumerate()
+        {
+            if !overlapped.contains(&i) {
+                assert_eq!(a, b, "{en} at {pos}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lossy_rule() {
+    let mut rng = rand::rng();
+    for _ in 0..1_000 {
+        let len = rng.random_range(0..24);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..=len);
+        bytes[..zeros].fill(0);
+        let en = encode(&bytes);
+        if en.is_empty() {
+            continue;
+        }
+
+        let count = rng.random_range(1..=3.min(en.len()));
+        let positions: Vec<_> =
+            (0..count).map(|_| rng.random_range(0..en.len())).collect();
+        let corrupted = corrupt(&en, &positions);
+
+        // Assert that the bytes hold the number of substituting `0`, padded
+        // to the length of substituting `1`.
+        let (de, _) = decode_lossy(&corrupted);
+        let values = decode(&corrupted.replace('!', "0")).unwrap();
+        let number = &values[leading_zero_bytes(&values)..];
+        let len = decode(&corrupted.replace('!', "1")).unwrap().len();
+        let mut expected = vec![0u8; len - number.len()];
+        expected.extend_from_slice(number);
+        assert_eq!(de, expected, "{corrupted}");
+    }
+}
+
+#[test]
+fn test_lossy_fixed_width() {
+    // Assert that a corrupted leading character preserves the length.
+    for bytes in [[0xAB; 20], [0x20; 20], [0xFF; 20]] {
+        let en = encode(bytes);
+        for pos in [0, 1, en.len() - 1] {
+            let (de, substituted) = decode_lossy(&corrupt(&en, &[pos]));
+            assert_eq!(de.len(), 20, "{en} at {pos}");
+            assert_eq!(substituted, [pos]);
+        }
+    }
+}
+
+#[test]
+fn test_lossy_leading_zeros() {
+    let en = encode([0, 0, 42, 42, 42]);
+    assert_eq!(en, "002MAHA");
+
+    let (bytes, substituted) = decode_lossy("00!MAHA");
+    assert_eq!(bytes, [0, 0, 10, 42, 42]);
+    assert_eq!(substituted, [2]);
+
+    // Assert that a corrupted leading zero is decoded as significant.
+    let (bytes, substituted) = decode_lossy("0!2MAHA");
+    assert_eq!(bytes.len(), 5);
+    assert_eq!(substituted, [1]);
+}
+
+#[test]
+fn test_lossy_fully_invalid() {
+    let (bytes, substituted) = decode_lossy("!!!!!!!!");
+    assert_eq!(bytes, [0; 5]);
+    assert_eq!(substituted, (0..8).collect::<Vec<_>>());
+
+    let (bytes, substituted) = decode_lossy("!!!!!");
+    assert_eq!(bytes, [0; 3]);
+    assert_eq!(substituted, (0..5).collect::<Vec<_>>());
+
+    let (bytes, substituted) = decode_lossy("");
+    assert!(bytes.is_empty());
+    assert!(substituted.is_empty());
+}
+
+#[test]
+fn test_lossy_valid() {
+    for str in ["", "0", "2MAHA", "0000", "7ZZZZZZZZZZZZZZZZZZZZZZZZZZ"] {
+        let (bytes, substituted) = decode_lossy(str);
+        assert_eq!(bytes, decode(str).unwrap());
+        assert!(substituted.is_empty());
+    }
+}
+
+#[test]
+fn test_lossy_into() {
+    let mut dst = [0u8; 16];
+    let (offset, substituted) = decode_lossy_into(b"2M!H!", &mut dst).unwrap();
+    assert_eq!(&dst[..offset], decode("2M0H0").unwrap());
+    assert_eq!(substituted, 2);
+
+    assert_eq!(
+        decode_lossy_into(b"2MAHA", &mut dst[..4]),
+        Err(Error::BufferTooSmall { min: 5, len: 4 })
+    );
+}
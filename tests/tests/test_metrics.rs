@@ -0,0 +1,107 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use metrics_util::debugging::DebugValue;
+use metrics_util::debugging::DebuggingRecorder;
+use metrics_util::debugging::Snapshotter;
+use metrics_util::CompositeKey;
+
+/// Takes a snapshot of the counters, which resets them.
+fn snapshot(snapshotter: &Snapshotter) -> Vec<(CompositeKey, DebugValue)> {
+    let entries = snapshotter.snapshot().into_vec();
+    entries
+        .into_iter()
+        .map(|(key, _, _, value)| (key, value))
+        .collect()
+}
+
+/// Returns the value of the counter `name` with the given `labels`.
+fn counter(
+    snapshot: &[(CompositeKey, DebugValue)],
+    name: &str,
+    labels: &[(&str, &str)],
+) -> u64 {
+    snapshot
+        .iter()
+        .find_map(|(key, value)| {
+            let key = key.key();
+            let matches = key.name() == name
+                && key.labels().count() == labels.len()
+                && labels.iter().all(|(k, v)| {
+                    key.labels().any(|l| l.key() == *k && l.value() == *v)
+                });
+            match value {
+                DebugValue::Counter(n) if matches => Some(*n),
+                _ => None,
+            }
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_metrics_encode_decode() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+
+    metrics::with_local_recorder(&recorder, || {
+        assert_eq!(c32::encode([42, 42, 42]), "2MAHA");
+        assert_eq!(c32::decode("2MAHA"), Ok(vec![42, 42, 42]));
+    });
+
+    let s = &snapshot(&snapshotter);
+    assert_eq!(counter(s, "c32_calls_total", &[("op", "encode")]), 1);
+    assert_eq!(counter(s, "c32_bytes_in_total", &[("op", "encode")]), 3);
+    assert_eq!(counter(s, "c32_bytes_out_total", &[("op", "encode")]), 5);
+    assert_eq!(counter(s, "c32_calls_total", &[("op", "decode")]), 1);
+    assert_eq!(counter(s, "c32_bytes_in_total", &[("op", "decode")]), 5);
+    assert_eq!(counter(s, "c32_bytes_out_total", &[("op", "decode")]), 3);
+}
+
+#[test]
+fn test_metrics_failure() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+
+    metrics::with_local_recorder(&recorder, || {
+        assert!(c32::decode("2M!HA").is_err());
+        assert!(c32::decode_check_prefixed("0AHA59B9201Z", 'S').is_err());
+    });
+
+    let s = &snapshot(&snapshotter);
+    let labels = [("op", "decode"), ("kind", "invalid_character")];
+    assert_eq!(counter(s, "c32_calls_total", &[("op", "decode")]), 1);
+    assert_eq!(counter(s, "c32_errors_total", &labels), 1);
+    assert_eq!(counter(s, "c32_bytes_out_total", &[("op", "decode")]), 0);
+
+    let labels = [("op", "decode_check_prefixed"), ("kind", "missing_prefix")];
+    assert_eq!(counter(s, "c32_errors_total", &labels), 1);
+}
+
+#[test]
+fn test_metrics_prefixed_counted_once() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+
+    metrics::with_local_recorder(&recorder, || {
+        let en = c32::encode_check_prefixed([42, 42, 42], 'S', 0).unwrap();
+        assert_eq!(en, "S0AHA59B9201Z");
+        let de = c32::decode_check_prefixed(&en, 'S').unwrap();
+        assert_eq!(de, (vec![42, 42, 42], 0));
+    });
+
+    // Assert that the prefixed variants do not record the inner calls.
+    let s = &snapshot(&snapshotter);
+    let op = [("op", "encode_check_prefixed")];
+    assert_eq!(counter(s, "c32_calls_total", &op), 1);
+    assert_eq!(counter(s, "c32_bytes_out_total", &op), 13);
+    let op = [("op", "decode_check_prefixed")];
+    assert_eq!(counter(s, "c32_calls_total", &op), 1);
+    assert_eq!(counter(s, "c32_bytes_out_total", &op), 3);
+    assert_eq!(counter(s, "c32_calls_total", &[("op", "encode_check")]), 0);
+    assert_eq!(counter(s, "c32_calls_total", &[("op", "decode_check")]), 0);
+}
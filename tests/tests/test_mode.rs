@@ -0,0 +1,124 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_mode;
+use c32::encode_mode;
+use c32::Error;
+use c32::Mode;
+
+mod __internal {
+    use super::*;
+
+    /// Parses a mode from a configuration value.
+    pub fn parse(config: &str) -> Mode {
+        match config {
+            "default" => Mode::Default,
+            "prefixed" => Mode::Prefixed('S'),
+            "check" => Mode::Check { version: 22 },
+            "check-prefixed" => Mode::CheckPrefixed {
+                prefix: 'S',
+                version: 22,
+            },
+            _ => panic!("unknown mode: {config}"),
+        }
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_mode_dynamic() {
+    let bytes = [42, 42, 42];
+    let cases = [
+        ("default", c32::encode(bytes)),
+        ("prefixed", c32::encode_prefixed(bytes, 'S')),
+        ("check", c32::encode_check(bytes, 22).unwrap()),
+        (
+            "check-prefixed",
+            c32::encode_check_prefixed(bytes, 'S', 22).unwrap(),
+        ),
+    ];
+
+    for (config, expected) in cases {
+        let mode = parse(config);
+
+        // Assert that the mode dispatches to the matching function.
+        let en = encode_mode(bytes, mode).unwrap();
+        assert_eq!(en, expected, "{config}");
+
+        let de = decode_mode(&en, mode).unwrap();
+        assert_eq!(de, bytes, "{config}");
+    }
+}
+
+#[test]
+fn test_mode_vectors() {
+    let bytes = [42, 42, 42];
+    assert_eq!(encode_mode(bytes, Mode::Default).unwrap(), "2MAHA");
+    assert_eq!(encode_mode(bytes, Mode::Prefixed('S')).unwrap(), "S2MAHA");
+    assert_eq!(
+        encode_mode(bytes, Mode::Check { version: 0 }).unwrap(),
+        "0AHA59B9201Z"
+    );
+    assert_eq!(
+        encode_mode(
+            bytes,
+            Mode::CheckPrefixed {
+                prefix: 'S',
+                version: 0
+            }
+        )
+        .unwrap(),
+        "S0AHA59B9201Z"
+    );
+}
+
+#[test]
+fn test_mode_invalid_version() {
+    let result = encode_mode([42], Mode::Check { version: 32 });
+    assert!(matches!(
+        result,
+        Err(Error::InvalidVersion { version: 32, .. })
+    ));
+}
+
+#[test]
+fn test_mode_version_mismatch() {
+    // Assert that a check mode rejects a different version.
+    let en = c32::encode_check([42, 42, 42], 0).unwrap();
+    let result = decode_mode(&en, Mode::Check { version: 22 });
+    assert_eq!(
+        result,
+        Err(Error::InvalidVersion {
+            expected: "must match the expected version",
+            version: 0,
+        })
+    );
+
+    let en = c32::encode_check_prefixed([42, 42, 42], 'S', 0).unwrap();
+    let mode = Mode::CheckPrefixed {
+        prefix: 'S',
+        version: 22,
+    };
+    assert!(matches!(
+        decode_mode(&en, mode),
+        Err(Error::InvalidVersion { version: 0, .. })
+    ));
+}
+
+#[test]
+fn test_mode_missing_prefix() {
+    let result = decode_mode("2MAHA", Mode::Prefixed('S'));
+    assert_eq!(
+        result,
+        Err(Error::MissingPrefix {
+            char: 'S',
+            got: Some('2')
+        })
+    );
+}
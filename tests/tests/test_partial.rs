@@ -0,0 +1,183 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_check_partial;
+use c32::decode_check_partial_into;
+use c32::decode_partial;
+use c32::decode_partial_into;
+use c32::decode_prefix;
+use c32::Error;
+
+#[test]
+fn test_partial_full_input() {
+    let (de, consumed) = decode_partial("2MAHA").unwrap();
+    assert_eq!(de, [42, 42, 42]);
+    assert_eq!(consumed, 5);
+}
+
+#[test]
+fn test_partial_empty_input() {
+    let (de, consumed) = decode_partial("").unwrap();
+    assert!(de.is_empty());
+    assert_eq!(consumed, 0);
+}
+
+#[test]
+fn test_partial_stops_at_invalid() {
+    let (de, consumed) = decode_partial("2MAHA-0G0001").unwrap();
+    assert_eq!(de, [42, 42, 42]);
+    assert_eq!(consumed, 5);
+}
+
+#[test]
+fn test_partial_stops_at_unicode() {
+    let (de, consumed) = decode_partial("2MAHAé").unwrap();
+    assert_eq!(de, [42, 42, 42]);
+    assert_eq!(consumed, 5);
+}
+
+#[test]
+fn test_partial_leading_invalid() {
+    let (de, consumed) = decode_partial("!2MAHA").unwrap();
+    assert!(de.is_empty());
+    assert_eq!(consumed, 0);
+}
+
+#[test]
+fn test_partial_leading_zeros() {
+    let (de, consumed) = decode_partial("0G0001 trailing").unwrap();
+    assert_eq!(de, [0, 1, 0, 0, 1]);
+    assert_eq!(consumed, 6);
+}
+
+#[test]
+fn test_partial_into() {
+    let mut dst = [0u8; 5];
+    let (offset, consumed) = decode_partial_into(b"2MAHA?", &mut dst).unwrap();
+    assert_eq!(&dst[..offset], [42, 42, 42]);
+    assert_eq!(consumed, 5);
+}
+
+#[test]
+fn test_partial_into_buffer_sized_to_consumed() {
+    let mut dst = [0u8; 5];
+    let input = b"2MAHA?????????????";
+    let (offset, consumed) = decode_partial_into(input, &mut dst).unwrap();
+    assert_eq!(&dst[..offset], [42, 42, 42]);
+    assert_eq!(consumed, 5);
+}
+
+#[test]
+fn test_partial_into_buffer_too_small() {
+    let mut dst = [0u8; 2];
+    let result = decode_partial_into(b"2MAHA?", &mut dst);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 2 }));
+}
+
+#[test]
+fn test_prefix_stops_at_delimiter() {
+    let src = b"2MAHA:rest";
+    let mut dst = [0u8; 5];
+    let (consumed, written) = decode_prefix(src, &mut dst).unwrap();
+    assert_eq!(consumed, 5);
+    assert_eq!(written, 3);
+    assert_eq!(&dst[..written], [42, 42, 42]);
+    assert_eq!(&src[consumed..], b":rest");
+}
+
+#[test]
+fn test_prefix_empty_field() {
+    let mut dst = [0u8; 5];
+    assert_eq!(decode_prefix(b":rest", &mut dst), Ok((0, 0)));
+    assert_eq!(decode_prefix(b"", &mut dst), Ok((0, 0)));
+}
+
+#[test]
+fn test_prefix_lowercase() {
+    let src = b"2maha:rest";
+    let mut dst = [0u8; 5];
+    let (consumed, written) = decode_prefix(src, &mut dst).unwrap();
+    assert_eq!(consumed, 5);
+    assert_eq!(&dst[..written], [42, 42, 42]);
+}
+
+#[test]
+fn test_prefix_delimited() {
+    let src = b"2MAHA:1|0G0001 ";
+    let mut dst = [0u8; 16];
+    let mut fields = Vec::new();
+
+    // Decode each field up to its delimiter, and skip over the delimiter.
+    let mut pos = 0;
+    while pos < src.len() {
+        let (consumed, written) = decode_prefix(&src[pos..], &mut dst).unwrap();
+        fields.push((consumed, dst[..written].to_vec()));
+
+        pos += consumed;
+        assert!(matches!(src[pos], b':' | b'|' | b' '));
+        pos += 1;
+    }
+
+    assert_eq!(
+        fields,
+        [
+            (5, vec![42, 42, 42]),
+            (1, vec![1]),
+            (6, vec![0, 1, 0, 0, 1]),
+        ]
+    );
+}
+
+#[test]
+fn test_prefix_matches_partial_into() {
+    let src = b"0G0001 trailing";
+    let mut a = [0u8; 8];
+    let mut b = [0u8; 8];
+    let (consumed, written) = decode_prefix(src, &mut a).unwrap();
+    let (offset, partial) = decode_partial_into(src, &mut b).unwrap();
+    assert_eq!((consumed, written), (partial, offset));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_prefix_buffer_too_small() {
+    let mut dst = [0u8; 2];
+    let result = decode_prefix(b"2MAHA:rest", &mut dst);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 2 }));
+}
+
+#[test]
+fn test_check_partial() {
+    let (de, version, consumed) =
+        decode_check_partial("0AHA59B9201Z/path").unwrap();
+    assert_eq!(de, [42, 42, 42]);
+    assert_eq!(version, 0);
+    assert_eq!(consumed, 12);
+}
+
+#[test]
+fn test_check_partial_into() {
+    let mut dst = [0u8; 12];
+    let (offset, version, consumed) =
+        decode_check_partial_into(b"0AHA59B9201Z?", &mut dst).unwrap();
+    assert_eq!(&dst[..offset], [42, 42, 42]);
+    assert_eq!(version, 0);
+    assert_eq!(consumed, 12);
+}
+
+#[test]
+fn test_check_partial_checksum_mismatch() {
+    let result = decode_check_partial("0AHA59B9202Z/path");
+    assert!(matches!(result, Err(Error::ChecksumMismatch { .. })));
+}
+
+#[test]
+fn test_check_partial_insufficient_data() {
+    let result = decode_check_partial("0/path");
+    assert_eq!(result, Err(Error::InsufficientData { min: 2, len: 1 }));
+}
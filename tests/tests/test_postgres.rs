@@ -0,0 +1,80 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use bytes::BytesMut;
+use c32::C32Text;
+use c32::Error;
+use postgres_types::FromSql;
+use postgres_types::ToSql;
+use postgres_types::Type;
+
+#[test]
+fn test_postgres_round_trip() {
+    let text = C32Text::new([42, 42, 42]);
+
+    let mut buf = BytesMut::new();
+    text.to_sql_checked(&Type::TEXT, &mut buf).unwrap();
+    assert_eq!(&buf[..], b"2MAHA");
+
+    let de = C32Text::<3>::from_sql(&Type::TEXT, &buf).unwrap();
+    assert_eq!(de, text);
+}
+
+#[test]
+fn test_postgres_round_trip_varchar() {
+    let text = C32Text::new([0, 0, 1, 2, 3]);
+
+    let mut buf = BytesMut::new();
+    text.to_sql_checked(&Type::VARCHAR, &mut buf).unwrap();
+    assert_eq!(&buf[..], b"0020G3");
+
+    let de = C32Text::<5>::from_sql(&Type::VARCHAR, &buf).unwrap();
+    assert_eq!(de, text);
+}
+
+#[test]
+fn test_postgres_accepts() {
+    assert!(<C32Text<3> as ToSql>::accepts(&Type::TEXT));
+    assert!(<C32Text<3> as FromSql>::accepts(&Type::VARCHAR));
+    assert!(!<C32Text<3> as ToSql>::accepts(&Type::BYTEA));
+    assert!(!<C32Text<3> as FromSql>::accepts(&Type::INT4));
+}
+
+#[test]
+fn test_postgres_wrong_type() {
+    let text = C32Text::new([42, 42, 42]);
+    let mut buf = BytesMut::new();
+    assert!(text.to_sql_checked(&Type::BYTEA, &mut buf).is_err());
+}
+
+#[test]
+fn test_postgres_invalid_data_size() {
+    let err = C32Text::<4>::from_sql(&Type::TEXT, b"2MAHA").unwrap_err();
+    let err = err.downcast_ref::<Error>().unwrap();
+    assert_eq!(
+        err,
+        &Error::InvalidDataSize {
+            expected: 4,
+            got: 3
+        }
+    );
+}
+
+#[test]
+fn test_postgres_invalid_character() {
+    let err = C32Text::<3>::from_sql(&Type::TEXT, b"2MAHU").unwrap_err();
+    let err = err.downcast_ref::<Error>().unwrap();
+    assert_eq!(
+        err,
+        &Error::InvalidCharacter {
+            char: 'U',
+            index: 4,
+            decoded: 2,
+        }
+    );
+}
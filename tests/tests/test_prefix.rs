@@ -0,0 +1,172 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_check_prefixed;
+use c32::decode_check_prefixed_heapless;
+use c32::decode_check_prefixed_into;
+use c32::decode_prefixed;
+use c32::en::Check;
+use c32::encode_check_prefixed;
+use c32::encode_check_prefixed_heapless;
+use c32::encode_check_prefixed_into;
+use c32::encode_prefixed;
+use c32::Buffer;
+use c32::Error;
+
+mod __internal {
+    use super::*;
+
+    /// Returns the index of an [`Error::InvalidCharacter`].
+    pub fn error_index<T: std::fmt::Debug>(result: Result<T, Error>) -> usize {
+        match result {
+            Err(Error::InvalidCharacter { index, .. }) => index,
+            result => panic!("expected an invalid character, got {result:?}"),
+        }
+    }
+
+    /// The error of a non-ASCII prefix in the `Buffer` and `_into` APIs.
+    pub const NON_ASCII: Error = Error::InvalidCharacter {
+        char: '€',
+        index: 0,
+        decoded: 0,
+    };
+}
+
+use __internal::*;
+
+#[test]
+fn test_prefix_multibyte_roundtrip() {
+    let bytes = [42, 42, 42];
+
+    let en = encode_prefixed(bytes, '€');
+    assert_eq!(en, "€2MAHA");
+    assert_eq!(decode_prefixed(&en, '€').unwrap(), bytes);
+
+    let en = encode_check_prefixed(bytes, '€', 22).unwrap();
+    assert_eq!(en, "€PAHA58QT2DJ9");
+    assert_eq!(
+        decode_check_prefixed(&en, '€').unwrap(),
+        (bytes.to_vec(), 22)
+    );
+}
+
+#[test]
+fn test_prefix_multibyte_error_index() {
+    // Assert that the index is a byte offset into the prefixed input.
+    for prefix in ['S', 'é', '€', '😀'] {
+        let en = format!("{prefix}2M!HA");
+        let index = error_index(decode_prefixed(&en, prefix));
+        assert_eq!(index, prefix.len_utf8() + 2);
+        assert_eq!(&en[index..index + 1], "!");
+
+        let en = format!("{prefix}PAH!58QT2DJ9");
+        let index = error_index(decode_check_prefixed(&en, prefix));
+        assert_eq!(index, prefix.len_utf8() + 3);
+        assert_eq!(&en[index..index + 1], "!");
+    }
+}
+
+#[test]
+fn test_prefix_ascii_error_index() {
+    // Assert that every path reports the same index for the same input.
+    let en = "S2M!HA";
+    let indexes = [
+        error_index(decode_prefixed(en, 'S')),
+        error_index(Buffer::<6, true>::try_decode(en.as_bytes(), 'S')),
+    ];
+    assert_eq!(indexes, [3; 2]);
+
+    let en = "SPAH!58QT2DJ9";
+    let src = en.as_bytes();
+    let mut dst = [0u8; 16];
+    let indexes = [
+        error_index(decode_check_prefixed(en, 'S')),
+        error_index(Buffer::<16, true, Check>::try_decode(src, 'S')),
+        error_index(decode_check_prefixed_into(src, &mut dst, 'S')),
+        error_index(decode_check_prefixed_heapless::<16>(src, 'S')),
+    ];
+    assert_eq!(indexes, [4; 4]);
+}
+
+#[test]
+fn test_prefix_non_ascii_rejected() {
+    let bytes = [42, 42, 42];
+    let en = "€PAHA58QT2DJ9".as_bytes();
+    let mut dst = [0u8; 32];
+
+    // Assert that the `Buffer` and `_into` APIs reject, rather than panic.
+    assert_eq!(
+        Buffer::<6, true>::try_encode(&bytes, '€').unwrap_err(),
+        NON_ASCII
+    );
+    assert_eq!(
+        Buffer::<8, true>::try_decode("€2MAHA".as_bytes(), '€').unwrap_err(),
+        NON_ASCII
+    );
+    assert_eq!(
+        Buffer::<16, true, Check>::try_encode(&bytes, '€', 22).unwrap_err(),
+        NON_ASCII
+    );
+    assert_eq!(
+        Buffer::<16, true, Check>::try_decode(en, '€').unwrap_err(),
+        NON_ASCII
+    );
+    assert_eq!(
+        encode_check_prefixed_into(&bytes, &mut dst, '€', 22),
+        Err(NON_ASCII)
+    );
+    assert_eq!(
+        decode_check_prefixed_into(en, &mut dst, '€'),
+        Err(NON_ASCII)
+    );
+    assert_eq!(
+        encode_check_prefixed_heapless::<32>(&bytes, '€', 22),
+        Err(NON_ASCII)
+    );
+    assert_eq!(
+        decode_check_prefixed_heapless::<32>(en, '€'),
+        Err(NON_ASCII)
+    );
+
+    // Assert that the rejection precedes the other checks.
+    assert_eq!(
+        Buffer::<8, true>::try_decode(b"", '€').unwrap_err(),
+        NON_ASCII
+    );
+    assert_eq!(
+        encode_check_prefixed_into(&bytes, &mut [], '€', 22),
+        Err(NON_ASCII)
+    );
+}
+
+#[test]
+fn test_prefix_multibyte_char_position() {
+    // Assert that the char position skips the bytes of the prefix.
+    for prefix in ['S', 'é', '€', '😀'] {
+        let en = format!("{prefix}2M!HA");
+        let err = decode_prefixed(&en, prefix).unwrap_err();
+        assert_eq!(err.invalid_char_position(&en), Some(3));
+        assert_eq!(en.chars().nth(3), Some('!'));
+
+        let en = format!("{prefix}PAH!58QT2DJ9");
+        let err = decode_check_prefixed(&en, prefix).unwrap_err();
+        assert_eq!(err.invalid_char_position(&en), Some(4));
+    }
+}
+
+#[test]
+fn test_prefix_char_position_none() {
+    let err = decode_prefixed("€2M!HA", '€').unwrap_err();
+
+    // Assert that a mismatched input does not produce a position.
+    assert_eq!(err.invalid_char_position("€2"), None);
+    assert_eq!(err.invalid_char_position("€€"), None);
+
+    let err = decode_prefixed("2MAHA", '€').unwrap_err();
+    assert_eq!(err.invalid_char_position("2MAHA"), None);
+}
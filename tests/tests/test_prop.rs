@@ -0,0 +1,132 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode;
+use c32::decode_check;
+use c32::decode_le;
+use c32::decode_lossy;
+use c32::decode_owned;
+use c32::decode_prefixed;
+use c32::decode_unchecked_into;
+use c32::decoded_len;
+use c32::encode;
+use c32::encode_check;
+use c32::encode_le;
+use c32::encode_prefixed;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+mod __internal {
+    use super::*;
+
+    /// A strategy for byte vectors padded with leading and trailing zeros.
+    pub fn zero_padded() -> impl Strategy<Value = Vec<u8>> {
+        (0..8usize, vec(any::<u8>(), 0..64), 0..8usize).prop_map(
+            |(lead, body, trail)| {
+                let mut bytes = vec![0u8; lead];
+                bytes.extend_from_slice(&body);
+                bytes.resize(bytes.len() + trail, 0);
+                bytes
+            },
+        )
+    }
+}
+
+use __internal::*;
+
+proptest! {
+    #[test]
+    fn test_prop_roundtrip(bytes in vec(any::<u8>(), 0..256)) {
+        prop_assert_eq!(decode(&encode(&bytes))?, bytes);
+    }
+
+    #[test]
+    fn test_prop_roundtrip_zero_padded(bytes in zero_padded()) {
+        prop_assert_eq!(decode(&encode(&bytes))?, bytes);
+    }
+
+    #[test]
+    fn test_prop_roundtrip_le(bytes in zero_padded()) {
+        prop_assert_eq!(decode_le(&encode_le(&bytes))?, bytes);
+    }
+
+    #[test]
+    fn test_prop_roundtrip_prefixed(bytes in zero_padded()) {
+        prop_assert_eq!(decode_prefixed(&encode_prefixed(&bytes, 'S'), 'S')?, bytes);
+    }
+
+    #[test]
+    fn test_prop_roundtrip_check(bytes in zero_padded(), version in 0..32u8) {
+        let (de, de_version) = decode_check(&encode_check(&bytes, version)?)?;
+        prop_assert_eq!(de, bytes);
+        prop_assert_eq!(de_version, version);
+    }
+
+    #[test]
+    fn test_prop_decode_owned(bytes in zero_padded()) {
+        let en = encode(&bytes);
+        prop_assert_eq!(decode_owned(en.clone()), decode(&en));
+    }
+
+    #[test]
+    fn test_prop_decode_owned_invalid(src in "[0-9A-Za-z!]{0,64}") {
+        prop_assert_eq!(decode_owned(src.clone()), decode(&src));
+    }
+
+    #[test]
+    fn test_prop_decode_unchecked(bytes in zero_padded()) {
+        let en = encode(&bytes);
+        let mut dst = vec![0u8; decoded_len(en.len())];
+        let offset = decode_unchecked_into(en.as_bytes(), &mut dst)?;
+        prop_assert_eq!(&dst[..offset], &bytes[..]);
+    }
+
+    #[test]
+    fn test_prop_decode_unchecked_invalid(
+        lead in 0..8usize,
+        body in vec(any::<u8>(), 0..64),
+    ) {
+        let mut src = vec![b'0'; lead];
+        src.extend_from_slice(&body);
+
+        // Assert that invalid input neither panics nor overruns the buffer.
+        let mut dst = vec![0u8; decoded_len(src.len())];
+        let offset = decode_unchecked_into(&src, &mut dst)?;
+        prop_assert!(offset <= dst.len());
+    }
+
+    #[test]
+    fn test_prop_decode_lossy(bytes in zero_padded()) {
+        let en = encode(&bytes);
+        let (de, substituted) = decode_lossy(&en);
+        prop_assert_eq!(de, bytes);
+        prop_assert!(substituted.is_empty());
+    }
+
+    #[test]
+    fn test_prop_decode_lossy_length(
+        bytes in vec(any::<u8>(), 1..64),
+        pos in any::<prop::sample::Index>(),
+    ) {
+        let en = encode(&bytes);
+        let pos = pos.index(en.len());
+        let mut src = en.into_bytes();
+        src[pos] = b'!';
+
+        // Assert that a substitution preserves the length, unless it replaces
+        // a leading or the first significant character.
+        let (de, substituted) = decode_lossy(std::str::from_utf8(&src).unwrap());
+        prop_assert_eq!(&substituted, &[pos]);
+        let first = src.iter().position(|&b| b != b'0').unwrap_or(src.len());
+        if pos > first {
+            prop_assert_eq!(de.len(), bytes.len());
+        } else {
+            prop_assert!(de.len() <= bytes.len());
+        }
+    }
+}
@@ -21,7 +21,14 @@ use c32::encode_check_prefixed;
 use c32::encode_into;
 use c32::encode_prefixed;
 use c32::encoded_check_len;
+use c32::encoded_check_len_exact;
 use c32::encoded_len;
+use c32::encoded_len_exact;
+use c32::random;
+use c32::random_check;
+use c32::random_into;
+use c32::random_string;
+use c32::Buffer;
 use c32::Result;
 use rand::distr::Alphanumeric;
 use rand::distr::SampleString;
@@ -57,6 +64,8 @@ mod __internal {
             let en = encode(&input);
             let de = decode(&en)?;
             assert_eq!(de, input.as_bytes());
+            assert_eq!(en.capacity(), en.len());
+            assert_eq!(encoded_len_exact(input.as_bytes()), en.len());
         }
         Ok(())
     }
@@ -94,6 +103,35 @@ mod __internal {
 
             assert_eq!(de, input.as_bytes());
             assert_eq!(de_version, version);
+            assert_eq!(en.capacity(), en.len());
+            assert_eq!(
+                encoded_check_len_exact(input.as_bytes(), version),
+                en.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// A test helper for exact lengths of inputs with leading zeros.
+    pub fn test_exact_len(len: usize, rounds: usize) -> Result<()> {
+        let mut rng = rand::rng();
+        for _ in 0..rounds {
+            let len = rng.random_range(0..=len);
+            let mut input = vec![0u8; len];
+            rng.fill(&mut input[..]);
+
+            // Zero out a random number of leading bytes.
+            let zeros = rng.random_range(0..=len);
+            input[..zeros].fill(0);
+            let version = rng.random_range(0..32);
+
+            let en = encode(&input);
+            assert_eq!(en.capacity(), en.len());
+            assert_eq!(encoded_len_exact(&input), en.len());
+
+            let en = encode_check(&input, version)?;
+            assert_eq!(en.capacity(), en.len());
+            assert_eq!(encoded_check_len_exact(&input, version), en.len());
         }
         Ok(())
     }
@@ -163,6 +201,16 @@ fn test_rand_xs_check_prefixed() {
     __internal::test_check_prefixed(1, 10_000).unwrap()
 }
 
+#[test]
+fn test_rand_xs_exact_len() {
+    __internal::test_exact_len(1, 10_000).unwrap()
+}
+
+#[test]
+fn test_rand_sm_exact_len() {
+    __internal::test_exact_len(10, 10_000).unwrap()
+}
+
 #[test]
 fn test_rand_sm() {
     __internal::test_default(10, 10_000).unwrap()
@@ -252,3 +300,101 @@ fn test_rand_xl_prefixed() {
 fn test_rand_xl_check_prefixed() {
     __internal::test_check_prefixed(10_000, 1_000).unwrap()
 }
+
+#[test]
+fn test_random_buffer() {
+    let mut rng = rand::rng();
+    for _ in 0..1_000 {
+        let id = Buffer::<32>::random::<20, _>(&mut rng);
+        let de = decode(id.as_str()).unwrap();
+        assert_eq!(de.len(), 20);
+    }
+}
+
+#[test]
+fn test_random_string() {
+    let mut rng = rand::rng();
+    for len in 0..64 {
+        let id = random_string(len, &mut rng);
+        let de = decode(&id).unwrap();
+        assert_eq!(de.len(), len);
+    }
+}
+
+#[test]
+fn test_random_check() {
+    let mut rng = rand::rng();
+    for len in 0..64 {
+        let version = rng.random_range(0..32);
+        let id = random_check(len, version, &mut rng).unwrap();
+        let (de, de_version) = decode_check(&id).unwrap();
+        assert_eq!(de.len(), len);
+        assert_eq!(de_version, version);
+    }
+}
+
+#[test]
+fn test_random_check_invalid_version() {
+    let mut rng = rand::rng();
+    assert!(random_check(20, 32, &mut rng).is_err());
+}
+
+#[test]
+fn test_random_alphabet_coverage() {
+    let mut rng = rand::rng();
+    let mut seen = [false; 128];
+    for _ in 0..1_000 {
+        for byte in random_string(16, &mut rng).bytes() {
+            seen[byte as usize] = true;
+        }
+    }
+    for byte in b"0123456789ABCDEFGHJKMNPQRSTVWXYZ" {
+        assert!(seen[*byte as usize], "missing '{}'", *byte as char);
+    }
+}
+
+#[test]
+fn test_random() {
+    const ALPHABET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    for _ in 0..1_000 {
+        let id = random::<16>();
+
+        // Assert the length, the alphabet and the decoded size.
+        assert!(id.len() <= encoded_len(16));
+        assert!(id.chars().all(|c| ALPHABET.contains(c)));
+        assert_eq!(decode(&id).unwrap().len(), 16);
+    }
+
+    // Assert that two calls differ, with 128 bits of entropy.
+    assert_ne!(random::<16>(), random::<16>());
+    assert_eq!(random::<0>(), "");
+}
+
+#[test]
+fn test_random_into() {
+    let mut rng = rand::rng();
+    for _ in 0..1_000 {
+        let mut dst = [0u8; encoded_len(20)];
+        let n = random_into::<20, _>(&mut dst, &mut rng).unwrap();
+
+        let id = core::str::from_utf8(&dst[..n]).unwrap();
+        assert_eq!(decode(id).unwrap().len(), 20);
+    }
+
+    let mut a = [0u8; encoded_len(16)];
+    let mut b = [0u8; encoded_len(16)];
+    let n = random_into::<16, _>(&mut a, &mut rng).unwrap();
+    let m = random_into::<16, _>(&mut b, &mut rng).unwrap();
+    assert_ne!(a[..n], b[..m]);
+}
+
+#[test]
+fn test_random_into_buffer_too_small() {
+    let mut rng = rand::rng();
+    let mut dst = [0u8; 25];
+    assert_eq!(
+        random_into::<16, _>(&mut dst, &mut rng),
+        Err(c32::Error::BufferTooSmall { min: 26, len: 25 })
+    );
+}
@@ -0,0 +1,221 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::fs;
+use std::io;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+
+use c32::encode_check;
+use c32::io::decode_check_from_reader;
+use c32::io::decode_check_from_reader_with_len;
+use c32::io::encode_check_from_reader;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    use super::*;
+
+    /// Asserts that streaming matches `encode_check` for `bytes`.
+    pub fn test_stream(bytes: &[u8], version: u8) {
+        let mut dst = Vec::new();
+        let len =
+            encode_check_from_reader(Cursor::new(bytes), version, &mut dst)
+                .unwrap();
+
+        let expected = encode_check(bytes, version).unwrap();
+        assert_eq!(dst, expected.as_bytes());
+        assert_eq!(len, expected.len() as u64);
+
+        // Assert that the stream decodes back to the input.
+        let mut de = Vec::new();
+        let (len, got) =
+            decode_check_from_reader(Cursor::new(&dst), &mut de).unwrap();
+        assert_eq!(de, bytes);
+        assert_eq!(len, bytes.len() as u64);
+        assert_eq!(got, version);
+
+        // Assert that the stream decodes the same with a known length.
+        let mut de = Vec::new();
+        let result = decode_check_from_reader_with_len(
+            &dst[..],
+            dst.len() as u64,
+            &mut de,
+        );
+        assert_eq!(result.unwrap(), (len, got));
+        assert_eq!(de, bytes);
+    }
+
+    /// Returns the [`Error`] wrapped by a decoding failure of `src`.
+    pub fn decode_error(src: &[u8]) -> Error {
+        let err =
+            decode_check_from_reader(Cursor::new(src), io::sink()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = *err.into_inner().unwrap().downcast::<Error>().unwrap();
+
+        // Assert that the error is the same with a known length.
+        let len = src.len() as u64;
+        let result = decode_check_from_reader_with_len(src, len, io::sink());
+        let got = result.unwrap_err().into_inner().unwrap();
+        assert_eq!(*got.downcast::<Error>().unwrap(), err);
+
+        err
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_reader_sample_4m() {
+    let input = fs::read("../samples/c32_s_4m.in").unwrap();
+    test_stream(&input, 22);
+}
+
+#[test]
+fn test_reader_random() {
+    let mut rng = rand::rng();
+    for len in 0..64 {
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        test_stream(&bytes, rng.random_range(0..32));
+    }
+}
+
+#[test]
+fn test_reader_leading_zeros() {
+    for zeros in 0..8 {
+        let mut bytes = vec![0u8; zeros];
+        bytes.extend_from_slice(&[0, 1, 2, 3]);
+        test_stream(&bytes, 0);
+        test_stream(&vec![0u8; zeros], 31);
+    }
+}
+
+#[test]
+fn test_reader_start_position() {
+    let mut reader = Cursor::new(b"xxusque ad finem");
+    reader.seek(io::SeekFrom::Start(2)).unwrap();
+
+    let mut dst = Vec::new();
+    encode_check_from_reader(reader, 22, &mut dst).unwrap();
+    assert_eq!(dst, b"P7AWVHENJJ0RB441K6JVK5DNJ7J3V5");
+}
+
+#[test]
+fn test_reader_invalid_version() {
+    let mut dst = Vec::new();
+    let err =
+        encode_check_from_reader(Cursor::new(b""), 32, &mut dst).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert!(dst.is_empty());
+}
+
+#[test]
+fn test_reader_decode_start_position() {
+    let mut reader = Cursor::new(b"xxP7AWVHENJJ0RB441K6JVK5DNJ7J3V5");
+    reader.seek(io::SeekFrom::Start(2)).unwrap();
+
+    let mut de = Vec::new();
+    let (len, version) = decode_check_from_reader(reader, &mut de).unwrap();
+    assert_eq!(de, b"usque ad finem");
+    assert_eq!((len, version), (14, 22));
+}
+
+#[test]
+fn test_reader_decode_invalid_character() {
+    let err = decode_error(b"P7AWVHENJJ0RB4!1K6JVK5DNJ7J3V5");
+    assert_eq!(
+        err,
+        Error::InvalidCharacter {
+            char: '!',
+            index: 14,
+            decoded: 8,
+        }
+    );
+
+    // Assert that the index counts from the start of the stream.
+    let mut input = encode_check([42u8; 4096], 0).unwrap().into_bytes();
+    input[3000] = b'U';
+    let err = decode_error(&input);
+    assert_eq!(
+        err,
+        Error::InvalidCharacter {
+            char: 'U',
+            index: 3000,
+            decoded: 1874,
+        }
+    );
+
+    // Assert that the last invalid character is reported, as by `decode`.
+    input[100] = b'!';
+    assert_eq!(decode_error(&input), err);
+}
+
+#[test]
+fn test_reader_decode_insufficient_data() {
+    assert_eq!(
+        decode_error(b""),
+        Error::InsufficientData { min: 2, len: 0 }
+    );
+    assert_eq!(
+        decode_error(b"P"),
+        Error::InsufficientData { min: 2, len: 1 }
+    );
+    assert_eq!(
+        decode_error(b"P0"),
+        Error::InsufficientData { min: 4, len: 1 }
+    );
+}
+
+#[test]
+fn test_reader_decode_checksum_mismatch() {
+    let err = decode_error(b"P7AWVHENJJ0RB441K6JVK5DNJ7J3V6");
+    assert!(matches!(err, Error::ChecksumMismatch { .. }));
+
+    // Assert that the checksum covers the version.
+    let err = decode_error(b"Q7AWVHENJJ0RB441K6JVK5DNJ7J3V5");
+    assert!(matches!(err, Error::ChecksumMismatch { .. }));
+}
+
+#[test]
+fn test_reader_decode_unseekable() {
+    // Assert that a reader which cannot seek is decoded.
+    let reader = b"P7AWVHENJJ0RB4".chain(&b"41K6JVK5DNJ7J3V5"[..]);
+
+    let mut de = Vec::new();
+    let (len, version) = decode_check_from_reader(reader, &mut de).unwrap();
+    assert_eq!(de, b"usque ad finem");
+    assert_eq!((len, version), (14, 22));
+}
+
+#[test]
+fn test_reader_decode_with_len() {
+    let mut reader = Cursor::new(b"P7AWVHENJJ0RB441K6JVK5DNJ7J3V5 rest");
+
+    let mut de = Vec::new();
+    let (len, version) =
+        decode_check_from_reader_with_len(&mut reader, 30, &mut de).unwrap();
+    assert_eq!(de, b"usque ad finem");
+    assert_eq!((len, version), (14, 22));
+
+    // Assert that the reader is not read past the declared length.
+    assert_eq!(reader.position(), 30);
+}
+
+#[test]
+fn test_reader_decode_with_len_unexpected_eof() {
+    let src = b"P7AWVHENJJ0RB441K6JVK5DNJ7J3V5";
+    let err = decode_check_from_reader_with_len(&src[..], 31, io::sink())
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    let err =
+        decode_check_from_reader_with_len(&b""[..], 2, io::sink()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
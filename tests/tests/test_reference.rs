@@ -0,0 +1,298 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::fs;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+mod __internal {
+    use num_bigint::BigUint;
+    use sha2_rustcrypto::Digest;
+    use sha2_rustcrypto::Sha256;
+
+    /// A naive reference implementation of `c32` and `c32check`.
+    ///
+    /// The input is read as a single big-endian number, which is written in
+    /// base 32. Every leading zero byte is written as one leading `'0'`, and
+    /// every leading `'0'` is read back as one leading zero byte.
+    pub mod reference {
+        use super::*;
+
+        /// The Crockford Base32 alphabet, as published by the specification.
+        const ALPHABET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+        /// Returns the value of a character, after normalization.
+        pub fn value(char: char) -> Option<u8> {
+            let char = match char.to_ascii_uppercase() {
+                'O' => '0',
+                'I' | 'L' => '1',
+                char => char,
+            };
+            ALPHABET.find(char).map(|i| i as u8)
+        }
+
+        pub fn encode(bytes: &[u8]) -> String {
+            let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+            let number = BigUint::from_bytes_be(&bytes[zeros..]);
+
+            let mut str = "0".repeat(zeros);
+            if number.bits() > 0 {
+                for digit in number.to_radix_be(32) {
+                    str.push(ALPHABET.as_bytes()[digit as usize] as char);
+                }
+            }
+            str
+        }
+
+        pub fn decode(str: &str) -> Option<Vec<u8>> {
+            let digits = str.chars().map(value).collect::<Option<Vec<u8>>>()?;
+            let zeros = digits.iter().take_while(|&&d| d == 0).count();
+
+            let mut bytes = vec![0u8; zeros];
+            if zeros < digits.len() {
+                let number = BigUint::from_radix_be(&digits[zeros..], 32)?;
+                bytes.extend(number.to_bytes_be());
+            }
+            Some(bytes)
+        }
+
+        pub fn checksum(bytes: &[u8], version: u8) -> [u8; 4] {
+            let once = Sha256::new()
+                .chain_update([version])
+                .chain_update(bytes)
+                .finalize();
+            let twice = Sha256::digest(once);
+            [twice[0], twice[1], twice[2], twice[3]]
+        }
+
+        pub fn encode_check(bytes: &[u8], version: u8) -> String {
+            let mut payload = bytes.to_vec();
+            payload.extend(checksum(bytes, version));
+
+            let version = ALPHABET.as_bytes()[version as usize] as char;
+            format!("{version}{}", encode(&payload))
+        }
+
+        pub fn decode_check(str: &str) -> Option<(Vec<u8>, u8)> {
+            let mut chars = str.chars();
+            let version = value(chars.next()?)?;
+
+            let mut bytes = decode(chars.as_str())?;
+            let sum = bytes.split_off(bytes.len().checked_sub(4)?);
+            (sum == checksum(&bytes, version)).then_some((bytes, version))
+        }
+
+        pub fn encode_prefixed(bytes: &[u8], prefix: char) -> String {
+            format!("{prefix}{}", encode(bytes))
+        }
+
+        pub fn decode_prefixed(str: &str, prefix: char) -> Option<Vec<u8>> {
+            decode(str.strip_prefix(prefix)?)
+        }
+
+        pub fn encode_check_prefixed(
+            bytes: &[u8],
+            prefix: char,
+            version: u8,
+        ) -> String {
+            format!("{prefix}{}", encode_check(bytes, version))
+        }
+
+        pub fn decode_check_prefixed(
+            str: &str,
+            prefix: char,
+        ) -> Option<(Vec<u8>, u8)> {
+            decode_check(str.strip_prefix(prefix)?)
+        }
+    }
+
+    /// Asserts that the crate and the reference agree in every mode.
+    pub fn assert_modes(bytes: &[u8], version: u8) {
+        let en = c32::encode(bytes);
+        assert_eq!(en, reference::encode(bytes), "{bytes:?}");
+        assert_eq!(c32::decode(&en).unwrap(), bytes);
+        assert_eq!(reference::decode(&en).unwrap(), bytes);
+
+        let en = c32::encode_prefixed(bytes, 'S');
+        assert_eq!(en, reference::encode_prefixed(bytes, 'S'), "{bytes:?}");
+        assert_eq!(c32::decode_prefixed(&en, 'S').unwrap(), bytes);
+        assert_eq!(reference::decode_prefixed(&en, 'S').unwrap(), bytes);
+
+        let expected = (bytes.to_vec(), version);
+
+        let en = c32::encode_check(bytes, version).unwrap();
+        assert_eq!(en, reference::encode_check(bytes, version), "{bytes:?}");
+        assert_eq!(c32::decode_check(&en).unwrap(), expected);
+        assert_eq!(reference::decode_check(&en).unwrap(), expected);
+
+        let en = c32::encode_check_prefixed(bytes, 'S', version).unwrap();
+        assert_eq!(
+            en,
+            reference::encode_check_prefixed(bytes, 'S', version),
+            "{bytes:?}"
+        );
+        assert_eq!(c32::decode_check_prefixed(&en, 'S').unwrap(), expected);
+        assert_eq!(
+            reference::decode_check_prefixed(&en, 'S').unwrap(),
+            expected
+        );
+    }
+
+    /// Asserts that the crate and the reference decode a string alike.
+    pub fn assert_decode(str: &str) {
+        assert_eq!(c32::decode(str).ok(), reference::decode(str), "{str:?}");
+        assert_eq!(
+            c32::decode_check(str).ok(),
+            reference::decode_check(str),
+            "{str:?}"
+        );
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_reference_unit_vectors() {
+    let vectors: [&[u8]; 11] = [
+        &[],
+        &[1],
+        &[1, 2],
+        &[1, 2, 3],
+        &[1, 2, 3, 4],
+        &[1, 2, 3, 4, 5],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 1],
+        &[0, 1, 0, 1, 0],
+    ];
+
+    for bytes in vectors {
+        for version in [0, 1, 22, 26, 31] {
+            assert_modes(bytes, version);
+        }
+    }
+}
+
+#[test]
+fn test_reference_zero_bytes() {
+    // Assert every short input made of zero and non-zero bytes.
+    for len in 0..=6 {
+        for mask in 0..(1u32 << len) {
+            let bytes = (0..len)
+                .map(|i| if mask & (1 << i) == 0 { 0 } else { 0x80 >> i })
+                .collect::<Vec<u8>>();
+            assert_modes(&bytes, (mask % 32) as u8);
+        }
+    }
+
+    // Assert checksums that start with zero bytes, with an empty payload.
+    for version in 0..32 {
+        assert_modes(&[], version);
+    }
+}
+
+#[test]
+fn test_reference_samples() {
+    let paths = [
+        "../samples/c32_m_100x32b.in",
+        "../samples/c32_m_100x64b.in",
+        "../samples/c32_m_100x128b.in",
+        "../samples/c32_m_100x256b.in",
+        "../samples/c32_s_32k.in",
+        "../samples/c32_s_64k.in",
+    ];
+
+    for (i, path) in paths.into_iter().enumerate() {
+        let input = fs::read(path).unwrap();
+        assert_modes(&input, i as u8);
+    }
+}
+
+#[test]
+fn test_reference_random() {
+    let mut rng = StdRng::seed_from_u64(0xC32);
+
+    for _ in 0..4096 {
+        // Prepend a run of zero bytes to a random payload.
+        let zeros = rng.random_range(0..4);
+        let len = rng.random_range(0..48);
+        let mut bytes = vec![0u8; zeros];
+        bytes.extend((0..len).map(|_| rng.random::<u8>()));
+
+        assert_modes(&bytes, rng.random_range(0..32));
+    }
+}
+
+#[test]
+fn test_reference_random_strings() {
+    const CHARS: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZabcdeoOiIlL";
+    let mut rng = StdRng::seed_from_u64(0xC32);
+
+    for _ in 0..4096 {
+        // Assert non-canonical strings, with leading zeros and aliases.
+        let zeros = rng.random_range(0..4);
+        let len = rng.random_range(0..48);
+        let mut str = "0".repeat(zeros);
+        str.extend(
+            (0..len).map(|_| CHARS[rng.random_range(0..CHARS.len())] as char),
+        );
+        assert_decode(&str);
+
+        // Assert valid checksums, and corrupted ones.
+        let bytes = (0..len).map(|_| rng.random::<u8>()).collect::<Vec<_>>();
+        let mut en = reference::encode_check(&bytes, rng.random_range(0..32))
+            .into_bytes();
+        assert_decode(std::str::from_utf8(&en).unwrap());
+
+        let index = rng.random_range(0..en.len());
+        en[index] = CHARS[rng.random_range(0..CHARS.len())];
+        assert_decode(std::str::from_utf8(&en).unwrap());
+    }
+}
+
+#[test]
+fn test_reference_module() {
+    let mut rng = StdRng::seed_from_u64(0xC32);
+
+    for _ in 0..1024 {
+        let zeros = rng.random_range(0..4);
+        let len = rng.random_range(0..48);
+        let mut bytes = vec![0u8; zeros];
+        bytes.extend((0..len).map(|_| rng.random::<u8>()));
+        let version = rng.random_range(0..32);
+
+        // Assert that the exposed reference agrees with this one.
+        let en = c32::reference::encode(&bytes);
+        assert_eq!(en, reference::encode(&bytes));
+        assert_eq!(c32::reference::decode(&en), reference::decode(&en));
+
+        let en = c32::reference::encode_check(&bytes, version).unwrap();
+        assert_eq!(en, reference::encode_check(&bytes, version));
+        assert_eq!(
+            c32::reference::decode_check(&en),
+            reference::decode_check(&en)
+        );
+
+        c32::reference::assert_equivalent(&bytes);
+        c32::reference::assert_equivalent(en.as_bytes());
+    }
+
+    assert_eq!(c32::reference::encode_check(&[42], 32), None);
+    assert_eq!(c32::reference::decode("2M!HA"), None);
+}
+
+#[test]
+fn test_reference_module_samples() {
+    let input = fs::read("../samples/c32_m_100x32b.in").unwrap();
+    for line in input.split(|&b| b == b'\n') {
+        c32::reference::assert_equivalent(line);
+    }
+}
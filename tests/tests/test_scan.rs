@@ -0,0 +1,242 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_check;
+use c32::encode_check;
+use c32::encode_check_prefixed;
+use c32::scan::find_tokens;
+use c32::scan_check;
+use rand::Rng;
+
+#[test]
+fn test_scan_empty() {
+    assert!(scan_check("", None).next().is_none());
+    assert!(scan_check("", Some('S')).next().is_none());
+}
+
+#[test]
+fn test_scan_no_candidates() {
+    let text = "nothing to see here, move along!";
+    assert!(scan_check(text, None).next().is_none());
+}
+
+#[test]
+fn test_scan_unprefixed() {
+    let en = encode_check([1, 2, 3, 4, 5, 6, 7, 8], 22).unwrap();
+    let text = format!("id={en}; done");
+
+    let found: Vec<_> = scan_check(&text, None).collect();
+    assert_eq!(found.len(), 1);
+
+    let (range, version, bytes) = &found[0];
+    assert_eq!(&text[range.clone()], en);
+    assert_eq!(*version, 22);
+    assert_eq!(bytes, &[1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_scan_prefixed() {
+    let a = encode_check_prefixed([0xAA; 20], 'S', 22).unwrap();
+    let b = encode_check_prefixed([0xBB; 20], 'S', 26).unwrap();
+    let text = format!("from {a} to {b}.");
+
+    let found: Vec<_> = scan_check(&text, Some('S')).collect();
+    assert_eq!(found.len(), 2);
+
+    assert_eq!(&text[found[0].0.clone()], a);
+    assert_eq!(found[0].1, 22);
+    assert_eq!(found[0].2, [0xAA; 20]);
+
+    assert_eq!(&text[found[1].0.clone()], b);
+    assert_eq!(found[1].1, 26);
+    assert_eq!(found[1].2, [0xBB; 20]);
+}
+
+#[test]
+fn test_scan_maximal_runs() {
+    // Assert that only whole runs are verified, so a token that neither
+    // starts nor ends its run is not found.
+    let en = encode_check_prefixed([0xCC; 20], 'S', 22).unwrap();
+    let text = format!("[S{en}] Z{en}Z {en}{en}");
+    assert!(scan_check(&text, Some('S')).next().is_none());
+
+    let en = encode_check([0x44; 20], 22).unwrap();
+    let padding = "Z".repeat(500);
+    let text = format!("{padding}{en}{padding} {en}{en}");
+    assert!(scan_check(&text, None).next().is_none());
+}
+
+#[test]
+fn test_scan_long_payload() {
+    // Assert that candidates are not capped in length.
+    for len in [35, 36, 64, 1024] {
+        let bytes: Vec<u8> = (0..len).map(|i| (i * 7 + 1) as u8).collect();
+        let en = encode_check_prefixed(&bytes, 'S', 22).unwrap();
+        let text = format!("<{en}>");
+
+        let found: Vec<_> = scan_check(&text, Some('S')).collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, 1..text.len() - 1);
+        assert_eq!(found[0].1, 22);
+        assert_eq!(found[0].2, bytes);
+    }
+}
+
+#[test]
+fn test_scan_matches_tokens() {
+    let a = encode_check([0x22; 20], 22).unwrap();
+    let b = encode_check([0x33; 40], 26).unwrap();
+    let text = format!("({a},{b});{a}. 2MAHA 0AHA59B9201Y");
+
+    // Assert that the scanner yields the verified tokens of `scan`.
+    let found: Vec<_> = scan_check(&text, None).map(|f| f.0).collect();
+    let spans: Vec<_> = find_tokens(&text, 8)
+        .verified_only()
+        .map(|t| t.span())
+        .collect();
+    assert_eq!(found, spans);
+    assert_eq!(found.len(), 3);
+}
+
+#[test]
+fn test_scan_non_alphabet_prefix() {
+    let en = encode_check([42, 42, 42], 0).unwrap();
+    let text = format!("a #{en} b {en}");
+
+    let found: Vec<_> = scan_check(&text, Some('#')).collect();
+    assert_eq!(found.len(), 1);
+    assert_eq!(&text[found[0].0.clone()], format!("#{en}"));
+}
+
+#[test]
+fn test_scan_skips_corrupted() {
+    let en = encode_check([0xDD; 20], 22).unwrap();
+    let mut bad = en.clone().into_bytes();
+    bad[5] = if bad[5] == b'A' { b'B' } else { b'A' };
+    let bad = String::from_utf8(bad).unwrap();
+    let text = format!("{bad} {en}");
+
+    let found: Vec<_> = scan_check(&text, None).collect();
+    assert_eq!(found.len(), 1);
+    assert_eq!(&text[found[0].0.clone()], en);
+}
+
+#[test]
+fn test_scan_unicode() {
+    let en = encode_check_prefixed([0xEE; 20], 'S', 22).unwrap();
+    let text = format!("→{en}←");
+
+    let found: Vec<_> = scan_check(&text, Some('S')).collect();
+    assert_eq!(found.len(), 1);
+    assert_eq!(&text[found[0].0.clone()], en);
+}
+
+#[test]
+fn test_tokens_boundaries() {
+    let en = encode_check([0x11; 20], 22).unwrap();
+
+    // Assert that tokens at both ends of the haystack are found.
+    let text = format!("{en} middle {en}");
+    let spans: Vec<_> = find_tokens(&text, 8).map(|t| t.span()).collect();
+    assert_eq!(spans, [0..en.len(), text.len() - en.len()..text.len()]);
+
+    // Assert that a haystack consisting of a single token is found.
+    let tokens: Vec<_> = find_tokens(&en, 8).collect();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].as_str(), en);
+    assert_eq!(tokens[0].validate_check(), Ok(22));
+}
+
+#[test]
+fn test_tokens_back_to_back() {
+    let a = encode_check([0x22; 20], 22).unwrap();
+    let b = encode_check([0x33; 20], 26).unwrap();
+    let text = format!("({a},{b});{a}.");
+
+    let tokens: Vec<_> = find_tokens(&text, 8).verified_only().collect();
+    let found: Vec<_> = tokens.iter().map(|t| t.as_str()).collect();
+    assert_eq!(found, [a.as_str(), b.as_str(), a.as_str()]);
+
+    let versions: Vec<_> =
+        tokens.iter().map(|t| t.validate_check().unwrap()).collect();
+    assert_eq!(versions, [22, 26, 22]);
+}
+
+#[test]
+fn test_tokens_min_len() {
+    let text = "a ab abc abcd";
+    let found: Vec<_> = find_tokens(text, 3).map(|t| t.as_str()).collect();
+    assert_eq!(found, ["abc", "abcd"]);
+
+    let found: Vec<_> = find_tokens(text, 0).map(|t| t.as_str()).collect();
+    assert_eq!(found, ["a", "ab", "abc", "abcd"]);
+}
+
+#[test]
+fn test_tokens_verified_only() {
+    let en = encode_check_prefixed([0x44; 20], 'S', 22).unwrap();
+
+    // Lookalikes: a corrupted character, a truncation and a word.
+    let mut bad = en.clone().into_bytes();
+    bad[7] = if bad[7] == b'A' { b'B' } else { b'A' };
+    let bad = String::from_utf8(bad).unwrap();
+    let short = &en[..en.len() - 1];
+    let text = format!("{bad} {short} SPEEDBOAT42XYZ {en}");
+
+    // Assert that every candidate is yielded without verification.
+    assert_eq!(find_tokens(&text, 8).prefix('S').count(), 4);
+
+    let tokens: Vec<_> =
+        find_tokens(&text, 8).prefix('S').verified_only().collect();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].as_str(), en);
+    assert_eq!(tokens[0].encoded(), &en[1..]);
+}
+
+#[test]
+fn test_tokens_prefixed() {
+    let en = encode_check([42, 42, 42], 0).unwrap();
+    let text = format!("{en} #{en} S{en}");
+
+    // Assert that an alphabet prefix must start the run.
+    let tokens: Vec<_> = find_tokens(&text, 8).prefix('S').collect();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].as_str(), format!("S{en}"));
+
+    // Assert that a non-alphabet prefix must precede the run.
+    let tokens: Vec<_> = find_tokens(&text, 8).prefix('#').collect();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].as_str(), format!("#{en}"));
+    assert_eq!(tokens[0].encoded(), en);
+    assert_eq!(tokens[0].validate_check(), Ok(0));
+}
+
+#[test]
+fn test_tokens_validate_matches_decode() {
+    let mut rng = rand::rng();
+    for _ in 0..256 {
+        let len = rng.random_range(0..48);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..4).min(len);
+        bytes[..zeros].fill(0);
+
+        let en = encode_check(&bytes, rng.random_range(0..32)).unwrap();
+        let token = find_tokens(&en, 0).next().unwrap();
+        let expected = decode_check(&en).map(|(_, version)| version);
+        assert_eq!(token.validate_check(), expected);
+
+        // Assert that a corrupted token fails the same way.
+        let mut bad = en.into_bytes();
+        let i = rng.random_range(0..bad.len());
+        bad[i] = if bad[i] == b'0' { b'1' } else { b'0' };
+        let bad = String::from_utf8(bad).unwrap();
+        let token = find_tokens(&bad, 0).next().unwrap();
+        assert_eq!(token.validate_check(), decode_check(&bad).map(|de| de.1));
+    }
+}
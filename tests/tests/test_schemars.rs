@@ -0,0 +1,94 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::AsC32;
+use c32::AsC32Check;
+use c32::C32Text;
+use schemars::schema_for;
+use schemars::JsonSchema;
+use serde_json::json;
+use serde_json::Value;
+use serde_with::serde_as;
+
+#[serde_as]
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct Record {
+    text: C32Text<20>,
+    #[serde_as(as = "AsC32")]
+    bytes: Vec<u8>,
+    #[serde_as(as = "AsC32Check<22>")]
+    hash: [u8; 20],
+}
+
+mod __internal {
+    use super::*;
+
+    /// Returns the generated schema of `T` as a JSON value.
+    pub fn schema<T: JsonSchema>() -> Value {
+        schema_for!(T).to_value()
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_schemars_text() {
+    assert_eq!(
+        schema::<C32Text<3>>(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "C32Text_3",
+            "type": "string",
+            "format": "c32",
+            "pattern": "^[0-9A-TV-Za-tv-z]*$",
+            "minLength": 3,
+            "maxLength": 5,
+        })
+    );
+}
+
+#[test]
+fn test_schemars_text_len() {
+    let schema = schema::<C32Text<20>>();
+    let shortest = C32Text::new([0; 20]).to_string();
+    let longest = C32Text::new([255; 20]).to_string();
+
+    // Assert that the length range covers the extreme encodings.
+    assert_eq!(schema["minLength"], shortest.len());
+    assert_eq!(schema["maxLength"], longest.len());
+}
+
+#[test]
+fn test_schemars_adapters() {
+    let schema = schema::<Record>();
+    assert_eq!(
+        schema["properties"],
+        json!({
+            "text": {
+                "type": "string",
+                "format": "c32",
+                "pattern": "^[0-9A-TV-Za-tv-z]*$",
+                "minLength": 20,
+                "maxLength": 32,
+            },
+            "bytes": {
+                "type": "string",
+                "format": "c32",
+                "pattern": "^[0-9A-TV-Za-tv-z]*$",
+            },
+            "hash": {
+                "type": "string",
+                "format": "c32check",
+                "pattern": "^[0-9A-TV-Za-tv-z]*$",
+                "minLength": 25,
+                "maxLength": 40,
+            },
+        })
+    );
+}
@@ -0,0 +1,99 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::en::Check;
+use c32::Buffer;
+use serde::de::value::Error as ValueError;
+use serde::de::value::StrDeserializer;
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+use serde::Serialize;
+
+mod __internal {
+    /// A firmware configuration with a device identifier.
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    pub struct Config {
+        pub id: c32::Buffer<16>,
+        pub interval: u32,
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_serde_serialize() {
+    let id = Buffer::<16>::encode(&[42, 42, 42]);
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "\"2MAHA\"");
+
+    // Assert that any encoding format serializes its textual form.
+    let id = Buffer::<13, false, Check>::encode(&[42, 42, 42], 0);
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "\"0AHA59B9201Z\"");
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let config = Config {
+        id: Buffer::<16>::encode(&[1, 2, 3, 4, 5, 6, 7, 8, 9]),
+        interval: 60,
+    };
+
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, "{\"id\":\"G40R40M30E209\",\"interval\":60}");
+
+    let de: Config = serde_json::from_str(&json).unwrap();
+    assert_eq!(de.id.as_str(), config.id.as_str());
+    assert_eq!(de.interval, 60);
+}
+
+#[test]
+fn test_serde_deserialize_as_written() {
+    // Assert that the string is stored as written, including aliases.
+    let id: Buffer<8> = serde_json::from_str("\"2mahO\"").unwrap();
+    assert_eq!(id.as_str(), "2mahO");
+    assert_eq!(id.pos(), 5);
+
+    let id: Buffer<8> = serde_json::from_str("\"\"").unwrap();
+    assert_eq!(id.as_str(), "");
+}
+
+#[test]
+fn test_serde_deserialize_invalid_character() {
+    let err = serde_json::from_str::<Buffer<8>>("\"2MAHU\"").unwrap_err();
+    assert!(err.to_string().contains("Invalid character 'U'"));
+}
+
+#[test]
+fn test_serde_deserialize_overflow() {
+    let err = serde_json::from_str::<Buffer<4>>("\"2MAHA\"").unwrap_err();
+    assert!(err.to_string().starts_with("Buffer too small: 4 < 5"));
+
+    // Assert that a string of exactly `N` characters fits.
+    let id: Buffer<5> = serde_json::from_str("\"2MAHA\"").unwrap();
+    assert_eq!(id.as_str(), "2MAHA");
+}
+
+#[test]
+fn test_serde_deserialize_no_alloc() {
+    // Deserialize from a borrowed string, without an allocating format.
+    let deserializer: StrDeserializer<ValueError> = "2MAHA".into_deserializer();
+    let id = Buffer::<8>::deserialize(deserializer).unwrap();
+    assert_eq!(id.as_str(), "2MAHA");
+
+    let deserializer: StrDeserializer<ValueError> = "2MA!A".into_deserializer();
+    assert!(Buffer::<8>::deserialize(deserializer).is_err());
+}
+
+#[test]
+fn test_serde_serialize_trait() {
+    fn assert_serialize<T: Serialize>() {}
+    assert_serialize::<Buffer<16>>();
+    assert_serialize::<Buffer<17, true>>();
+    assert_serialize::<Buffer<13, false, Check>>();
+}
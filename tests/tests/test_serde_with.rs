@@ -0,0 +1,159 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::AsC32;
+use c32::AsC32Check;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_with::serde_as;
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    #[serde_as(as = "AsC32")]
+    bytes: Vec<u8>,
+    #[serde_as(as = "AsC32")]
+    array: [u8; 3],
+    #[serde_as(as = "Option<AsC32>")]
+    optional: Option<Vec<u8>>,
+    #[serde_as(as = "Vec<AsC32>")]
+    hashes: Vec<[u8; 20]>,
+}
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CheckRecord {
+    #[serde_as(as = "AsC32Check<22>")]
+    bytes: Vec<u8>,
+    #[serde_as(as = "Option<AsC32Check<22>>")]
+    optional: Option<[u8; 20]>,
+    #[serde_as(as = "Vec<AsC32Check<22>>")]
+    hashes: Vec<[u8; 20]>,
+}
+
+#[test]
+fn test_serde_with_roundtrip() {
+    let record = Record {
+        bytes: vec![0, 0, 1, 2, 3],
+        array: [42, 42, 42],
+        optional: Some(vec![42, 42, 42]),
+        hashes: vec![[0; 20], [255; 20]],
+    };
+
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(
+        json,
+        format!(
+            r#"{{"bytes":"0020G3","array":"2MAHA","optional":"2MAHA","hashes":["{}","{}"]}}"#,
+            c32::encode([0; 20]),
+            c32::encode([255; 20])
+        )
+    );
+
+    let de: Record = serde_json::from_str(&json).unwrap();
+    assert_eq!(de, record);
+}
+
+#[test]
+fn test_serde_with_none() {
+    let record = Record {
+        bytes: vec![],
+        array: [0; 3],
+        optional: None,
+        hashes: vec![],
+    };
+
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(
+        json,
+        r#"{"bytes":"","array":"000","optional":null,"hashes":[]}"#
+    );
+
+    let de: Record = serde_json::from_str(&json).unwrap();
+    assert_eq!(de, record);
+}
+
+#[test]
+fn test_serde_with_invalid_character() {
+    let json =
+        r#"{"bytes":"2MAH!","array":"2MAHA","optional":null,"hashes":[]}"#;
+    let err = serde_json::from_str::<Record>(json).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Invalid character '!' at position 4"));
+}
+
+#[test]
+fn test_serde_with_invalid_data_size() {
+    let json = r#"{"bytes":"","array":"2MAHAG","optional":null,"hashes":[]}"#;
+    let err = serde_json::from_str::<Record>(json).unwrap_err();
+    assert!(err.to_string().contains("Invalid data size: 4 != 3"));
+
+    let json =
+        r#"{"bytes":"","array":"000","optional":null,"hashes":["2MAHA"]}"#;
+    let err = serde_json::from_str::<Record>(json).unwrap_err();
+    assert!(err.to_string().contains("Invalid data size: 3 != 20"));
+}
+
+#[test]
+fn test_serde_with_check_roundtrip() {
+    let record = CheckRecord {
+        bytes: vec![42, 42, 42],
+        optional: Some([1; 20]),
+        hashes: vec![[0; 20], [255; 20]],
+    };
+
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(
+        json,
+        format!(
+            r#"{{"bytes":"{}","optional":"{}","hashes":["{}","{}"]}}"#,
+            c32::encode_check([42, 42, 42], 22).unwrap(),
+            c32::encode_check([1; 20], 22).unwrap(),
+            c32::encode_check([0; 20], 22).unwrap(),
+            c32::encode_check([255; 20], 22).unwrap()
+        )
+    );
+
+    let de: CheckRecord = serde_json::from_str(&json).unwrap();
+    assert_eq!(de, record);
+
+    let json = r#"{"bytes":"P7AWVHENJJ0RB441K6JVK5DNJ7J3V5","optional":null,"hashes":[]}"#;
+    let de: CheckRecord = serde_json::from_str(json).unwrap();
+    assert_eq!(de.bytes, b"usque ad finem");
+    assert_eq!(de.optional, None);
+}
+
+#[test]
+fn test_serde_with_check_version_mismatch() {
+    let bytes = c32::encode_check([42, 42, 42], 0).unwrap();
+    let json = format!(r#"{{"bytes":"{bytes}","optional":null,"hashes":[]}}"#);
+    let err = serde_json::from_str::<CheckRecord>(&json).unwrap_err();
+    assert!(err.to_string().contains("Invalid version 0:"));
+}
+
+#[test]
+fn test_serde_with_check_invalid_version() {
+    #[serde_as]
+    #[derive(Debug, Serialize)]
+    struct Invalid {
+        #[serde_as(as = "AsC32Check<32>")]
+        bytes: Vec<u8>,
+    }
+
+    let record = Invalid { bytes: vec![1] };
+    let err = serde_json::to_string(&record).unwrap_err();
+    assert!(err.to_string().contains("Invalid version 32:"));
+}
+
+#[test]
+fn test_serde_with_check_checksum_mismatch() {
+    let json = r#"{"bytes":"P7AWVHENJJ0RB441K6JVK5DNJ7J3V4","optional":null,"hashes":[]}"#;
+    let err = serde_json::from_str::<CheckRecord>(json).unwrap_err();
+    assert!(err.to_string().contains("Checksum mismatch"));
+}
@@ -0,0 +1,100 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_i128;
+use c32::decode_i64;
+use c32::encode_i128;
+use c32::encode_i64;
+use c32::encoded_len;
+use c32::Error;
+use rand::Rng;
+
+#[test]
+fn test_signed_i64() {
+    let cases = [
+        (0, "0000000000000"),
+        (-1, "FZZZZZZZZZZZZ"),
+        (i64::MIN, "8000000000000"),
+        (i64::MAX, "7ZZZZZZZZZZZZ"),
+    ];
+    for (value, expected) in cases {
+        let en = encode_i64(value);
+        assert_eq!(en, expected);
+        assert_eq!(decode_i64(&en), Ok(value));
+    }
+}
+
+#[test]
+fn test_signed_i128() {
+    let cases = [
+        (0, "00000000000000000000000000"),
+        (-1, "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+        (i128::MIN, "40000000000000000000000000"),
+        (i128::MAX, "3ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+    ];
+    for (value, expected) in cases {
+        let en = encode_i128(value);
+        assert_eq!(en, expected);
+        assert_eq!(decode_i128(&en), Ok(value));
+    }
+}
+
+#[test]
+fn test_signed_rand() {
+    let mut rng = rand::rng();
+    for _ in 0..1024 {
+        let value: i64 = rng.random();
+        let en = encode_i64(value);
+        assert_eq!(en.len(), encoded_len(8));
+        assert_eq!(decode_i64(&en), Ok(value));
+
+        let value: i128 = rng.random();
+        let en = encode_i128(value);
+        assert_eq!(en.len(), encoded_len(16));
+        assert_eq!(decode_i128(&en), Ok(value));
+    }
+}
+
+#[test]
+fn test_signed_fixed_width() {
+    // Assert that the sign-extension bytes are kept, unlike in `encode`.
+    assert_eq!(c32::encode((-1i64).to_be_bytes()), "FZZZZZZZZZZZZ");
+    assert_eq!(c32::encode(1i64.to_be_bytes()), "00000001");
+    assert_eq!(encode_i64(1), "0000000000001");
+
+    // Assert that the decoding accepts lowercase symbols and aliases.
+    assert_eq!(decode_i64("fzzzzzzzzzzzz"), Ok(-1));
+    assert_eq!(decode_i64("OOOOOOOOOOOOl"), Ok(1));
+}
+
+#[test]
+fn test_signed_errors() {
+    assert_eq!(
+        decode_i64("000000000001"),
+        Err(Error::InvalidDataSize {
+            expected: 13,
+            got: 12
+        })
+    );
+    assert_eq!(
+        decode_i64("G000000000000"),
+        Err(Error::OutputTooLarge { max: 8, got: 9 })
+    );
+    assert_eq!(
+        decode_i128("80000000000000000000000000"),
+        Err(Error::OutputTooLarge { max: 16, got: 17 })
+    );
+    assert!(matches!(
+        decode_i64("000000000001U"),
+        Err(Error::InvalidCharacter {
+            char: 'U',
+            index: 12,
+            ..
+        })
+    ));
+}
@@ -0,0 +1,157 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::buffer_for;
+use c32::en::Check;
+use c32::encode;
+use c32::encode_check;
+use c32::encode_check_prefixed;
+use c32::encode_prefixed;
+use c32::encoded_check_len;
+use c32::encoded_len;
+use c32::Buffer;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    /// Returns `len` bytes, decided at runtime.
+    pub fn input(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i as u8).wrapping_mul(37) | 1).collect()
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_slice_exact_fit() {
+    let src = input(20);
+
+    let en = <buffer_for!(20)>::encode_slice(&src).unwrap();
+    assert_eq!(en.as_str(), encode(&src));
+
+    let en = <buffer_for!(prefixed, 20)>::encode_slice(&src, 'S').unwrap();
+    assert_eq!(en.as_str(), encode_prefixed(&src, 'S'));
+
+    let en = <buffer_for!(check, 20)>::encode_slice(&src, 22).unwrap();
+    assert_eq!(en.as_str(), encode_check(&src, 22).unwrap());
+
+    let en = <buffer_for!(check, prefixed, 20)>::encode_slice(&src, 'S', 22)
+        .unwrap();
+    assert_eq!(en.as_str(), encode_check_prefixed(&src, 'S', 22).unwrap());
+}
+
+#[test]
+fn test_slice_one_over() {
+    let src = input(20);
+
+    // Assert that a buffer one byte too small is rejected.
+    let min = encoded_len(20);
+    assert_eq!(
+        Buffer::<{ encoded_len(20) - 1 }>::encode_slice(&src).unwrap_err(),
+        Error::BufferTooSmall { min, len: min - 1 }
+    );
+
+    let min = encoded_len(20) + 1;
+    assert_eq!(
+        Buffer::<{ encoded_len(20) }, true>::encode_slice(&src, 'S')
+            .unwrap_err(),
+        Error::BufferTooSmall { min, len: min - 1 }
+    );
+
+    let min = encoded_check_len(20);
+    assert_eq!(
+        Buffer::<{ encoded_check_len(20) - 1 }, false, Check>::encode_slice(
+            &src, 22
+        )
+        .unwrap_err(),
+        Error::BufferTooSmall { min, len: min - 1 }
+    );
+
+    let min = encoded_check_len(20) + 1;
+    assert_eq!(
+        Buffer::<{ encoded_check_len(20) }, true, Check>::encode_slice(
+            &src, 'S', 22
+        )
+        .unwrap_err(),
+        Error::BufferTooSmall { min, len: min - 1 }
+    );
+}
+
+#[test]
+fn test_slice_empty() {
+    let src = input(0);
+
+    let en = Buffer::<0>::encode_slice(&src).unwrap();
+    assert_eq!(en.as_str(), "");
+
+    let en = Buffer::<1, true>::encode_slice(&src, 'S').unwrap();
+    assert_eq!(en.as_str(), "S");
+
+    let en = <buffer_for!(check, 0)>::encode_slice(&src, 0).unwrap();
+    assert_eq!(en.as_str(), encode_check(&src, 0).unwrap());
+
+    let en =
+        <buffer_for!(check, prefixed, 0)>::encode_slice(&src, 'S', 0).unwrap();
+    assert_eq!(en.as_str(), encode_check_prefixed(&src, 'S', 0).unwrap());
+}
+
+#[test]
+fn test_slice_runtime_len() {
+    const MAX: usize = 64;
+
+    let mut rng = rand::rng();
+    for _ in 0..1_000 {
+        let len = rng.random_range(0..=MAX);
+        let mut src = vec![0u8; len];
+        rng.fill(&mut src[..]);
+
+        // Assert that the same buffer type serves every length.
+        let en = <buffer_for!(MAX)>::encode_slice(&src).unwrap();
+        assert_eq!(en.as_str(), encode(&src));
+
+        let en =
+            <buffer_for!(check, prefixed, MAX)>::encode_slice(&src, 'S', 7)
+                .unwrap();
+        assert_eq!(en.as_str(), encode_check_prefixed(&src, 'S', 7).unwrap());
+    }
+}
+
+#[test]
+fn test_slice_matches_try_encode() {
+    let src = [42, 42, 42];
+    assert_eq!(
+        Buffer::<5>::encode_slice(&src).unwrap().as_str(),
+        Buffer::<5>::try_encode(&src).unwrap().as_str()
+    );
+    assert_eq!(
+        Buffer::<14, true, Check>::encode_slice(&src, 'S', 0)
+            .unwrap()
+            .as_str(),
+        Buffer::<14, true, Check>::try_encode(&src, 'S', 0)
+            .unwrap()
+            .as_str()
+    );
+}
+
+#[test]
+fn test_slice_invalid_arguments() {
+    let src = input(3);
+
+    assert!(matches!(
+        Buffer::<13, false, Check>::encode_slice(&src, 32),
+        Err(Error::InvalidVersion { version: 32, .. })
+    ));
+    assert!(matches!(
+        Buffer::<6, true>::encode_slice(&src, 'Ö'),
+        Err(Error::InvalidCharacter { char: 'Ö', .. })
+    ));
+    assert!(matches!(
+        Buffer::<14, true, Check>::encode_slice(&src, 'Ö', 0),
+        Err(Error::InvalidCharacter { char: 'Ö', .. })
+    ));
+}
@@ -0,0 +1,58 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_small;
+use c32::Error;
+use rand::Rng;
+
+#[test]
+fn test_decode_small_inline() {
+    let mut rng = rand::rng();
+    for len in 0..=32 {
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+
+        let en = c32::encode(&bytes);
+        let de = decode_small::<32>(&en).unwrap();
+        assert_eq!(de.as_slice(), bytes);
+        assert_eq!(de.as_slice(), c32::decode(&en).unwrap());
+    }
+
+    // Leading zeros are preserved.
+    let de = decode_small::<32>("0020G3").unwrap();
+    assert_eq!(de.as_slice(), [0, 0, 1, 2, 3]);
+    assert!(!de.spilled());
+}
+
+#[test]
+fn test_decode_small_spilled() {
+    let bytes = [42u8; 64];
+    let en = c32::encode(bytes);
+
+    let de = decode_small::<32>(&en).unwrap();
+    assert_eq!(de.as_slice(), bytes);
+    assert!(de.spilled());
+
+    // A larger inline capacity keeps the result on the stack.
+    let de = decode_small::<128>(&en).unwrap();
+    assert_eq!(de.as_slice(), bytes);
+    assert!(!de.spilled());
+}
+
+#[test]
+fn test_decode_small_invalid_character() {
+    let result = decode_small::<32>("2MAH!");
+    assert_eq!(
+        result,
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 4,
+            decoded: 2,
+        })
+    );
+}
@@ -0,0 +1,76 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::encode_sortable;
+use c32::encoded_len;
+use c32::Error;
+use rand::Rng;
+
+#[test]
+fn test_sortable_order() {
+    let mut rng = rand::rng();
+    let width = encoded_len(16);
+
+    // Mix small and large values, so the canonical lengths differ.
+    let mut values: Vec<u128> = (0..1000)
+        .map(|_| {
+            let bits = rng.random_range(0..=128);
+            rng.random::<u128>().checked_shr(128 - bits).unwrap_or(0)
+        })
+        .collect();
+    values.extend([0, 1, 31, 32, u128::MAX]);
+
+    let mut encoded: Vec<(String, u128)> = values
+        .iter()
+        .map(|v| (encode_sortable(&v.to_be_bytes(), width).unwrap(), *v))
+        .collect();
+    encoded.sort();
+    values.sort_unstable();
+
+    let sorted: Vec<u128> = encoded.iter().map(|(_, v)| *v).collect();
+    assert_eq!(sorted, values);
+
+    for (en, _) in &encoded {
+        assert_eq!(en.len(), width);
+    }
+}
+
+#[test]
+fn test_sortable_variable_length() {
+    // Inputs of different byte lengths sort by their numeric value.
+    let inputs: [&[u8]; 5] = [&[], &[0, 0, 1], &[2], &[0, 1, 0], &[1, 0, 0]];
+    let encoded: Vec<String> = inputs
+        .iter()
+        .map(|bytes| encode_sortable(bytes, 5).unwrap())
+        .collect();
+    assert_eq!(encoded, ["00000", "00001", "00002", "00080", "02000"]);
+
+    let mut sorted = encoded.clone();
+    sorted.sort();
+    assert_eq!(sorted, encoded);
+}
+
+#[test]
+fn test_sortable_roundtrip() {
+    let bytes = 0x2A2A2A_u32.to_be_bytes();
+    let en = encode_sortable(&bytes, encoded_len(bytes.len())).unwrap();
+    assert_eq!(en, "002MAHA");
+
+    // Decoding yields a zero byte per padding symbol.
+    let de = c32::decode(&en).unwrap();
+    assert_eq!(de[de.len() - bytes.len()..], bytes);
+}
+
+#[test]
+fn test_sortable_width_too_small() {
+    let result = encode_sortable(&[42, 42, 42], 4);
+    assert_eq!(result, Err(Error::BufferTooSmall { min: 5, len: 4 }));
+
+    // Leading zero bytes do not count towards the width.
+    assert_eq!(encode_sortable(&[0, 0, 0, 1], 1), Ok("1".to_string()));
+}
@@ -0,0 +1,40 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::C32Text;
+use sqlx::postgres::PgArgumentBuffer;
+use sqlx::Encode;
+use sqlx::Postgres;
+use sqlx::Type;
+
+#[test]
+fn test_sqlx_encode() {
+    let text = C32Text::new([42, 42, 42]);
+
+    let mut buf = PgArgumentBuffer::default();
+    let _ = <C32Text<3> as Encode<Postgres>>::encode_by_ref(&text, &mut buf)
+        .unwrap();
+    assert_eq!(&buf[..], b"2MAHA");
+}
+
+#[test]
+fn test_sqlx_encode_leading_zeros() {
+    let text = C32Text::new([0, 0, 1, 2, 3]);
+
+    let mut buf = PgArgumentBuffer::default();
+    let _ = <C32Text<5> as Encode<Postgres>>::encode_by_ref(&text, &mut buf)
+        .unwrap();
+    assert_eq!(&buf[..], b"0020G3");
+}
+
+#[test]
+fn test_sqlx_type() {
+    let info = <C32Text<3> as Type<Postgres>>::type_info();
+    assert_eq!(info, <String as Type<Postgres>>::type_info());
+    assert!(<C32Text<3> as Type<Postgres>>::compatible(&info));
+}
@@ -0,0 +1,373 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::alphabet::Alphabet;
+use c32::en::Check;
+use c32::Buffer;
+
+mod __internal {
+    use super::*;
+
+    /// A lowercase variant of the Crockford alphabet.
+    pub const LOWER: Alphabet =
+        Alphabet::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+
+    /// The versions covered by the check snapshots.
+    pub const VERSIONS: [u8; 4] = [0, 22, 26, 31];
+
+    /// The prefixes covered by the prefixed snapshots.
+    pub const PREFIXES: [char; 2] = ['S', 'T'];
+
+    /// The canonical inputs of the snapshots.
+    pub const EMPTY: [u8; 0] = [];
+    pub const ZEROS: [u8; 4] = [0; 4];
+    pub const LEADING_ZEROS: [u8; 5] = [0, 0, 42, 42, 42];
+    pub const HASH160: [u8; 20] = [
+        0xa4, 0x6f, 0xf8, 0x88, 0x86, 0xc2, 0xef, 0x97, 0x62, 0xd9, 0x70, 0xb4,
+        0xd2, 0xc6, 0x3a, 0xcd, 0x41, 0x19, 0x43, 0xe0,
+    ];
+    pub const KEY: [u8; 32] = [
+        0x03, 0x2d, 0x0c, 0x69, 0xd1, 0x9a, 0x87, 0xba, 0x68, 0x2c, 0x46, 0x2f,
+        0x3d, 0x2e, 0x8b, 0x44, 0xee, 0x3a, 0x2f, 0x1e, 0x5e, 0x91, 0x83, 0x55,
+        0xff, 0x00, 0x7f, 0x80, 0x01, 0xfe, 0x10, 0x20,
+    ];
+
+    /// Appends the outputs of every encoding function for `input`.
+    macro_rules! snapshot {
+        ($out:expr, $name:literal, $input:expr) => {{
+            let out: &mut Vec<(String, String)> = $out;
+            let input = &$input;
+            let mut push = |key: String, value: String| {
+                out.push((format!("{}/{key}", $name), value));
+            };
+
+            push("encode".into(), c32::encode(input));
+            push("encode_le".into(), c32::encode_le(input));
+            push(
+                "encode_sortable".into(),
+                c32::encode_sortable(input, 56).unwrap(),
+            );
+            push("encode_lower".into(), {
+                let mut dst = [0u8; 64];
+                let n = c32::encode_into_with_alphabet(&LOWER, input, &mut dst)
+                    .unwrap();
+                String::from_utf8(dst[..n].to_vec()).unwrap()
+            });
+            push("buffer".into(), Buffer::<64>::encode(input).as_str().into());
+
+            for prefix in PREFIXES {
+                push(
+                    format!("encode_prefixed/{prefix}"),
+                    c32::encode_prefixed(input, prefix),
+                );
+                push(
+                    format!("buffer_prefixed/{prefix}"),
+                    Buffer::<64, true>::encode(input, prefix).as_str().into(),
+                );
+            }
+
+            for version in VERSIONS {
+                push(
+                    format!("encode_check/{version}"),
+                    c32::encode_check(input, version).unwrap(),
+                );
+                push(
+                    format!("encode_check_lower/{version}"),
+                    c32::encode_check_with(&LOWER, input, version).unwrap(),
+                );
+                push(
+                    format!("buffer_check/{version}"),
+                    Buffer::<64, false, Check>::encode(input, version)
+                        .as_str()
+                        .into(),
+                );
+
+                for prefix in PREFIXES {
+                    push(
+                        format!("encode_check_prefixed/{prefix}/{version}"),
+                        c32::encode_check_prefixed(input, prefix, version)
+                            .unwrap(),
+                    );
+                    push(
+                        format!("buffer_check_prefixed/{prefix}/{version}"),
+                        Buffer::<64, true, Check>::encode(
+                            input, prefix, version,
+                        )
+                        .as_str()
+                        .into(),
+                    );
+                }
+            }
+        }};
+    }
+
+    /// Returns the outputs of every encoding function for every input.
+    pub fn outputs() -> Vec<(String, String)> {
+        let mut out = Vec::new();
+        snapshot!(&mut out, "empty", EMPTY);
+        snapshot!(&mut out, "zeros", ZEROS);
+        snapshot!(&mut out, "leading_zeros", LEADING_ZEROS);
+        snapshot!(&mut out, "hash160", HASH160);
+        snapshot!(&mut out, "key", KEY);
+        out
+    }
+}
+
+use __internal::*;
+
+/// The pinned outputs, see the stability policy in the crate documentation.
+#[rustfmt::skip]
+const SNAPSHOTS: &[(&str, &str)] = &[
+    ("empty/encode", ""),
+    ("empty/encode_le", ""),
+    ("empty/encode_sortable", "00000000000000000000000000000000000000000000000000000000"),
+    ("empty/encode_lower", ""),
+    ("empty/buffer", ""),
+    ("empty/encode_prefixed/S", "S"),
+    ("empty/buffer_prefixed/S", "S"),
+    ("empty/encode_prefixed/T", "T"),
+    ("empty/buffer_prefixed/T", "T"),
+    ("empty/encode_check/0", "0A0DR2R"),
+    ("empty/encode_check_lower/0", "0a0dr2r"),
+    ("empty/buffer_check/0", "0A0DR2R"),
+    ("empty/encode_check_prefixed/S/0", "S0A0DR2R"),
+    ("empty/buffer_check_prefixed/S/0", "S0A0DR2R"),
+    ("empty/encode_check_prefixed/T/0", "T0A0DR2R"),
+    ("empty/buffer_check_prefixed/T/0", "T0A0DR2R"),
+    ("empty/encode_check/22", "P37JJX3D"),
+    ("empty/encode_check_lower/22", "p37jjx3d"),
+    ("empty/buffer_check/22", "P37JJX3D"),
+    ("empty/encode_check_prefixed/S/22", "SP37JJX3D"),
+    ("empty/buffer_check_prefixed/S/22", "SP37JJX3D"),
+    ("empty/encode_check_prefixed/T/22", "TP37JJX3D"),
+    ("empty/buffer_check_prefixed/T/22", "TP37JJX3D"),
+    ("empty/encode_check/26", "TEY92JD"),
+    ("empty/encode_check_lower/26", "tey92jd"),
+    ("empty/buffer_check/26", "TEY92JD"),
+    ("empty/encode_check_prefixed/S/26", "STEY92JD"),
+    ("empty/buffer_check_prefixed/S/26", "STEY92JD"),
+    ("empty/encode_check_prefixed/T/26", "TTEY92JD"),
+    ("empty/buffer_check_prefixed/T/26", "TTEY92JD"),
+    ("empty/encode_check/31", "Z44N8Q4"),
+    ("empty/encode_check_lower/31", "z44n8q4"),
+    ("empty/buffer_check/31", "Z44N8Q4"),
+    ("empty/encode_check_prefixed/S/31", "SZ44N8Q4"),
+    ("empty/buffer_check_prefixed/S/31", "SZ44N8Q4"),
+    ("empty/encode_check_prefixed/T/31", "TZ44N8Q4"),
+    ("empty/buffer_check_prefixed/T/31", "TZ44N8Q4"),
+    ("zeros/encode", "0000"),
+    ("zeros/encode_le", "0000"),
+    ("zeros/encode_sortable", "00000000000000000000000000000000000000000000000000000000"),
+    ("zeros/encode_lower", "0000"),
+    ("zeros/buffer", "0000"),
+    ("zeros/encode_prefixed/S", "S0000"),
+    ("zeros/buffer_prefixed/S", "S0000"),
+    ("zeros/encode_prefixed/T", "T0000"),
+    ("zeros/buffer_prefixed/T", "T0000"),
+    ("zeros/encode_check/0", "000001WG3BWK"),
+    ("zeros/encode_check_lower/0", "000001wg3bwk"),
+    ("zeros/buffer_check/0", "000001WG3BWK"),
+    ("zeros/encode_check_prefixed/S/0", "S000001WG3BWK"),
+    ("zeros/buffer_check_prefixed/S/0", "S000001WG3BWK"),
+    ("zeros/encode_check_prefixed/T/0", "T000001WG3BWK"),
+    ("zeros/buffer_check_prefixed/T/0", "T000001WG3BWK"),
+    ("zeros/encode_check/22", "P00003B3Y35D"),
+    ("zeros/encode_check_lower/22", "p00003b3y35d"),
+    ("zeros/buffer_check/22", "P00003B3Y35D"),
+    ("zeros/encode_check_prefixed/S/22", "SP00003B3Y35D"),
+    ("zeros/buffer_check_prefixed/S/22", "SP00003B3Y35D"),
+    ("zeros/encode_check_prefixed/T/22", "TP00003B3Y35D"),
+    ("zeros/buffer_check_prefixed/T/22", "TP00003B3Y35D"),
+    ("zeros/encode_check/26", "T0000PHP24Z"),
+    ("zeros/encode_check_lower/26", "t0000php24z"),
+    ("zeros/buffer_check/26", "T0000PHP24Z"),
+    ("zeros/encode_check_prefixed/S/26", "ST0000PHP24Z"),
+    ("zeros/buffer_check_prefixed/S/26", "ST0000PHP24Z"),
+    ("zeros/encode_check_prefixed/T/26", "TT0000PHP24Z"),
+    ("zeros/buffer_check_prefixed/T/26", "TT0000PHP24Z"),
+    ("zeros/encode_check/31", "Z00003A05Q74"),
+    ("zeros/encode_check_lower/31", "z00003a05q74"),
+    ("zeros/buffer_check/31", "Z00003A05Q74"),
+    ("zeros/encode_check_prefixed/S/31", "SZ00003A05Q74"),
+    ("zeros/buffer_check_prefixed/S/31", "SZ00003A05Q74"),
+    ("zeros/encode_check_prefixed/T/31", "TZ00003A05Q74"),
+    ("zeros/buffer_check_prefixed/T/31", "TZ00003A05Q74"),
+    ("leading_zeros/encode", "002MAHA"),
+    ("leading_zeros/encode_le", "58N2M000"),
+    ("leading_zeros/encode_sortable", "0000000000000000000000000000000000000000000000000002MAHA"),
+    ("leading_zeros/encode_lower", "002maha"),
+    ("leading_zeros/buffer", "002MAHA"),
+    ("leading_zeros/encode_prefixed/S", "S002MAHA"),
+    ("leading_zeros/buffer_prefixed/S", "S002MAHA"),
+    ("leading_zeros/encode_prefixed/T", "T002MAHA"),
+    ("leading_zeros/buffer_prefixed/T", "T002MAHA"),
+    ("leading_zeros/encode_check/0", "000AHA5BRXBSGZ"),
+    ("leading_zeros/encode_check_lower/0", "000aha5brxbsgz"),
+    ("leading_zeros/buffer_check/0", "000AHA5BRXBSGZ"),
+    ("leading_zeros/encode_check_prefixed/S/0", "S000AHA5BRXBSGZ"),
+    ("leading_zeros/buffer_check_prefixed/S/0", "S000AHA5BRXBSGZ"),
+    ("leading_zeros/encode_check_prefixed/T/0", "T000AHA5BRXBSGZ"),
+    ("leading_zeros/buffer_check_prefixed/T/0", "T000AHA5BRXBSGZ"),
+    ("leading_zeros/encode_check/22", "P00AHA588DG2FZ"),
+    ("leading_zeros/encode_check_lower/22", "p00aha588dg2fz"),
+    ("leading_zeros/buffer_check/22", "P00AHA588DG2FZ"),
+    ("leading_zeros/encode_check_prefixed/S/22", "SP00AHA588DG2FZ"),
+    ("leading_zeros/buffer_check_prefixed/S/22", "SP00AHA588DG2FZ"),
+    ("leading_zeros/encode_check_prefixed/T/22", "TP00AHA588DG2FZ"),
+    ("leading_zeros/buffer_check_prefixed/T/22", "TP00AHA588DG2FZ"),
+    ("leading_zeros/encode_check/26", "T00AHA5B2PRMXP"),
+    ("leading_zeros/encode_check_lower/26", "t00aha5b2prmxp"),
+    ("leading_zeros/buffer_check/26", "T00AHA5B2PRMXP"),
+    ("leading_zeros/encode_check_prefixed/S/26", "ST00AHA5B2PRMXP"),
+    ("leading_zeros/buffer_check_prefixed/S/26", "ST00AHA5B2PRMXP"),
+    ("leading_zeros/encode_check_prefixed/T/26", "TT00AHA5B2PRMXP"),
+    ("leading_zeros/buffer_check_prefixed/T/26", "TT00AHA5B2PRMXP"),
+    ("leading_zeros/encode_check/31", "Z00AHA58KZRWH1"),
+    ("leading_zeros/encode_check_lower/31", "z00aha58kzrwh1"),
+    ("leading_zeros/buffer_check/31", "Z00AHA58KZRWH1"),
+    ("leading_zeros/encode_check_prefixed/S/31", "SZ00AHA58KZRWH1"),
+    ("leading_zeros/buffer_check_prefixed/S/31", "SZ00AHA58KZRWH1"),
+    ("leading_zeros/encode_check_prefixed/T/31", "TZ00AHA58KZRWH1"),
+    ("leading_zeros/buffer_check_prefixed/T/31", "TZ00AHA58KZRWH1"),
+    ("hash160/encode", "MHQZH246RBQSERPSE2TD5HHTSN0HJGZ0"),
+    ("hash160/encode_le", "W11HJGED7B3D5D3GV5H9FVY2GT4FGVX4"),
+    ("hash160/encode_sortable", "000000000000000000000000MHQZH246RBQSERPSE2TD5HHTSN0HJGZ0"),
+    ("hash160/encode_lower", "mhqzh246rbqserpse2td5hhtsn0hjgz0"),
+    ("hash160/buffer", "MHQZH246RBQSERPSE2TD5HHTSN0HJGZ0"),
+    ("hash160/encode_prefixed/S", "SMHQZH246RBQSERPSE2TD5HHTSN0HJGZ0"),
+    ("hash160/buffer_prefixed/S", "SMHQZH246RBQSERPSE2TD5HHTSN0HJGZ0"),
+    ("hash160/encode_prefixed/T", "TMHQZH246RBQSERPSE2TD5HHTSN0HJGZ0"),
+    ("hash160/buffer_prefixed/T", "TMHQZH246RBQSERPSE2TD5HHTSN0HJGZ0"),
+    ("hash160/encode_check/0", "02J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2E8T7SA"),
+    ("hash160/encode_check_lower/0", "02j6zy48gv1ez5v2v5rb9mp67b6m26a3w2e8t7sa"),
+    ("hash160/buffer_check/0", "02J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2E8T7SA"),
+    ("hash160/encode_check_prefixed/S/0", "S02J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2E8T7SA"),
+    ("hash160/buffer_check_prefixed/S/0", "S02J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2E8T7SA"),
+    ("hash160/encode_check_prefixed/T/0", "T02J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2E8T7SA"),
+    ("hash160/buffer_check_prefixed/T/0", "T02J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2E8T7SA"),
+    ("hash160/encode_check/22", "P2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W15G8DC1"),
+    ("hash160/encode_check_lower/22", "p2j6zy48gv1ez5v2v5rb9mp67b6m26a3w15g8dc1"),
+    ("hash160/buffer_check/22", "P2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W15G8DC1"),
+    ("hash160/encode_check_prefixed/S/22", "SP2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W15G8DC1"),
+    ("hash160/buffer_check_prefixed/S/22", "SP2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W15G8DC1"),
+    ("hash160/encode_check_prefixed/T/22", "TP2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W15G8DC1"),
+    ("hash160/buffer_check_prefixed/T/22", "TP2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W15G8DC1"),
+    ("hash160/encode_check/26", "T2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2GCT2FG"),
+    ("hash160/encode_check_lower/26", "t2j6zy48gv1ez5v2v5rb9mp67b6m26a3w2gct2fg"),
+    ("hash160/buffer_check/26", "T2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2GCT2FG"),
+    ("hash160/encode_check_prefixed/S/26", "ST2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2GCT2FG"),
+    ("hash160/buffer_check_prefixed/S/26", "ST2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2GCT2FG"),
+    ("hash160/encode_check_prefixed/T/26", "TT2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2GCT2FG"),
+    ("hash160/buffer_check_prefixed/T/26", "TT2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W2GCT2FG"),
+    ("hash160/encode_check/31", "Z2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W3K8R4JS"),
+    ("hash160/encode_check_lower/31", "z2j6zy48gv1ez5v2v5rb9mp67b6m26a3w3k8r4js"),
+    ("hash160/buffer_check/31", "Z2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W3K8R4JS"),
+    ("hash160/encode_check_prefixed/S/31", "SZ2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W3K8R4JS"),
+    ("hash160/buffer_check_prefixed/S/31", "SZ2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W3K8R4JS"),
+    ("hash160/encode_check_prefixed/T/31", "TZ2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W3K8R4JS"),
+    ("hash160/buffer_check_prefixed/T/31", "TZ2J6ZY48GV1EZ5V2V5RB9MP67B6M26A3W3K8R4JS"),
+    ("key/encode", "SD1HMX36M7Q9M2RHHF7MQ8PH7E78QHWQMHGDAZY03ZG00ZW410"),
+    ("key/encode_le", "80GZR0R0ZR0ZXAR74AY3RQKNVJ4HCQ3TBT65HMBN1WTT5MGRB83"),
+    ("key/encode_sortable", "000000SD1HMX36M7Q9M2RHHF7MQ8PH7E78QHWQMHGDAZY03ZG00ZW410"),
+    ("key/encode_lower", "sd1hmx36m7q9m2rhhf7mq8ph7e78qhwqmhgdazy03zg00zw410"),
+    ("key/buffer", "SD1HMX36M7Q9M2RHHF7MQ8PH7E78QHWQMHGDAZY03ZG00ZW410"),
+    ("key/encode_prefixed/S", "SSD1HMX36M7Q9M2RHHF7MQ8PH7E78QHWQMHGDAZY03ZG00ZW410"),
+    ("key/buffer_prefixed/S", "SSD1HMX36M7Q9M2RHHF7MQ8PH7E78QHWQMHGDAZY03ZG00ZW410"),
+    ("key/encode_prefixed/T", "TSD1HMX36M7Q9M2RHHF7MQ8PH7E78QHWQMHGDAZY03ZG00ZW410"),
+    ("key/buffer_prefixed/T", "TSD1HMX36M7Q9M2RHHF7MQ8PH7E78QHWQMHGDAZY03ZG00ZW410"),
+    ("key/encode_check/0", "035M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG429CQ22D"),
+    ("key/encode_check_lower/0", "035m66kmctgyx6gb265wyjx2t4xrx2y7jyj61nbzr0fy003zgg429cq22d"),
+    ("key/buffer_check/0", "035M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG429CQ22D"),
+    ("key/encode_check_prefixed/S/0", "S035M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG429CQ22D"),
+    ("key/buffer_check_prefixed/S/0", "S035M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG429CQ22D"),
+    ("key/encode_check_prefixed/T/0", "T035M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG429CQ22D"),
+    ("key/buffer_check_prefixed/T/0", "T035M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG429CQ22D"),
+    ("key/encode_check/22", "P35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41QTDWKE"),
+    ("key/encode_check_lower/22", "p35m66kmctgyx6gb265wyjx2t4xrx2y7jyj61nbzr0fy003zgg41qtdwke"),
+    ("key/buffer_check/22", "P35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41QTDWKE"),
+    ("key/encode_check_prefixed/S/22", "SP35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41QTDWKE"),
+    ("key/buffer_check_prefixed/S/22", "SP35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41QTDWKE"),
+    ("key/encode_check_prefixed/T/22", "TP35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41QTDWKE"),
+    ("key/buffer_check_prefixed/T/22", "TP35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41QTDWKE"),
+    ("key/encode_check/26", "T35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41C09V7Z"),
+    ("key/encode_check_lower/26", "t35m66kmctgyx6gb265wyjx2t4xrx2y7jyj61nbzr0fy003zgg41c09v7z"),
+    ("key/buffer_check/26", "T35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41C09V7Z"),
+    ("key/encode_check_prefixed/S/26", "ST35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41C09V7Z"),
+    ("key/buffer_check_prefixed/S/26", "ST35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41C09V7Z"),
+    ("key/encode_check_prefixed/T/26", "TT35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41C09V7Z"),
+    ("key/buffer_check_prefixed/T/26", "TT35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG41C09V7Z"),
+    ("key/encode_check/31", "Z35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG42SP26B3"),
+    ("key/encode_check_lower/31", "z35m66kmctgyx6gb265wyjx2t4xrx2y7jyj61nbzr0fy003zgg42sp26b3"),
+    ("key/buffer_check/31", "Z35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG42SP26B3"),
+    ("key/encode_check_prefixed/S/31", "SZ35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG42SP26B3"),
+    ("key/buffer_check_prefixed/S/31", "SZ35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG42SP26B3"),
+    ("key/encode_check_prefixed/T/31", "TZ35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG42SP26B3"),
+    ("key/buffer_check_prefixed/T/31", "TZ35M66KMCTGYX6GB265WYJX2T4XRX2Y7JYJ61NBZR0FY003ZGG42SP26B3"),
+];
+
+#[test]
+fn test_stability_snapshots() {
+    let outputs = outputs();
+
+    let mut diff = String::new();
+    for (key, expected) in SNAPSHOTS {
+        match outputs.iter().find(|(k, _)| k == key) {
+            Some((_, got)) if got == expected => {}
+            Some((_, got)) => {
+                diff += &format!("  {key}:\n    - {expected}\n    + {got}\n");
+            }
+            None => diff += &format!("  {key}: missing output\n"),
+        }
+    }
+    for (key, got) in &outputs {
+        if !SNAPSHOTS.iter().any(|(k, _)| k == key) {
+            diff += &format!("  {key}: not pinned\n    + {got}\n");
+        }
+    }
+
+    assert!(
+        diff.is_empty(),
+        "\n\
+         ************************************************************\n\
+         *  THE WIRE FORMAT OF C32 HAS CHANGED                      *\n\
+         *                                                          *\n\
+         *  These snapshots freeze the encoded output. Per the      *\n\
+         *  stability policy in the crate documentation, changing   *\n\
+         *  them requires a major version bump.                     *\n\
+         ************************************************************\n\
+         \n{diff}"
+    );
+}
+
+#[test]
+fn test_stability_roundtrip() {
+    // Assert that the pinned outputs decode to their inputs.
+    let inputs: [(&str, &[u8]); 5] = [
+        ("empty", &EMPTY),
+        ("zeros", &ZEROS),
+        ("leading_zeros", &LEADING_ZEROS),
+        ("hash160", &HASH160),
+        ("key", &KEY),
+    ];
+
+    for (name, input) in inputs {
+        let get = |key: &str| {
+            let key = format!("{name}/{key}");
+            SNAPSHOTS.iter().find(|(k, _)| *k == key).unwrap().1
+        };
+
+        assert_eq!(c32::decode(get("encode")).unwrap(), input);
+        for version in VERSIONS {
+            let en = get(&format!("encode_check/{version}"));
+            assert_eq!(
+                c32::decode_check(en).unwrap(),
+                (input.to_vec(), version)
+            );
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::stacks::decode_address;
+use c32::stacks::decode_address_any_version;
+use c32::stacks::encode_address;
+use c32::stacks::encode_address_any_version;
+use c32::stacks::encode_address_any_version_into;
+use c32::stacks::encode_address_into;
+use c32::stacks::ADDRESS_MAX_LENGTH;
+use c32::Error;
+
+mod __internal {
+    pub const HASH160: [u8; 20] = [
+        0xa4, 0x6f, 0xf8, 0x88, 0x86, 0xc2, 0xef, 0x97, 0x62, 0xd9, 0x70, 0xb4,
+        0xd2, 0xc6, 0x36, 0x78, 0x83, 0x5b, 0xd3, 0x9d,
+    ];
+
+    /// Addresses of the Stacks reference implementation, as
+    /// `(version, hash160, address)`.
+    pub const FIXTURES: &[(u8, [u8; 20], &str)] = &[
+        (22, HASH160, "SP2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7"),
+        (20, HASH160, "SM2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKQVX8X0G"),
+        (26, HASH160, "ST2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKQYAC0RQ"),
+        (21, HASH160, "SN2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKP6D2ZK9"),
+        (22, [0; 20], "SP000000000000000000002Q6VF78"),
+        (20, [0; 20], "SM0000000000000000000062QV6X"),
+        (26, [0; 20], "ST000000000000000000002AMW42H"),
+        (21, [0; 20], "SN000000000000000000003YDHWKJ"),
+    ];
+}
+
+use __internal::*;
+
+#[test]
+fn test_stacks_fixtures() {
+    for &(version, hash160, addr) in FIXTURES {
+        assert_eq!(encode_address(version, &hash160).unwrap(), addr);
+
+        let mut dst = [0u8; ADDRESS_MAX_LENGTH];
+        let offset = encode_address_into(version, &hash160, &mut dst).unwrap();
+        assert_eq!(&dst[..offset], addr.as_bytes());
+
+        // Assert that the address decodes to the same parts.
+        assert_eq!(decode_address(addr), Ok((version, hash160)));
+        assert_eq!(c32::address::decode_address(addr).unwrap().2, hash160);
+    }
+}
+
+#[test]
+fn test_stacks_max_length() {
+    // Assert that the largest 'hash160' fills the buffer.
+    let mut dst = [0u8; ADDRESS_MAX_LENGTH];
+    let offset = encode_address_into(22, &[0xFF; 20], &mut dst).unwrap();
+    assert_eq!(offset, ADDRESS_MAX_LENGTH);
+    assert_eq!(ADDRESS_MAX_LENGTH, 41);
+}
+
+#[test]
+fn test_stacks_unknown_version() {
+    let mut dst = [0u8; ADDRESS_MAX_LENGTH];
+    for version in [0, 1, 23, 31] {
+        assert!(matches!(
+            encode_address(version, &HASH160),
+            Err(Error::InvalidVersion { version: v, .. }) if v == version
+        ));
+        assert!(matches!(
+            encode_address_into(version, &HASH160, &mut dst),
+            Err(Error::InvalidVersion { .. })
+        ));
+
+        // Assert that the escape hatch accepts any version below 32.
+        let en = encode_address_any_version(version, &HASH160).unwrap();
+        assert_eq!(
+            en,
+            c32::encode_check_prefixed(HASH160, 'S', version).unwrap()
+        );
+        let offset =
+            encode_address_any_version_into(version, &HASH160, &mut dst)
+                .unwrap();
+        assert_eq!(&dst[..offset], en.as_bytes());
+    }
+
+    assert!(matches!(
+        encode_address_any_version(32, &HASH160),
+        Err(Error::InvalidVersion { version: 32, .. })
+    ));
+}
+
+#[test]
+fn test_stacks_decode_payload_size() {
+    // Assert that truncated and extended payloads are rejected.
+    for len in [0, 1, 19, 21, 32] {
+        let en = c32::encode_check_prefixed(vec![0xA5; len], 'S', 22).unwrap();
+        assert_eq!(
+            decode_address(&en),
+            Err(Error::InvalidDataSize {
+                expected: 20,
+                got: len,
+            }),
+            "{en}"
+        );
+    }
+
+    // Assert that the leading zeros are counted as payload bytes.
+    let en = c32::encode_check_prefixed([0; 21], 'S', 22).unwrap();
+    assert_eq!(
+        decode_address(&en),
+        Err(Error::InvalidDataSize {
+            expected: 20,
+            got: 21,
+        })
+    );
+}
+
+#[test]
+fn test_stacks_decode_errors() {
+    let addr = FIXTURES[0].2;
+
+    assert_eq!(
+        decode_address(&addr[1..]),
+        Err(Error::MissingPrefix {
+            char: 'S',
+            got: Some('P'),
+        })
+    );
+    assert!(matches!(
+        decode_address(&addr.replacen('J', "!", 1)),
+        Err(Error::InvalidCharacter { index: 3, .. })
+    ));
+    assert!(matches!(
+        decode_address(&addr.replace("EJ7", "EJ8")),
+        Err(Error::ChecksumMismatch { .. })
+    ));
+    assert!(matches!(
+        decode_address("S"),
+        Err(Error::InsufficientData { .. })
+    ));
+}
+
+#[test]
+fn test_stacks_decode_unknown_version() {
+    for version in [0, 1, 23, 31] {
+        let en = encode_address_any_version(version, &HASH160).unwrap();
+        assert!(matches!(
+            decode_address(&en),
+            Err(Error::InvalidVersion { version: v, .. }) if v == version
+        ));
+        assert_eq!(decode_address_any_version(&en), Ok((version, HASH160)));
+    }
+}
@@ -0,0 +1,231 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use std::fs;
+
+use c32::stream::C32StreamExt;
+use c32::stream::DecodeStream;
+use c32::stream::EncodeStream;
+use c32::Error;
+use futures::executor::block_on;
+use futures::stream;
+use futures::stream::FusedStream;
+use futures::Stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use rand::Rng;
+
+mod __internal {
+    use super::*;
+
+    /// Splits `src` into randomly sized chunks.
+    pub fn chunks(src: &[u8]) -> Vec<Vec<u8>> {
+        let mut rng = rand::rng();
+        let mut chunks = Vec::new();
+        let mut pos = 0;
+        while pos < src.len() {
+            let len = rng.random_range(1..=1024).min(src.len() - pos);
+            chunks.push(src[pos..pos + len].to_vec());
+            pos += len;
+        }
+        chunks
+    }
+
+    /// A stream which is pending before yielding each item.
+    pub struct Throttled<S> {
+        pub inner: S,
+        pub ready: bool,
+    }
+
+    impl<S: Stream + Unpin> Stream for Throttled<S> {
+        type Item = S::Item;
+
+        fn poll_next(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Self::Item>> {
+            if !self.ready {
+                self.ready = true;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            self.ready = false;
+            Pin::new(&mut self.inner).poll_next(cx)
+        }
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_stream_sample() {
+    for sample in ["c32_s_32k.in", "c32_s_64k.in", "c32_m_100x32b.in"] {
+        let input = fs::read(format!("../samples/{sample}")).unwrap();
+
+        // Assert that the concatenated output matches `encode`.
+        let en: String =
+            block_on(stream::iter(chunks(&input)).encode_c32().try_collect())
+                .unwrap();
+        assert_eq!(en, c32::encode(&input));
+
+        // Assert that the output decodes back to the input.
+        let de: Vec<_> = block_on(
+            stream::iter(chunks(en.as_bytes())).decode_c32().collect(),
+        );
+        assert_eq!(de, [Ok(input)]);
+    }
+}
+
+#[test]
+fn test_stream_throttled() {
+    let input = fs::read("../samples/c32_s_32k.in").unwrap();
+
+    let inner = stream::iter(chunks(&input));
+    let throttled = Throttled {
+        inner,
+        ready: false,
+    };
+    let en: Vec<String> =
+        block_on(throttled.encode_c32().try_collect()).unwrap();
+    assert_eq!(en, [c32::encode(&input)]);
+
+    let inner = stream::iter(chunks(en[0].as_bytes()));
+    let throttled = Throttled {
+        inner,
+        ready: false,
+    };
+    let de: Vec<_> = block_on(throttled.decode_c32().collect());
+    assert_eq!(de, [Ok(input)]);
+}
+
+#[test]
+fn test_stream_empty() {
+    let mut en = stream::empty::<Vec<u8>>().encode_c32();
+    assert!(!en.is_terminated());
+    assert_eq!(block_on(en.next()), Some(Ok(String::new())));
+    assert!(en.is_terminated());
+    assert_eq!(block_on(en.next()), None);
+
+    let mut de = stream::empty::<Vec<u8>>().decode_c32();
+    assert_eq!(block_on(de.next()), Some(Ok(Vec::new())));
+    assert_eq!(block_on(de.next()), None);
+}
+
+#[test]
+fn test_stream_invalid_character() {
+    let chunks = stream::iter(["2MA", "H!A"]);
+    let de: Vec<_> = block_on(chunks.decode_c32().collect());
+    assert_eq!(
+        de,
+        [Err(Error::InvalidCharacter {
+            char: '!',
+            index: 4,
+            decoded: 2,
+        })]
+    );
+}
+
+#[test]
+fn test_stream_known_length() {
+    for sample in ["c32_s_32k.in", "c32_m_100x32b.in"] {
+        let input = fs::read(format!("../samples/{sample}")).unwrap();
+        let expected = c32::encode(&input);
+
+        // Assert that output is yielded per chunk, and the concatenated
+        // output matches `encode`.
+        let inner = stream::iter(chunks(&input));
+        let en: Vec<String> = block_on(
+            EncodeStream::with_len(inner, input.len() as u64).try_collect(),
+        )
+        .unwrap();
+        assert!(en.len() > 1);
+        assert_eq!(en.concat(), expected);
+
+        // Assert that the output decodes back to the input.
+        let inner = stream::iter(chunks(expected.as_bytes()));
+        let de: Vec<_> = block_on(
+            DecodeStream::with_len(inner, expected.len() as u64).collect(),
+        );
+        let de: Result<Vec<_>, _> = de.into_iter().collect();
+        assert_eq!(de.unwrap().concat(), input);
+    }
+}
+
+#[test]
+fn test_stream_known_length_errors() {
+    // Assert that the bytes before an invalid character are yielded.
+    let chunks = stream::iter(["2MA", "H!A"]);
+    let de: Vec<_> = block_on(DecodeStream::with_len(chunks, 6).collect());
+    assert_eq!(
+        de,
+        [
+            Ok(vec![5, 69]),
+            Err(Error::InvalidCharacter {
+                char: '!',
+                index: 4,
+                decoded: 2,
+            })
+        ]
+    );
+
+    // Assert that more or fewer characters than declared fail.
+    let chunks = stream::iter(["2MA", "HA"]);
+    let de: Vec<_> = block_on(DecodeStream::with_len(chunks, 4).collect());
+    assert_eq!(
+        de.last(),
+        Some(&Err(Error::InvalidDataSize {
+            expected: 4,
+            got: 5
+        }))
+    );
+
+    let chunks = stream::iter(["2MA"]);
+    let de: Vec<_> = block_on(DecodeStream::with_len(chunks, 5).collect());
+    assert_eq!(
+        de.last(),
+        Some(&Err(Error::InvalidDataSize {
+            expected: 5,
+            got: 3
+        }))
+    );
+}
+
+#[test]
+fn test_stream_known_length_encode_errors() {
+    // Assert that fewer bytes than declared fail after the output.
+    let chunks = stream::iter([vec![42, 42]]);
+    let mut en = EncodeStream::with_len(chunks, 3);
+    assert_eq!(block_on(en.next()), Some(Ok("2MA".to_string())));
+    assert!(!en.is_terminated());
+    assert_eq!(
+        block_on(en.next()),
+        Some(Err(Error::InvalidDataSize {
+            expected: 3,
+            got: 2
+        }))
+    );
+    assert!(en.is_terminated());
+    assert_eq!(block_on(en.next()), None);
+
+    // Assert that more bytes than declared fail, and end the stream.
+    let chunks = stream::iter([vec![42, 42], vec![42, 42], vec![42]]);
+    let en: Vec<_> = block_on(EncodeStream::with_len(chunks, 3).collect());
+    assert_eq!(
+        en,
+        [
+            Ok("2MA".to_string()),
+            Err(Error::InvalidDataSize {
+                expected: 3,
+                got: 4
+            })
+        ]
+    );
+}
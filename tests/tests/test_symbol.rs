@@ -0,0 +1,116 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::checksum;
+use c32::decode_check;
+use c32::decode_check_symbol;
+use c32::encode_check;
+use c32::encode_check_symbol;
+use c32::version_char;
+use c32::Error;
+
+const HASH: [u8; 20] = [
+    0xa4, 0x6f, 0xf8, 0x88, 0x86, 0xc2, 0xef, 0x97, 0x62, 0xd9, 0x70, 0xb4,
+    0xd2, 0xc6, 0x36, 0x78, 0x83, 0x5b, 0xd3, 0x9d,
+];
+
+#[test]
+fn test_symbol_compute() {
+    // Assert the known-answer checksums of both conventions.
+    let bytes = [42, 42, 42];
+    assert_eq!(checksum::compute(&bytes, 22), [47, 161, 54, 73]);
+    assert_eq!(
+        checksum::compute_over_symbol(&bytes, b'P'),
+        [214, 249, 44, 252]
+    );
+}
+
+#[test]
+fn test_symbol_known_answer() {
+    // Assert the known-answer vectors of the numeric convention.
+    assert_eq!(encode_check([42, 42, 42], 0).unwrap(), "0AHA59B9201Z");
+    assert_eq!(encode_check([42, 42, 42], 22).unwrap(), "PAHA58QT2DJ9");
+    assert_eq!(
+        encode_check(HASH, 22).unwrap(),
+        "P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKNRV9EJ7"
+    );
+
+    // Assert the known-answer vectors of the symbol convention.
+    assert_eq!(
+        encode_check_symbol([42, 42, 42], 0).unwrap(),
+        "0AHA580XANNM"
+    );
+    assert_eq!(
+        encode_check_symbol([42, 42, 42], 22).unwrap(),
+        "PAHA5BBFJB7W"
+    );
+    assert_eq!(
+        encode_check_symbol(HASH, 22).unwrap(),
+        "P2J6ZY48GV1EZ5V2V5RB9MP66SW86PYKKM6K73B5"
+    );
+}
+
+#[test]
+fn test_symbol_roundtrip() {
+    let bytes = [0, 0, 1, 2, 3, 42, 255];
+    for version in 0..32 {
+        let en = encode_check_symbol(bytes, version).unwrap();
+        assert!(en.starts_with(version_char(version).unwrap()));
+        assert_eq!(
+            decode_check_symbol(&en).unwrap(),
+            (bytes.to_vec(), version)
+        );
+    }
+}
+
+#[test]
+fn test_symbol_convention_mismatch() {
+    for version in 0..32 {
+        let numeric = encode_check(HASH, version).unwrap();
+        let symbol = encode_check_symbol(HASH, version).unwrap();
+
+        // Assert that the conventions only share the version character.
+        assert_eq!(numeric[..1], symbol[..1]);
+        assert!(matches!(
+            decode_check_symbol(&numeric),
+            Err(Error::ChecksumMismatch { .. })
+        ));
+        assert!(matches!(
+            decode_check(&symbol),
+            Err(Error::ChecksumMismatch { .. })
+        ));
+    }
+}
+
+#[test]
+fn test_symbol_case_insensitive() {
+    // Assert that the checksum covers the canonical version character.
+    let de = decode_check_symbol("paha5bbfjb7w").unwrap();
+    assert_eq!(de, (vec![42, 42, 42], 22));
+    let de = decode_check_symbol("oaha580xannm").unwrap();
+    assert_eq!(de, (vec![42, 42, 42], 0));
+}
+
+#[test]
+fn test_symbol_errors() {
+    assert_eq!(
+        encode_check_symbol([42], 32),
+        Err(Error::InvalidVersion {
+            expected: "must be < 32",
+            version: 32
+        })
+    );
+    assert!(matches!(
+        decode_check_symbol("P!HA5BBFJB7W"),
+        Err(Error::InvalidCharacter { index: 1, .. })
+    ));
+    assert!(matches!(
+        decode_check_symbol("P"),
+        Err(Error::InsufficientData { .. })
+    ));
+}
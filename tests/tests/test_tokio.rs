@@ -0,0 +1,253 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::io;
+
+use c32::io::AsyncDecodeReader;
+use c32::io::AsyncEncodeWriter;
+use c32::Error;
+use rand::Rng;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+
+mod __internal {
+    use super::*;
+
+    /// Generates a random message, including leading zero bytes.
+    pub fn message(len: usize) -> Vec<u8> {
+        let mut rng = rand::rng();
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        if len > 2 {
+            bytes[0] = 0;
+            bytes[1] = 0;
+        }
+        bytes
+    }
+
+    /// Encodes `src` through a duplex pipe, writing `chunk` bytes at a time.
+    ///
+    /// If `known`, the writer is created with the length of `src`.
+    pub async fn encode(
+        src: &[u8],
+        chunk: usize,
+        pipe: usize,
+        known: bool,
+    ) -> Vec<u8> {
+        let (tx, mut rx) = tokio::io::duplex(pipe);
+        let src = src.to_vec();
+
+        let task = tokio::spawn(async move {
+            let mut writer = if known {
+                AsyncEncodeWriter::with_len(tx, src.len() as u64)
+            } else {
+                AsyncEncodeWriter::new(tx)
+            };
+            for part in src.chunks(chunk) {
+                writer.write_all(part).await.unwrap();
+            }
+            writer.shutdown().await.unwrap();
+        });
+
+        let mut dst = Vec::new();
+        rx.read_to_end(&mut dst).await.unwrap();
+        task.await.unwrap();
+        dst
+    }
+
+    /// Decodes `src` through a duplex pipe, writing `chunk` bytes at a time.
+    ///
+    /// If `known`, the reader is created with the length of `src`.
+    pub async fn decode(
+        src: &[u8],
+        chunk: usize,
+        pipe: usize,
+        known: bool,
+    ) -> io::Result<Vec<u8>> {
+        let (mut tx, rx) = tokio::io::duplex(pipe);
+        let len = src.len() as u64;
+        let src = src.to_vec();
+
+        let task = tokio::spawn(async move {
+            for part in src.chunks(chunk) {
+                if tx.write_all(part).await.is_err() {
+                    break;
+                }
+            }
+            let _ = tx.shutdown().await;
+        });
+
+        let mut reader = if known {
+            AsyncDecodeReader::with_len(rx, len)
+        } else {
+            AsyncDecodeReader::new(rx)
+        };
+        let mut dst = Vec::new();
+        let result = reader.read_to_end(&mut dst).await;
+
+        // A failed reader stops early, so close the pipe to end the writer.
+        drop(reader);
+        task.await.unwrap();
+        result.map(|_| dst)
+    }
+}
+
+use __internal::*;
+
+#[tokio::test]
+async fn test_tokio_encode_boundaries() {
+    for len in [0, 1, 2, 5, 31, 1000, 10_000] {
+        let bytes = message(len);
+        let expected = c32::encode(&bytes);
+
+        for (chunk, pipe) in [
+            (1, 1),
+            (1, 64),
+            (3, 7),
+            (len.max(1), 1),
+            (len.max(1), 1 << 16),
+        ] {
+            for known in [false, true] {
+                let en = encode(&bytes, chunk, pipe, known).await;
+                assert_eq!(en, expected.as_bytes());
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_tokio_decode_boundaries() {
+    for len in [0, 1, 2, 5, 31, 1000, 10_000] {
+        let bytes = message(len);
+        let encoded = c32::encode(&bytes);
+
+        for (chunk, pipe) in [
+            (1, 1),
+            (1, 64),
+            (3, 7),
+            (encoded.len().max(1), 1),
+            (encoded.len().max(1), 1 << 16),
+        ] {
+            for known in [false, true] {
+                let de = decode(encoded.as_bytes(), chunk, pipe, known).await;
+                assert_eq!(de.unwrap(), bytes);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_tokio_decode_invalid_character() {
+    let mut encoded = c32::encode(message(100)).into_bytes();
+    encoded[150] = b'!';
+
+    // The decoded count depends on the random length, so take it from
+    // `decode_into`.
+    let mut dst = vec![0u8; encoded.len()];
+    let expected = c32::decode_into(&encoded, &mut dst).unwrap_err();
+    assert!(matches!(
+        expected,
+        Error::InvalidCharacter {
+            char: '!',
+            index: 150,
+            ..
+        }
+    ));
+
+    for (chunk, pipe, known) in [
+        (1, 1, false),
+        (7, 3, false),
+        (encoded.len(), 1 << 16, false),
+        (1, 1, true),
+        (encoded.len(), 1 << 16, true),
+    ] {
+        let err = decode(&encoded, chunk, pipe, known).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // Assert that the index is relative to the start of the stream.
+        let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+        assert_eq!(*inner, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_tokio_write_after_shutdown() {
+    let mut writer = AsyncEncodeWriter::new(Vec::new());
+    writer.write_all(&[42, 42, 42]).await.unwrap();
+    writer.shutdown().await.unwrap();
+
+    let err = writer.write_all(&[1]).await.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    assert_eq!(writer.into_inner(), b"2MAHA");
+}
+
+#[tokio::test]
+async fn test_tokio_streams_known_length() {
+    let bytes = message(10_000);
+    let encoded = c32::encode(&bytes);
+    let half = bytes.len() / 2;
+
+    // Assert that the writer emits output before the input is complete.
+    let (tx, mut rx) = tokio::io::duplex(1 << 16);
+    let mut writer = AsyncEncodeWriter::with_len(tx, bytes.len() as u64);
+    writer.write_all(&bytes[..half]).await.unwrap();
+    writer.flush().await.unwrap();
+    let mut prefix = vec![0u8; 1000];
+    rx.read_exact(&mut prefix).await.unwrap();
+    assert_eq!(prefix, encoded.as_bytes()[..1000]);
+
+    // Assert that the rest of the output follows.
+    writer.write_all(&bytes[half..]).await.unwrap();
+    writer.shutdown().await.unwrap();
+    let mut rest = Vec::new();
+    rx.read_to_end(&mut rest).await.unwrap();
+    assert_eq!(rest, encoded.as_bytes()[1000..]);
+
+    // Assert that the reader decodes before the input is complete.
+    let (mut tx, rx) = tokio::io::duplex(64);
+    let mut reader = AsyncDecodeReader::with_len(rx, encoded.len() as u64);
+    tx.write_all(&encoded.as_bytes()[..32]).await.unwrap();
+    let mut buf = [0u8; 16];
+    let n = reader.read(&mut buf).await.unwrap();
+    assert!(n > 0);
+    assert_eq!(buf[..n], bytes[..n]);
+}
+
+#[tokio::test]
+async fn test_tokio_invalid_data_size() {
+    // Assert that the writer rejects more bytes than declared.
+    let mut writer = AsyncEncodeWriter::with_len(Vec::new(), 2);
+    let err = writer.write_all(&[42, 42, 42]).await.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // Assert that the writer rejects fewer bytes than declared.
+    let mut writer = AsyncEncodeWriter::with_len(Vec::new(), 2);
+    writer.write_all(&[42]).await.unwrap();
+    let err = writer.shutdown().await.unwrap_err();
+    let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+    assert_eq!(
+        *inner,
+        Error::InvalidDataSize {
+            expected: 2,
+            got: 1
+        }
+    );
+
+    // Assert that the reader fails if the input ends early.
+    let mut reader = AsyncDecodeReader::with_len(&b"2MA"[..], 5);
+    let mut dst = Vec::new();
+    let err = reader.read_to_end(&mut dst).await.unwrap_err();
+    let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+    assert_eq!(
+        *inner,
+        Error::InvalidDataSize {
+            expected: 5,
+            got: 3
+        }
+    );
+}
@@ -0,0 +1,164 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::span::Attributes;
+use tracing::span::Id;
+use tracing::span::Record;
+use tracing::Event;
+use tracing::Metadata;
+use tracing::Subscriber;
+
+mod __internal {
+    use super::*;
+
+    /// The recorded fields of a span.
+    pub type Fields = Vec<(&'static str, String)>;
+
+    /// A [`Subscriber`] that records the fields of every span.
+    #[derive(Clone, Default)]
+    pub struct Recorder(pub Arc<Mutex<Vec<Fields>>>);
+
+    /// A [`Visit`] that appends the visited fields.
+    struct Visitor<'a>(&'a mut Fields);
+
+    impl Visit for Visitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.push((field.name(), value.to_string()));
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.push((field.name(), format!("{value:?}")));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut fields = Fields::new();
+            attrs.record(&mut Visitor(&mut fields));
+
+            let mut spans = self.0.lock().unwrap();
+            spans.push(fields);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, id: &Id, values: &Record<'_>) {
+            let mut spans = self.0.lock().unwrap();
+            let fields = &mut spans[id.into_u64() as usize - 1];
+            values.record(&mut Visitor(fields));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    /// Runs `f` with a [`Recorder`], returning the recorded spans.
+    pub fn record(f: impl FnOnce()) -> Vec<Fields> {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), f);
+        let spans = recorder.0.lock().unwrap();
+        spans.clone()
+    }
+
+    /// Returns the expected fields of a span.
+    pub fn fields(entries: &[(&'static str, &str)]) -> Fields {
+        entries.iter().map(|(k, v)| (*k, v.to_string())).collect()
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_tracing_encode_decode() {
+    let spans = record(|| {
+        assert_eq!(c32::encode([42, 42, 42]), "2MAHA");
+        assert_eq!(c32::decode("2MAHA"), Ok(vec![42, 42, 42]));
+    });
+
+    assert_eq!(
+        spans,
+        [
+            fields(&[("op", "encode"), ("input", "3"), ("output", "5")]),
+            fields(&[("op", "decode"), ("input", "5"), ("output", "3")]),
+        ]
+    );
+}
+
+#[test]
+fn test_tracing_check() {
+    let spans = record(|| {
+        let en = c32::encode_check_prefixed([42, 42, 42], 'S', 0).unwrap();
+        assert_eq!(en, "S0AHA59B9201Z");
+        let de = c32::decode_check_prefixed(&en, 'S').unwrap();
+        assert_eq!(de, (vec![42, 42, 42], 0));
+    });
+
+    assert_eq!(
+        spans,
+        [
+            fields(&[
+                ("op", "encode_check_prefixed"),
+                ("input", "3"),
+                ("output", "13"),
+            ]),
+            fields(&[
+                ("op", "decode_check_prefixed"),
+                ("input", "13"),
+                ("output", "3"),
+            ]),
+        ]
+    );
+}
+
+#[test]
+fn test_tracing_failure() {
+    let spans = record(|| {
+        assert!(c32::decode("2M!HA").is_err());
+        assert!(c32::decode_check("0AHA59B9201Y").is_err());
+    });
+
+    assert_eq!(
+        spans,
+        [
+            fields(&[
+                ("op", "decode"),
+                ("input", "5"),
+                ("error", "invalid_character"),
+            ]),
+            fields(&[
+                ("op", "decode_check"),
+                ("input", "12"),
+                ("error", "checksum_mismatch"),
+            ]),
+        ]
+    );
+}
+
+#[test]
+fn test_tracing_unobserved() {
+    // Assert that the output is unchanged without a subscriber.
+    assert_eq!(c32::encode([42, 42, 42]), "2MAHA");
+    assert_eq!(
+        c32::decode("2M!HA").unwrap_err().kind(),
+        "invalid_character"
+    );
+}
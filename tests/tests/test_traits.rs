@@ -0,0 +1,103 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::borrow::Cow;
+
+use c32::C32Decode;
+use c32::C32Encode;
+use c32::C32Text;
+use c32::Error;
+
+mod __internal {
+    use super::*;
+
+    /// A content hash that strips its leading tag byte when encoded.
+    #[derive(Debug, PartialEq)]
+    pub struct ContentHash(pub [u8; 4]);
+
+    impl C32Encode for ContentHash {
+        fn c32_bytes(&self) -> Cow<'_, [u8]> {
+            Cow::Owned(self.0[1..].to_vec())
+        }
+    }
+
+    /// An error type that wraps [`Error`].
+    #[derive(Debug, PartialEq)]
+    pub enum IdError {
+        Codec(Error),
+        Empty,
+    }
+
+    impl From<Error> for IdError {
+        fn from(e: Error) -> Self {
+            Self::Codec(e)
+        }
+    }
+
+    /// An identifier that rejects empty input.
+    #[derive(Debug, PartialEq)]
+    pub struct Id(pub Vec<u8>);
+
+    impl C32Decode for Id {
+        type Error = IdError;
+
+        fn from_c32_bytes(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+            if bytes.is_empty() {
+                return Err(IdError::Empty);
+            }
+            Ok(Self(bytes))
+        }
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_traits_blanket_impls() {
+    let bytes = [42u8, 42, 42];
+    assert_eq!(bytes.to_c32(), "2MAHA");
+    assert_eq!(bytes[..].to_c32(), "2MAHA");
+    assert_eq!(bytes.to_vec().to_c32(), "2MAHA");
+    assert_eq!(C32Text::new(bytes).to_c32(), "2MAHA");
+
+    assert_eq!(<[u8; 3]>::from_c32("2MAHA"), Ok(bytes));
+    assert_eq!(Vec::<u8>::from_c32("2MAHA"), Ok(bytes.to_vec()));
+    assert_eq!(C32Text::<3>::from_c32("2MAHA"), Ok(C32Text::new(bytes)));
+}
+
+#[test]
+fn test_traits_owned_bytes() {
+    let hash = ContentHash([1, 42, 42, 42]);
+    assert_eq!(hash.to_c32(), "2MAHA");
+}
+
+#[test]
+fn test_traits_invalid_size() {
+    let result = <[u8; 4]>::from_c32("2MAHA");
+    assert_eq!(
+        result,
+        Err(Error::InvalidDataSize {
+            expected: 4,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn test_traits_custom_error() {
+    assert_eq!(Id::from_c32("2MAHA"), Ok(Id(vec![42, 42, 42])));
+    assert_eq!(Id::from_c32(""), Err(IdError::Empty));
+
+    let result = Id::from_c32("2MAHU");
+    let expected = Error::InvalidCharacter {
+        char: 'U',
+        index: 4,
+        decoded: 2,
+    };
+    assert_eq!(result, Err(IdError::Codec(expected)));
+}
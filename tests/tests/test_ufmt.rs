@@ -0,0 +1,149 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::encode;
+use c32::u_display;
+use c32::Buffer;
+use c32::Error;
+use rand::Rng;
+
+mod __internal {
+    use ufmt_write::uWrite;
+
+    /// A sink that records every write, to observe the chunk sizes.
+    #[derive(Default)]
+    pub struct Sink {
+        pub output: String,
+        pub writes: Vec<usize>,
+    }
+
+    impl uWrite for Sink {
+        type Error = core::convert::Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+            self.output.push_str(s);
+            self.writes.push(s.len());
+            Ok(())
+        }
+    }
+
+    /// A sink that fails after `n` writes.
+    pub struct Failing(pub usize);
+
+    impl uWrite for Failing {
+        type Error = ();
+
+        fn write_str(&mut self, _: &str) -> Result<(), ()> {
+            if self.0 == 0 {
+                return Err(());
+            }
+            self.0 -= 1;
+            Ok(())
+        }
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_ufmt_u_display() {
+    let mut sink = Sink::default();
+    u_display(&[42, 42, 42]).uwrite_to(&mut sink).unwrap();
+    assert_eq!(sink.output, "2MAHA");
+
+    let mut sink = Sink::default();
+    u_display(&[]).uwrite_to(&mut sink).unwrap();
+    assert_eq!(sink.output, "");
+
+    let mut sink = Sink::default();
+    u_display(&[0, 0, 1]).uwrite_to(&mut sink).unwrap();
+    assert_eq!(sink.output, "001");
+}
+
+#[test]
+fn test_ufmt_u_display_rand() {
+    let mut rng = rand::rng();
+    for _ in 0..256 {
+        let len = rng.random_range(0..128);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..4).min(len);
+        bytes[..zeros].fill(0);
+
+        let mut sink = Sink::default();
+        u_display(&bytes).uwrite_to(&mut sink).unwrap();
+
+        // Assert that the output matches, and is written in small chunks.
+        assert_eq!(sink.output, encode(&bytes));
+        assert!(sink.writes.iter().all(|&n| n <= 8));
+    }
+}
+
+#[test]
+fn test_ufmt_u_display_error() {
+    let bytes = [0xFFu8; 64];
+    assert_eq!(u_display(&bytes).uwrite_to(&mut Failing(0)), Err(()));
+    assert_eq!(u_display(&bytes).uwrite_to(&mut Failing(3)), Err(()));
+}
+
+#[test]
+fn test_ufmt_buffer() {
+    let buffer = Buffer::<5>::encode(&[42, 42, 42]);
+    let mut sink = Sink::default();
+    buffer.uwrite_to(&mut sink).unwrap();
+    assert_eq!(sink.output, "2MAHA");
+}
+
+#[test]
+fn test_ufmt_error() {
+    let errors = [
+        Error::BufferTooSmall { min: 10, len: 5 },
+        Error::InvalidDataSize {
+            expected: 20,
+            got: 0,
+        },
+        Error::InvalidCharacter {
+            char: 'ü',
+            index: 3,
+            decoded: 1,
+        },
+        Error::MissingPrefix {
+            char: 'S',
+            got: Some('T'),
+        },
+        Error::MissingPrefix {
+            char: 'S',
+            got: None,
+        },
+        Error::InvalidFrame {
+            field: 2,
+            reason: "length exceeds the input",
+        },
+        Error::OutputTooLarge { max: 1, got: 2 },
+        Error::InvalidPadding { index: 7 },
+        Error::AllocationFailed { requested: 64 },
+        Error::InvalidVersion {
+            expected: "must be < 32",
+            version: 255,
+        },
+        Error::InsufficientData { min: 4, len: 3 },
+        Error::ChecksumMismatch {
+            expected: [0, 1, 2, 255],
+            got: [10, 20, 30, 40],
+            version: 22,
+            len: 20,
+        },
+    ];
+
+    // Assert that the output matches the `Display` implementation.
+    for error in errors {
+        let mut sink = Sink::default();
+        error.uwrite_to(&mut sink).unwrap();
+        assert_eq!(sink.output, error.to_string());
+    }
+}
@@ -11,8 +11,11 @@ use core::str;
 use c32::decode;
 use c32::decode_check;
 use c32::decode_check_into;
+use c32::decode_check_into_slice;
 use c32::decode_check_prefixed;
+use c32::decode_check_prefixed_into;
 use c32::decode_into;
+use c32::decode_into_slice;
 use c32::decode_prefixed;
 use c32::decoded_check_len;
 use c32::decoded_len;
@@ -20,10 +23,14 @@ use c32::encode;
 use c32::encode_check;
 use c32::encode_check_into;
 use c32::encode_check_prefixed;
+use c32::encode_check_prefixed_into;
 use c32::encode_into;
 use c32::encode_prefixed;
 use c32::encoded_check_len;
+use c32::encoded_check_len_exact;
 use c32::encoded_len;
+use c32::encoded_len_exact;
+use c32::C32CollectExt;
 use c32::Result;
 
 mod __internal {
@@ -32,12 +39,16 @@ mod __internal {
     /// A test helper for default features.
     pub fn test_default(bytes: &[u8], str: &str) -> Result<()> {
         let mut ebuf = vec![0u8; encoded_len(bytes.len())];
-        let epos = encode_into(&bytes, &mut ebuf)?;
+        let epos = encode_into(bytes, &mut ebuf)?;
 
         let mut dbuf = vec![0u8; decoded_len(epos)];
         let dpos = decode_into(&ebuf[..epos], &mut dbuf)?;
         assert_eq!(&dbuf[..dpos], bytes);
 
+        let mut sbuf = vec![0u8; decoded_len(epos)];
+        let de = decode_into_slice(&ebuf[..epos], &mut sbuf)?;
+        assert_eq!(de, bytes);
+
         let en = str::from_utf8(&ebuf[..epos]).unwrap();
         assert_eq!(en, str);
         Ok(())
@@ -45,23 +56,32 @@ mod __internal {
 
     /// A test helper for `[feature = "alloc"]`.
     pub fn test_alloc(bytes: &[u8], expected: &str) -> Result<()> {
-        let en = encode(&bytes);
+        let en = encode(bytes);
         assert_eq!(en, expected);
+        assert_eq!(en.capacity(), en.len());
+        assert_eq!(encoded_len_exact(bytes), en.len());
         let de = decode(&en)?;
         assert_eq!(de, bytes);
+        let en = bytes.iter().copied().collect_c32();
+        assert_eq!(en, expected);
         Ok(())
     }
 
     /// A test helper for `[feature = "check"]`.
     pub fn test_check(bytes: &[u8], expected: &str) -> Result<()> {
         let mut ebuf = vec![0u8; encoded_check_len(bytes.len())];
-        let epos = encode_check_into(&bytes, &mut ebuf, 0)?;
+        let epos = encode_check_into(bytes, &mut ebuf, 0)?;
 
         let mut dbuf = vec![0u8; decoded_check_len(epos)];
         let (dpos, dver) = decode_check_into(&ebuf[..epos], &mut dbuf)?;
         assert_eq!(&dbuf[..dpos], bytes);
         assert_eq!(dver, 0);
 
+        let mut sbuf = vec![0u8; decoded_check_len(epos)];
+        let (sver, de) = decode_check_into_slice(&ebuf[..epos], &mut sbuf)?;
+        assert_eq!(de, bytes);
+        assert_eq!(sver, 0);
+
         let en = str::from_utf8(&ebuf[..epos]).unwrap();
         assert_eq!(en, expected);
         Ok(())
@@ -69,8 +89,10 @@ mod __internal {
 
     /// A test helper for `[feature = "check"]` + `[feature = "alloc"]`.
     pub fn test_check_alloc(bytes: &[u8], expected: &str) -> Result<()> {
-        let en = encode_check(&bytes, 0)?;
+        let en = encode_check(bytes, 0)?;
         assert_eq!(en, expected);
+        assert_eq!(en.capacity(), en.len());
+        assert_eq!(encoded_check_len_exact(bytes, 0), en.len());
         println!("{en}");
         let (de, de_version) = decode_check(&en)?;
         assert_eq!(de_version, 0);
@@ -80,7 +102,7 @@ mod __internal {
 
     /// A test helper for prefixed encoding/decoding.
     pub fn test_prefixed(input: &[u8], expected: &str) -> Result<()> {
-        let en = encode_prefixed(&input, 'S');
+        let en = encode_prefixed(input, 'S');
         assert!(en.starts_with('S'));
         assert!(en.ends_with(expected));
         let de = decode_prefixed(&en, 'S')?;
@@ -90,12 +112,22 @@ mod __internal {
 
     /// A test helper for `[feature = "check"]` prefixed encoding/decoding.
     pub fn test_check_prefixed(input: &[u8], expected: &str) -> Result<()> {
-        let en = encode_check_prefixed(&input, 'S', 0)?;
+        let en = encode_check_prefixed(input, 'S', 0)?;
         assert!(en.starts_with('S'));
         assert!(en.ends_with(expected));
         let (de, de_version) = decode_check_prefixed(&en, 'S')?;
         assert_eq!(de_version, 0);
         assert_eq!(de, input);
+
+        let mut ebuf = vec![0u8; 1 + encoded_check_len(input.len())];
+        let epos = encode_check_prefixed_into(input, &mut ebuf, 'S', 0)?;
+        assert_eq!(&ebuf[..epos], en.as_bytes());
+
+        let mut dbuf = vec![0u8; decoded_check_len(epos)];
+        let (dpos, dver) =
+            decode_check_prefixed_into(&ebuf[..epos], &mut dbuf, 'S')?;
+        assert_eq!(&dbuf[..dpos], input);
+        assert_eq!(dver, 0);
         Ok(())
     }
 }
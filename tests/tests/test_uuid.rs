@@ -0,0 +1,95 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_uuid;
+use c32::encode_uuid;
+use c32::Error;
+use uuid::Uuid;
+
+#[test]
+fn test_uuid_roundtrip() {
+    let id = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+
+    let en = encode_uuid(&id);
+    assert_eq!(en, "37WN84845H89QS4HXVD075ZR68");
+    assert_eq!(en, c32::encode(id.as_bytes()));
+    assert_eq!(decode_uuid(&en), Ok(id));
+}
+
+#[test]
+fn test_uuid_leading_zeros() {
+    let id = Uuid::from_u128(0x0000_2a2a_2a2a_2a2a_2a2a_2a2a_2a2a_2a2a);
+
+    // Assert that the leading zero bytes survive the roundtrip.
+    let en = encode_uuid(&id);
+    assert_eq!(en, "00N2MAHA58N2MAHA58N2MAHA");
+    assert_eq!(decode_uuid(&en), Ok(id));
+    assert_eq!(decode_uuid(&en).unwrap().as_bytes()[..2], [0, 0]);
+}
+
+#[test]
+fn test_uuid_nil_and_max() {
+    assert_eq!(encode_uuid(&Uuid::nil()), "0000000000000000");
+    assert_eq!(decode_uuid("0000000000000000"), Ok(Uuid::nil()));
+
+    let en = encode_uuid(&Uuid::max());
+    assert_eq!(decode_uuid(&en), Ok(Uuid::max()));
+}
+
+#[test]
+fn test_uuid_lowercase() {
+    let id = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+    assert_eq!(decode_uuid("37wn84845h89qs4hxvd075zr68"), Ok(id));
+}
+
+#[test]
+fn test_uuid_invalid_size() {
+    // Assert that short inputs are rejected.
+    assert_eq!(
+        decode_uuid("2MAHA"),
+        Err(Error::InvalidDataSize {
+            expected: 16,
+            got: 3
+        })
+    );
+    assert_eq!(
+        decode_uuid(""),
+        Err(Error::InvalidDataSize {
+            expected: 16,
+            got: 0
+        })
+    );
+
+    // Assert that long inputs are rejected, including extra leading zeros.
+    assert_eq!(
+        decode_uuid("00000000000000000"),
+        Err(Error::InvalidDataSize {
+            expected: 16,
+            got: 17
+        })
+    );
+    assert_eq!(
+        decode_uuid("037WN84845H89QS4HXVD075ZR68"),
+        Err(Error::InvalidDataSize {
+            expected: 16,
+            got: 17
+        })
+    );
+}
+
+#[test]
+fn test_uuid_invalid_character() {
+    assert!(matches!(
+        decode_uuid("37WN84845H89QS4HXVD075ZR6!"),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 25,
+            ..
+        })
+    ));
+}
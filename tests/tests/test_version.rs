@@ -0,0 +1,294 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_check;
+use c32::decode_check_full;
+use c32::decode_check_with_version;
+use c32::en::Check;
+use c32::encode_check;
+use c32::encode_check_into;
+use c32::encode_check_into_versioned;
+use c32::encode_check_prefixed;
+use c32::encode_check_prefixed_versioned;
+use c32::encode_check_versioned;
+use c32::peek_version;
+use c32::stacks;
+use c32::version_char;
+use c32::version_from_char;
+use c32::Buffer;
+use c32::Error;
+use c32::Version;
+
+#[test]
+fn test_version_char_roundtrip() {
+    for version in 0..32 {
+        let char = version_char(version).unwrap();
+        assert_eq!(version_from_char(char), Some(version));
+        assert_eq!(version_from_char(char.to_ascii_lowercase()), Some(version));
+
+        // Assert that the character matches the encoded output.
+        let en = encode_check([42, 42, 42], version).unwrap();
+        assert!(en.starts_with(char));
+    }
+}
+
+#[test]
+fn test_version_char_known() {
+    assert_eq!(version_char(0), Some('0'));
+    assert_eq!(version_char(22), Some('P'));
+    assert_eq!(version_char(26), Some('T'));
+    assert_eq!(version_char(31), Some('Z'));
+}
+
+#[test]
+fn test_version_char_invalid() {
+    for version in 32..=u8::MAX {
+        assert_eq!(version_char(version), None);
+    }
+}
+
+#[test]
+fn test_version_from_char_invalid() {
+    for char in ['U', 'u', '!', ' ', '\0', '\u{7F}', 'é', '€', '😀'] {
+        assert_eq!(version_from_char(char), None);
+    }
+
+    // Assert that every character maps to a valid version or `None`.
+    for code in 0..=0x10FFFF {
+        if let Some(char) = char::from_u32(code) {
+            if let Some(version) = version_from_char(char) {
+                assert!(version < 32);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_version_from_char_aliases() {
+    assert_eq!(version_from_char('O'), Some(0));
+    assert_eq!(version_from_char('o'), Some(0));
+    assert_eq!(version_from_char('I'), Some(1));
+    assert_eq!(version_from_char('L'), Some(1));
+    assert_eq!(version_from_char('l'), Some(1));
+}
+
+#[test]
+fn test_decode_check_full() {
+    let en = encode_check(b"usque ad finem", 22).unwrap();
+    let (bytes, version, char) = decode_check_full(&en).unwrap();
+    assert_eq!(bytes, b"usque ad finem");
+    assert_eq!(version, 22);
+    assert_eq!(char, 'P');
+
+    // Assert that an alias is returned as it appears in the input.
+    let en = encode_check([42, 42, 42], 0).unwrap();
+    let aliased = en.replacen('0', "O", 1);
+    let (bytes, version, char) = decode_check_full(&aliased).unwrap();
+    assert_eq!(bytes, [42, 42, 42]);
+    assert_eq!(version, 0);
+    assert_eq!(char, 'O');
+}
+
+#[test]
+fn test_decode_check_full_error() {
+    let result = decode_check_full("");
+    assert_eq!(result, Err(Error::InsufficientData { min: 2, len: 0 }));
+}
+
+#[test]
+fn test_decode_check_with_version() {
+    for version in [0, 22, 31] {
+        let bytes = [0u8, 42, 42, 42];
+        let en = encode_check(bytes, version).unwrap();
+
+        // Assert that the input without the version character decodes.
+        let de = decode_check_with_version(&en[1..], version).unwrap();
+        assert_eq!(de, bytes);
+    }
+}
+
+#[test]
+fn test_decode_check_with_version_mismatch() {
+    let en = encode_check([42, 42, 42], 22).unwrap();
+    let result = decode_check_with_version(&en[1..], 21);
+    assert!(matches!(
+        result,
+        Err(Error::ChecksumMismatch {
+            version: 21,
+            len: 3,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_decode_check_with_version_error() {
+    let result = decode_check_with_version("AHA59B9201Z", 32);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidVersion { version: 32, .. })
+    ));
+
+    let result = decode_check_with_version("AHA", 0);
+    assert!(matches!(
+        result,
+        Err(Error::InsufficientData { min: 4, len: 2 })
+    ));
+
+    let result = decode_check_with_version("AHA!", 0);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidCharacter { index: 3, .. })
+    ));
+}
+
+#[test]
+fn test_peek_version() {
+    for version in 0..32 {
+        let en = encode_check([42, 42, 42], version).unwrap();
+        assert_eq!(peek_version(&en), Ok(version));
+        assert_eq!(peek_version(&en.to_ascii_lowercase()), Ok(version));
+        assert_eq!(decode_check(&en).unwrap().1, version);
+    }
+}
+
+#[test]
+fn test_peek_version_ignores_payload() {
+    // Assert that neither the payload nor the checksum are inspected.
+    assert_eq!(peek_version("P"), Ok(22));
+    assert_eq!(peek_version("P!!!"), Ok(22));
+    assert_eq!(peek_version("0AHA59B9201Y"), Ok(0));
+    assert!(decode_check("0AHA59B9201Y").is_err());
+}
+
+#[test]
+fn test_peek_version_error() {
+    assert_eq!(
+        peek_version(""),
+        Err(Error::InsufficientData { min: 1, len: 0 })
+    );
+    assert_eq!(
+        peek_version("U0AHA"),
+        Err(Error::InvalidCharacter {
+            char: 'U',
+            index: 0,
+            decoded: 0
+        })
+    );
+    assert_eq!(
+        peek_version("ü0AHA"),
+        Err(Error::InvalidCharacter {
+            char: 'ü',
+            index: 0,
+            decoded: 0
+        })
+    );
+}
+
+#[test]
+fn test_version_boundaries() {
+    for version in [0, 31] {
+        let typed = Version::new(version).unwrap();
+        assert_eq!(Version::try_from(version), Ok(typed));
+        assert_eq!(u8::from(typed), version);
+
+        // Assert that the typed and legacy paths agree.
+        let en = encode_check([42, 42, 42], version).unwrap();
+        assert_eq!(encode_check_versioned([42, 42, 42], typed), en);
+        let en = encode_check_prefixed([42, 42, 42], 'S', version).unwrap();
+        assert_eq!(
+            encode_check_prefixed_versioned([42, 42, 42], 'S', typed),
+            en
+        );
+
+        let (mut a, mut b) = ([0u8; 13], [0u8; 13]);
+        let len = encode_check_into(&[42, 42, 42], &mut a, version).unwrap();
+        let typed_len =
+            encode_check_into_versioned(&[42, 42, 42], &mut b, typed).unwrap();
+        assert_eq!(a[..len], b[..typed_len]);
+
+        let buffer = Buffer::<13, false, Check>::encode(&[42, 42, 42], version);
+        let typed_buffer =
+            Buffer::<13, false, Check>::encode_versioned(&[42, 42, 42], typed);
+        assert_eq!(buffer.as_str(), typed_buffer.as_str());
+    }
+
+    for version in [32, 255] {
+        assert_eq!(Version::new(version), None);
+        assert!(matches!(
+            Version::try_from(version),
+            Err(Error::InvalidVersion { version: v, .. }) if v == version
+        ));
+        assert!(matches!(
+            encode_check([42, 42, 42], version),
+            Err(Error::InvalidVersion { version: v, .. }) if v == version
+        ));
+        assert!(matches!(
+            encode_check_into(&[42, 42, 42], &mut [0u8; 13], version),
+            Err(Error::InvalidVersion { version: v, .. }) if v == version
+        ));
+    }
+}
+
+#[test]
+fn test_version_from_str() {
+    assert_eq!("0".parse::<Version>().map(u8::from), Ok(0));
+    assert_eq!("31".parse::<Version>().map(u8::from), Ok(31));
+    assert_eq!(Version::try_from("022").map(u8::from), Ok(22));
+
+    assert!(matches!(
+        "32".parse::<Version>(),
+        Err(Error::InvalidVersion { version: 32, .. })
+    ));
+    assert!(matches!(
+        "255".parse::<Version>(),
+        Err(Error::InvalidVersion { version: 255, .. })
+    ));
+    assert!(matches!(
+        "1000".parse::<Version>(),
+        Err(Error::InvalidVersion { version: 255, .. })
+    ));
+    assert_eq!(
+        "".parse::<Version>(),
+        Err(Error::InsufficientData { min: 1, len: 0 })
+    );
+    assert_eq!(
+        "-1".parse::<Version>(),
+        Err(Error::InvalidCharacter {
+            char: '-',
+            index: 0,
+            decoded: 0
+        })
+    );
+}
+
+#[test]
+fn test_version_const() {
+    const VERSION: Version = match Version::new(31) {
+        Some(version) => version,
+        None => panic!("invalid version"),
+    };
+    const INVALID: Option<Version> = Version::new(32);
+    const MAINNET: u8 = stacks::MAINNET_SINGLESIG.get();
+    const EN: Buffer<14, true, Check> =
+        Buffer::<14, true, Check>::encode_versioned(
+            &[42, 42, 42],
+            'S',
+            stacks::TESTNET_SINGLESIG,
+        );
+    const _: () = assert!(INVALID.is_none());
+    const _: () = assert!(MAINNET == 22);
+
+    assert_eq!(VERSION.get(), 31);
+    assert_eq!(stacks::MAINNET_MULTISIG.get(), 20);
+    assert_eq!(stacks::TESTNET_MULTISIG.get(), 21);
+    assert_eq!(
+        EN.as_str(),
+        encode_check_prefixed([42, 42, 42], 'S', 26).unwrap()
+    );
+}
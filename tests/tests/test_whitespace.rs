@@ -0,0 +1,219 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use std::fs;
+
+use c32::decode;
+use c32::decode_check;
+use c32::decode_check_ignoring_whitespace;
+use c32::decode_check_into_with_whitespace;
+use c32::decode_ignoring_whitespace;
+use c32::decode_into_with_whitespace;
+use c32::encode;
+use c32::encode_check;
+use c32::Error;
+use c32::Whitespace;
+
+mod __internal {
+    /// The sample files of the corpus equivalence test.
+    pub const SAMPLES: &[&str] = &[
+        "../samples/c32_m_100x32b.in",
+        "../samples/c32_m_100x64b.in",
+        "../samples/c32_m_100x128b.in",
+        "../samples/c32_s_32k.in",
+    ];
+
+    /// Wraps a string into lines of 76 characters, as in an email, and adds
+    /// stray spaces and tabs.
+    pub fn mangle(str: &str) -> String {
+        let mut out = String::from(" \t");
+        for (i, char) in str.chars().enumerate() {
+            if i > 0 && i % 76 == 0 {
+                out.push_str("\r\n");
+            } else if i % 29 == 7 {
+                out.push(' ');
+            } else if i % 41 == 3 {
+                out.push('\t');
+            }
+            out.push(char);
+        }
+        out.push_str("\r\n\n");
+        out
+    }
+
+    /// Strips the whitespace skipped by the decoder.
+    pub fn strip(str: &str) -> String {
+        str.chars()
+            .filter(|c| !matches!(c, ' ' | '\t' | '\r' | '\n'))
+            .collect()
+    }
+}
+
+use __internal::*;
+
+#[test]
+fn test_whitespace_positions() {
+    for input in [
+        "2MAHA",
+        "  2MAHA",
+        "2MAHA\r\n",
+        "\t2MAHA \n",
+        "2M AH\tA",
+        "2\r\nM\r\nA\r\nH\r\nA",
+    ] {
+        assert_eq!(decode_ignoring_whitespace(input).unwrap(), [42, 42, 42]);
+    }
+
+    // Assert that leading zeros are preserved across whitespace.
+    assert_eq!(decode_ignoring_whitespace("0 0\n1").unwrap(), [0, 0, 1]);
+    assert_eq!(decode_ignoring_whitespace(" \r\n\t").unwrap(), [0u8; 0]);
+    assert_eq!(decode_ignoring_whitespace("").unwrap(), [0u8; 0]);
+}
+
+#[test]
+fn test_whitespace_invalid_character_index() {
+    // Assert that the index refers to the original input.
+    assert!(matches!(
+        decode_ignoring_whitespace("  2M!HA"),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 4,
+            ..
+        })
+    ));
+    assert!(matches!(
+        decode_ignoring_whitespace("2MAHA\r\n2MAHA\r\n2M!HA"),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 16,
+            ..
+        })
+    ));
+
+    // Assert that other whitespace is still invalid.
+    assert!(matches!(
+        decode_ignoring_whitespace("2MA\x0CHA"),
+        Err(Error::InvalidCharacter { index: 3, .. })
+    ));
+}
+
+#[test]
+fn test_whitespace_reject() {
+    let mut dst = [0u8; 8];
+    assert!(matches!(
+        decode_into_with_whitespace(b"2M AHA", &mut dst, Whitespace::Reject),
+        Err(Error::InvalidCharacter { index: 2, .. })
+    ));
+    assert_eq!(Whitespace::default(), Whitespace::Reject);
+
+    let offset =
+        decode_into_with_whitespace(b"2MAHA", &mut dst, Whitespace::Reject)
+            .unwrap();
+    assert_eq!(&dst[..offset], [42, 42, 42]);
+}
+
+#[test]
+fn test_whitespace_buffer_capacity() {
+    // Assert that the capacity only counts the significant characters.
+    let src = b"2M   AH    A";
+    let mut dst = [0u8; 5];
+    let offset =
+        decode_into_with_whitespace(src, &mut dst, Whitespace::Ignore).unwrap();
+    assert_eq!(&dst[..offset], [42, 42, 42]);
+
+    let mut dst = [0u8; 4];
+    assert_eq!(
+        decode_into_with_whitespace(src, &mut dst, Whitespace::Ignore),
+        Err(Error::BufferTooSmall { min: 5, len: 4 })
+    );
+}
+
+#[test]
+fn test_whitespace_check_version_position() {
+    let en = encode_check([42, 42, 42], 22).unwrap();
+    assert_eq!(en, "PAHA58QT2DJ9");
+
+    for input in [
+        " PAHA58QT2DJ9",
+        "\r\nPAHA58QT2DJ9",
+        "P AHA58QT2DJ9",
+        "\tP\nAHA58QT2DJ9\n",
+        "PAHA58 QT2D J9",
+    ] {
+        assert_eq!(
+            decode_check_ignoring_whitespace(input).unwrap(),
+            (vec![42, 42, 42], 22)
+        );
+    }
+
+    // Assert that an invalid version character is reported in place.
+    assert!(matches!(
+        decode_check_ignoring_whitespace("  !AHA58QT2DJ9"),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 2,
+            ..
+        })
+    ));
+    assert!(matches!(
+        decode_check_ignoring_whitespace(" P AHA5!QT2DJ9"),
+        Err(Error::InvalidCharacter {
+            char: '!',
+            index: 7,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_whitespace_check_errors() {
+    assert_eq!(
+        decode_check_ignoring_whitespace(" 0 \n"),
+        Err(Error::InsufficientData { min: 2, len: 1 })
+    );
+    assert!(matches!(
+        decode_check_ignoring_whitespace("P AHA58QT2DJ8"),
+        Err(Error::ChecksumMismatch { .. })
+    ));
+
+    let mut dst = [0u8; 16];
+    assert!(matches!(
+        decode_check_into_with_whitespace(
+            b"P AHA58QT2DJ9",
+            &mut dst,
+            Whitespace::Reject
+        ),
+        Err(Error::InvalidCharacter { index: 1, .. })
+    ));
+    assert_eq!(
+        decode_check_into_with_whitespace(
+            b"P AHA58QT2DJ9",
+            &mut dst,
+            Whitespace::Ignore
+        ),
+        Ok((3, 22))
+    );
+}
+
+#[test]
+fn test_whitespace_sample_corpus() {
+    for path in SAMPLES {
+        let input = fs::read(path).unwrap();
+
+        // Assert that decoding is equivalent to decoding after stripping.
+        let en = mangle(&encode(&input));
+        let de = decode_ignoring_whitespace(&en).unwrap();
+        assert_eq!(de, decode(&strip(&en)).unwrap(), "{path}");
+        assert_eq!(de, input, "{path}");
+
+        let en = mangle(&encode_check(&input, 22).unwrap());
+        let de = decode_check_ignoring_whitespace(&en).unwrap();
+        assert_eq!(de, decode_check(&strip(&en)).unwrap(), "{path}");
+        assert_eq!(de, (input, 22), "{path}");
+    }
+}
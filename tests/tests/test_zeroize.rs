@@ -0,0 +1,149 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode_check_into;
+use c32::decode_in_place;
+use c32::decode_into;
+use c32::encode;
+use c32::encode_check;
+use c32::Buffer;
+use c32::C32Builder;
+use c32::C32Text;
+use c32::Error;
+use zeroize::Zeroize;
+
+mod __internal {
+    /// A private key sized payload, without any zero bytes.
+    pub const SECRET: [u8; 32] = [0xA5; 32];
+
+    /// Asserts that a type implements [`zeroize::ZeroizeOnDrop`].
+    pub fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+}
+
+use __internal::*;
+
+#[test]
+fn test_zeroize_decode_into_invalid_character() {
+    // The first character is invalid, so the whole payload is decoded into
+    // the buffer before the error is returned.
+    let mut encoded = encode(SECRET).into_bytes();
+    encoded[0] = b'!';
+
+    let mut dst = [0xFFu8; 64];
+    let result = decode_into(&encoded, &mut dst);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidCharacter { index: 0, .. })
+    ));
+
+    // Assert that the partially decoded secret has been wiped, and that the
+    // bytes the call did not write are untouched.
+    let written = dst.iter().position(|&b| b == 0xFF).unwrap();
+    assert!(written >= 31);
+    assert!(dst[..written].iter().all(|&b| b == 0));
+    assert!(dst[written..].iter().all(|&b| b == 0xFF));
+}
+
+#[test]
+fn test_zeroize_decode_into_buffer_too_small() {
+    let encoded = encode(SECRET);
+
+    // Assert that the buffer is untouched if nothing was decoded.
+    let mut dst = [0xFFu8; 8];
+    let result = decode_into(encoded.as_bytes(), &mut dst);
+    assert!(matches!(result, Err(Error::BufferTooSmall { .. })));
+    assert_eq!(dst, [0xFFu8; 8]);
+}
+
+#[test]
+fn test_zeroize_decode_check_into_checksum_mismatch() {
+    let mut encoded = encode_check(SECRET, 22).unwrap().into_bytes();
+    let last = encoded.len() - 1;
+    encoded[last] = if encoded[last] == b'0' { b'1' } else { b'0' };
+
+    let mut dst = [0xFFu8; 64];
+    let result = decode_check_into(&encoded, &mut dst);
+    assert!(matches!(result, Err(Error::ChecksumMismatch { .. })));
+
+    // Assert that the decoded secret has been wiped, and nothing else.
+    assert_eq!(dst[..32], [0u8; 32]);
+    assert_eq!(dst[32..], [0xFFu8; 32]);
+}
+
+#[test]
+fn test_zeroize_decode_check_into_checksum_tail() {
+    let encoded = encode_check(SECRET, 22).unwrap();
+
+    let mut dst = [0xFFu8; 64];
+    let (offset, version) =
+        decode_check_into(encoded.as_bytes(), &mut dst).unwrap();
+    assert_eq!(version, 22);
+    assert_eq!(dst[..offset], SECRET);
+
+    // Assert that the checksum never reached the buffer.
+    assert_eq!(dst[offset..], [0xFFu8; 64][offset..]);
+}
+
+#[test]
+fn test_zeroize_decode_in_place_invalid_character() {
+    let mut buf = encode(SECRET).into_bytes();
+    buf[0] = b'!';
+
+    let result = decode_in_place(&mut buf);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidCharacter { index: 0, .. })
+    ));
+
+    // Assert that the decoded bytes at the end have been wiped, and that the
+    // characters which were not overwritten are untouched.
+    assert!(!buf.contains(&0xA5));
+    assert_eq!(buf[0], b'!');
+    assert!(buf[buf.len() - 31..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_zeroize_decode_check_into_invalid_character() {
+    let mut encoded = encode_check(SECRET, 22).unwrap().into_bytes();
+    let index = encoded.len() - 2;
+    encoded[index] = b'!';
+
+    // Assert that the streamed payload has been wiped, and nothing else.
+    let mut dst = [0xFFu8; 64];
+    let result = decode_check_into(&encoded, &mut dst);
+    assert!(matches!(result, Err(Error::InvalidCharacter { .. })));
+    assert!(!dst.contains(&0xA5));
+    let written = dst.iter().position(|&b| b == 0xFF).unwrap();
+    assert!(dst[..written].iter().all(|&b| b == 0));
+    assert!(dst[written..].iter().all(|&b| b == 0xFF));
+}
+
+#[test]
+fn test_zeroize_owned_types() {
+    let mut buffer = Buffer::<64>::encode(&SECRET);
+    buffer.zeroize();
+    assert_eq!(buffer.pos(), 0);
+    assert!(buffer.as_bytes().is_empty());
+
+    let mut text = C32Text(SECRET);
+    text.zeroize();
+    assert_eq!(text.0, [0u8; 32]);
+
+    let mut builder = C32Builder::<64>::new();
+    builder.push_bytes(&SECRET).unwrap();
+    builder.zeroize();
+    assert_eq!(builder.remaining_capacity(), 40);
+    assert_eq!(builder.finish().as_str(), "");
+}
+
+#[test]
+fn test_zeroize_on_drop() {
+    assert_zeroize_on_drop::<C32Builder<64>>();
+    assert_zeroize_on_drop::<c32::io::DecodeReader<&[u8]>>();
+    assert_zeroize_on_drop::<c32::io::AsyncDecodeReader<&[u8]>>();
+}
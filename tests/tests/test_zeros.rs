@@ -0,0 +1,84 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::decode;
+use c32::decode_check;
+use c32::encode;
+use c32::encode_check;
+use c32::encoded_check_len_exact;
+use c32::leading_zero_bytes;
+use c32::leading_zero_chars;
+use rand::Rng;
+
+#[test]
+fn test_leading_zero_bytes() {
+    assert_eq!(leading_zero_bytes(&[]), 0);
+    assert_eq!(leading_zero_bytes(&[0]), 1);
+    assert_eq!(leading_zero_bytes(&[0, 0, 0]), 3);
+    assert_eq!(leading_zero_bytes(&[1, 0, 0]), 0);
+    assert_eq!(leading_zero_bytes(&[0, 1, 0]), 1);
+}
+
+#[test]
+fn test_leading_zero_chars() {
+    assert_eq!(leading_zero_chars(b""), 0);
+    assert_eq!(leading_zero_chars(b"0"), 1);
+    assert_eq!(leading_zero_chars(b"000"), 3);
+    assert_eq!(leading_zero_chars(b"100"), 0);
+    assert_eq!(leading_zero_chars(b"010"), 1);
+
+    // Assert that aliases are counted like `0`.
+    assert_eq!(leading_zero_chars(b"O0"), 2);
+    assert_eq!(leading_zero_chars(b"o0"), 2);
+    assert_eq!(leading_zero_chars(b"0o1O"), 2);
+}
+
+#[test]
+fn test_leading_zeros_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..1_000 {
+        let len = rng.random_range(0..=16);
+        let mut bytes = vec![0u8; len];
+        rng.fill(&mut bytes[..]);
+        let zeros = rng.random_range(0..=len);
+        bytes[..zeros].fill(0);
+
+        // Assert that each leading zero byte encodes as one `0` character.
+        let en = encode(&bytes);
+        assert_eq!(
+            leading_zero_chars(en.as_bytes()),
+            leading_zero_bytes(&bytes)
+        );
+
+        // Assert that each leading `0` character decodes as one zero byte.
+        let de = decode(&en).unwrap();
+        assert_eq!(leading_zero_bytes(&de), leading_zero_chars(en.as_bytes()));
+    }
+}
+
+#[test]
+fn test_leading_zeros_checksum() {
+    // The checksum of this payload starts with a zero byte, which extends
+    // the leading zeros of the all-zero payload.
+    let en = encode_check([0; 12], 12).unwrap();
+    assert_eq!(en, "C00000000000006Q077");
+    assert_eq!(encoded_check_len_exact(&[0; 12], 12), en.len());
+    assert_eq!(decode_check(&en), Ok((vec![0; 12], 12)));
+
+    // Assert that all-zero payloads roundtrip for every version.
+    for len in 0..=32 {
+        for version in 0..32 {
+            let en = encode_check(vec![0; len], version).unwrap();
+            assert_eq!(
+                encoded_check_len_exact(&vec![0; len], version),
+                en.len()
+            );
+            assert_eq!(decode_check(&en), Ok((vec![0; len], version)));
+        }
+    }
+}
@@ -0,0 +1,18 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::en::Check;
+use c32::Buffer;
+
+// `Buffer::<0, false, Check>::required_for(3)` is 13.
+const EN: Buffer<12, false, Check> =
+    Buffer::<12, false, Check>::encode(&[42, 42, 42], 0);
+
+fn main() {
+    assert_eq!(EN.as_str(), "0AHA59B9201Z");
+}
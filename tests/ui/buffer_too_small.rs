@@ -0,0 +1,16 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::Buffer;
+
+// `Buffer::<0, true>::required_for(3)` is 6.
+const EN: Buffer<5, true> = Buffer::<5, true>::encode(&[42, 42, 42], 'S');
+
+fn main() {
+    assert_eq!(EN.as_str(), "S2MAHA");
+}
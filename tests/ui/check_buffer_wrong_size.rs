@@ -0,0 +1,16 @@
+// © 2025 Max Karou. All Rights Reserved.
+// Licensed under Apache Version 2.0, or MIT License, at your discretion.
+//
+// Apache License: http://www.apache.org/licenses/LICENSE-2.0
+// MIT License: http://opensource.org/licenses/MIT
+//
+// Usage of this file is permitted solely under a sanctioned license.
+
+use c32::CheckBuffer20;
+
+// `CheckBuffer20` only accepts a 20-byte payload.
+const EN: CheckBuffer20 = CheckBuffer20::encode_payload(&[42; 19], 22);
+
+fn main() {
+    assert_eq!(EN.as_str().len(), 40);
+}